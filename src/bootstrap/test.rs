@@ -1022,11 +1022,13 @@ impl Step for Compiletest {
             if !builder.config.dry_run && suite == "run-make-fulldeps" {
                 let llvm_components = output(Command::new(&llvm_config).arg("--components"));
                 let llvm_cxxflags = output(Command::new(&llvm_config).arg("--cxxflags"));
+                let llvm_bin_dir = output(Command::new(&llvm_config).arg("--bindir"));
                 cmd.arg("--cc").arg(builder.cc(target))
                 .arg("--cxx").arg(builder.cxx(target).unwrap())
                 .arg("--cflags").arg(builder.cflags(target).join(" "))
                 .arg("--llvm-components").arg(llvm_components.trim())
-                .arg("--llvm-cxxflags").arg(llvm_cxxflags.trim());
+                .arg("--llvm-cxxflags").arg(llvm_cxxflags.trim())
+                .arg("--llvm-bin-dir").arg(llvm_bin_dir.trim());
                 if let Some(ar) = builder.ar(target) {
                     cmd.arg("--ar").arg(ar);
                 }
@@ -87,6 +87,17 @@
 //! arguments which have names. Like with positional parameters, it is not
 //! valid to provide named parameters that are unused by the format string.
 //!
+//! With the unstable `format_args_capture` feature, a name that is not given
+//! as an argument is captured from the surrounding scope instead, as long as
+//! the format string is written as a literal:
+//!
+//! ```
+//! #![feature(format_args_capture)]
+//!
+//! let name = "world";
+//! assert_eq!(format!("hello {name}"), "hello world");
+//! ```
+//!
 //! ## Argument types
 //!
 //! Each argument's type is dictated by the format string.
@@ -1703,6 +1703,7 @@ where
     type Output = I::Output;
 
     #[inline]
+    #[cfg_attr(not(stage0), track_caller)]
     fn index(&self, index: I) -> &Self::Output {
         Index::index(&**self, index)
     }
@@ -1715,6 +1716,7 @@ where
     I: ::core::slice::SliceIndex<[T]>,
 {
     #[inline]
+    #[cfg_attr(not(stage0), track_caller)]
     fn index_mut(&mut self, index: I) -> &mut Self::Output {
         IndexMut::index_mut(&mut **self, index)
     }
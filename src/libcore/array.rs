@@ -8,9 +8,9 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-//! Implementations of things like `Eq` for fixed-length arrays
-//! up to a certain length. Eventually we should able to generalize
-//! to all lengths.
+//! Implementations of things like `Eq` for fixed-length arrays. These are
+//! generic over the length, except for `Default`, which is still only
+//! implemented up to a certain length.
 //!
 //! *[See also the array primitive type](../../std/primitive.array.html).*
 
@@ -82,6 +82,7 @@ impl TryFromSliceError {
     }
 }
 
+#[cfg(stage0)]
 macro_rules! __impl_slice_eq1 {
     ($Lhs: ty, $Rhs: ty) => {
         __impl_slice_eq1! { $Lhs, $Rhs, Sized }
@@ -97,6 +98,7 @@ macro_rules! __impl_slice_eq1 {
     }
 }
 
+#[cfg(stage0)]
 macro_rules! __impl_slice_eq2 {
     ($Lhs: ty, $Rhs: ty) => {
         __impl_slice_eq2! { $Lhs, $Rhs, Sized }
@@ -115,6 +117,7 @@ macro_rules! __impl_slice_eq2 {
 }
 
 // macro for implementing n-element array functions and operations
+#[cfg(stage0)]
 macro_rules! array_impls {
     ($($N:expr)+) => {
         $(
@@ -256,6 +259,7 @@ macro_rules! array_impls {
     }
 }
 
+#[cfg(stage0)]
 array_impls! {
      0  1  2  3  4  5  6  7  8  9
     10 11 12 13 14 15 16 17 18 19
@@ -263,8 +267,190 @@ array_impls! {
     30 31 32
 }
 
+#[cfg(not(stage0))]
+#[stable(feature = "rust1", since = "1.0.0")]
+impl<T, const N: usize> AsRef<[T]> for [T; N] {
+    #[inline]
+    fn as_ref(&self) -> &[T] {
+        &self[..]
+    }
+}
+
+#[cfg(not(stage0))]
+#[stable(feature = "rust1", since = "1.0.0")]
+impl<T, const N: usize> AsMut<[T]> for [T; N] {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [T] {
+        &mut self[..]
+    }
+}
+
+#[cfg(not(stage0))]
+#[stable(feature = "array_borrow", since = "1.4.0")]
+impl<T, const N: usize> Borrow<[T]> for [T; N] {
+    fn borrow(&self) -> &[T] {
+        self
+    }
+}
+
+#[cfg(not(stage0))]
+#[stable(feature = "array_borrow", since = "1.4.0")]
+impl<T, const N: usize> BorrowMut<[T]> for [T; N] {
+    fn borrow_mut(&mut self) -> &mut [T] {
+        self
+    }
+}
+
+#[cfg(not(stage0))]
+#[unstable(feature = "try_from", issue = "33417")]
+impl<'a, T, const N: usize> TryFrom<&'a [T]> for &'a [T; N] {
+    type Error = TryFromSliceError;
+
+    fn try_from(slice: &[T]) -> Result<&[T; N], TryFromSliceError> {
+        if slice.len() == N {
+            let ptr = slice.as_ptr() as *const [T; N];
+            unsafe { Ok(&*ptr) }
+        } else {
+            Err(TryFromSliceError(()))
+        }
+    }
+}
+
+#[cfg(not(stage0))]
+#[unstable(feature = "try_from", issue = "33417")]
+impl<'a, T, const N: usize> TryFrom<&'a mut [T]> for &'a mut [T; N] {
+    type Error = TryFromSliceError;
+
+    fn try_from(slice: &mut [T]) -> Result<&mut [T; N], TryFromSliceError> {
+        if slice.len() == N {
+            let ptr = slice.as_mut_ptr() as *mut [T; N];
+            unsafe { Ok(&mut *ptr) }
+        } else {
+            Err(TryFromSliceError(()))
+        }
+    }
+}
+
+#[cfg(not(stage0))]
+#[stable(feature = "rust1", since = "1.0.0")]
+impl<T: Hash, const N: usize> Hash for [T; N] {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        Hash::hash(&self[..], state)
+    }
+}
+
+#[cfg(not(stage0))]
+#[stable(feature = "rust1", since = "1.0.0")]
+impl<T: fmt::Debug, const N: usize> fmt::Debug for [T; N] {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&&self[..], f)
+    }
+}
+
+#[cfg(not(stage0))]
+#[stable(feature = "rust1", since = "1.0.0")]
+impl<'a, T, const N: usize> IntoIterator for &'a [T; N] {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+#[cfg(not(stage0))]
+#[stable(feature = "rust1", since = "1.0.0")]
+impl<'a, T, const N: usize> IntoIterator for &'a mut [T; N] {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+// macro for implementing `PartialEq` between arrays of any length and slices
+#[cfg(not(stage0))]
+macro_rules! __impl_array_eq1 {
+    ($Lhs: ty, $Rhs: ty) => {
+        #[stable(feature = "rust1", since = "1.0.0")]
+        impl<'a, 'b, A, B, const N: usize> PartialEq<$Rhs> for $Lhs where A: PartialEq<B> {
+            #[inline]
+            fn eq(&self, other: &$Rhs) -> bool { self[..] == other[..] }
+            #[inline]
+            fn ne(&self, other: &$Rhs) -> bool { self[..] != other[..] }
+        }
+    }
+}
+
+#[cfg(not(stage0))]
+macro_rules! __impl_array_eq2 {
+    ($Lhs: ty, $Rhs: ty) => {
+        __impl_array_eq1!($Lhs, $Rhs);
+
+        #[stable(feature = "rust1", since = "1.0.0")]
+        impl<'a, 'b, A, B, const N: usize> PartialEq<$Lhs> for $Rhs where B: PartialEq<A> {
+            #[inline]
+            fn eq(&self, other: &$Lhs) -> bool { self[..] == other[..] }
+            #[inline]
+            fn ne(&self, other: &$Lhs) -> bool { self[..] != other[..] }
+        }
+    }
+}
+
+// NOTE: some less important impls are omitted to reduce code bloat
+#[cfg(not(stage0))]
+__impl_array_eq1! { [A; N], [B; N] }
+#[cfg(not(stage0))]
+__impl_array_eq2! { [A; N], [B] }
+#[cfg(not(stage0))]
+__impl_array_eq2! { [A; N], &'b [B] }
+#[cfg(not(stage0))]
+__impl_array_eq2! { [A; N], &'b mut [B] }
+
+#[cfg(not(stage0))]
+#[stable(feature = "rust1", since = "1.0.0")]
+impl<T: Eq, const N: usize> Eq for [T; N] { }
+
+#[cfg(not(stage0))]
+#[stable(feature = "rust1", since = "1.0.0")]
+impl<T: PartialOrd, const N: usize> PartialOrd for [T; N] {
+    #[inline]
+    fn partial_cmp(&self, other: &[T; N]) -> Option<Ordering> {
+        PartialOrd::partial_cmp(&&self[..], &&other[..])
+    }
+    #[inline]
+    fn lt(&self, other: &[T; N]) -> bool {
+        PartialOrd::lt(&&self[..], &&other[..])
+    }
+    #[inline]
+    fn le(&self, other: &[T; N]) -> bool {
+        PartialOrd::le(&&self[..], &&other[..])
+    }
+    #[inline]
+    fn ge(&self, other: &[T; N]) -> bool {
+        PartialOrd::ge(&&self[..], &&other[..])
+    }
+    #[inline]
+    fn gt(&self, other: &[T; N]) -> bool {
+        PartialOrd::gt(&&self[..], &&other[..])
+    }
+}
+
+#[cfg(not(stage0))]
+#[stable(feature = "rust1", since = "1.0.0")]
+impl<T: Ord, const N: usize> Ord for [T; N] {
+    #[inline]
+    fn cmp(&self, other: &[T; N]) -> Ordering {
+        Ord::cmp(&&self[..], &&other[..])
+    }
+}
+
 // The Default impls cannot be generated using the array_impls! macro because
-// they require array literals.
+// they require array literals. They are not generic over the length either:
+// `[T; 0]` is `Default` for any `T`, which would overlap with an impl for
+// `[T; N]` where `T: Default`, and building `[T; N]` from `N` calls to
+// `T::default()` needs an array literal of that length.
 
 macro_rules! array_impl_default {
     {$n:expr, $t:ident $($ts:ident)*} => {
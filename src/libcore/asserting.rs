@@ -0,0 +1,88 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Support for capturing the operands of a failed `assert!`.
+//!
+//! With `#![feature(generic_assert)]`, every comparison operand of an
+//! `assert!` condition is recorded in a `Capture` as it is evaluated, and the
+//! captured values are printed if the assertion fails. Operands are recorded
+//! by reference, so any operand that implements `Debug` is printed without
+//! being moved or copied. Everything else is printed as `N/A`.
+//!
+//! Which of the two is done is decided by method resolution, without trait
+//! specialization: `(&Wrapper(&operand)).try_capture(&mut capture)` picks
+//! `TryCapturePrintable`, implemented for `Wrapper<&E>` itself, when `E`
+//! implements `Debug`, and otherwise falls back to `TryCaptureGeneric`, which
+//! is only reachable through one more auto-reference.
+
+#![allow(missing_docs, missing_debug_implementations)]
+#![unstable(feature = "generic_assert_internals",
+            reason = "internal details of the implementation of `assert!`",
+            issue = "44838")]
+
+use fmt;
+use marker::PhantomData;
+
+/// Marker for a `Capture` that records its value.
+pub struct TryCaptureWithDebug;
+
+/// Marker for a `Capture` that never records anything.
+pub struct TryCaptureWithoutDebug;
+
+/// Storage for a single operand of an assertion.
+pub struct Capture<E, M> {
+    elem: Option<E>,
+    phantom: PhantomData<M>,
+}
+
+impl<E, M> Capture<E, M> {
+    #[inline]
+    pub fn new() -> Self {
+        Capture { elem: None, phantom: PhantomData }
+    }
+}
+
+impl<E: fmt::Debug> fmt::Debug for Capture<E, TryCaptureWithDebug> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.elem {
+            Some(ref elem) => elem.fmt(f),
+            None => f.write_str("N/A"),
+        }
+    }
+}
+
+impl<E> fmt::Debug for Capture<E, TryCaptureWithoutDebug> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("N/A")
+    }
+}
+
+/// Receiver of `try_capture`, used to steer method resolution.
+pub struct Wrapper<T>(pub T);
+
+pub trait TryCaptureGeneric {
+    fn try_capture(&self, to: &mut Capture<(), TryCaptureWithoutDebug>);
+}
+
+impl<'a, E: ?Sized> TryCaptureGeneric for &'a Wrapper<&'a E> {
+    #[inline]
+    fn try_capture(&self, _: &mut Capture<(), TryCaptureWithoutDebug>) {}
+}
+
+pub trait TryCapturePrintable<E> {
+    fn try_capture(&self, to: &mut Capture<E, TryCaptureWithDebug>);
+}
+
+impl<'a, E: fmt::Debug + ?Sized> TryCapturePrintable<&'a E> for Wrapper<&'a E> {
+    #[inline]
+    fn try_capture(&self, to: &mut Capture<&'a E, TryCaptureWithDebug>) {
+        to.elem = Some(self.0);
+    }
+}
@@ -62,6 +62,21 @@
 /// This trait can be used with `#[derive]` if all of the type's fields implement
 /// `Default`. When `derive`d, it will use the default value for each field's type.
 ///
+/// With the unstable `derive_default_enum` feature, enums can derive `Default`
+/// too, by marking exactly one unit variant with `#[default]`:
+///
+/// ```
+/// #![feature(derive_default_enum)]
+///
+/// #[derive(Default)]
+/// enum Kind {
+///     #[default]
+///     A,
+///     B,
+///     C,
+/// }
+/// ```
+///
 /// ## How can I implement `Default`?
 ///
 /// Provide an implementation for the `default()` method that returns the value of
@@ -692,6 +692,11 @@ extern "rust-intrinsic" {
     /// Gets a static string slice containing the name of a type.
     pub fn type_name<T: ?Sized>() -> &'static str;
 
+    /// Gets the file, line and column of the caller of the enclosing
+    /// `#[track_caller]` function, or of this call if there is none.
+    #[cfg(not(stage0))]
+    pub fn caller_location() -> &'static (&'static str, u32, u32);
+
     /// Gets an identifier which is globally unique to the specified type. This
     /// function will return the same value for a type regardless of whichever
     /// crate it is invoked in.
@@ -65,6 +65,7 @@
 #![deny(missing_debug_implementations)]
 
 #![feature(allow_internal_unstable)]
+#![feature(arbitrary_self_types)]
 #![feature(asm)]
 #![feature(associated_type_defaults)]
 #![feature(attr_literals)]
@@ -112,6 +113,8 @@
 #![cfg_attr(not(stage0), feature(mips_target_feature))]
 #![cfg_attr(not(stage0), feature(aarch64_target_feature))]
 
+#![cfg_attr(not(stage0), feature(track_caller))]
+#![cfg_attr(not(stage0), feature(const_generics))]
 #![cfg_attr(stage0, feature(target_feature))]
 #![cfg_attr(stage0, feature(cfg_target_feature))]
 
@@ -180,6 +183,7 @@ pub mod borrow;
 pub mod any;
 pub mod array;
 pub mod ascii;
+pub mod asserting;
 pub mod sync;
 pub mod cell;
 pub mod char;
@@ -196,6 +200,9 @@ pub mod hash;
 pub mod fmt;
 pub mod time;
 
+pub mod future;
+pub mod task;
+
 pub mod unicode;
 
 /* Heap memory allocator trait */
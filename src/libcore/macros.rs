@@ -12,6 +12,7 @@
 #[macro_export]
 #[allow_internal_unstable]
 #[stable(feature = "core", since = "1.6.0")]
+#[cfg(stage0)]
 macro_rules! panic {
     () => (
         panic!("explicit panic")
@@ -28,6 +29,27 @@ macro_rules! panic {
     });
 }
 
+/// Entry point of thread panic, for details, see std::macros
+#[macro_export]
+#[allow_internal_unstable]
+#[stable(feature = "core", since = "1.6.0")]
+#[cfg(not(stage0))]
+macro_rules! panic {
+    () => (
+        panic!("explicit panic")
+    );
+    ($msg:expr) => ({
+        $crate::panicking::panic_str($msg, $crate::panicking::caller_location())
+    });
+    ($msg:expr,) => (
+        panic!($msg)
+    );
+    ($fmt:expr, $($arg:tt)+) => ({
+        $crate::panicking::panic_fmt(format_args!($fmt, $($arg)*),
+                                     $crate::panicking::caller_location())
+    });
+}
+
 /// Asserts that two expressions are equal to each other (using [`PartialEq`]).
 ///
 /// On panic, this macro will print the values of the expressions with their
@@ -396,6 +418,25 @@ macro_rules! write {
 /// ```
 #[macro_export]
 #[stable(feature = "rust1", since = "1.0.0")]
+#[allow_internal_unstable]
+#[cfg(not(stage0))]
+macro_rules! writeln {
+    ($dst:expr) => (
+        write!($dst, "\n")
+    );
+    ($dst:expr,) => (
+        writeln!($dst)
+    );
+    ($dst:expr, $($arg:tt)*) => (
+        $dst.write_fmt(format_args_nl!($($arg)*))
+    );
+}
+
+/// Write formatted data into a buffer, with a newline appended, see the
+/// `writeln!` above for details.
+#[macro_export]
+#[stable(feature = "rust1", since = "1.0.0")]
+#[cfg(stage0)]
 macro_rules! writeln {
     ($dst:expr) => (
         write!($dst, "\n")
@@ -251,6 +251,36 @@ impl<'a> Location<'a> {
     }
 }
 
+impl Location<'static> {
+    /// Returns the source location of the caller of this function. If that
+    /// function is itself annotated with `#[track_caller]`, the location of
+    /// its caller is returned instead, and so on up the call stack.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(track_caller)]
+    /// use std::panic::Location;
+    ///
+    /// #[track_caller]
+    /// fn get_caller_location() -> Location<'static> {
+    ///     Location::caller()
+    /// }
+    ///
+    /// let location = get_caller_location();
+    /// assert_eq!(location.file(), file!());
+    /// assert_eq!(location.line(), line!() - 2);
+    /// ```
+    #[cfg(not(stage0))]
+    #[unstable(feature = "track_caller", issue = "47809")]
+    #[track_caller]
+    #[inline]
+    pub fn caller() -> Location<'static> {
+        let &(file, line, col) = ::panicking::caller_location();
+        Location { file, line, col }
+    }
+}
+
 #[stable(feature = "panic_hook_display", since = "1.26.0")]
 impl<'a> fmt::Display for Location<'a> {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
@@ -51,6 +51,20 @@ pub fn panic(expr_file_line_col: &(&'static str, &'static str, u32, u32)) -> ! {
     panic_fmt(fmt::Arguments::new_v1(&[expr], &[]), &(file, line, col))
 }
 
+#[cold] #[inline(never)]
+pub fn panic_str(expr: &'static str, file_line_col: &(&'static str, u32, u32)) -> ! {
+    panic_fmt(fmt::Arguments::new_v1(&[expr], &[]), file_line_col)
+}
+
+/// The location reported by `panic!`: the location of its caller when used
+/// in a `#[track_caller]` function, and its own location otherwise.
+#[cfg(not(stage0))]
+#[track_caller]
+#[inline(always)]
+pub fn caller_location() -> &'static (&'static str, u32, u32) {
+    unsafe { ::intrinsics::caller_location() }
+}
+
 #[cold] #[inline(never)]
 #[lang = "panic_bounds_check"]
 fn panic_bounds_check(file_line_col: &(&'static str, u32, u32),
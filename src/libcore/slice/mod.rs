@@ -2235,6 +2235,7 @@ impl<T, I> ops::Index<I> for [T]
     type Output = I::Output;
 
     #[inline]
+    #[cfg_attr(not(stage0), track_caller)]
     fn index(&self, index: I) -> &I::Output {
         index.index(self)
     }
@@ -2246,6 +2247,7 @@ impl<T, I> ops::IndexMut<I> for [T]
     where I: SliceIndex<[T]>
 {
     #[inline]
+    #[cfg_attr(not(stage0), track_caller)]
     fn index_mut(&mut self, index: I) -> &mut I::Output {
         index.index_mut(self)
     }
@@ -2332,12 +2334,14 @@ impl<T> SliceIndex<[T]> for usize {
     }
 
     #[inline]
+    #[cfg_attr(not(stage0), track_caller)]
     fn index(self, slice: &[T]) -> &T {
         // NB: use intrinsic indexing
         &(*slice)[self]
     }
 
     #[inline]
+    #[cfg_attr(not(stage0), track_caller)]
     fn index_mut(self, slice: &mut [T]) -> &mut T {
         // NB: use intrinsic indexing
         &mut (*slice)[self]
@@ -46,6 +46,36 @@ fn array_try_from() {
          0  1  2  3  4  5  6  7  8  9
         10 11 12 13 14 15 16 17 18 19
         20 21 22 23 24 25 26 27 28 29
-        30 31 32
+        30 31 32 33 64
     }
 }
+
+#[test]
+fn array_impls_33() {
+    use core::hash::{Hash, Hasher};
+    use std::collections::hash_map::DefaultHasher;
+
+    let mut a = [0u8; 33];
+    let mut b = [0u8; 33];
+    a[32] = 1;
+
+    assert_eq!(format!("{:?}", b), format!("{:?}", &b[..]));
+    assert!(a != b);
+    assert!(a > b);
+    assert_eq!(b.cmp(&b), ::core::cmp::Ordering::Equal);
+    assert_eq!(a, &a[..]);
+    assert_eq!(&b[..], b);
+    assert_eq!(a.as_ref().len(), 33);
+    assert_eq!(b.as_mut().len(), 33);
+    assert_eq!((&a).into_iter().sum::<u8>(), 1);
+    for x in &mut b {
+        *x = 2;
+    }
+    assert_eq!(b, [2; 33]);
+
+    let mut h1 = DefaultHasher::new();
+    let mut h2 = DefaultHasher::new();
+    a.hash(&mut h1);
+    a[..].hash(&mut h2);
+    assert_eq!(h1.finish(), h2.finish());
+}
@@ -167,6 +167,14 @@ impl<'a, 'tcx> CFGBuilder<'a, 'tcx> {
                 let post_exit = self.pats_all(post.iter(), vec_exit);
                 self.add_ast_node(pat.hir_id.local_id, &[post_exit])
             }
+
+            PatKind::Or(ref alternatives) => {
+                // Any one of the alternatives may match.
+                let alternative_exits: Vec<_> = alternatives.iter()
+                    .map(|p| self.pat(&p, pred))
+                    .collect();
+                self.add_ast_node(pat.hir_id.local_id, &alternative_exits)
+            }
         }
     }
 
@@ -2139,6 +2139,8 @@ register_diagnostics! {
     E0657, // `impl Trait` can only capture lifetimes bound at the fn level
     E0687, // in-band lifetimes cannot be used in `fn`/`Fn` syntax
     E0688, // in-band lifetimes cannot be mixed with explicit lifetime binders
+    E0707, // multiple elided lifetimes used in arguments of `async fn`
+    E0708, // multiple different lifetimes used in arguments of `async fn`
 
     E0906, // closures cannot be static
 }
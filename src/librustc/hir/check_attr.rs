@@ -14,6 +14,7 @@
 //! conflicts between multiple such attributes attached to the same
 //! item.
 
+use syntax::ast;
 use syntax_pos::Span;
 use ty::TyCtxt;
 
@@ -68,6 +69,8 @@ impl<'a, 'tcx> CheckAttrVisitor<'a, 'tcx> {
                 self.check_inline(attr, &item.span, target)
             } else if attr.check_name("non_exhaustive") {
                 self.check_non_exhaustive(attr, item, target)
+            } else if attr.check_name("track_caller") {
+                self.check_track_caller(attr, &item.span, target)
             } else if attr.check_name("wasm_import_module") {
                 has_wasm_import_module = true;
                 if attr.value_str().is_none() {
@@ -115,6 +118,23 @@ impl<'a, 'tcx> CheckAttrVisitor<'a, 'tcx> {
         }
     }
 
+    /// Check if a `#[track_caller]` is applied to a function.
+    fn check_track_caller(&self, attr: &hir::Attribute, span: &Span, target: Target) {
+        if target != Target::Fn {
+            self.tcx.sess.struct_span_err(attr.span, "attribute should be applied to a function")
+                .span_label(*span, "not a function")
+                .emit();
+        }
+    }
+
+    /// Check the attributes of a trait or impl method, which are otherwise
+    /// only validated once the method is translated.
+    fn check_method_attributes(&self, id: ast::NodeId, attrs: &[hir::Attribute]) {
+        if attrs.iter().any(|a| a.check_name("track_caller")) {
+            self.tcx.trans_fn_attrs(self.tcx.hir.local_def_id(id));
+        }
+    }
+
     /// Check if the `#[non_exhaustive]` attribute on an `item` is valid.
     fn check_non_exhaustive(&self, attr: &hir::Attribute, item: &hir::Item, target: Target) {
         match target {
@@ -313,6 +333,19 @@ impl<'a, 'tcx> Visitor<'tcx> for CheckAttrVisitor<'a, 'tcx> {
         intravisit::walk_item(self, item)
     }
 
+    fn visit_trait_item(&mut self, trait_item: &'tcx hir::TraitItem) {
+        if let hir::TraitItemKind::Method(..) = trait_item.node {
+            self.check_method_attributes(trait_item.id, &trait_item.attrs);
+        }
+        intravisit::walk_trait_item(self, trait_item)
+    }
+
+    fn visit_impl_item(&mut self, impl_item: &'tcx hir::ImplItem) {
+        if let hir::ImplItemKind::Method(..) = impl_item.node {
+            self.check_method_attributes(impl_item.id, &impl_item.attrs);
+        }
+        intravisit::walk_impl_item(self, impl_item)
+    }
 
     fn visit_stmt(&mut self, stmt: &'tcx hir::Stmt) {
         self.check_stmt_attributes(stmt);
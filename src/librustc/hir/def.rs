@@ -51,6 +51,7 @@ pub enum Def {
     VariantCtor(DefId, CtorKind),
     Method(DefId),
     AssociatedConst(DefId),
+    ConstParam(DefId),
 
     Local(ast::NodeId),
     Upvar(ast::NodeId,  // node id of closed over local
@@ -164,7 +165,7 @@ impl Def {
             Def::TyAlias(id) | Def::TraitAlias(id) |
             Def::AssociatedTy(id) | Def::TyParam(id) | Def::Struct(id) | Def::StructCtor(id, ..) |
             Def::Union(id) | Def::Trait(id) | Def::Method(id) | Def::Const(id) |
-            Def::AssociatedConst(id) | Def::Macro(id, ..) |
+            Def::AssociatedConst(id) | Def::ConstParam(id) | Def::Macro(id, ..) |
             Def::GlobalAsm(id) | Def::TyForeign(id) => {
                 id
             }
@@ -204,6 +205,7 @@ impl Def {
             Def::Method(..) => "method",
             Def::Const(..) => "constant",
             Def::AssociatedConst(..) => "associated constant",
+            Def::ConstParam(..) => "const parameter",
             Def::TyParam(..) => "type parameter",
             Def::PrimTy(..) => "builtin type",
             Def::Local(..) => "local variable",
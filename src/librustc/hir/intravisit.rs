@@ -278,6 +278,9 @@ pub trait Visitor<'v> : Sized {
     fn visit_ty(&mut self, t: &'v Ty) {
         walk_ty(self, t)
     }
+    fn visit_const_arg(&mut self, c: &'v ConstArg) {
+        walk_const_arg(self, c)
+    }
     fn visit_generic_param(&mut self, p: &'v GenericParam) {
         walk_generic_param(self, p)
     }
@@ -635,9 +638,20 @@ pub fn walk_path_parameters<'v, V: Visitor<'v>>(visitor: &mut V,
                                                 path_parameters: &'v PathParameters) {
     walk_list!(visitor, visit_lifetime, &path_parameters.lifetimes);
     walk_list!(visitor, visit_ty, &path_parameters.types);
+    walk_list!(visitor, visit_const_arg, &path_parameters.consts);
     walk_list!(visitor, visit_assoc_type_binding, &path_parameters.bindings);
 }
 
+pub fn walk_const_arg<'v, V: Visitor<'v>>(visitor: &mut V, const_arg: &'v ConstArg) {
+    visitor.visit_id(const_arg.id);
+    match const_arg.node {
+        ConstArgLit(..) => {}
+        ConstArgPath(ref path) => {
+            visitor.visit_path(path, const_arg.id);
+        }
+    }
+}
+
 pub fn walk_assoc_type_binding<'v, V: Visitor<'v>>(visitor: &mut V,
                                                    type_binding: &'v TypeBinding) {
     visitor.visit_id(type_binding.id);
@@ -666,6 +680,9 @@ pub fn walk_pat<'v, V: Visitor<'v>>(visitor: &mut V, pattern: &'v Pat) {
         PatKind::Tuple(ref tuple_elements, _) => {
             walk_list!(visitor, visit_pat, tuple_elements);
         }
+        PatKind::Or(ref alternatives) => {
+            walk_list!(visitor, visit_pat, alternatives);
+        }
         PatKind::Box(ref subpattern) |
         PatKind::Ref(ref subpattern, _) => {
             visitor.visit_pat(subpattern)
@@ -732,6 +749,11 @@ pub fn walk_generic_param<'v, V: Visitor<'v>>(visitor: &mut V, param: &'v Generi
             walk_list!(visitor, visit_ty_param_bound, &ty_param.bounds);
             walk_list!(visitor, visit_ty, &ty_param.default);
         }
+        GenericParam::Const(ref const_param) => {
+            visitor.visit_id(const_param.id);
+            visitor.visit_name(const_param.span, const_param.name);
+            visitor.visit_ty(&const_param.ty);
+        }
     }
 }
 
@@ -147,6 +147,10 @@ pub struct LoweringContext<'a> {
     current_hir_id_owner: Vec<(DefIndex, u32)>,
     item_local_id_counters: NodeMap<u32>,
     node_id_to_hir_id: IndexVec<NodeId, hir::HirId>,
+
+    // The bindings of the pattern of the `let...else` being lowered, mapped to
+    // the fresh ids they get inside its `match`. See `lower_let_else`.
+    let_else_bindings: NodeMap<NodeId>,
 }
 
 pub trait Resolver {
@@ -229,6 +233,7 @@ pub fn lower_crate(
         lifetimes_to_define: Vec::new(),
         is_collecting_in_band_lifetimes: false,
         in_scope_lifetimes: Vec::new(),
+        let_else_bindings: NodeMap(),
     }.lower_crate(krate)
 }
 
@@ -827,6 +832,54 @@ impl<'a> LoweringContext<'a> {
         return r;
     }
 
+    /// Lowers the body of an `async` block or `async fn` into
+    /// `::std::future::from_generator(static move? || { body })`.
+    fn make_async_expr<F>(
+        &mut self,
+        capture_clause: CaptureBy,
+        closure_node_id: NodeId,
+        body: F,
+    ) -> hir::Expr_
+    where
+        F: FnOnce(&mut LoweringContext) -> hir::Expr,
+    {
+        let prev_is_generator = mem::replace(&mut self.is_generator, true);
+        let body_expr = self.with_parent_def(closure_node_id, |this| body(this));
+        let span = body_expr.span;
+        let decl = FnDecl {
+            inputs: vec![],
+            output: FunctionRetTy::Default(span),
+            variadic: false,
+        };
+        let body_id = self.record_body(body_expr, Some(&decl));
+        self.is_generator = prev_is_generator;
+
+        let capture_clause = self.lower_capture_clause(capture_clause);
+        let LoweredNodeId { node_id, hir_id } = self.lower_node_id(closure_node_id);
+        let decl = self.lower_fn_decl(&decl, None, false, None);
+        let generator = hir::Expr {
+            id: node_id,
+            hir_id,
+            node: hir::ExprClosure(
+                capture_clause,
+                decl,
+                body_id,
+                span,
+                Some(hir::GeneratorMovability::Static),
+            ),
+            span,
+            attrs: ThinVec::new(),
+        };
+
+        let unstable_span = self.allow_internal_unstable(CompilerDesugaringKind::Async, span);
+        let gen_future = self.expr_std_path(
+            unstable_span,
+            &["future", "from_generator"],
+            ThinVec::new(),
+        );
+        hir::ExprCall(P(gen_future), hir_vec![generator])
+    }
+
     fn with_loop_scope<T, F>(&mut self, loop_id: NodeId, f: F) -> T
     where
         F: FnOnce(&mut LoweringContext) -> T,
@@ -1023,6 +1076,17 @@ impl<'a> LoweringContext<'a> {
         }
     }
 
+    /// `type Foo = impl Trait;` (in a module or an impl) introduces an
+    /// existential type whose concrete type is inferred from its uses, and
+    /// `let x: impl Trait = ..;` one whose concrete type is inferred from
+    /// the enclosing body.
+    fn top_level_impl_trait_context(t: &Ty) -> ImplTraitContext {
+        match t.node {
+            TyKind::ImplTrait(..) => ImplTraitContext::Existential,
+            _ => ImplTraitContext::Disallowed,
+        }
+    }
+
     fn lower_ty(&mut self, t: &Ty, itctx: ImplTraitContext) -> P<hir::Ty> {
         let kind = match t.node {
             TyKind::Infer => hir::TyInfer,
@@ -1051,7 +1115,7 @@ impl<'a> LoweringContext<'a> {
                         ),
                         unsafety: this.lower_unsafety(f.unsafety),
                         abi: f.abi,
-                        decl: this.lower_fn_decl(&f.decl, None, false),
+                        decl: this.lower_fn_decl(&f.decl, None, false, None),
                         arg_names: this.lower_fn_args_to_names(&f.decl),
                     }))
                 },
@@ -1627,13 +1691,31 @@ impl<'a> LoweringContext<'a> {
         let &AngleBracketedParameterData {
             ref lifetimes,
             ref types,
+            ref consts,
             ref bindings,
             ..
         } = data;
+
+        // Paths that were resolved to const parameters or constants are const
+        // arguments, they come before those that could only be parsed as such.
+        let (const_tys, types): (Vec<_>, Vec<_>) = types.iter().partition(|ty| {
+            match self.resolver.get_resolution(ty.id).map(|pr| pr.base_def()) {
+                Some(Def::ConstParam(..)) | Some(Def::Const(..)) => true,
+                _ => false,
+            }
+        });
+        let consts = const_tys.into_iter()
+            .map(|ty| match ty.node {
+                TyKind::Path(None, ref path) => self.lower_const_arg_path(ty.id, path, ty.span),
+                _ => bug!("const argument parsed as a type is not a path: {:?}", ty),
+            })
+            .chain(consts.iter().map(|c| self.lower_const_arg(c)))
+            .collect();
         (
             hir::PathParameters {
                 lifetimes: self.lower_lifetimes(lifetimes),
                 types: types.iter().map(|ty| self.lower_ty(ty, itctx)).collect(),
+                consts,
                 bindings: bindings
                     .iter()
                     .map(|b| self.lower_ty_binding(b, itctx))
@@ -1644,6 +1726,75 @@ impl<'a> LoweringContext<'a> {
         )
     }
 
+    fn lower_const_arg(&mut self, expr: &Expr) -> hir::ConstArg {
+        match expr.node {
+            ExprKind::Paren(ref inner) => return self.lower_const_arg(inner),
+            ExprKind::Block(ref block, None) if block.stmts.len() == 1 => {
+                if let StmtKind::Expr(ref inner) = block.stmts[0].node {
+                    return self.lower_const_arg(inner);
+                }
+            }
+            ExprKind::Lit(ref lit) => {
+                return hir::ConstArg {
+                    id: self.lower_node_id(expr.id).node_id,
+                    node: hir::ConstArgLit(P((**lit).clone()), false),
+                    span: expr.span,
+                };
+            }
+            ExprKind::Unary(UnOp::Neg, ref inner) => {
+                if let ExprKind::Lit(ref lit) = inner.node {
+                    return hir::ConstArg {
+                        id: self.lower_node_id(expr.id).node_id,
+                        node: hir::ConstArgLit(P((**lit).clone()), true),
+                        span: expr.span,
+                    };
+                }
+            }
+            ExprKind::Path(None, ref path) => {
+                return self.lower_const_arg_path(expr.id, path, expr.span);
+            }
+            _ => {}
+        }
+
+        self.diagnostic().span_err(
+            expr.span,
+            "const arguments must be literals, const parameters or constants",
+        );
+        let path = P(hir::Path {
+            span: expr.span,
+            def: Def::Err,
+            segments: hir_vec![],
+        });
+        hir::ConstArg {
+            id: self.lower_node_id(expr.id).node_id,
+            node: hir::ConstArgPath(path),
+            span: expr.span,
+        }
+    }
+
+    fn lower_const_arg_path(&mut self, id: NodeId, path: &Path, span: Span) -> hir::ConstArg {
+        let path = match self.lower_qpath(id, &None, path, ParamMode::Explicit,
+                                          ImplTraitContext::Disallowed) {
+            hir::QPath::Resolved(None, path) => path,
+            _ => {
+                self.diagnostic().span_err(
+                    span,
+                    "const arguments must be literals, const parameters or constants",
+                );
+                P(hir::Path {
+                    span: path.span,
+                    def: Def::Err,
+                    segments: hir_vec![],
+                })
+            }
+        };
+        hir::ConstArg {
+            id: self.lower_node_id(id).node_id,
+            node: hir::ConstArgPath(path),
+            span,
+        }
+    }
+
     fn lower_parenthesized_parameter_data(
         &mut self,
         data: &ParenthesizedParameterData,
@@ -1696,7 +1847,7 @@ impl<'a> LoweringContext<'a> {
             hir_id,
             ty: l.ty
                 .as_ref()
-                .map(|t| self.lower_ty(t, ImplTraitContext::Disallowed)),
+                .map(|t| self.lower_ty(t, Self::top_level_impl_trait_context(t))),
             pat: self.lower_pat(&l.pat),
             init: l.init.as_ref().map(|e| P(self.lower_expr(e))),
             span: l.span,
@@ -1736,6 +1887,7 @@ impl<'a> LoweringContext<'a> {
         decl: &FnDecl,
         fn_def_id: Option<DefId>,
         impl_trait_return_allow: bool,
+        make_ret_async: Option<NodeId>,
     ) -> P<hir::FnDecl> {
         // NOTE: The two last parameters here have to do with impl Trait. If fn_def_id is Some,
         //       then impl Trait arguments are lowered into generic parameters on the given
@@ -1744,18 +1896,25 @@ impl<'a> LoweringContext<'a> {
         //       Furthermore, if impl_trait_return_allow is true, then impl Trait may be used in
         //       return positions as well. This guards against trait declarations and their impls
         //       where impl Trait is disallowed. (again for now)
-        P(hir::FnDecl {
-            inputs: decl.inputs
-                .iter()
-                .map(|arg| {
-                    if let Some(def_id) = fn_def_id {
-                        self.lower_ty(&arg.ty, ImplTraitContext::Universal(def_id))
-                    } else {
-                        self.lower_ty(&arg.ty, ImplTraitContext::Disallowed)
-                    }
-                })
-                .collect(),
-            output: match decl.output {
+        //
+        //       Finally, if make_ret_async is Some, the function is an `async fn` and its
+        //       return type `T` is lowered to `impl Future<Output = T>`, using the given
+        //       NodeId for the existential type.
+        let inputs = decl.inputs
+            .iter()
+            .map(|arg| {
+                if let Some(def_id) = fn_def_id {
+                    self.lower_ty(&arg.ty, ImplTraitContext::Universal(def_id))
+                } else {
+                    self.lower_ty(&arg.ty, ImplTraitContext::Disallowed)
+                }
+            })
+            .collect::<HirVec<_>>();
+
+        let output = if let Some(return_impl_trait_id) = make_ret_async {
+            self.lower_async_fn_ret_ty(&inputs, &decl.output, return_impl_trait_id)
+        } else {
+            match decl.output {
                 FunctionRetTy::Ty(ref ty) => match fn_def_id {
                     Some(_) if impl_trait_return_allow => {
                         hir::Return(self.lower_ty(ty, ImplTraitContext::Existential))
@@ -1763,7 +1922,12 @@ impl<'a> LoweringContext<'a> {
                     _ => hir::Return(self.lower_ty(ty, ImplTraitContext::Disallowed)),
                 },
                 FunctionRetTy::Default(span) => hir::DefaultReturn(span),
-            },
+            }
+        };
+
+        P(hir::FnDecl {
+            inputs,
+            output,
             variadic: decl.variadic,
             has_implicit_self: decl.inputs.get(0).map_or(false, |arg| match arg.ty.node {
                 TyKind::ImplicitSelf => true,
@@ -1773,6 +1937,238 @@ impl<'a> LoweringContext<'a> {
         })
     }
 
+    // Transform `-> T` for `async fn` into `-> impl Future<Output = T> + 'lt`,
+    // where `'lt` is the single lifetime appearing in the argument types, if any.
+    fn lower_async_fn_ret_ty(
+        &mut self,
+        inputs: &[P<hir::Ty>],
+        output: &FunctionRetTy,
+        return_impl_trait_id: NodeId,
+    ) -> hir::FunctionRetTy {
+        // Get the lifetimes used in the arguments of the function. The returned future
+        // borrows the arguments, so it must be bounded by that same lifetime.
+        // FIXME: multiple different lifetimes are not allowed, because
+        // `impl Trait + 'a + 'b` does not capture `'a` and `'b` where neither outlives
+        // the other. What is really needed is a lifetime which is the intersection of
+        // all input lifetimes, but no such lifetime can be expressed at the moment.
+        struct AsyncFnLifetimeCollector<'r, 'a: 'r> {
+            context: &'r mut LoweringContext<'a>,
+            // Lifetimes bound by HRTB.
+            currently_bound_lifetimes: Vec<hir::LifetimeName>,
+            // Whether to count elided lifetimes.
+            // Disabled inside of `Fn` or `fn` syntax.
+            collect_elided_lifetimes: bool,
+            // The lifetime found so far.
+            output_lifetime: Option<(hir::LifetimeName, Span)>,
+        }
+
+        impl<'r, 'a: 'r, 'v> hir::intravisit::Visitor<'v> for AsyncFnLifetimeCollector<'r, 'a> {
+            fn nested_visit_map<'this>(
+                &'this mut self,
+            ) -> hir::intravisit::NestedVisitorMap<'this, 'v> {
+                hir::intravisit::NestedVisitorMap::None
+            }
+
+            fn visit_path_parameters(&mut self, span: Span, parameters: &'v hir::PathParameters) {
+                // Don't collect elided lifetimes used inside of `Fn()` syntax.
+                if parameters.parenthesized {
+                    let old_collect_elided_lifetimes = self.collect_elided_lifetimes;
+                    self.collect_elided_lifetimes = false;
+                    hir::intravisit::walk_path_parameters(self, span, parameters);
+                    self.collect_elided_lifetimes = old_collect_elided_lifetimes;
+                } else {
+                    hir::intravisit::walk_path_parameters(self, span, parameters);
+                }
+            }
+
+            fn visit_ty(&mut self, t: &'v hir::Ty) {
+                // Don't collect elided lifetimes used inside of `fn()` syntax
+                if let &hir::Ty_::TyBareFn(_) = &t.node {
+                    let old_collect_elided_lifetimes = self.collect_elided_lifetimes;
+                    self.collect_elided_lifetimes = false;
+                    hir::intravisit::walk_ty(self, t);
+                    self.collect_elided_lifetimes = old_collect_elided_lifetimes;
+                } else {
+                    hir::intravisit::walk_ty(self, t);
+                }
+            }
+
+            fn visit_poly_trait_ref(
+                &mut self,
+                polytr: &'v hir::PolyTraitRef,
+                _: hir::TraitBoundModifier,
+            ) {
+                let old_len = self.currently_bound_lifetimes.len();
+
+                // Record the introduction of 'a in `for<'a> ...`
+                for param in &polytr.bound_generic_params {
+                    if let hir::GenericParam::Lifetime(ref lt_def) = *param {
+                        self.currently_bound_lifetimes.push(lt_def.lifetime.name);
+                    }
+                }
+
+                hir::intravisit::walk_trait_ref(self, &polytr.trait_ref);
+
+                self.currently_bound_lifetimes.truncate(old_len);
+            }
+
+            fn visit_lifetime(&mut self, lifetime: &'v hir::Lifetime) {
+                let name = match lifetime.name {
+                    hir::LifetimeName::Implicit | hir::LifetimeName::Underscore => {
+                        if self.collect_elided_lifetimes {
+                            // Use `'_` for both implicit and underscore lifetimes in
+                            // `impl Future<Output = T> + '_`
+                            hir::LifetimeName::Underscore
+                        } else {
+                            return;
+                        }
+                    }
+                    name @ hir::LifetimeName::Fresh(_) => name,
+                    name @ hir::LifetimeName::Name(_) => name,
+                    hir::LifetimeName::Static => return,
+                };
+
+                if self.currently_bound_lifetimes.contains(&name) {
+                    return;
+                }
+
+                match self.output_lifetime {
+                    None => self.output_lifetime = Some((name, lifetime.span)),
+                    Some((current_name, current_span)) => {
+                        // We don't have a reliable way to desugar an `async fn` with
+                        // several potentially unrelated input lifetimes into
+                        // `-> impl Trait + 'lt`, so report an error in this case.
+                        if current_name == hir::LifetimeName::Underscore &&
+                            name == hir::LifetimeName::Underscore
+                        {
+                            struct_span_err!(
+                                self.context.sess,
+                                current_span.between(lifetime.span),
+                                E0707,
+                                "multiple elided lifetimes used in arguments of `async fn`"
+                            )
+                                .span_label(current_span, "first lifetime here")
+                                .span_label(lifetime.span, "different lifetime here")
+                                .help("consider giving these arguments named lifetimes")
+                                .emit();
+                        } else if current_name != name {
+                            struct_span_err!(
+                                self.context.sess,
+                                current_span.between(lifetime.span),
+                                E0708,
+                                "multiple different lifetimes used in arguments of `async fn`"
+                            )
+                                .span_label(current_span, "first lifetime here")
+                                .span_label(lifetime.span, "different lifetime here")
+                                .help("`async fn` can only accept borrowed values \
+                                       with identical lifetimes")
+                                .emit();
+                        }
+                    }
+                }
+            }
+        }
+
+        let bound_lifetime = {
+            let mut lifetime_collector = AsyncFnLifetimeCollector {
+                context: self,
+                currently_bound_lifetimes: Vec::new(),
+                collect_elided_lifetimes: true,
+                output_lifetime: None,
+            };
+
+            for arg in inputs {
+                hir::intravisit::walk_ty(&mut lifetime_collector, arg);
+            }
+            lifetime_collector.output_lifetime
+        };
+
+        let span = match *output {
+            FunctionRetTy::Ty(ref ty) => ty.span,
+            FunctionRetTy::Default(span) => span,
+        };
+
+        let output_ty = match *output {
+            FunctionRetTy::Ty(ref ty) => self.lower_ty(ty, ImplTraitContext::Existential),
+            FunctionRetTy::Default(span) => {
+                let LoweredNodeId { node_id, hir_id } = self.next_id();
+                P(hir::Ty {
+                    id: node_id,
+                    hir_id,
+                    node: hir::TyTup(hir_vec![]),
+                    span,
+                })
+            }
+        };
+
+        // `::std::future::Future<Output = T>`
+        let mut future_path = self.std_path(span, &["future", "Future"], false);
+        future_path.segments.last_mut().unwrap().parameters = Some(P(hir::PathParameters {
+            lifetimes: hir_vec![],
+            types: hir_vec![],
+            consts: hir_vec![],
+            bindings: hir_vec![hir::TypeBinding {
+                id: self.next_id().node_id,
+                name: Symbol::intern(FN_OUTPUT_NAME),
+                ty: output_ty,
+                span,
+            }],
+            parenthesized: false,
+        }));
+
+        let mut bounds = vec![hir::TraitTyParamBound(
+            hir::PolyTraitRef {
+                bound_generic_params: hir_vec![],
+                trait_ref: hir::TraitRef {
+                    path: future_path,
+                    ref_id: self.next_id().node_id,
+                },
+                span,
+            },
+            hir::TraitBoundModifier::None,
+        )];
+
+        if let Some((name, span)) = bound_lifetime {
+            bounds.push(hir::RegionTyParamBound(hir::Lifetime {
+                id: self.next_id().node_id,
+                name,
+                span,
+            }));
+        }
+
+        let hir_bounds = HirVec::from(bounds);
+        let def_index = self.resolver
+            .definitions()
+            .opt_def_index(return_impl_trait_id)
+            .unwrap();
+        let (lifetimes, lifetime_defs) =
+            self.lifetimes_from_impl_trait_bounds(def_index, &hir_bounds);
+
+        let impl_trait_ty = hir::TyImplTraitExistential(
+            hir::ExistTy {
+                generics: hir::Generics {
+                    params: lifetime_defs,
+                    where_clause: hir::WhereClause {
+                        id: self.next_id().node_id,
+                        predicates: Vec::new().into(),
+                    },
+                    span,
+                },
+                bounds: hir_bounds,
+            },
+            lifetimes,
+        );
+
+        // The type must use the id the `ImplTrait` definition was created for.
+        let LoweredNodeId { node_id, hir_id } = self.lower_node_id(return_impl_trait_id);
+        hir::Return(P(hir::Ty {
+            id: node_id,
+            node: impl_trait_ty,
+            span,
+            hir_id,
+        }))
+    }
+
     fn lower_ty_param_bound(
         &mut self,
         tpb: &TyParamBound,
@@ -1901,10 +2297,23 @@ impl<'a> LoweringContext<'a> {
                     add_bounds.get(&ty_param.id).map_or(&[][..], |x| &x),
                     itctx,
                 )),
+                GenericParam::Const(ref const_param) => {
+                    hir::GenericParam::Const(self.lower_const_param(const_param))
+                }
             })
             .collect()
     }
 
+    fn lower_const_param(&mut self, cp: &ConstParam) -> hir::ConstParam {
+        hir::ConstParam {
+            id: self.lower_node_id(cp.id).node_id,
+            name: self.lower_ident(cp.ident),
+            ty: self.lower_ty(&cp.ty, ImplTraitContext::Disallowed),
+            span: cp.ident.span,
+            attrs: self.lower_attrs(&cp.attrs),
+        }
+    }
+
     fn lower_generics(&mut self, g: &Generics, itctx: ImplTraitContext) -> hir::Generics {
         // Collect `?Trait` bounds in where clause and move them to parameter definitions.
         // FIXME: This could probably be done with less rightward drift. Also looks like two control
@@ -2170,6 +2579,196 @@ impl<'a> LoweringContext<'a> {
         })
     }
 
+    // Desugar `let PAT: TY = EXPR else { ELSE };` into
+    //
+    // ```
+    // let __let_else_init: TY = EXPR;
+    // let (x, y, ..) = match __let_else_init {
+    //     PAT => (x, y, ..),
+    //     _ => { ELSE }
+    // };
+    // ```
+    //
+    // where `x, y, ..` are the bindings of `PAT`. The first `let` is only
+    // there when `TY` is given, so that `EXPR` is coerced to it like the
+    // initializer of a plain `let`; otherwise `EXPR` is the scrutinee itself
+    // and `PAT` may bind by reference into it. Either way, the temporaries of
+    // `EXPR` are dropped at the end of the statement rather than being kept
+    // alive for the rest of the block.
+    //
+    // The rest of the block resolved to the bindings of `PAT`, so those ids
+    // go to the outer `let`, and the bindings in the `match` get fresh ones.
+    // Typeck checks that `ELSE` diverges.
+    fn lower_let_else(&mut self, s: &Stmt, l: &Local, els: &Block) -> SmallVector<hir::Stmt> {
+        let mut stmts = SmallVector::new();
+
+        let init = P(self.lower_expr(l.init.as_ref().unwrap()));
+        let scrutinee = match l.ty {
+            Some(ref ty) => {
+                let span = init.span;
+                let name = self.str_to_ident("__let_else_init");
+                let pat = self.pat_ident(span, name);
+                let pat_id = pat.id;
+                let LoweredNodeId { node_id, hir_id } = self.next_id();
+                let local = P(hir::Local {
+                    pat,
+                    ty: Some(self.lower_ty(ty, ImplTraitContext::Disallowed)),
+                    init: Some(init),
+                    id: node_id,
+                    hir_id,
+                    span,
+                    attrs: ThinVec::new(),
+                    source: hir::LocalSource::Normal,
+                });
+                let decl = respan(span, hir::DeclLocal(local));
+                stmts.push(respan(span, hir::StmtDecl(P(decl), self.next_id().node_id)));
+                P(self.expr_ident(span, name, pat_id))
+            }
+            None => init,
+        };
+
+        // The bindings of an or-pattern all resolve to those of its first
+        // alternative, so only those are collected.
+        let mut bindings = vec![];
+        {
+            let resolver = &mut self.resolver;
+            l.pat.walk(&mut |p| {
+                if let PatKind::Ident(binding_mode, ident, _) = p.node {
+                    match resolver.get_resolution(p.id).map(|d| d.base_def()) {
+                        Some(Def::Local(id)) if id == p.id => {
+                            bindings.push((p.id, binding_mode, ident, p.span));
+                        }
+                        _ => {}
+                    }
+                }
+                true
+            });
+        }
+
+        for &(id, ..) in &bindings {
+            let fresh_id = self.sess.next_node_id();
+            self.let_else_bindings.insert(id, fresh_id);
+        }
+        let then_arm = {
+            let pat = self.lower_pat(&l.pat);
+            let mut fields = Vec::with_capacity(bindings.len());
+            for &(id, _, ident, span) in &bindings {
+                let fresh_id = self.let_else_bindings.remove(&id).unwrap();
+                fields.push(self.expr_ident(span, ident.name, fresh_id));
+            }
+            let body = self.expr_tuple(l.pat.span, fields.into());
+            self.arm(hir_vec![pat], body)
+        };
+
+        let else_arm = {
+            let pat = self.pat_wild(els.span);
+            let block = self.lower_block(els, false);
+            let body = P(self.expr_block(block, ThinVec::new()));
+            self.arm(hir_vec![pat], body)
+        };
+
+        let match_expr = P(self.expr_match(l.span,
+                                           scrutinee,
+                                           hir_vec![then_arm, else_arm],
+                                           hir::MatchSource::LetElseDesugar));
+
+        // The bindings are moved out of the `match` by value, so only their
+        // mutability carries over to the outer `let`.
+        let pats = bindings.iter().map(|&(id, binding_mode, ident, span)| {
+            let LoweredNodeId { node_id, hir_id } = self.lower_node_id(id);
+            let binding_mode = match binding_mode {
+                BindingMode::ByValue(Mutability::Mutable) => hir::BindingAnnotation::Mutable,
+                _ => hir::BindingAnnotation::Unannotated,
+            };
+            P(hir::Pat {
+                id: node_id,
+                hir_id,
+                node: hir::PatKind::Binding(binding_mode,
+                                            node_id,
+                                            respan(ident.span, ident.name),
+                                            None),
+                span,
+            })
+        }).collect();
+        let pat = self.pat(l.pat.span, hir::PatKind::Tuple(pats, None));
+
+        let LoweredNodeId { node_id, hir_id } = self.lower_node_id(l.id);
+        let local = P(hir::Local {
+            pat,
+            ty: None,
+            init: Some(match_expr),
+            id: node_id,
+            hir_id,
+            span: l.span,
+            attrs: l.attrs.clone(),
+            source: hir::LocalSource::Normal,
+        });
+        let decl = respan(s.span, hir::DeclLocal(local));
+        stmts.push(respan(s.span, hir::StmtDecl(P(decl), self.lower_node_id(s.id).node_id)));
+        stmts
+    }
+
+    /// Nests `inner` in the conditions of an `if let` or `while let` chain:
+    /// a `match` for each `let` and an `if` for each other condition, where
+    /// `on_fail` builds what a failed condition evaluates to. Returns the
+    /// outermost expression for the caller to wrap.
+    fn lower_chain_conds<F>(
+        &mut self,
+        conds: &[ChainCond],
+        span: Span,
+        inner: P<hir::Expr>,
+        is_while: bool,
+        on_fail: &mut F,
+    ) -> hir::Expr_
+    where
+        F: FnMut(&mut Self, Span) -> P<hir::Expr>,
+    {
+        let (cond, rest) = conds.split_first().unwrap();
+
+        // Like `while let`, the conditions of a `while` chain are lowered in
+        // the loop condition scope.
+        let cond_expr = match *cond {
+            ChainCond::Let(_, ref expr) | ChainCond::Expr(ref expr) => expr,
+        };
+        let cond_expr = if is_while {
+            self.with_loop_condition_scope(|this| P(this.lower_expr(cond_expr)))
+        } else {
+            P(self.lower_expr(cond_expr))
+        };
+
+        let then = if rest.is_empty() {
+            inner
+        } else {
+            let node = self.lower_chain_conds(rest, span, inner, is_while, on_fail);
+            P(self.expr(span, node, ThinVec::new()))
+        };
+        let fail = on_fail(self, cond_expr.span);
+
+        match *cond {
+            ChainCond::Let(ref pats, _) => {
+                let pats = pats.iter().map(|pat| self.lower_pat(pat)).collect();
+                let pat_arm = self.arm(pats, then);
+                let wildcard_pattern = self.pat_wild(cond_expr.span);
+                let wildcard_arm = self.arm(hir_vec![wildcard_pattern], fail);
+                let source = if is_while {
+                    hir::MatchSource::WhileLetDesugar
+                } else {
+                    hir::MatchSource::IfLetDesugar {
+                        contains_else_clause: false,
+                    }
+                };
+                hir::ExprMatch(cond_expr, hir_vec![pat_arm, wildcard_arm], source)
+            }
+            ChainCond::Expr(_) => {
+                let then_blk = P(self.block_expr(then));
+                let then_expr = P(self.expr_block(then_blk, ThinVec::new()));
+                let else_blk = P(self.block_expr(fail));
+                let else_expr = P(self.expr_block(else_blk, ThinVec::new()));
+                hir::ExprIf(cond_expr, then_expr, Some(else_expr))
+            }
+        }
+    }
+
     fn lower_item_kind(
         &mut self,
         id: NodeId,
@@ -2201,18 +2800,28 @@ impl<'a> LoweringContext<'a> {
                 let value = self.lower_body(None, |this| this.lower_expr(e));
                 hir::ItemConst(self.lower_ty(t, ImplTraitContext::Disallowed), value)
             }
-            ItemKind::Fn(ref decl, unsafety, constness, abi, ref generics, ref body) => {
+            ItemKind::Fn(ref decl, unsafety, asyncness, constness, abi, ref generics, ref body) => {
                 let fn_def_id = self.resolver.definitions().local_def_id(id);
                 self.with_new_scopes(|this| {
                     let body_id = this.lower_body(Some(decl), |this| {
-                        let body = this.lower_block(body, false);
-                        this.expr_block(body, ThinVec::new())
+                        if let IsAsync::Async { closure_id, .. } = asyncness {
+                            let async_expr = this.make_async_expr(
+                                CaptureBy::Value, closure_id, |this| {
+                                    let body = this.lower_block(body, false);
+                                    this.expr_block(body, ThinVec::new())
+                                });
+                            this.expr(body.span, async_expr, ThinVec::new())
+                        } else {
+                            let body = this.lower_block(body, false);
+                            this.expr_block(body, ThinVec::new())
+                        }
                     });
                     let (generics, fn_decl) = this.add_in_band_defs(
                         generics,
                         fn_def_id,
                         AnonymousLifetimeMode::PassThrough,
-                        |this| this.lower_fn_decl(decl, Some(fn_def_id), true),
+                        |this| this.lower_fn_decl(
+                            decl, Some(fn_def_id), true, asyncness.opt_return_id()),
                     );
 
                     hir::ItemFn(
@@ -2229,7 +2838,7 @@ impl<'a> LoweringContext<'a> {
             ItemKind::ForeignMod(ref nm) => hir::ItemForeignMod(self.lower_foreign_mod(nm)),
             ItemKind::GlobalAsm(ref ga) => hir::ItemGlobalAsm(self.lower_global_asm(ga)),
             ItemKind::Ty(ref t, ref generics) => hir::ItemTy(
-                self.lower_ty(t, ImplTraitContext::Disallowed),
+                self.lower_ty(t, Self::top_level_impl_trait_context(t)),
                 self.lower_generics(generics, ImplTraitContext::Disallowed),
             ),
             ItemKind::Enum(ref enum_definition, ref generics) => hir::ItemEnum(
@@ -2581,8 +3190,17 @@ impl<'a> LoweringContext<'a> {
                 }
                 ImplItemKind::Method(ref sig, ref body) => {
                     let body_id = this.lower_body(Some(&sig.decl), |this| {
-                        let body = this.lower_block(body, false);
-                        this.expr_block(body, ThinVec::new())
+                        if let IsAsync::Async { closure_id, .. } = sig.asyncness {
+                            let async_expr = this.make_async_expr(
+                                CaptureBy::Value, closure_id, |this| {
+                                    let body = this.lower_block(body, false);
+                                    this.expr_block(body, ThinVec::new())
+                                });
+                            this.expr(body.span, async_expr, ThinVec::new())
+                        } else {
+                            let body = this.lower_block(body, false);
+                            this.expr_block(body, ThinVec::new())
+                        }
                     });
                     let impl_trait_return_allow = !this.is_in_trait_impl;
 
@@ -2602,10 +3220,13 @@ impl<'a> LoweringContext<'a> {
                         },
                     )
                 }
-                ImplItemKind::Type(ref ty) => (
-                    this.lower_generics(&i.generics, ImplTraitContext::Disallowed),
-                    hir::ImplItemKind::Type(this.lower_ty(ty, ImplTraitContext::Disallowed)),
-                ),
+                ImplItemKind::Type(ref ty) => {
+                    let itctx = Self::top_level_impl_trait_context(ty);
+                    (
+                        this.lower_generics(&i.generics, ImplTraitContext::Disallowed),
+                        hir::ImplItemKind::Type(this.lower_ty(ty, itctx)),
+                    )
+                }
                 ImplItemKind::Macro(..) => panic!("Shouldn't exist any more"),
             };
 
@@ -2730,7 +3351,7 @@ impl<'a> LoweringContext<'a> {
                             |this| {
                                 (
                                     // Disallow impl Trait in foreign items
-                                    this.lower_fn_decl(fdec, None, false),
+                                    this.lower_fn_decl(fdec, None, false, None),
                                     this.lower_fn_args_to_names(fdec),
                                 )
                             },
@@ -2760,7 +3381,12 @@ impl<'a> LoweringContext<'a> {
             abi: sig.abi,
             unsafety: self.lower_unsafety(sig.unsafety),
             constness: self.lower_constness(sig.constness),
-            decl: self.lower_fn_decl(&sig.decl, Some(fn_def_id), impl_trait_return_allow),
+            decl: self.lower_fn_decl(
+                &sig.decl,
+                Some(fn_def_id),
+                impl_trait_return_allow,
+                sig.asyncness.opt_return_id(),
+            ),
         }
     }
 
@@ -2830,8 +3456,20 @@ impl<'a> LoweringContext<'a> {
                             Some(Def::Local(id)) => id,
                             _ => p.id,
                         };
+                        let (canonical_id, binding_mode) =
+                            match self.let_else_bindings.get(&canonical_id).cloned() {
+                                // The `let` of a `let...else` owns the mutability
+                                // of the binding, see `lower_let_else`.
+                                Some(fresh_id) => (fresh_id, match *binding_mode {
+                                    BindingMode::ByValue(_) => {
+                                        hir::BindingAnnotation::Unannotated
+                                    }
+                                    _ => self.lower_binding_mode(binding_mode),
+                                }),
+                                None => (canonical_id, self.lower_binding_mode(binding_mode)),
+                            };
                         hir::PatKind::Binding(
-                            self.lower_binding_mode(binding_mode),
+                            binding_mode,
                             canonical_id,
                             respan(ident.span, ident.name),
                             sub.as_ref().map(|x| self.lower_pat(x)),
@@ -2910,10 +3548,14 @@ impl<'a> LoweringContext<'a> {
                 after.iter().map(|x| self.lower_pat(x)).collect(),
             ),
             PatKind::Paren(ref inner) => return self.lower_pat(inner),
+            PatKind::Or(ref pats) => {
+                hir::PatKind::Or(pats.iter().map(|x| self.lower_pat(x)).collect())
+            }
             PatKind::Mac(_) => panic!("Shouldn't exist here"),
         };
 
-        let LoweredNodeId { node_id, hir_id } = self.lower_node_id(p.id);
+        let id = self.let_else_bindings.get(&p.id).cloned().unwrap_or(p.id);
+        let LoweredNodeId { node_id, hir_id } = self.lower_node_id(id);
         P(hir::Pat {
             id: node_id,
             hir_id,
@@ -2990,7 +3632,7 @@ impl<'a> LoweringContext<'a> {
             ExprKind::If(ref cond, ref blk, ref else_opt) => {
                 let else_opt = else_opt.as_ref().map(|els| {
                     match els.node {
-                        ExprKind::IfLet(..) => {
+                        ExprKind::IfLet(..) | ExprKind::IfLetChain(..) => {
                             // wrap the if-let expr in a block
                             let span = els.span;
                             let els = P(self.lower_expr(els));
@@ -3054,6 +3696,14 @@ impl<'a> LoweringContext<'a> {
                     hir::ExprBlock(P(block))
                 })
             }
+            ExprKind::Async(capture_clause, closure_node_id, ref block) => {
+                self.make_async_expr(capture_clause, closure_node_id, |this| {
+                    this.with_new_scopes(|this| {
+                        let block = this.lower_block(block, false);
+                        this.expr_block(block, ThinVec::new())
+                    })
+                })
+            }
             ExprKind::Match(ref expr, ref arms) => hir::ExprMatch(
                 P(self.lower_expr(expr)),
                 arms.iter().map(|x| self.lower_arm(x)).collect(),
@@ -3095,7 +3745,7 @@ impl<'a> LoweringContext<'a> {
                         };
                         hir::ExprClosure(
                             this.lower_capture_clause(capture_clause),
-                            this.lower_fn_decl(decl, None, false),
+                            this.lower_fn_decl(decl, None, false, None),
                             body_id,
                             fn_decl_span,
                             generator_option,
@@ -3311,6 +3961,60 @@ impl<'a> LoweringContext<'a> {
                 )
             }
 
+            // Desugar ExprIfLetChain
+            // From: `if <cond_1> && ... && <cond_n> <body> [else <else_opt>]`
+            ExprKind::IfLetChain(ref conds, ref body, ref else_opt) => {
+                // to, with a `match` for each `let <pat> = <sub_expr>` and an
+                // `if` for each other condition:
+                //
+                //   match <sub_expr_1> {
+                //     <pat_1> => if <cond_2> { ... <body> ... } else { () },
+                //     _ => ()
+                //   }
+                //
+                // With an `else`, every failing condition falls through to it
+                // and the innermost arm breaks out with the value of the body:
+                //
+                //   'chain: {
+                //     match <sub_expr_1> { <pat_1> => ... break 'chain <body> ..., _ => () };
+                //     <else_opt>
+                //   }
+                //
+                // As with nested `if let`s, the temporaries of each condition
+                // live until the end of the body.
+                let body = self.lower_block(body, false);
+                let body_expr = P(self.expr_block(body, ThinVec::new()));
+                let mut unit = |this: &mut Self, span| this.expr_tuple(span, hir_vec![]);
+                match *else_opt {
+                    None => self.lower_chain_conds(conds, e.span, body_expr, false, &mut unit),
+                    Some(ref else_expr) => {
+                        let LoweredNodeId { node_id, hir_id } = self.next_id();
+                        let break_expr = hir::ExprBreak(
+                            hir::Destination {
+                                label: None,
+                                target_id: hir::ScopeTarget::Block(node_id),
+                            },
+                            Some(body_expr),
+                        );
+                        let break_expr = P(self.expr(e.span, break_expr, ThinVec::new()));
+                        let chain =
+                            self.lower_chain_conds(conds, e.span, break_expr, false, &mut unit);
+                        let chain = P(self.expr(e.span, chain, ThinVec::new()));
+                        let stmt = respan(e.span, hir::StmtSemi(chain, self.next_id().node_id));
+                        hir::ExprBlock(P(hir::Block {
+                            stmts: hir_vec![stmt],
+                            expr: Some(P(self.lower_expr(else_expr))),
+                            id: node_id,
+                            hir_id,
+                            rules: hir::DefaultBlock,
+                            span: e.span,
+                            targeted_by_break: true,
+                            recovered: false,
+                        }))
+                    }
+                }
+            }
+
             // Desugar ExprWhileLet
             // From: `[opt_ident]: while let <pat> = <sub_expr> <body>`
             ExprKind::WhileLet(ref pats, ref sub_expr, ref body, opt_label) => {
@@ -3365,6 +4069,35 @@ impl<'a> LoweringContext<'a> {
                 loop_expr
             }
 
+            // Desugar ExprWhileLetChain
+            // From: `[opt_ident]: while <cond_1> && ... && <cond_n> <body>`
+            ExprKind::WhileLetChain(ref conds, ref body, opt_label) => {
+                // to:
+                //
+                //   [opt_ident]: loop {
+                //     match <sub_expr_1> {
+                //       <pat_1> => if <cond_2> { ... <body> ... } else { break },
+                //       _ => break
+                //     }
+                //   }
+                let chain = self.with_loop_scope(e.id, |this| {
+                    let body = this.lower_block(body, false);
+                    let body_expr = P(this.expr_block(body, ThinVec::new()));
+                    this.lower_chain_conds(conds, e.span, body_expr, true, &mut |this, span| {
+                        this.expr_break(span, ThinVec::new())
+                    })
+                });
+                let chain = P(self.expr(e.span, chain, ThinVec::new()));
+
+                // `[opt_ident]: loop { ... }`
+                let loop_block = P(self.block_expr(chain));
+                hir::ExprLoop(
+                    loop_block,
+                    self.lower_label(opt_label),
+                    hir::LoopSource::WhileLet,
+                )
+            }
+
             // Desugar ExprForLoop
             // From: `[opt_ident]: for <pat> in <head> <body>`
             ExprKind::ForLoop(ref pat, ref head, ref body, opt_label) => {
@@ -3627,6 +4360,9 @@ impl<'a> LoweringContext<'a> {
 
     fn lower_stmt(&mut self, s: &Stmt) -> SmallVector<hir::Stmt> {
         SmallVector::one(match s.node {
+            StmtKind::Local(ref l) if l.els.is_some() => {
+                return self.lower_let_else(s, l, l.els.as_ref().unwrap());
+            }
             StmtKind::Local(ref l) => Spanned {
                 node: hir::StmtDecl(
                     P(Spanned {
@@ -74,6 +74,43 @@ impl<'a> DefCollector<'a> {
         self.parent_def = parent;
     }
 
+    fn visit_async_fn(
+        &mut self,
+        id: NodeId,
+        name: Name,
+        span: Span,
+        asyncness: IsAsync,
+        generics: &'a Generics,
+        decl: &'a FnDecl,
+        body: &'a Block,
+    ) {
+        let (closure_id, return_impl_trait_id) = match asyncness {
+            IsAsync::Async { closure_id, return_impl_trait_id } => {
+                (closure_id, return_impl_trait_id)
+            }
+            IsAsync::NotAsync => bug!("visit_async_fn on a non-async fn"),
+        };
+
+        // For async functions, we need to create their inner defs inside of a
+        // closure to match their desugared representation.
+        let fn_def_data = DefPathData::ValueNs(name.as_str());
+        let fn_def = self.create_def(id, fn_def_data, ITEM_LIKE_SPACE, span);
+        self.with_parent(fn_def, |this| {
+            this.create_def(return_impl_trait_id, DefPathData::ImplTrait, REGULAR_SPACE, span);
+
+            visit::walk_generics(this, generics);
+            visit::walk_fn_decl(this, decl);
+
+            let closure_def = this.create_def(closure_id,
+                                              DefPathData::ClosureExpr,
+                                              REGULAR_SPACE,
+                                              span);
+            this.with_parent(closure_def, |this| {
+                visit::walk_block(this, body);
+            })
+        })
+    }
+
     pub fn visit_const_expr(&mut self, expr: &Expr) {
         match expr.node {
             // Find the node which will be used after lowering.
@@ -116,6 +153,12 @@ impl<'a> visit::Visitor<'a> for DefCollector<'a> {
                 return visit::walk_item(self, i);
             }
             ItemKind::Mod(..) => DefPathData::Module(i.ident.name.as_str()),
+            ItemKind::Fn(ref decl, _, asyncness, _, _, ref generics, ref body)
+                if asyncness.is_async() =>
+            {
+                return self.visit_async_fn(i.id, i.ident.name, i.span, asyncness,
+                                           generics, decl, body);
+            }
             ItemKind::Static(..) | ItemKind::Const(..) | ItemKind::Fn(..) =>
                 DefPathData::ValueNs(i.ident.name.as_str()),
             ItemKind::MacroDef(..) => DefPathData::MacroDef(i.ident.name.as_str()),
@@ -214,6 +257,14 @@ impl<'a> visit::Visitor<'a> for DefCollector<'a> {
                     ty_param.ident.span
                 );
             }
+            GenericParam::Const(ref const_param) => {
+                self.create_def(
+                    const_param.id,
+                    DefPathData::ConstParam(const_param.ident.name.as_str()),
+                    REGULAR_SPACE,
+                    const_param.ident.span
+                );
+            }
         }
 
         visit::walk_generic_param(self, param);
@@ -239,6 +290,12 @@ impl<'a> visit::Visitor<'a> for DefCollector<'a> {
 
     fn visit_impl_item(&mut self, ii: &'a ImplItem) {
         let def_data = match ii.node {
+            ImplItemKind::Method(MethodSig { asyncness, ref decl, .. }, ref body)
+                if asyncness.is_async() =>
+            {
+                return self.visit_async_fn(ii.id, ii.ident.name, ii.span, asyncness,
+                                           &ii.generics, decl, body);
+            }
             ImplItemKind::Method(..) | ImplItemKind::Const(..) =>
                 DefPathData::ValueNs(ii.ident.name.as_str()),
             ImplItemKind::Type(..) => DefPathData::AssocTypeInImpl(ii.ident.name.as_str()),
@@ -275,6 +332,13 @@ impl<'a> visit::Visitor<'a> for DefCollector<'a> {
                                           expr.span);
                 self.parent_def = Some(def);
             }
+            ExprKind::Async(_, async_id, _) => {
+                let async_def = self.create_def(async_id,
+                                                DefPathData::ClosureExpr,
+                                                REGULAR_SPACE,
+                                                expr.span);
+                self.parent_def = Some(async_def);
+            }
             _ => {}
         }
 
@@ -13,6 +13,7 @@
 pub use self::BinOp_::*;
 pub use self::BlockCheckMode::*;
 pub use self::CaptureClause::*;
+pub use self::ConstArg_::*;
 pub use self::Decl_::*;
 pub use self::Expr_::*;
 pub use self::FunctionRetTy::*;
@@ -377,6 +378,8 @@ pub struct PathParameters {
     pub lifetimes: HirVec<Lifetime>,
     /// The type parameters for this path segment, if present.
     pub types: HirVec<P<Ty>>,
+    /// The const parameters for this path segment, if present.
+    pub consts: HirVec<ConstArg>,
     /// Bindings (equality constraints) on associated types, if present.
     /// E.g., `Foo<A=Bar>`.
     pub bindings: HirVec<TypeBinding>,
@@ -391,13 +394,14 @@ impl PathParameters {
         Self {
             lifetimes: HirVec::new(),
             types: HirVec::new(),
+            consts: HirVec::new(),
             bindings: HirVec::new(),
             parenthesized: false,
         }
     }
 
     pub fn is_empty(&self) -> bool {
-        self.lifetimes.is_empty() && self.types.is_empty() &&
+        self.lifetimes.is_empty() && self.types.is_empty() && self.consts.is_empty() &&
             self.bindings.is_empty() && !self.parenthesized
     }
 
@@ -413,6 +417,22 @@ impl PathParameters {
     }
 }
 
+/// A const argument, e.g. the `3` in `Buf<3>` or the `N` in `Buf<N>`.
+#[derive(Clone, PartialEq, Eq, RustcEncodable, RustcDecodable, Hash, Debug)]
+pub struct ConstArg {
+    pub id: NodeId,
+    pub node: ConstArg_,
+    pub span: Span,
+}
+
+#[derive(Clone, PartialEq, Eq, RustcEncodable, RustcDecodable, Hash, Debug)]
+pub enum ConstArg_ {
+    /// A literal, e.g. `3` or `true`; the flag is set if it is negated, as in `-1`.
+    ConstArgLit(P<Lit>, bool),
+    /// A path to a const parameter or a constant, e.g. `N`.
+    ConstArgPath(P<Path>),
+}
+
 /// The AST represents all type param bounds as types.
 /// typeck::collect::compute_bounds matches these against
 /// the "special" built-in traits (see middle::lang_items) and
@@ -454,10 +474,21 @@ pub struct TyParam {
     pub attrs: HirVec<Attribute>,
 }
 
+/// A const parameter, e.g. the `const N: usize` in `struct Buf<const N: usize>`.
+#[derive(Clone, PartialEq, Eq, RustcEncodable, RustcDecodable, Hash, Debug)]
+pub struct ConstParam {
+    pub name: Name,
+    pub id: NodeId,
+    pub ty: P<Ty>,
+    pub span: Span,
+    pub attrs: HirVec<Attribute>,
+}
+
 #[derive(Clone, PartialEq, Eq, RustcEncodable, RustcDecodable, Hash, Debug)]
 pub enum GenericParam {
     Lifetime(LifetimeDef),
     Type(TyParam),
+    Const(ConstParam),
 }
 
 impl GenericParam {
@@ -474,6 +505,13 @@ impl GenericParam {
             _ => false,
         }
     }
+
+    pub fn is_const_param(&self) -> bool {
+        match *self {
+            GenericParam::Const(_) => true,
+            _ => false,
+        }
+    }
 }
 
 pub trait GenericParamsExt {
@@ -486,6 +524,11 @@ pub trait GenericParamsExt {
         slice::Iter<GenericParam>,
         fn(&GenericParam) -> Option<&TyParam>,
     >;
+
+    fn const_params<'a>(&'a self) -> iter::FilterMap<
+        slice::Iter<GenericParam>,
+        fn(&GenericParam) -> Option<&ConstParam>,
+    >;
 }
 
 impl GenericParamsExt for [GenericParam] {
@@ -508,6 +551,15 @@ impl GenericParamsExt for [GenericParam] {
             _ => None,
         })
     }
+    fn const_params<'a>(&'a self) -> iter::FilterMap<
+        slice::Iter<GenericParam>,
+        fn(&GenericParam) -> Option<&ConstParam>,
+    > {
+        self.iter().filter_map(|param| match *param {
+            GenericParam::Const(ref c) => Some(c),
+            _ => None,
+        })
+    }
 }
 
 /// Represents lifetimes and type parameters attached to a declaration
@@ -546,6 +598,10 @@ impl Generics {
     pub fn ty_params<'a>(&'a self) -> impl Iterator<Item = &'a TyParam> {
         self.params.ty_params()
     }
+
+    pub fn const_params<'a>(&'a self) -> impl Iterator<Item = &'a ConstParam> {
+        self.params.const_params()
+    }
 }
 
 pub enum UnsafeGeneric {
@@ -576,6 +632,7 @@ impl Generics {
                         return Some(UnsafeGeneric::Type(t.clone(), "may_dangle"));
                     }
                 }
+                GenericParam::Const(_) => {}
             }
         }
 
@@ -792,7 +849,7 @@ impl Pat {
             PatKind::Struct(_, ref fields, _) => {
                 fields.iter().all(|field| field.node.pat.walk_(it))
             }
-            PatKind::TupleStruct(_, ref s, _) | PatKind::Tuple(ref s, _) => {
+            PatKind::TupleStruct(_, ref s, _) | PatKind::Tuple(ref s, _) | PatKind::Or(ref s) => {
                 s.iter().all(|p| p.walk_(it))
             }
             PatKind::Box(ref s) | PatKind::Ref(ref s, _) => {
@@ -903,6 +960,9 @@ pub enum PatKind {
     /// `[a, b, ..i, y, z]` is represented as:
     ///     `PatKind::Slice(box [a, b], Some(i), box [y, z])`
     Slice(HirVec<P<Pat>>, Option<P<Pat>>, HirVec<P<Pat>>),
+    /// Alternatives nested inside another pattern, e.g. the `A | B` in `Some(A | B)`.
+    /// Bindings in different alternatives share their canonical ID, as in a `match` arm.
+    Or(HirVec<P<Pat>>),
 }
 
 #[derive(Clone, PartialEq, Eq, RustcEncodable, RustcDecodable, Hash, Debug, Copy)]
@@ -1445,6 +1505,8 @@ pub enum MatchSource {
     ForLoopDesugar,
     /// A desugared `?` operator
     TryDesugar,
+    /// A desugared `let PAT = EXPR else { .. };` and the rest of its block
+    LetElseDesugar,
 }
 
 /// The loop type that yielded an ExprLoop
@@ -2269,15 +2331,16 @@ pub struct TransFnAttrs {
 
 bitflags! {
     #[derive(RustcEncodable, RustcDecodable)]
-    pub struct TransFnAttrFlags: u8 {
-        const COLD                      = 0b0000_0001;
-        const ALLOCATOR                 = 0b0000_0010;
-        const UNWIND                    = 0b0000_0100;
-        const RUSTC_ALLOCATOR_NOUNWIND  = 0b0000_1000;
-        const NAKED                     = 0b0001_0000;
-        const NO_MANGLE                 = 0b0010_0000;
-        const RUSTC_STD_INTERNAL_SYMBOL = 0b0100_0000;
-        const NO_DEBUG                  = 0b1000_0000;
+    pub struct TransFnAttrFlags: u16 {
+        const COLD                      = 0b0000_0000_0001;
+        const ALLOCATOR                 = 0b0000_0000_0010;
+        const UNWIND                    = 0b0000_0000_0100;
+        const RUSTC_ALLOCATOR_NOUNWIND  = 0b0000_0000_1000;
+        const NAKED                     = 0b0000_0001_0000;
+        const NO_MANGLE                 = 0b0000_0010_0000;
+        const RUSTC_STD_INTERNAL_SYMBOL = 0b0000_0100_0000;
+        const NO_DEBUG                  = 0b0000_1000_0000;
+        const TRACK_CALLER              = 0b0001_0000_0000;
     }
 }
 
@@ -71,6 +71,7 @@ impl hir::Pat {
                 }
             }
             PatKind::Slice(..) => true,
+            PatKind::Or(ref pats) => pats.iter().any(|p| p.is_refutable()),
             _ => false
         }
     }
@@ -101,6 +102,22 @@ impl hir::Pat {
         });
     }
 
+    /// Like `each_binding`, but skips the bindings in the later alternatives of
+    /// a nested or-pattern, e.g. the second `a` in `Some(Ok(a) | Err(a))`, as
+    /// those bind the same variable as the first alternative.
+    pub fn each_canonical_binding<F>(&self, mut f: F)
+        where F: FnMut(hir::BindingAnnotation, ast::NodeId, Span, &Spanned<ast::Name>),
+    {
+        self.walk(|p| {
+            if let PatKind::Binding(binding_mode, canonical_id, ref pth, _) = p.node {
+                if canonical_id == p.id {
+                    f(binding_mode, p.id, p.span, pth);
+                }
+            }
+            true
+        });
+    }
+
     /// Checks if the pattern contains any patterns that bind something to
     /// an ident, e.g. `foo`, or `Foo(foo)` or `foo @ Bar(..)`.
     pub fn contains_bindings(&self) -> bool {
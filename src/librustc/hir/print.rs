@@ -1685,6 +1685,11 @@ impl<'a> State<'a> {
                 self.commasep(Inconsistent, &parameters.types, |s, ty| s.print_type(&ty))?;
             }
 
+            if !parameters.consts.is_empty() {
+                start_or_comma(self)?;
+                self.commasep(Inconsistent, &parameters.consts, |s, c| s.print_const_arg(c))?;
+            }
+
             // FIXME(eddyb) This would leak into error messages, e.g.:
             // "non-exhaustive patterns: `Some::<..>(_)` not covered".
             if infer_types && false {
@@ -1861,6 +1866,21 @@ impl<'a> State<'a> {
                 self.commasep(Inconsistent, &after[..], |s, p| s.print_pat(&p))?;
                 self.s.word("]")?;
             }
+            PatKind::Or(ref pats) => {
+                // Always parenthesized, there is no `Paren` pattern in the HIR.
+                self.popen()?;
+                let mut first = true;
+                for p in pats.iter() {
+                    if first {
+                        first = false;
+                    } else {
+                        self.s.space()?;
+                        self.word_space("|")?;
+                    }
+                    self.print_pat(&p)?;
+                }
+                self.pclose()?;
+            }
         }
         self.ann.post(self, NodePat(pat))
     }
@@ -2051,6 +2071,7 @@ impl<'a> State<'a> {
                 match *param {
                     hir::GenericParam::Lifetime(ref ld) => s.print_lifetime_def(ld),
                     hir::GenericParam::Type(ref tp) => s.print_ty_param(tp),
+                    hir::GenericParam::Const(ref cp) => s.print_const_param(cp),
                 }
             })?;
 
@@ -2072,6 +2093,25 @@ impl<'a> State<'a> {
         }
     }
 
+    pub fn print_const_param(&mut self, param: &hir::ConstParam) -> io::Result<()> {
+        self.word_space("const")?;
+        self.print_name(param.name)?;
+        self.word_space(":")?;
+        self.print_type(&param.ty)
+    }
+
+    pub fn print_const_arg(&mut self, arg: &hir::ConstArg) -> io::Result<()> {
+        match arg.node {
+            hir::ConstArgLit(ref lit, negated) => {
+                if negated {
+                    self.s.word("-")?;
+                }
+                self.print_literal(lit)
+            }
+            hir::ConstArgPath(ref path) => self.print_path(path, false),
+        }
+    }
+
     pub fn print_where_clause(&mut self, where_clause: &hir::WhereClause) -> io::Result<()> {
         if where_clause.predicates.is_empty() {
             return Ok(());
@@ -183,10 +183,22 @@ impl_stable_hash_for!(struct hir::PathSegment {
 impl_stable_hash_for!(struct hir::PathParameters {
     lifetimes,
     types,
+    consts,
     bindings,
     parenthesized
 });
 
+impl_stable_hash_for!(struct hir::ConstArg {
+    id,
+    node,
+    span
+});
+
+impl_stable_hash_for!(enum hir::ConstArg_ {
+    ConstArgLit(lit, negated),
+    ConstArgPath(path)
+});
+
 impl_stable_hash_for!(enum hir::TyParamBound {
     TraitTyParamBound(poly_trait_ref, trait_bound_modifier),
     RegionTyParamBound(lifetime)
@@ -208,9 +220,18 @@ impl_stable_hash_for!(struct hir::TyParam {
     attrs
 });
 
+impl_stable_hash_for!(struct hir::ConstParam {
+    name,
+    id,
+    ty,
+    span,
+    attrs
+});
+
 impl_stable_hash_for!(enum hir::GenericParam {
     Lifetime(lifetime_def),
-    Type(ty_param)
+    Type(ty_param),
+    Const(const_param)
 });
 
 impl_stable_hash_for!(struct hir::Generics {
@@ -461,7 +482,8 @@ impl_stable_hash_for!(enum hir::PatKind {
     Ref(sub, mutability),
     Lit(expr),
     Range(start, end, end_kind),
-    Slice(one, two, three)
+    Slice(one, two, three),
+    Or(pats)
 });
 
 impl_stable_hash_for!(enum hir::BinOp_ {
@@ -627,7 +649,8 @@ impl<'a> HashStable<StableHashingContext<'a>> for hir::MatchSource {
             MatchSource::Normal |
             MatchSource::WhileLetDesugar |
             MatchSource::ForLoopDesugar |
-            MatchSource::TryDesugar => {
+            MatchSource::TryDesugar |
+            MatchSource::LetElseDesugar => {
                 // No fields to hash.
             }
             MatchSource::IfLetDesugar { contains_else_clause } => {
@@ -1059,6 +1082,7 @@ impl_stable_hash_for!(enum hir::def::Def {
     VariantCtor(def_id, ctor_kind),
     Method(def_id),
     AssociatedConst(def_id),
+    ConstParam(def_id),
     Local(def_id),
     Upvar(def_id, index, expr_id),
     Label(node_id),
@@ -18,6 +18,8 @@ use rustc_data_structures::stable_hasher::{HashStable, StableHasher,
 use std::mem;
 
 impl_stable_hash_for!(struct mir::GeneratorLayout<'tcx> { fields });
+impl_stable_hash_for!(struct mir::CoverageInfo { hash, counters });
+impl_stable_hash_for!(struct mir::CoverageCounter { block, regions });
 impl_stable_hash_for!(struct mir::SourceInfo { span, scope });
 impl_stable_hash_for!(enum mir::Mutability { Mut, Not });
 impl_stable_hash_for!(enum mir::LocalKind { Var, Temp, Arg, ReturnPointer });
@@ -373,7 +373,8 @@ impl_stable_hash_for!(enum ::syntax_pos::hygiene::ExpnFormat {
 impl_stable_hash_for!(enum ::syntax_pos::hygiene::CompilerDesugaringKind {
     DotFill,
     QuestionMark,
-    Catch
+    Catch,
+    Async
 });
 
 impl_stable_hash_for!(enum ::syntax_pos::FileName {
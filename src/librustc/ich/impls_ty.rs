@@ -84,6 +84,7 @@ for ty::subst::UnpackedKind<'gcx> {
         match self {
             ty::subst::UnpackedKind::Lifetime(lt) => lt.hash_stable(hcx, hasher),
             ty::subst::UnpackedKind::Type(ty) => ty.hash_stable(hcx, hasher),
+            ty::subst::UnpackedKind::Const(ct) => ct.hash_stable(hcx, hasher),
         }
     }
 }
@@ -244,6 +245,13 @@ for ty::UpvarCapture<'gcx> {
     }
 }
 
+impl_stable_hash_for!(enum ty::CaptureProjection {
+    Deref,
+    Field(index, name)
+});
+
+impl_stable_hash_for!(struct ty::CapturedPlace<'tcx> { projections, capture });
+
 impl_stable_hash_for!(struct ty::GenSig<'tcx> {
     yield_ty,
     return_ty
@@ -380,6 +388,12 @@ for ::middle::const_val::ConstVal<'gcx> {
             Value(ref value) => {
                 value.hash_stable(hcx, hasher);
             }
+            Param(param) => {
+                param.hash_stable(hcx, hasher);
+            }
+            Infer(infer) => {
+                infer.hash_stable(hcx, hasher);
+            }
         }
     }
 }
@@ -551,6 +565,7 @@ for ::mir::interpret::EvalError<'gcx> {
             ReadUndefBytes |
             DeadLocal |
             StackFrameLimitReached |
+            StepLimitReached |
             OutOfTls |
             TlsOutOfBounds |
             CalledClosureAsFunction |
@@ -566,6 +581,7 @@ for ::mir::interpret::EvalError<'gcx> {
             ReadFromReturnPointer |
             UnimplementedTraitSelection |
             TypeckError |
+            TooGeneric |
             DerefFunctionPointer |
             ExecuteMemory |
             ReferencedConstant |
@@ -707,12 +723,15 @@ impl<'a> HashStable<StableHashingContext<'a>> for ty::Generics {
             parent,
             parent_regions,
             parent_types,
+            parent_consts,
             ref regions,
             ref types,
+            ref consts,
 
             // Reverse map to each `TypeParameterDef`'s `index` field, from
             // `def_id.index` (`def_id.krate` is the same as the item's).
             type_param_to_index: _, // Don't hash this
+            const_param_to_index: _, // Don't hash this either
             has_self,
             has_late_bound_regions,
         } = *self;
@@ -720,8 +739,10 @@ impl<'a> HashStable<StableHashingContext<'a>> for ty::Generics {
         parent.hash_stable(hcx, hasher);
         parent_regions.hash_stable(hcx, hasher);
         parent_types.hash_stable(hcx, hasher);
+        parent_consts.hash_stable(hcx, hasher);
         regions.hash_stable(hcx, hasher);
         types.hash_stable(hcx, hasher);
+        consts.hash_stable(hcx, hasher);
         has_self.hash_stable(hcx, hasher);
         has_late_bound_regions.hash_stable(hcx, hasher);
     }
@@ -756,6 +777,12 @@ impl_stable_hash_for!(struct ty::TypeParameterDef {
     synthetic
 });
 
+impl_stable_hash_for!(struct ty::ConstParameterDef {
+    name,
+    def_id,
+    index
+});
+
 impl<'a, 'gcx, T> HashStable<StableHashingContext<'a>>
 for ::middle::resolve_lifetime::Set1<T>
     where T: HashStable<StableHashingContext<'a>>
@@ -983,6 +1010,38 @@ impl_stable_hash_for!(struct ty::ParamTy {
     name
 });
 
+impl_stable_hash_for!(struct ty::ParamConst {
+    idx,
+    name
+});
+
+impl<'a, 'tcx> HashStable<StableHashingContext<'a>>
+for ty::InferConst<'tcx>
+{
+    fn hash_stable<W: StableHasherResult>(&self,
+                                          hcx: &mut StableHashingContext<'a>,
+                                          hasher: &mut StableHasher<W>) {
+        mem::discriminant(self).hash_stable(hcx, hasher);
+
+        match *self {
+            ty::InferConst::Var(vid) => vid.hash_stable(hcx, hasher),
+            ty::InferConst::Fresh(n) => n.hash_stable(hcx, hasher),
+        }
+    }
+}
+
+impl<'a, 'tcx> HashStable<StableHashingContext<'a>>
+for ty::ConstVid<'tcx>
+{
+    fn hash_stable<W: StableHasherResult>(&self,
+                                          _hcx: &mut StableHashingContext<'a>,
+                                          _hasher: &mut StableHasher<W>) {
+        // ConstVid values are confined to an inference context and hence
+        // should not be hashed.
+        bug!("ty::ConstVal::hash_stable() - can't hash a ConstVid {:?}.", *self)
+    }
+}
+
 impl_stable_hash_for!(struct ty::TypeAndMut<'tcx> {
     ty,
     mutbl
@@ -1038,6 +1097,9 @@ impl<'a, 'gcx> HashStable<StableHashingContext<'a>> for ty::InstanceDef<'gcx> {
             ty::InstanceDef::Intrinsic(def_id) => {
                 def_id.hash_stable(hcx, hasher);
             }
+            ty::InstanceDef::ReifyShim(def_id) => {
+                def_id.hash_stable(hcx, hasher);
+            }
             ty::InstanceDef::FnPtrShim(def_id, ty) => {
                 def_id.hash_stable(hcx, hasher);
                 ty.hash_stable(hcx, hasher);
@@ -8,6 +8,7 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use hir;
 use hir::def_id::DefId;
 use infer::{self, InferCtxt, InferOk, TypeVariableOrigin};
 use infer::outlives::free_region_map::FreeRegionRelations;
@@ -632,6 +633,28 @@ impl<'cx, 'gcx, 'tcx> TypeFolder<'gcx, 'tcx> for ReverseMapper<'cx, 'gcx, 'tcx>
                 self.tcx.mk_closure(def_id, ty::ClosureSubsts { substs })
             }
 
+            ty::TyParam(..) => {
+                // Type parameters of the defining function must map onto
+                // parameters of the `impl Trait` type alias, otherwise the
+                // concrete type could not be expressed outside the function.
+                match self.map.get(&ty.into()).map(|k| k.unpack()) {
+                    Some(UnpackedKind::Type(t1)) => t1,
+                    Some(u) => bug!("type mapped to unexpected kind: {:?}", u),
+                    None => {
+                        if !self.tainted_by_errors {
+                            let span = self.tcx.def_span(self.anon_type_def_id);
+                            self.tcx.sess.span_err(
+                                span,
+                                &format!("type parameter `{}` is part of concrete type but not \
+                                          used in parameter list for the `impl Trait` type alias",
+                                         ty),
+                            );
+                        }
+                        self.tcx.types.err
+                    }
+                }
+            }
+
             _ => ty.super_fold_with(self),
         }
     }
@@ -691,7 +714,8 @@ impl<'a, 'gcx, 'tcx> Instantiator<'a, 'gcx, 'tcx> {
                     if let Some(anon_node_id) = tcx.hir.as_local_node_id(def_id) {
                         let anon_parent_node_id = tcx.hir.get_parent(anon_node_id);
                         let anon_parent_def_id = tcx.hir.local_def_id(anon_parent_node_id);
-                        if self.parent_def_id == anon_parent_def_id {
+                        if self.parent_def_id == anon_parent_def_id ||
+                           may_define_existential_type(tcx, self.parent_def_id, anon_node_id) {
                             return self.fold_anon_ty(ty, def_id, substs);
                         }
 
@@ -769,3 +793,49 @@ impl<'a, 'gcx, 'tcx> Instantiator<'a, 'gcx, 'tcx> {
         ty_var
     }
 }
+
+/// Whether `def_id` is allowed to constrain the concrete type of the
+/// `impl Trait` with node id `anon_node_id` when that `impl Trait` is the
+/// type of a type alias or of an associated type in an impl:
+///
+/// ```text
+/// pub mod foo {
+///     pub mod bar {
+///         pub type Baz = impl Debug;
+///         // `baz` may define `Baz`
+///         fn baz() -> Baz { 5i32 }
+///     }
+///     // `foo` is outside of the scope of `Baz` and may not define it
+///     fn foo() -> bar::Baz { bar::baz() }
+/// }
+/// ```
+///
+/// The defining scope is the module (or impl) containing the alias; any
+/// item nested inside of it may define the concrete type.
+pub fn may_define_existential_type(
+    tcx: TyCtxt,
+    def_id: DefId,
+    anon_node_id: ast::NodeId,
+) -> bool {
+    use hir::map::{NodeImplItem, NodeItem};
+
+    let alias_node_id = tcx.hir.get_parent(anon_node_id);
+    let is_alias = match tcx.hir.find(alias_node_id) {
+        Some(NodeItem(&hir::Item { node: hir::ItemTy(..), .. })) |
+        Some(NodeImplItem(&hir::ImplItem { node: hir::ImplItemKind::Type(..), .. })) => true,
+        _ => false,
+    };
+    if !is_alias {
+        return false;
+    }
+
+    let mut node_id = match tcx.hir.as_local_node_id(def_id) {
+        Some(node_id) => node_id,
+        None => return false,
+    };
+    let scope_node_id = tcx.hir.get_parent(alias_node_id);
+    while node_id != scope_node_id && node_id != ast::CRATE_NODE_ID {
+        node_id = tcx.hir.get_parent(node_id);
+    }
+    node_id == scope_node_id
+}
@@ -32,6 +32,7 @@
 //! [c]: https://rust-lang-nursery.github.io/rustc-guide/traits-canonicalization.html
 
 use infer::{InferCtxt, InferOk, InferResult, RegionVariableOrigin, TypeVariableOrigin};
+use middle::const_val::ConstVal;
 use rustc_data_structures::indexed_vec::Idx;
 use serialize::UseSpecializedDecodable;
 use std::fmt::Debug;
@@ -41,6 +42,7 @@ use syntax::codemap::Span;
 use traits::{Obligation, ObligationCause, PredicateObligation};
 use ty::{self, CanonicalVar, Lift, Region, Slice, Ty, TyCtxt, TypeFlags};
 use ty::subst::{Kind, UnpackedKind};
+use ty::flags::FlagComputation;
 use ty::fold::{TypeFoldable, TypeFolder};
 
 use rustc_data_structures::indexed_vec::IndexVec;
@@ -325,6 +327,10 @@ impl<'cx, 'gcx, 'tcx> InferCtxt<'cx, 'gcx, 'tcx> {
                         opt_values[index] = Some(original_value);
                     }
                 }
+                UnpackedKind::Const(_) => {
+                    // Const inference variables are never canonicalized,
+                    // so there is nothing to record here.
+                }
             }
         }
 
@@ -399,6 +405,9 @@ impl<'cx, 'gcx, 'tcx> InferCtxt<'cx, 'gcx, 'tcx> {
                         ty::Predicate::TypeOutlives(
                             ty::Binder::dummy(ty::OutlivesPredicate(t1, r2))),
                     ),
+
+                UnpackedKind::Const(c1) =>
+                    bug!("const `{:?}` in outlives constraint", c1),
             }
         })) as Box<dyn Iterator<Item = _>>
     }
@@ -633,6 +642,32 @@ impl<'cx, 'gcx, 'tcx> TypeFolder<'gcx, 'tcx> for Canonicalizer<'cx, 'gcx, 'tcx>
             }
         }
     }
+
+    fn fold_const(&mut self, c: &'tcx ty::Const<'tcx>) -> &'tcx ty::Const<'tcx> {
+        match c.val {
+            ConstVal::Infer(ty::InferConst::Var(_)) => {
+                let infcx = self.infcx.expect("encountered const-var without infcx");
+                let bound_to = infcx.shallow_resolve_const(c);
+                if bound_to != c {
+                    self.fold_const(bound_to)
+                } else {
+                    bug!("encountered an unresolved const variable during canonicalization")
+                }
+            }
+
+            ConstVal::Infer(ty::InferConst::Fresh(_)) => {
+                bug!("encountered a fresh const during canonicalization")
+            }
+
+            _ => {
+                if FlagComputation::for_const(c).intersects(self.needs_canonical_flags) {
+                    c.super_fold_with(self)
+                } else {
+                    c
+                }
+            }
+        }
+    }
 }
 
 impl<'cx, 'gcx, 'tcx> Canonicalizer<'cx, 'gcx, 'tcx> {
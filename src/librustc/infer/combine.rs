@@ -40,6 +40,7 @@ use super::sub::Sub;
 use super::type_variable::TypeVariableValue;
 
 use hir::def_id::DefId;
+use middle::const_val::ConstVal;
 use ty::{IntType, UintType};
 use ty::{self, Ty, TyCtxt};
 use ty::error::TypeError;
@@ -124,6 +125,58 @@ impl<'infcx, 'gcx, 'tcx> InferCtxt<'infcx, 'gcx, 'tcx> {
         }
     }
 
+    pub fn super_combine_consts<R>(&self,
+                                   relation: &mut R,
+                                   a: &'tcx ty::Const<'tcx>,
+                                   b: &'tcx ty::Const<'tcx>)
+                                   -> RelateResult<'tcx, &'tcx ty::Const<'tcx>>
+        where R: TypeRelation<'infcx, 'gcx, 'tcx>
+    {
+        let a_is_expected = relation.a_is_expected();
+        let a = self.shallow_resolve_const(a);
+        let b = self.shallow_resolve_const(b);
+
+        match (a.val, b.val) {
+            (ConstVal::Infer(ty::InferConst::Var(a_vid)),
+             ConstVal::Infer(ty::InferConst::Var(b_vid))) => {
+                self.const_unification_table
+                    .borrow_mut()
+                    .unify_var_var(a_vid, b_vid)
+                    .map_err(|e| const_unification_error(a_is_expected, e))?;
+                Ok(a)
+            }
+            (ConstVal::Infer(ty::InferConst::Var(vid)), _) => {
+                self.unify_const_variable(a_is_expected, vid, b)
+            }
+            (_, ConstVal::Infer(ty::InferConst::Var(vid))) => {
+                self.unify_const_variable(!a_is_expected, vid, a)
+            }
+
+            // All other cases of inference are errors
+            (ConstVal::Infer(_), _) |
+            (_, ConstVal::Infer(_)) => {
+                Err(TypeError::ConstMismatch(ty::relate::expected_found(relation, &a, &b)))
+            }
+
+            _ => {
+                ty::relate::super_relate_consts(relation, a, b)
+            }
+        }
+    }
+
+    fn unify_const_variable(&self,
+                            vid_is_expected: bool,
+                            vid: ty::ConstVid<'tcx>,
+                            val: &'tcx ty::Const<'tcx>)
+                            -> RelateResult<'tcx, &'tcx ty::Const<'tcx>>
+    {
+        self.const_unification_table
+            .borrow_mut()
+            .unify_var_value(vid, Some(val))
+            .map_err(|e| const_unification_error(vid_is_expected, e))?;
+        Ok(val)
+    }
+
     fn unify_integral_variable(&self,
                                vid_is_expected: bool,
                                vid: ty::IntVid,
@@ -446,6 +499,26 @@ impl<'cx, 'gcx, 'tcx> TypeRelation<'cx, 'gcx, 'tcx> for Generalizer<'cx, 'gcx, '
         }
     }
 
+    fn consts(&mut self, c: &'tcx ty::Const<'tcx>, c2: &'tcx ty::Const<'tcx>)
+              -> RelateResult<'tcx, &'tcx ty::Const<'tcx>> {
+        assert_eq!(c, c2); // we are abusing TypeRelation here; both LHS and RHS ought to be ==
+
+        match c.val {
+            ConstVal::Infer(ty::InferConst::Var(vid)) => {
+                // Consts are always invariant, so an unresolved
+                // variable can be kept as it is.
+                let opt_ct = self.infcx.const_unification_table.borrow_mut().probe_value(vid);
+                match opt_ct {
+                    Some(u) => self.relate(&u, &u),
+                    None => Ok(c),
+                }
+            }
+            _ => {
+                relate::super_relate_consts(self, c, c)
+            }
+        }
+    }
+
     fn regions(&mut self, r: ty::Region<'tcx>, r2: ty::Region<'tcx>)
                -> RelateResult<'tcx, ty::Region<'tcx>> {
         assert_eq!(r, r2); // we are abusing TypeRelation here; both LHS and RHS ought to be ==
@@ -518,6 +591,14 @@ fn int_unification_error<'tcx>(a_is_expected: bool, v: (ty::IntVarValue, ty::Int
     TypeError::IntMismatch(ty::relate::expected_found_bool(a_is_expected, &a, &b))
 }
 
+fn const_unification_error<'tcx>(a_is_expected: bool,
+                                 v: (&'tcx ty::Const<'tcx>, &'tcx ty::Const<'tcx>))
+                                 -> TypeError<'tcx>
+{
+    let (a, b) = v;
+    TypeError::ConstMismatch(ty::relate::expected_found_bool(a_is_expected, &a, &b))
+}
+
 fn float_unification_error<'tcx>(a_is_expected: bool,
                                  v: (ty::FloatVarValue, ty::FloatVarValue))
                                  -> TypeError<'tcx>
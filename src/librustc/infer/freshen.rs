@@ -41,6 +41,7 @@
 //! variable only once, and it does so as soon as it can, so it is reasonable to ask what the type
 //! inferencer knows "so far".
 
+use middle::const_val::ConstVal;
 use ty::{self, Ty, TyCtxt, TypeFoldable};
 use ty::fold::TypeFolder;
 use util::nodemap::FxHashMap;
@@ -54,6 +55,7 @@ pub struct TypeFreshener<'a, 'gcx: 'a+'tcx, 'tcx: 'a> {
     infcx: &'a InferCtxt<'a, 'gcx, 'tcx>,
     freshen_count: u32,
     freshen_map: FxHashMap<ty::InferTy, Ty<'tcx>>,
+    const_freshen_map: FxHashMap<ty::InferConst<'tcx>, &'tcx ty::Const<'tcx>>,
 }
 
 impl<'a, 'gcx, 'tcx> TypeFreshener<'a, 'gcx, 'tcx> {
@@ -63,6 +65,7 @@ impl<'a, 'gcx, 'tcx> TypeFreshener<'a, 'gcx, 'tcx> {
             infcx,
             freshen_count: 0,
             freshen_map: FxHashMap(),
+            const_freshen_map: FxHashMap(),
         }
     }
 
@@ -202,4 +205,40 @@ impl<'a, 'gcx, 'tcx> TypeFolder<'gcx, 'tcx> for TypeFreshener<'a, 'gcx, 'tcx> {
             }
         }
     }
+
+    fn fold_const(&mut self, c: &'tcx ty::Const<'tcx>) -> &'tcx ty::Const<'tcx> {
+        match c.val {
+            ConstVal::Infer(ty::InferConst::Var(v)) => {
+                let opt_ct = self.infcx.const_unification_table.borrow_mut().probe_value(v);
+                if let Some(ct) = opt_ct {
+                    return ct.fold_with(self);
+                }
+
+                let key = ty::InferConst::Var(v);
+                match self.const_freshen_map.entry(key) {
+                    Entry::Occupied(entry) => *entry.get(),
+                    Entry::Vacant(entry) => {
+                        let index = self.freshen_count;
+                        self.freshen_count += 1;
+                        let ct = self.infcx.tcx.mk_const_infer(ty::InferConst::Fresh(index),
+                                                               c.ty);
+                        entry.insert(ct);
+                        ct
+                    }
+                }
+            }
+
+            ConstVal::Infer(ty::InferConst::Fresh(i)) => {
+                if i >= self.freshen_count {
+                    bug!("Encountered a freshend const with id {} \
+                          but our counter is only at {}",
+                         i,
+                         self.freshen_count);
+                }
+                c
+            }
+
+            _ => c.super_fold_with(self),
+        }
+    }
 }
@@ -21,8 +21,9 @@ use hir::def_id::DefId;
 use middle::free_region::RegionRelations;
 use middle::region;
 use middle::lang_items;
+use middle::const_val::ConstVal;
 use ty::subst::Substs;
-use ty::{TyVid, IntVid, FloatVid};
+use ty::{TyVid, IntVid, FloatVid, ConstVid};
 use ty::{self, Ty, TyCtxt};
 use ty::error::{ExpectedFound, TypeError, UnconstrainedNumeric};
 use ty::fold::TypeFoldable;
@@ -106,6 +107,9 @@ pub struct InferCtxt<'a, 'gcx: 'a+'tcx, 'tcx: 'a> {
     // Map from floating variable to the kind of float it represents
     float_unification_table: RefCell<ut::UnificationTable<ut::InPlace<ty::FloatVid>>>,
 
+    // Map from const variable to the value of the const argument it stands for
+    const_unification_table: RefCell<ut::UnificationTable<ut::InPlace<ty::ConstVid<'tcx>>>>,
+
     // Tracks the set of region variables and the constraints between
     // them.  This is initially `Some(_)` but when
     // `resolve_regions_and_report_errors` is invoked, this gets set
@@ -373,7 +377,8 @@ pub enum NLLRegionVariableOrigin {
 pub enum FixupError {
     UnresolvedIntTy(IntVid),
     UnresolvedFloatTy(FloatVid),
-    UnresolvedTy(TyVid)
+    UnresolvedTy(TyVid),
+    UnresolvedConst,
 }
 
 /// See the `region_obligations` field for more information.
@@ -397,7 +402,8 @@ impl fmt::Display for FixupError {
                 write!(f, "cannot determine the type of this number; \
                            add a suffix to specify the type explicitly")
             }
-            UnresolvedTy(_) => write!(f, "unconstrained type")
+            UnresolvedTy(_) => write!(f, "unconstrained type"),
+            UnresolvedConst => write!(f, "unconstrained const value"),
         }
     }
 }
@@ -446,6 +452,7 @@ impl<'a, 'gcx, 'tcx> InferCtxtBuilder<'a, 'gcx, 'tcx> {
             type_variables: RefCell::new(type_variable::TypeVariableTable::new()),
             int_unification_table: RefCell::new(ut::UnificationTable::new()),
             float_unification_table: RefCell::new(ut::UnificationTable::new()),
+            const_unification_table: RefCell::new(ut::UnificationTable::new()),
             region_constraints: RefCell::new(Some(RegionConstraintCollector::new())),
             lexical_region_resolutions: RefCell::new(None),
             selection_cache: traits::SelectionCache::new(),
@@ -487,6 +494,7 @@ pub struct CombinedSnapshot<'a, 'tcx:'a> {
     type_snapshot: type_variable::Snapshot<'tcx>,
     int_snapshot: ut::Snapshot<ut::InPlace<ty::IntVid>>,
     float_snapshot: ut::Snapshot<ut::InPlace<ty::FloatVid>>,
+    const_snapshot: ut::Snapshot<ut::InPlace<ty::ConstVid<'tcx>>>,
     region_constraints_snapshot: RegionSnapshot,
     region_obligations_snapshot: usize,
     was_in_snapshot: bool,
@@ -616,6 +624,7 @@ impl<'a, 'gcx, 'tcx> InferCtxt<'a, 'gcx, 'tcx> {
             type_snapshot: self.type_variables.borrow_mut().snapshot(),
             int_snapshot: self.int_unification_table.borrow_mut().snapshot(),
             float_snapshot: self.float_unification_table.borrow_mut().snapshot(),
+            const_snapshot: self.const_unification_table.borrow_mut().snapshot(),
             region_constraints_snapshot: self.borrow_region_constraints().start_snapshot(),
             region_obligations_snapshot: self.region_obligations.borrow().len(),
             was_in_snapshot: in_snapshot,
@@ -633,6 +642,7 @@ impl<'a, 'gcx, 'tcx> InferCtxt<'a, 'gcx, 'tcx> {
                                type_snapshot,
                                int_snapshot,
                                float_snapshot,
+                               const_snapshot,
                                region_constraints_snapshot,
                                region_obligations_snapshot,
                                was_in_snapshot,
@@ -652,6 +662,9 @@ impl<'a, 'gcx, 'tcx> InferCtxt<'a, 'gcx, 'tcx> {
         self.float_unification_table
             .borrow_mut()
             .rollback_to(float_snapshot);
+        self.const_unification_table
+            .borrow_mut()
+            .rollback_to(const_snapshot);
         self.region_obligations
             .borrow_mut()
             .truncate(region_obligations_snapshot);
@@ -665,6 +678,7 @@ impl<'a, 'gcx, 'tcx> InferCtxt<'a, 'gcx, 'tcx> {
                                type_snapshot,
                                int_snapshot,
                                float_snapshot,
+                               const_snapshot,
                                region_constraints_snapshot,
                                region_obligations_snapshot: _,
                                was_in_snapshot,
@@ -684,6 +698,9 @@ impl<'a, 'gcx, 'tcx> InferCtxt<'a, 'gcx, 'tcx> {
         self.float_unification_table
             .borrow_mut()
             .commit(float_snapshot);
+        self.const_unification_table
+            .borrow_mut()
+            .commit(const_snapshot);
         self.borrow_region_constraints()
             .commit(region_constraints_snapshot);
     }
@@ -864,6 +881,16 @@ impl<'a, 'gcx, 'tcx> InferCtxt<'a, 'gcx, 'tcx> {
             .new_key(None)
     }
 
+    pub fn next_const_var_id(&self) -> ConstVid<'tcx> {
+        self.const_unification_table
+            .borrow_mut()
+            .new_key(None)
+    }
+
+    pub fn next_const_var(&self, ty: Ty<'tcx>) -> &'tcx ty::Const<'tcx> {
+        self.tcx.mk_const_var(self.next_const_var_id(), ty)
+    }
+
     /// Create a fresh region variable with the next available index.
     ///
     /// # Parameters
@@ -915,8 +942,14 @@ impl<'a, 'gcx, 'tcx> InferCtxt<'a, 'gcx, 'tcx> {
         self.tcx.mk_var(ty_var_id)
     }
 
+    /// Create a const inference variable for the given
+    /// const parameter definition.
+    pub fn const_var_for_def(&self, def: &ty::ConstParameterDef) -> &'tcx ty::Const<'tcx> {
+        self.next_const_var(self.tcx.type_of(def.def_id))
+    }
+
     /// Given a set of generics defined on a type or impl, returns a substitution mapping each
-    /// type/region parameter to a fresh inference variable.
+    /// type/region/const parameter to a fresh inference variable.
     pub fn fresh_substs_for_item(&self,
                                  span: Span,
                                  def_id: DefId)
@@ -925,6 +958,8 @@ impl<'a, 'gcx, 'tcx> InferCtxt<'a, 'gcx, 'tcx> {
             self.region_var_for_def(span, def)
         }, |def, _| {
             self.type_var_for_def(span, def)
+        }, |def, _| {
+            self.const_var_for_def(def)
         })
     }
 
@@ -1120,6 +1155,22 @@ impl<'a, 'gcx, 'tcx> InferCtxt<'a, 'gcx, 'tcx> {
         }
     }
 
+    pub fn shallow_resolve_const(&self, ct: &'tcx ty::Const<'tcx>) -> &'tcx ty::Const<'tcx> {
+        match ct.val {
+            ConstVal::Infer(ty::InferConst::Var(v)) => {
+                self.const_unification_table
+                    .borrow_mut()
+                    .probe_value(v)
+                    .map(|c| self.shallow_resolve_const(c))
+                    .unwrap_or(ct)
+            }
+
+            _ => {
+                ct
+            }
+        }
+    }
+
     pub fn resolve_type_vars_if_possible<T>(&self, value: &T) -> T
         where T: TypeFoldable<'tcx>
     {
@@ -9,6 +9,7 @@
 // except according to those terms.
 
 use super::{InferCtxt, FixupError, FixupResult};
+use middle::const_val::ConstVal;
 use ty::{self, Ty, TyCtxt, TypeFoldable};
 use ty::fold::{TypeFolder, TypeVisitor};
 
@@ -43,6 +44,15 @@ impl<'a, 'gcx, 'tcx> TypeFolder<'gcx, 'tcx> for OpportunisticTypeResolver<'a, 'g
             t0.super_fold_with(self)
         }
     }
+
+    fn fold_const(&mut self, c: &'tcx ty::Const<'tcx>) -> &'tcx ty::Const<'tcx> {
+        if !c.has_infer_types() {
+            c
+        } else {
+            let c0 = self.infcx.shallow_resolve_const(c);
+            c0.super_fold_with(self)
+        }
+    }
 }
 
 /// The opportunistic type and region resolver is similar to the
@@ -72,6 +82,15 @@ impl<'a, 'gcx, 'tcx> TypeFolder<'gcx, 'tcx> for OpportunisticTypeAndRegionResolv
         }
     }
 
+    fn fold_const(&mut self, c: &'tcx ty::Const<'tcx>) -> &'tcx ty::Const<'tcx> {
+        if !c.needs_infer() {
+            c
+        } else {
+            let c0 = self.infcx.shallow_resolve_const(c);
+            c0.super_fold_with(self)
+        }
+    }
+
     fn fold_region(&mut self, r: ty::Region<'tcx>) -> ty::Region<'tcx> {
         match *r {
             ty::ReVar(rid) =>
@@ -118,6 +137,15 @@ impl<'a, 'gcx, 'tcx> TypeVisitor<'tcx> for UnresolvedTypeFinder<'a, 'gcx, 'tcx>
             false
         }
     }
+
+    fn visit_const(&mut self, c: &'tcx ty::Const<'tcx>) -> bool {
+        let c = self.infcx.shallow_resolve_const(c);
+        if let ConstVal::Infer(_) = c.val {
+            true
+        } else {
+            c.super_visit_with(self)
+        }
+    }
 }
 
 ///////////////////////////////////////////////////////////////////////////
@@ -180,6 +208,26 @@ impl<'a, 'gcx, 'tcx> TypeFolder<'gcx, 'tcx> for FullTypeResolver<'a, 'gcx, 'tcx>
         }
     }
 
+    fn fold_const(&mut self, c: &'tcx ty::Const<'tcx>) -> &'tcx ty::Const<'tcx> {
+        if !c.needs_infer() && !ty::keep_local(&c) {
+            c
+        } else {
+            let c = self.infcx.shallow_resolve_const(c);
+            match c.val {
+                ConstVal::Infer(ty::InferConst::Var(_)) => {
+                    self.err = Some(FixupError::UnresolvedConst);
+                    self.tcx().mk_const_err()
+                }
+                ConstVal::Infer(_) => {
+                    bug!("Unexpected const in full type resolver: {:?}", c);
+                }
+                _ => {
+                    c.super_fold_with(self)
+                }
+            }
+        }
+    }
+
     fn fold_region(&mut self, r: ty::Region<'tcx>) -> ty::Region<'tcx> {
         match *r {
             ty::ReVar(rid) => self.infcx.lexical_region_resolutions
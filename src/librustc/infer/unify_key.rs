@@ -11,6 +11,8 @@
 use ty::{self, FloatVarValue, IntVarValue, Ty, TyCtxt};
 use rustc_data_structures::unify::{NoError, EqUnifyValue, UnifyKey, UnifyValue};
 
+use std::marker::PhantomData;
+
 pub trait ToType {
     fn to_type<'a, 'gcx, 'tcx>(&self, tcx: TyCtxt<'a, 'gcx, 'tcx>) -> Ty<'tcx>;
 }
@@ -25,6 +27,20 @@ impl UnifyKey for ty::IntVid {
 impl EqUnifyValue for IntVarValue {
 }
 
+// Const generic argument keys
+
+impl<'tcx> UnifyKey for ty::ConstVid<'tcx> {
+    type Value = Option<&'tcx ty::Const<'tcx>>;
+    fn index(&self) -> u32 { self.index }
+    fn from_index(i: u32) -> ty::ConstVid<'tcx> {
+        ty::ConstVid { index: i, phantom: PhantomData }
+    }
+    fn tag() -> &'static str { "ConstVid" }
+}
+
+impl<'tcx> EqUnifyValue for &'tcx ty::Const<'tcx> {
+}
+
 #[derive(PartialEq, Copy, Clone, Debug)]
 pub struct RegionVidKey {
     /// The minimum region vid in the unification set. This is needed
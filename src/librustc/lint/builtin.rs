@@ -85,6 +85,12 @@ declare_lint! {
     "detects unreachable patterns"
 }
 
+declare_lint! {
+    pub OVERLAPPING_PATTERNS,
+    Warn,
+    "detects range patterns that partially overlap"
+}
+
 declare_lint! {
     pub UNUSED_MACROS,
     Warn,
@@ -236,6 +242,12 @@ declare_lint! {
    "detects single use lifetimes"
 }
 
+declare_lint! {
+    pub UNUSED_LIFETIMES,
+    Allow,
+    "detects lifetime parameters that are never used"
+}
+
 declare_lint! {
     pub TYVAR_BEHIND_RAW_POINTER,
     Warn,
@@ -273,6 +285,18 @@ declare_lint! {
     "detects name collision with an existing but unstable method"
 }
 
+declare_lint! {
+    pub LARGE_ASSIGNMENTS,
+    Warn,
+    "detects large moves or copies"
+}
+
+declare_lint! {
+    pub LONG_RUNNING_CONST_EVAL,
+    Warn,
+    "detects constants which take a long time to evaluate"
+}
+
 /// Does nothing as a lint pass, but registers some `Lint`s
 /// which are used by other parts of the compiler.
 #[derive(Copy, Clone)]
@@ -292,6 +316,7 @@ impl LintPass for HardwiredLints {
             DEAD_CODE,
             UNREACHABLE_CODE,
             UNREACHABLE_PATTERNS,
+            OVERLAPPING_PATTERNS,
             UNUSED_MACROS,
             WARNINGS,
             UNUSED_FEATURES,
@@ -318,11 +343,14 @@ impl LintPass for HardwiredLints {
             UNUSED_UNSAFE,
             UNUSED_MUT,
             SINGLE_USE_LIFETIME,
+            UNUSED_LIFETIMES,
             TYVAR_BEHIND_RAW_POINTER,
             ELIDED_LIFETIME_IN_PATH,
             BARE_TRAIT_OBJECT,
             ABSOLUTE_PATH_STARTING_WITH_MODULE,
             UNSTABLE_NAME_COLLISION,
+            LARGE_ASSIGNMENTS,
+            LONG_RUNNING_CONST_EVAL,
         )
     }
 }
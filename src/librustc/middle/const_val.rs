@@ -27,6 +27,10 @@ pub type EvalResult<'tcx> = Result<&'tcx ty::Const<'tcx>, ConstEvalErr<'tcx>>;
 pub enum ConstVal<'tcx> {
     Unevaluated(DefId, &'tcx Substs<'tcx>),
     Value(Value),
+    /// A const generic parameter, only known after monomorphization.
+    Param(ty::ParamConst),
+    /// A const argument that type inference has yet to determine.
+    Infer(ty::InferConst<'tcx>),
 }
 
 impl<'tcx> ConstVal<'tcx> {
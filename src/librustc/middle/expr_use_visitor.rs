@@ -914,31 +914,67 @@ impl<'a, 'gcx, 'tcx> ExprUseVisitor<'a, 'gcx, 'tcx> {
                     var_id: var_hir_id,
                     closure_expr_id: closure_def_id.to_local(),
                 };
-                let upvar_capture = self.mc.tables.upvar_capture(upvar_id);
                 let cmt_var = return_if_err!(self.cat_captured_var(closure_expr.id,
                                                                    fn_decl_span,
                                                                    freevar));
-                match upvar_capture {
-                    ty::UpvarCapture::ByValue => {
-                        let mode = copy_or_move(&self.mc,
-                                                self.param_env,
-                                                &cmt_var,
-                                                CaptureMove);
-                        self.delegate.consume(closure_expr.id, freevar.span, cmt_var, mode);
-                    }
-                    ty::UpvarCapture::ByRef(upvar_borrow) => {
-                        self.delegate.borrow(closure_expr.id,
-                                             fn_decl_span,
-                                             cmt_var,
-                                             upvar_borrow.region,
-                                             upvar_borrow.kind,
-                                             ClosureCapture(freevar.span));
+                if let Some(places) = self.mc.tables.upvar_places(upvar_id) {
+                    for place in places {
+                        let mut cmt_place = cmt_var.clone();
+                        for &(projection, ty) in &place.projections {
+                            cmt_place = match projection {
+                                ty::CaptureProjection::Deref => {
+                                    return_if_err!(self.mc.cat_deref(closure_expr,
+                                                                     cmt_place,
+                                                                     false))
+                                }
+                                ty::CaptureProjection::Field(index, name) => {
+                                    self.mc.cat_field(closure_expr, cmt_place, index, name, ty)
+                                }
+                            };
+                        }
+                        self.walk_capture(closure_expr.id,
+                                          fn_decl_span,
+                                          freevar.span,
+                                          cmt_place,
+                                          place.capture);
                     }
+                } else {
+                    let upvar_capture = self.mc.tables.upvar_capture(upvar_id);
+                    self.walk_capture(closure_expr.id,
+                                      fn_decl_span,
+                                      freevar.span,
+                                      cmt_var,
+                                      upvar_capture);
                 }
             }
         });
     }
 
+    fn walk_capture(&mut self,
+                    closure_id: ast::NodeId,
+                    fn_decl_span: Span,
+                    upvar_span: Span,
+                    cmt: mc::cmt<'tcx>,
+                    capture: ty::UpvarCapture<'tcx>) {
+        match capture {
+            ty::UpvarCapture::ByValue => {
+                let mode = copy_or_move(&self.mc,
+                                        self.param_env,
+                                        &cmt,
+                                        CaptureMove);
+                self.delegate.consume(closure_id, upvar_span, cmt, mode);
+            }
+            ty::UpvarCapture::ByRef(upvar_borrow) => {
+                self.delegate.borrow(closure_id,
+                                     fn_decl_span,
+                                     cmt,
+                                     upvar_borrow.region,
+                                     upvar_borrow.kind,
+                                     ClosureCapture(upvar_span));
+            }
+        }
+    }
+
     fn cat_captured_var(&mut self,
                         closure_id: ast::NodeId,
                         closure_span: Span,
@@ -175,6 +175,18 @@ pub enum Note {
     NoteNone                     // Nothing special
 }
 
+// The path from a closure's upvar to a place rooted at it, as computed by
+// `cmt_::upvar_place`.
+#[derive(Clone, Debug)]
+pub struct UpvarPlace<'tcx> {
+    pub upvar_id: ty::UpvarId,
+    pub var_ty: Ty<'tcx>,
+    pub projections: Vec<(ty::CaptureProjection, Ty<'tcx>)>,
+    // False if the path stops short of the place, at an index or downcast
+    // that cannot be expressed as a `ty::CaptureProjection`.
+    pub complete: bool,
+}
+
 // `cmt`: "Category, Mutability, and Type".
 //
 // a complete categorization of a value indicating where it originated
@@ -706,7 +718,7 @@ impl<'a, 'gcx, 'tcx> MemCategorizationContext<'a, 'gcx, 'tcx> {
                id, expr_ty, def);
 
         match def {
-          Def::StructCtor(..) | Def::VariantCtor(..) | Def::Const(..) |
+          Def::StructCtor(..) | Def::VariantCtor(..) | Def::Const(..) | Def::ConstParam(..) |
           Def::AssociatedConst(..) | Def::Fn(..) | Def::Method(..) => {
                 Ok(self.cat_rvalue_node(id, span, expr_ty))
           }
@@ -1354,6 +1366,13 @@ impl<'a, 'gcx, 'tcx> MemCategorizationContext<'a, 'gcx, 'tcx> {
             }
           }
 
+          PatKind::Or(ref alternatives) => {
+            // p1 | ... | pN, each alternative is matched against the same place
+            for alternative in alternatives {
+                self.cat_pattern_(cmt.clone(), &alternative, op)?;
+            }
+          }
+
           PatKind::Path(_) | PatKind::Binding(.., None) |
           PatKind::Lit(..) | PatKind::Range(..) | PatKind::Wild => {
             // always ok
@@ -1402,6 +1421,57 @@ impl<'tcx> cmt_<'tcx> {
         }
     }
 
+    /// If this place is (a part of) an upvar of the closure being
+    /// categorized, returns the upvar and the field and deref projections
+    /// that lead from it to this place.
+    pub fn upvar_place(&self) -> Option<UpvarPlace<'tcx>> {
+        match self.note {
+            NoteUpvarRef(upvar_id) | NoteClosureEnv(upvar_id) => {
+                return Some(UpvarPlace {
+                    upvar_id,
+                    var_ty: self.ty,
+                    projections: vec![],
+                    complete: true,
+                });
+            }
+            NoteNone => {}
+        }
+
+        match self.cat {
+            Categorization::Upvar(ref upvar) => {
+                Some(UpvarPlace {
+                    upvar_id: upvar.id,
+                    var_ty: self.ty,
+                    projections: vec![],
+                    complete: true,
+                })
+            }
+            Categorization::Interior(ref base, InteriorField(FieldIndex(index, name))) => {
+                let mut place = base.upvar_place()?;
+                if place.complete {
+                    place.projections.push((ty::CaptureProjection::Field(index, name), self.ty));
+                }
+                Some(place)
+            }
+            Categorization::Deref(ref base, _) => {
+                let mut place = base.upvar_place()?;
+                if place.complete {
+                    place.projections.push((ty::CaptureProjection::Deref, self.ty));
+                }
+                Some(place)
+            }
+            Categorization::Interior(ref base, InteriorElement(..)) |
+            Categorization::Downcast(ref base, _) => {
+                let mut place = base.upvar_place()?;
+                place.complete = false;
+                Some(place)
+            }
+            Categorization::Rvalue(..) |
+            Categorization::StaticItem |
+            Categorization::Local(..) => None,
+        }
+    }
+
     /// Returns `FreelyAliasable(_)` if this place represents a freely aliasable pointer type.
     pub fn freely_aliasable(&self) -> Aliasability {
         // Maybe non-obvious: copied upvars can only be considered
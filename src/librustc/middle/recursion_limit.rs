@@ -25,6 +25,12 @@ pub fn update_limits(sess: &Session, krate: &ast::Crate) {
                  "recursion limit", 64);
     update_limit(sess, krate, &sess.type_length_limit, "type_length_limit",
                  "type length limit", 1048576);
+    update_limit(sess, krate, &sess.move_size_limit, "move_size_limit",
+                 "move size limit", sess.opts.debugging_opts.move_size_limit.unwrap_or(0));
+    update_limit(sess, krate, &sess.const_eval_warn_limit, "const_eval_warn_limit",
+                 "const eval warning limit", 1_000_000);
+    update_limit(sess, krate, &sess.const_eval_limit, "const_eval_limit",
+                 "const eval limit", 100_000_000);
 }
 
 fn update_limit(sess: &Session, krate: &ast::Crate, limit: &Once<usize>,
@@ -1259,6 +1259,7 @@ impl<'a, 'tcx> LifetimeContext<'a, 'tcx> {
         let labels_in_fn = replace(&mut self.labels_in_fn, vec![]);
         let xcrate_object_lifetime_defaults =
             replace(&mut self.xcrate_object_lifetime_defaults, DefIdMap());
+        let lifetime_uses = replace(&mut self.lifetime_uses, DefIdMap());
         let mut this = LifetimeContext {
             tcx,
             map: *map,
@@ -1267,42 +1268,125 @@ impl<'a, 'tcx> LifetimeContext<'a, 'tcx> {
             is_in_fn_syntax: self.is_in_fn_syntax,
             labels_in_fn,
             xcrate_object_lifetime_defaults,
-            lifetime_uses: DefIdMap(),
+            lifetime_uses,
         };
         debug!("entering scope {:?}", this.scope);
         f(self.scope, &mut this);
+        this.check_uses_for_lifetimes_defined_by_scope();
         debug!("exiting scope {:?}", this.scope);
         self.labels_in_fn = this.labels_in_fn;
         self.xcrate_object_lifetime_defaults = this.xcrate_object_lifetime_defaults;
+        self.lifetime_uses = this.lifetime_uses;
+    }
 
-        for (def_id, lifetimeuseset) in &this.lifetime_uses {
-            match lifetimeuseset {
-                &LifetimeUseSet::One(_) => {
-                    let node_id = this.tcx.hir.as_local_node_id(*def_id).unwrap();
-                    debug!("node id first={:?}", node_id);
-                    if let hir::map::NodeLifetime(hir_lifetime) = this.tcx.hir.get(node_id) {
-                        let span = hir_lifetime.span;
-                        let id = hir_lifetime.id;
-                        debug!(
-                            "id ={:?} span = {:?} hir_lifetime = {:?}",
-                            node_id, span, hir_lifetime
-                        );
+    /// Reports the lifetimes declared by the current scope that are never
+    /// used, or that are used only once and could therefore be elided.
+    ///
+    /// Uses are recorded in `lifetime_uses` as they are resolved, so by the
+    /// time a scope is exited all references to its lifetimes, including
+    /// those in nested scopes, have been seen.
+    fn check_uses_for_lifetimes_defined_by_scope(&mut self) {
+        let scope = self.scope;
+        let defined_by = match *scope {
+            Scope::Binder { ref lifetimes, .. } => lifetimes,
+            _ => return,
+        };
 
-                        this.tcx
-                            .struct_span_lint_node(
-                                lint::builtin::SINGLE_USE_LIFETIME,
-                                id,
-                                span,
-                                &format!(
-                                    "lifetime name `{}` only used once",
-                                    hir_lifetime.name.name()
-                                ),
-                            )
-                            .emit();
+        let mut def_ids: Vec<_> = defined_by
+            .values()
+            .flat_map(|region| match *region {
+                Region::EarlyBound(_, def_id, _)
+                | Region::LateBound(_, def_id, _)
+                | Region::Free(_, def_id) => Some(def_id),
+                Region::LateBoundAnon(..) | Region::Static => None,
+            })
+            .collect();
+        // Issue the lints in a repeatable order.
+        def_ids.sort_by_key(|&def_id| self.tcx.def_path_hash(def_id));
+
+        for def_id in def_ids {
+            let node_id = match self.tcx.hir.as_local_node_id(def_id) {
+                Some(node_id) => node_id,
+                None => continue,
+            };
+
+            // Only the generics of functions, methods and impls are checked:
+            // unused lifetimes of type definitions are already an error.
+            let owner_id = self.tcx.hir.get_parent_node(node_id);
+            let (generics, decl) = match self.tcx.hir.find(owner_id) {
+                Some(hir::map::NodeItem(&hir::Item {
+                    node: hir::ItemFn(ref decl, _, _, _, ref generics, _),
+                    ..
+                })) => (generics, Some(&**decl)),
+                Some(hir::map::NodeItem(&hir::Item {
+                    node: hir::ItemImpl(_, _, _, ref generics, ..),
+                    ..
+                })) => (generics, None),
+                Some(hir::map::NodeImplItem(&hir::ImplItem {
+                    node: hir::ImplItemKind::Method(ref sig, _),
+                    ref generics,
+                    ..
+                }))
+                | Some(hir::map::NodeTraitItem(&hir::TraitItem {
+                    node: hir::TraitItemKind::Method(ref sig, _),
+                    ref generics,
+                    ..
+                })) => (generics, Some(&*sig.decl)),
+                _ => continue,
+            };
+
+            let def = match generics.lifetimes().find(|def| def.lifetime.id == node_id) {
+                Some(def) => def,
+                None => continue,
+            };
+            if def.in_band {
+                // There is no declaration to remove for in-band lifetimes.
+                continue;
+            }
+            let name = def.lifetime.name.name();
+
+            match self.lifetime_uses.get(&def_id) {
+                Some(&LifetimeUseSet::One(lifetime)) => {
+                    // Elision is only possible for lifetimes of functions
+                    // that appear in argument position.
+                    let decl = match decl {
+                        Some(decl) => decl,
+                        None => continue,
+                    };
+                    if !decl.inputs.iter().any(|input| input.span.contains(lifetime.span)) {
+                        continue;
                     }
+
+                    let mut err = self.tcx.struct_span_lint_node(
+                        lint::builtin::SINGLE_USE_LIFETIME,
+                        node_id,
+                        def.lifetime.span,
+                        &format!("lifetime parameter `{}` only used once", name),
+                    );
+                    err.span_label(def.lifetime.span, "this lifetime...");
+                    err.span_label(lifetime.span, "...is used only here");
+                    let mut parts = vec![(lifetime.span, "'_".to_string())];
+                    parts.extend(generic_param_removal_span(generics, node_id)
+                                    .map(|span| (span, String::new())));
+                    err.multipart_suggestion("elide the single-use lifetime", parts);
+                    err.emit();
                 }
-                _ => {
-                    debug!("Not one use lifetime");
+                Some(&LifetimeUseSet::Many) => {
+                    debug!("not one use lifetime");
+                }
+                None => {
+                    let mut err = self.tcx.struct_span_lint_node(
+                        lint::builtin::UNUSED_LIFETIMES,
+                        node_id,
+                        def.lifetime.span,
+                        &format!("lifetime parameter `{}` never used", name),
+                    );
+                    if let Some(span) = generic_param_removal_span(generics, node_id) {
+                        err.span_suggestion_short(span,
+                                                  "remove it",
+                                                  String::new());
+                    }
+                    err.emit();
                 }
             }
         }
@@ -2239,6 +2323,36 @@ impl<'a, 'tcx> LifetimeContext<'a, 'tcx> {
     }
 }
 
+/// Returns the span to delete in order to remove the lifetime parameter
+/// defined by `lifetime_id` from `generics`, along with its separating comma.
+fn generic_param_removal_span(generics: &hir::Generics, lifetime_id: ast::NodeId) -> Option<Span> {
+    fn param_span(param: &hir::GenericParam) -> Span {
+        match *param {
+            hir::GenericParam::Lifetime(ref def) => match def.bounds.last() {
+                Some(bound) => def.lifetime.span.to(bound.span),
+                None => def.lifetime.span,
+            },
+            hir::GenericParam::Type(ref ty_param) => ty_param.span,
+            hir::GenericParam::Const(ref const_param) => const_param.span,
+        }
+    }
+
+    let params = &generics.params;
+    let index = params.iter().position(|param| match *param {
+        hir::GenericParam::Lifetime(ref def) => def.lifetime.id == lifetime_id,
+        hir::GenericParam::Type(_) | hir::GenericParam::Const(_) => false,
+    })?;
+    let span = param_span(&params[index]);
+    Some(if params.len() == 1 {
+        // Remove the angle brackets as well.
+        generics.span
+    } else if index + 1 < params.len() {
+        span.with_hi(param_span(&params[index + 1]).lo())
+    } else {
+        span.with_lo(param_span(&params[index - 1]).hi())
+    })
+}
+
 ///////////////////////////////////////////////////////////////////////////
 
 /// Detects late-bound lifetimes and inserts them into
@@ -2301,6 +2415,7 @@ fn insert_late_bound_lifetimes(
                     &ty_param.bounds
                 );
             }
+            hir::GenericParam::Const(_) => {}
         }
     }
 
@@ -66,6 +66,7 @@ pub enum EvalErrorKind<'tcx> {
     OverflowingMath,
     InvalidChar(u128),
     StackFrameLimitReached,
+    StepLimitReached,
     OutOfTls,
     TlsOutOfBounds,
     AbiViolation(String),
@@ -118,6 +119,9 @@ pub enum EvalErrorKind<'tcx> {
     UnimplementedTraitSelection,
     /// Abort in case type errors are reached
     TypeckError,
+    /// Cannot compute this constant because it depends on a const
+    /// parameter that has not been substituted
+    TooGeneric,
     /// Cannot compute this constant because it depends on another one
     /// which already produced an error
     ReferencedConstant,
@@ -189,6 +193,9 @@ impl<'tcx> Error for EvalError<'tcx> {
                 "tried to interpret an invalid 32-bit value as a char",
             StackFrameLimitReached =>
                 "reached the configured maximum number of stack frames",
+            StepLimitReached =>
+                "exceeded the maximum number of evaluation steps, \
+                 evaluation might never terminate",
             OutOfTls =>
                 "reached the maximum number of representable TLS keys",
             TlsOutOfBounds =>
@@ -238,6 +245,8 @@ impl<'tcx> Error for EvalError<'tcx> {
                 "there were unresolved type arguments during trait selection",
             TypeckError =>
                 "encountered constants with type errors, stopping evaluation",
+            TooGeneric =>
+                "encountered overly generic constant",
             ReferencedConstant =>
                 "referenced constant has errors",
         }
@@ -98,6 +98,10 @@ pub struct Mir<'tcx> {
     /// The layout of a generator. Produced by the state transformation.
     pub generator_layout: Option<GeneratorLayout<'tcx>>,
 
+    /// Coverage counters for this function. Produced by the coverage
+    /// instrumentation pass when `-Z instrument-coverage` is given.
+    pub coverage_info: Option<CoverageInfo>,
+
     /// Declarations of locals.
     ///
     /// The first local is the return value pointer, followed by `arg_count`
@@ -157,6 +161,7 @@ impl<'tcx> Mir<'tcx> {
             yield_ty,
             generator_drop: None,
             generator_layout: None,
+            coverage_info: None,
             local_decls,
             arg_count,
             upvar_decls,
@@ -317,6 +322,7 @@ impl_stable_hash_for!(struct Mir<'tcx> {
     yield_ty,
     generator_drop,
     generator_layout,
+    coverage_info,
     local_decls,
     arg_count,
     upvar_decls,
@@ -1891,8 +1897,9 @@ impl<'tcx> Debug for Literal<'tcx> {
 fn fmt_const_val<W: Write>(fmt: &mut W, const_val: &ty::Const) -> fmt::Result {
     use middle::const_val::ConstVal::*;
     match const_val.val {
-        Unevaluated(..) => write!(fmt, "{:?}", const_val),
+        Unevaluated(..) | Infer(..) => write!(fmt, "{:?}", const_val),
         Value(val) => print_miri_value(val, const_val.ty, fmt),
+        Param(p) => write!(fmt, "{}", p.name),
     }
 }
 
@@ -2029,6 +2036,27 @@ pub struct GeneratorLayout<'tcx> {
     pub fields: Vec<LocalDecl<'tcx>>,
 }
 
+/// The coverage counters of a function, one per instrumented basic block.
+///
+/// Counter `i` is incremented on entry to `counters[i].block` and maps to
+/// the source regions `counters[i].regions`, which always lie within the
+/// span of the function body. Two regions are either disjoint or nested, the
+/// inner one accounting for its part of the outer one, and no two regions
+/// are the same.
+#[derive(Clone, Debug, RustcEncodable, RustcDecodable)]
+pub struct CoverageInfo {
+    /// A hash of the counter layout, used by the profile reader to match
+    /// counter values against this set of regions.
+    pub hash: u64,
+    pub counters: Vec<CoverageCounter>,
+}
+
+#[derive(Clone, Debug, RustcEncodable, RustcDecodable)]
+pub struct CoverageCounter {
+    pub block: BasicBlock,
+    pub regions: Vec<Span>,
+}
+
 /// After we borrow check a closure, we are left with various
 /// requirements that we have inferred between the free regions that
 /// appear in the closure's signature or on its field types.  These
@@ -2129,6 +2157,7 @@ pub enum ClosureOutlivesSubject<'tcx> {
  */
 
 CloneTypeFoldableAndLiftImpls! {
+    CoverageInfo,
     Mutability,
     SourceInfo,
     UpvarDecl,
@@ -2147,6 +2176,7 @@ BraceStructTypeFoldableImpl! {
         yield_ty,
         generator_drop,
         generator_layout,
+        coverage_info,
         local_decls,
         arg_count,
         upvar_decls,
@@ -208,6 +208,7 @@ pub struct Stats {
     pub llvm_insns: FxHashMap<String, usize>,
     // (ident, llvm-instructions)
     pub fn_stats: Vec<(String, usize)>,
+    pub instance_stats: Vec<InstanceStats>,
 }
 
 impl_stable_hash_for!(struct self::Stats {
@@ -219,7 +220,27 @@ impl_stable_hash_for!(struct self::Stats {
     n_closures,
     n_llvm_insns,
     llvm_insns,
-    fn_stats
+    fn_stats,
+    instance_stats
+});
+
+/// The LLVM IR translated for one copy of a function, as gathered for
+/// `-Z print-generic-bloat`.
+#[derive(Clone)]
+pub struct InstanceStats {
+    /// The definition the function is an instance of.
+    pub def_id: DefId,
+    pub instance: String,
+    pub codegen_unit: String,
+    /// The number of LLVM IR instructions before optimization.
+    pub llvm_insns: usize,
+}
+
+impl_stable_hash_for!(struct self::InstanceStats {
+    def_id,
+    instance,
+    codegen_unit,
+    llvm_insns
 });
 
 impl Stats {
@@ -236,6 +257,7 @@ impl Stats {
             *self.llvm_insns.entry(k).or_insert(0) += v;
         }
         self.fn_stats.extend(stats.fn_stats);
+        self.instance_stats.extend(stats.instance_stats);
     }
 }
 
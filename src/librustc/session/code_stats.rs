@@ -12,8 +12,11 @@ use ty::AdtKind;
 use ty::layout::{Align, Size};
 
 use rustc_data_structures::fx::{FxHashSet};
+use serialize::json::Json;
 
 use std::cmp::{self, Ordering};
+use std::collections::BTreeMap;
+use std::u64;
 
 #[derive(Clone, PartialEq, Eq, Hash, Debug)]
 pub struct VariantInfo {
@@ -56,6 +59,37 @@ pub enum DataTypeKind {
     Closure,
 }
 
+/// A scalar stored somewhere within a type, such as an enum tag.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ScalarInfo {
+    pub offset: u64,
+    pub size: u64,
+    /// Inclusive wrap-around range of the values the scalar may hold, see
+    /// `ty::layout::Scalar`.
+    pub valid_range_start: u128,
+    pub valid_range_end: u128,
+}
+
+/// How the variant of an enum value is encoded.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum TagEncoding {
+    /// The variant index is stored as a discriminant in `tag`.
+    Direct {
+        tag: ScalarInfo,
+    },
+    /// The dataful variant is stored as is, and the variants with indices
+    /// `niche_variants_start..=niche_variants_end` are encoded as the values
+    /// starting at `niche_start` of `niche`, which its dataful variant never
+    /// holds.
+    NicheFilling {
+        dataful_variant: String,
+        niche_variants_start: u64,
+        niche_variants_end: u64,
+        niche: ScalarInfo,
+        niche_start: u128,
+    },
+}
+
 #[derive(PartialEq, Eq, Hash, Debug)]
 pub struct TypeSizeInfo {
     pub kind: DataTypeKind,
@@ -65,6 +99,9 @@ pub struct TypeSizeInfo {
     pub packed: bool,
     pub opt_discr_size: Option<u64>,
     pub variants: Vec<VariantInfo>,
+    pub tag: Option<TagEncoding>,
+    /// The invalid values an enclosing enum would store its variants in.
+    pub niche: Option<ScalarInfo>,
 }
 
 #[derive(PartialEq, Eq, Debug)]
@@ -82,7 +119,9 @@ impl CodeStats {
                                          overall_size: Size,
                                          packed: bool,
                                          opt_discr_size: Option<Size>,
-                                         variants: Vec<VariantInfo>) {
+                                         variants: Vec<VariantInfo>,
+                                         tag: Option<TagEncoding>,
+                                         niche: Option<ScalarInfo>) {
         let info = TypeSizeInfo {
             kind,
             type_description: type_desc.to_string(),
@@ -91,11 +130,13 @@ impl CodeStats {
             packed: packed,
             opt_discr_size: opt_discr_size.map(|s| s.bytes()),
             variants,
+            tag,
+            niche,
         };
         self.type_sizes.insert(info);
     }
 
-    pub fn print_type_sizes(&self) {
+    fn sorted_type_sizes(&self) -> Vec<&TypeSizeInfo> {
         let mut sorted: Vec<_> = self.type_sizes.iter().collect();
 
         // Primary sort: large-to-small.
@@ -107,8 +148,11 @@ impl CodeStats {
                 other => other,
             }
         });
+        sorted
+    }
 
-        for info in &sorted {
+    pub fn print_type_sizes(&self) {
+        for info in &self.sorted_type_sizes() {
             println!("print-type-size type: `{}`: {} bytes, alignment: {} bytes",
                      info.type_description, info.overall_size, info.align);
             let indent = "    ";
@@ -191,4 +235,128 @@ impl CodeStats {
             }
         }
     }
+
+    /// Prints the recorded layouts as a single JSON document, for
+    /// `-Z print-type-layouts`.
+    pub fn print_type_layouts(&self) {
+        let types = self.sorted_type_sizes().into_iter().map(type_layout_json).collect();
+        let mut document = BTreeMap::new();
+        document.insert("types".to_string(), Json::Array(types));
+        println!("{}", Json::Object(document).pretty());
+    }
+}
+
+fn type_layout_json(info: &TypeSizeInfo) -> Json {
+    let mut ty = BTreeMap::new();
+    ty.insert("type".to_string(), Json::String(info.type_description.clone()));
+    ty.insert("kind".to_string(), Json::String(match info.kind {
+        DataTypeKind::Struct => "struct",
+        DataTypeKind::Union => "union",
+        DataTypeKind::Enum => "enum",
+        DataTypeKind::Closure => "closure",
+    }.to_string()));
+    ty.insert("size".to_string(), Json::U64(info.overall_size));
+    ty.insert("align".to_string(), Json::U64(info.align));
+    ty.insert("packed".to_string(), Json::Boolean(info.packed));
+    ty.insert("niche".to_string(), info.niche.as_ref().map_or(Json::Null, scalar_json));
+
+    // The bytes of the tag are in use in every variant, and so are those of
+    // the niche in the variants stored in it.
+    let (tag, tag_scalar) = match info.tag {
+        Some(TagEncoding::Direct { ref tag }) => {
+            let mut encoding = BTreeMap::new();
+            encoding.insert("encoding".to_string(), Json::String("direct".to_string()));
+            encoding.insert("tag".to_string(), scalar_json(tag));
+            (Json::Object(encoding), Some(tag))
+        }
+        Some(TagEncoding::NicheFilling {
+            ref dataful_variant,
+            niche_variants_start,
+            niche_variants_end,
+            ref niche,
+            niche_start,
+        }) => {
+            let mut encoding = BTreeMap::new();
+            encoding.insert("encoding".to_string(), Json::String("niche".to_string()));
+            encoding.insert("dataful_variant".to_string(),
+                            Json::String(dataful_variant.clone()));
+            encoding.insert("niche_variants".to_string(),
+                            Json::Array(vec![Json::U64(niche_variants_start),
+                                             Json::U64(niche_variants_end)]));
+            encoding.insert("niche".to_string(), scalar_json(niche));
+            encoding.insert("niche_start".to_string(), u128_json(niche_start));
+            (Json::Object(encoding), Some(niche))
+        }
+        None => (Json::Null, None),
+    };
+    ty.insert("tag".to_string(), tag);
+
+    let variants = info.variants.iter().map(|variant| {
+        // Fields in memory order, with the gaps between them.
+        let mut fields = variant.fields.clone();
+        fields.sort_by_key(|f| f.offset);
+        let mut occupied: Vec<_> = fields.iter().map(|f| (f.offset, f.size)).collect();
+        if let Some(scalar) = tag_scalar {
+            occupied.push((scalar.offset, scalar.size));
+            occupied.sort();
+        }
+        let mut padding = vec![];
+        let mut min_offset = 0;
+        for (offset, size) in occupied {
+            if offset > min_offset {
+                padding.push(region_json(min_offset, offset - min_offset));
+            }
+            min_offset = cmp::max(min_offset, offset + size);
+        }
+        if variant.kind == SizeKind::Exact && info.overall_size > min_offset {
+            padding.push(region_json(min_offset, info.overall_size - min_offset));
+        }
+
+        let mut json = BTreeMap::new();
+        json.insert("name".to_string(),
+                    variant.name.as_ref().map_or(Json::Null, |n| Json::String(n.clone())));
+        json.insert("size".to_string(), Json::U64(variant.size));
+        json.insert("align".to_string(), Json::U64(variant.align));
+        json.insert("sized".to_string(), Json::Boolean(variant.kind == SizeKind::Exact));
+        json.insert("fields".to_string(), Json::Array(fields.iter().map(|field| {
+            let mut json = BTreeMap::new();
+            json.insert("name".to_string(), Json::String(field.name.clone()));
+            json.insert("offset".to_string(), Json::U64(field.offset));
+            json.insert("size".to_string(), Json::U64(field.size));
+            json.insert("align".to_string(), Json::U64(field.align));
+            Json::Object(json)
+        }).collect()));
+        json.insert("padding".to_string(), Json::Array(padding));
+        Json::Object(json)
+    }).collect();
+    ty.insert("variants".to_string(), Json::Array(variants));
+
+    Json::Object(ty)
+}
+
+fn scalar_json(scalar: &ScalarInfo) -> Json {
+    let mut json = BTreeMap::new();
+    json.insert("offset".to_string(), Json::U64(scalar.offset));
+    json.insert("size".to_string(), Json::U64(scalar.size));
+    json.insert("valid_range".to_string(),
+                Json::Array(vec![u128_json(scalar.valid_range_start),
+                                 u128_json(scalar.valid_range_end)]));
+    Json::Object(json)
+}
+
+fn region_json(offset: u64, size: u64) -> Json {
+    let mut json = BTreeMap::new();
+    json.insert("offset".to_string(), Json::U64(offset));
+    json.insert("size".to_string(), Json::U64(size));
+    Json::Object(json)
+}
+
+/// JSON numbers are at most 64 bits wide, so larger values (which only occur
+/// for 128-bit scalars) are written as decimal strings.
+fn u128_json(value: u128) -> Json {
+    if value <= u64::MAX as u128 {
+        Json::U64(value as u64)
+    } else {
+        Json::String(value.to_string())
+    }
 }
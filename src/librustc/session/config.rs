@@ -65,6 +65,12 @@ pub enum Sanitizer {
     Thread,
 }
 
+#[derive(Clone, Copy, PartialEq, Hash, Debug)]
+pub enum SymbolManglingVersion {
+    Legacy,
+    V0,
+}
+
 #[derive(Clone, Copy, PartialEq, Hash)]
 pub enum OptLevel {
     No,         // -O0
@@ -769,6 +775,8 @@ macro_rules! options {
             Some("one of: `full`, `partial`, or `off`");
         pub const parse_sanitizer: Option<&'static str> =
             Some("one of: `address`, `leak`, `memory` or `thread`");
+        pub const parse_symbol_mangling_version: Option<&'static str> =
+            Some("either `legacy` or `v0`");
         pub const parse_linker_flavor: Option<&'static str> =
             Some(::rustc_back::LinkerFlavor::one_of());
         pub const parse_optimization_fuel: Option<&'static str> =
@@ -781,7 +789,8 @@ macro_rules! options {
 
     #[allow(dead_code)]
     mod $mod_set {
-        use super::{$struct_name, Passes, SomePasses, AllPasses, Sanitizer, Lto};
+        use super::{$struct_name, Passes, SomePasses, AllPasses, Sanitizer, Lto,
+                    SymbolManglingVersion};
         use rustc_back::{LinkerFlavor, PanicStrategy, RelroLevel};
         use std::path::PathBuf;
 
@@ -942,6 +951,16 @@ macro_rules! options {
             true
         }
 
+        fn parse_symbol_mangling_version(slot: &mut SymbolManglingVersion,
+                                         v: Option<&str>) -> bool {
+            match v {
+                Some("legacy") => *slot = SymbolManglingVersion::Legacy,
+                Some("v0") => *slot = SymbolManglingVersion::V0,
+                _ => return false,
+            }
+            true
+        }
+
         fn parse_linker_flavor(slote: &mut Option<LinkerFlavor>, v: Option<&str>) -> bool {
             match v.and_then(LinkerFlavor::from_str) {
                 Some(lf) => *slote = Some(lf),
@@ -1191,8 +1210,18 @@ options! {DebuggingOptions, DebuggingSetter, basic_debugging_options,
           "show spans for compiler debugging (expr|pat|ty)"),
     print_type_sizes: bool = (false, parse_bool, [UNTRACKED],
           "print layout information for each type encountered"),
+    print_type_layouts: bool = (false, parse_bool, [UNTRACKED],
+          "print the layout of each type encountered as JSON, including field offsets, \
+           padding, niches and how enum variants are encoded"),
     print_trans_items: Option<String> = (None, parse_opt_string, [UNTRACKED],
           "print the result of the translation item collection pass"),
+    print_generic_bloat: bool = (false, parse_bool, [UNTRACKED_WITH_WARNING(true,
+        "The output of `-Z print-generic-bloat` only covers re-translated \
+         codegen units when used with incremental compilation")],
+          "print the number of instances, codegen units and LLVM IR instructions \
+           of every function definition, largest first"),
+    move_size_limit: Option<usize> = (None, parse_opt_uint, [TRACKED],
+          "the size at which the `large_assignments` lint starts being emitted"),
     mir_opt_level: usize = (1, parse_uint, [TRACKED],
           "set the MIR optimization level (0-3, default: 1)"),
     mutable_noalias: bool = (false, parse_bool, [UNTRACKED],
@@ -1244,6 +1273,15 @@ options! {DebuggingOptions, DebuggingSetter, basic_debugging_options,
     disable_instrumentation_preinliner: bool =
         (false, parse_bool, [TRACKED], "Disable the instrumentation pre-inliner, \
         useful for profiling / PGO."),
+    instrument_coverage: bool = (false, parse_bool, [TRACKED],
+        "instrument the generated code with counters for source-based \
+         coverage reports (see `llvm-cov`)"),
+    emit_stack_sizes: bool = (false, parse_bool, [TRACKED],
+        "emit a section containing stack size metadata for every function (ELF only)"),
+    stack_size_report: bool = (false, parse_bool, [TRACKED],
+        "write the worst-case stack usage of every function, computed from its \
+         stack size and the call graph, to `<crate>.stack_sizes.json` \
+         (implies `-Z emit-stack-sizes`)"),
     relro_level: Option<RelroLevel> = (None, parse_relro_level, [TRACKED],
         "choose which RELRO level to use"),
     nll_subminimal_causes: bool = (false, parse_bool, [UNTRACKED],
@@ -1291,6 +1329,9 @@ options! {DebuggingOptions, DebuggingSetter, basic_debugging_options,
           "make the current crate share its generic instantiations"),
     chalk: bool = (false, parse_bool, [TRACKED],
           "enable the experimental Chalk-based trait solving engine"),
+    symbol_mangling_version: SymbolManglingVersion = (SymbolManglingVersion::Legacy,
+          parse_symbol_mangling_version, [TRACKED],
+          "which mangling version to use for symbol names"),
 }
 
 pub fn default_lib_output() -> CrateType {
@@ -2321,7 +2362,7 @@ mod dep_tracking {
     use std::path::PathBuf;
     use std::collections::hash_map::DefaultHasher;
     use super::{CrateType, DebugInfoLevel, ErrorOutputType, Lto, OptLevel, OutputTypes,
-                Passes, Sanitizer};
+                Passes, Sanitizer, SymbolManglingVersion};
     use syntax::feature_gate::UnstableFeatures;
     use rustc_back::{PanicStrategy, RelroLevel};
     use rustc_back::target::TargetTriple;
@@ -2384,6 +2425,7 @@ mod dep_tracking {
     impl_dep_tracking_hash_via_hash!(cstore::NativeLibraryKind);
     impl_dep_tracking_hash_via_hash!(Sanitizer);
     impl_dep_tracking_hash_via_hash!(Option<Sanitizer>);
+    impl_dep_tracking_hash_via_hash!(SymbolManglingVersion);
     impl_dep_tracking_hash_via_hash!(TargetTriple);
     impl_dep_tracking_hash_via_hash!(Edition);
 
@@ -2455,7 +2497,7 @@ mod tests {
     use std::collections::{BTreeMap, BTreeSet};
     use std::iter::FromIterator;
     use std::path::PathBuf;
-    use super::{Externs, OutputType, OutputTypes};
+    use super::{Externs, OutputType, OutputTypes, SymbolManglingVersion};
     use rustc_back::{PanicStrategy, RelroLevel};
     use syntax::symbol::Symbol;
     use syntax::edition::{Edition, DEFAULT_EDITION};
@@ -2954,6 +2996,18 @@ mod tests {
         opts.debugging_opts.pgo_use = String::from("abc");
         assert_ne!(reference.dep_tracking_hash(), opts.dep_tracking_hash());
 
+        opts = reference.clone();
+        opts.debugging_opts.instrument_coverage = true;
+        assert_ne!(reference.dep_tracking_hash(), opts.dep_tracking_hash());
+
+        opts = reference.clone();
+        opts.debugging_opts.emit_stack_sizes = true;
+        assert_ne!(reference.dep_tracking_hash(), opts.dep_tracking_hash());
+
+        opts = reference.clone();
+        opts.debugging_opts.stack_size_report = true;
+        assert_ne!(reference.dep_tracking_hash(), opts.dep_tracking_hash());
+
         opts = reference.clone();
         opts.cg.metadata = vec![String::from("A"), String::from("B")];
         assert!(reference.dep_tracking_hash() != opts.dep_tracking_hash());
@@ -3043,6 +3097,8 @@ mod tests {
         assert_eq!(reference.dep_tracking_hash(), opts.dep_tracking_hash());
         opts.debugging_opts.print_trans_items = Some(String::from("abc"));
         assert_eq!(reference.dep_tracking_hash(), opts.dep_tracking_hash());
+        opts.debugging_opts.print_generic_bloat = true;
+        assert_eq!(reference.dep_tracking_hash(), opts.dep_tracking_hash());
         opts.debugging_opts.dump_mir = Some(String::from("abc"));
         assert_eq!(reference.dep_tracking_hash(), opts.dep_tracking_hash());
         opts.debugging_opts.dump_mir_dir = String::from("abc");
@@ -3102,6 +3158,14 @@ mod tests {
         opts = reference.clone();
         opts.debugging_opts.relro_level = Some(RelroLevel::Full);
         assert!(reference.dep_tracking_hash() != opts.dep_tracking_hash());
+
+        opts = reference.clone();
+        opts.debugging_opts.move_size_limit = Some(1024);
+        assert!(reference.dep_tracking_hash() != opts.dep_tracking_hash());
+
+        opts = reference.clone();
+        opts.debugging_opts.symbol_mangling_version = SymbolManglingVersion::V0;
+        assert!(reference.dep_tracking_hash() != opts.dep_tracking_hash());
     }
 
     #[test]
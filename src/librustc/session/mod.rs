@@ -9,7 +9,7 @@
 // except according to those terms.
 
 pub use self::code_stats::{CodeStats, DataTypeKind, FieldInfo};
-pub use self::code_stats::{SizeKind, TypeSizeInfo, VariantInfo};
+pub use self::code_stats::{ScalarInfo, SizeKind, TagEncoding, TypeSizeInfo, VariantInfo};
 
 use hir::def_id::CrateNum;
 use ich::Fingerprint;
@@ -110,9 +110,21 @@ pub struct Session {
     /// The maximum length of types during monomorphization.
     pub type_length_limit: Once<usize>,
 
+    /// The size in bytes above which moves and copies are reported by the
+    /// `large_assignments` lint, or zero if they should not be checked.
+    pub move_size_limit: Once<usize>,
+
     /// The maximum number of stackframes allowed in const eval
     pub const_eval_stack_frame_limit: usize,
 
+    /// The number of terminators a single const eval may evaluate before the
+    /// `long_running_const_eval` lint is reported, or zero to never report it.
+    pub const_eval_warn_limit: Once<usize>,
+
+    /// The maximum number of terminators a single const eval may evaluate,
+    /// or zero for no limit.
+    pub const_eval_limit: Once<usize>,
+
     /// The metadata::creader module may inject an allocator/panic_runtime
     /// dependency if it didn't already find one, and this tracks what was
     /// injected.
@@ -1100,7 +1112,10 @@ pub fn build_session_(
         features: Once::new(),
         recursion_limit: Once::new(),
         type_length_limit: Once::new(),
+        move_size_limit: Once::new(),
         const_eval_stack_frame_limit: 100,
+        const_eval_warn_limit: Once::new(),
+        const_eval_limit: Once::new(),
         next_node_id: OneThread::new(Cell::new(NodeId::new(1))),
         injected_allocator: Once::new(),
         allocator_kind: Once::new(),
@@ -58,6 +58,8 @@ impl ObjectSafetyViolation {
                          in its arguments or return type", name).into(),
             ObjectSafetyViolation::Method(name, MethodViolationCode::Generic) =>
                 format!("method `{}` has generic type parameters", name).into(),
+            ObjectSafetyViolation::Method(name, MethodViolationCode::GenericConst) =>
+                format!("method `{}` has const parameters", name).into(),
             ObjectSafetyViolation::Method(name, MethodViolationCode::NonStandardSelfType) =>
                 format!("method `{}` has a non-standard `self` type", name).into(),
             ObjectSafetyViolation::AssociatedConst(name) =>
@@ -78,6 +80,9 @@ pub enum MethodViolationCode {
     /// e.g., `fn foo<A>()`
     Generic,
 
+    /// e.g., `fn foo<const N: usize>()`
+    GenericConst,
+
     /// arbitrary `self` type, e.g. `self: Rc<Self>`
     NonStandardSelfType,
 }
@@ -288,6 +293,11 @@ impl<'a, 'gcx, 'tcx> TyCtxt<'a, 'gcx, 'tcx> {
             return Some(MethodViolationCode::Generic);
         }
 
+        // Nor things like `fn foo<const N: usize>(...)`.
+        if !self.generics_of(method.def_id).consts.is_empty() {
+            return Some(MethodViolationCode::GenericConst);
+        }
+
         None
     }
 
@@ -8,6 +8,7 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use middle::const_val::ConstVal;
 use ty::{self, Ty, TyCtxt};
 use ty::error::TypeError;
 use ty::relate::{self, Relate, TypeRelation, RelateResult};
@@ -88,6 +89,27 @@ impl<'a, 'gcx, 'tcx> TypeRelation<'a, 'gcx, 'tcx> for Match<'a, 'gcx, 'tcx> {
         }
     }
 
+    fn consts(&mut self, a: &'tcx ty::Const<'tcx>, b: &'tcx ty::Const<'tcx>)
+              -> RelateResult<'tcx, &'tcx ty::Const<'tcx>> {
+        debug!("{}.consts({:?}, {:?})", self.tag(), a, b);
+        if a == b { return Ok(a); }
+
+        match (a.val, b.val) {
+            (_, ConstVal::Infer(ty::InferConst::Fresh(_))) => {
+                Ok(a)
+            }
+
+            (ConstVal::Infer(_), _) |
+            (_, ConstVal::Infer(_)) => {
+                Err(TypeError::ConstMismatch(relate::expected_found(self, &a, &b)))
+            }
+
+            _ => {
+                relate::super_relate_consts(self, a, b)
+            }
+        }
+    }
+
     fn binders<T>(&mut self, a: &ty::Binder<T>, b: &ty::Binder<T>)
                   -> RelateResult<'tcx, ty::Binder<T>>
         where T: Relate<'tcx>
@@ -44,7 +44,7 @@ use ty::{TyS, TypeVariants, Slice};
 use ty::{AdtKind, AdtDef, ClosureSubsts, GeneratorInterior, Region, Const};
 use ty::{PolyFnSig, InferTy, ParamTy, ProjectionTy, ExistentialPredicate, Predicate};
 use ty::RegionKind;
-use ty::{TyVar, TyVid, IntVar, IntVid, FloatVar, FloatVid};
+use ty::{TyVar, TyVid, IntVar, IntVid, FloatVar, FloatVid, ConstVid, ParamConst};
 use ty::TypeVariants::*;
 use ty::layout::{LayoutDetails, TargetDataLayout};
 use ty::maps;
@@ -396,6 +396,10 @@ pub struct TypeckTables<'tcx> {
     /// Borrows
     pub upvar_capture_map: ty::UpvarCaptureMap<'tcx>,
 
+    /// The places captured from upvars that are not captured as a whole
+    /// (see `ty::CapturedPlace`).
+    pub upvar_places_map: ty::UpvarPlacesMap<'tcx>,
+
     /// Records the reasons that we picked the kind of each closure;
     /// not all closures are present in the map.
     closure_kind_origins: ItemLocalMap<(Span, ast::Name)>,
@@ -431,6 +435,10 @@ pub struct TypeckTables<'tcx> {
     /// its where clauses and parameter types. These are then
     /// read-again by borrowck.
     pub free_region_map: FreeRegionMap<'tcx>,
+
+    /// The concrete types this body infers for `impl Trait` type aliases
+    /// and associated types whose defining scope it is in.
+    pub concrete_existential_types: FxHashMap<DefId, Ty<'tcx>>,
 }
 
 impl<'tcx> TypeckTables<'tcx> {
@@ -446,6 +454,7 @@ impl<'tcx> TypeckTables<'tcx> {
             pat_binding_modes: ItemLocalMap(),
             pat_adjustments: ItemLocalMap(),
             upvar_capture_map: FxHashMap(),
+            upvar_places_map: FxHashMap(),
             closure_kind_origins: ItemLocalMap(),
             liberated_fn_sigs: ItemLocalMap(),
             fru_field_types: ItemLocalMap(),
@@ -453,6 +462,7 @@ impl<'tcx> TypeckTables<'tcx> {
             used_trait_imports: Lrc::new(DefIdSet()),
             tainted_by_errors: false,
             free_region_map: FreeRegionMap::new(),
+            concrete_existential_types: FxHashMap(),
         }
     }
 
@@ -669,6 +679,12 @@ impl<'tcx> TypeckTables<'tcx> {
         self.upvar_capture_map[&upvar_id]
     }
 
+    /// Returns the places captured from `upvar_id`, or `None` if the
+    /// variable is captured as a whole.
+    pub fn upvar_places(&self, upvar_id: ty::UpvarId) -> Option<&[ty::CapturedPlace<'tcx>]> {
+        self.upvar_places_map.get(&upvar_id).map(|places| &places[..])
+    }
+
     pub fn closure_kind_origins(&self) -> LocalTableInContext<(Span, ast::Name)> {
         LocalTableInContext {
             local_id_root: self.local_id_root,
@@ -741,6 +757,7 @@ impl<'a, 'gcx> HashStable<StableHashingContext<'a>> for TypeckTables<'gcx> {
             ref pat_binding_modes,
             ref pat_adjustments,
             ref upvar_capture_map,
+            ref upvar_places_map,
             ref closure_kind_origins,
             ref liberated_fn_sigs,
             ref fru_field_types,
@@ -750,6 +767,7 @@ impl<'a, 'gcx> HashStable<StableHashingContext<'a>> for TypeckTables<'gcx> {
             ref used_trait_imports,
             tainted_by_errors,
             ref free_region_map,
+            ref concrete_existential_types,
         } = *self;
 
         hcx.with_node_id_hashing_mode(NodeIdHashingMode::HashDefPath, |hcx| {
@@ -761,7 +779,7 @@ impl<'a, 'gcx> HashStable<StableHashingContext<'a>> for TypeckTables<'gcx> {
             adjustments.hash_stable(hcx, hasher);
             pat_binding_modes.hash_stable(hcx, hasher);
             pat_adjustments.hash_stable(hcx, hasher);
-            hash_stable_hashmap(hcx, hasher, upvar_capture_map, |up_var_id, hcx| {
+            let upvar_id_key = |up_var_id: &ty::UpvarId, hcx: &StableHashingContext<'a>| {
                 let ty::UpvarId {
                     var_id,
                     closure_expr_id
@@ -781,7 +799,9 @@ impl<'a, 'gcx> HashStable<StableHashingContext<'a>> for TypeckTables<'gcx> {
                 (hcx.def_path_hash(var_owner_def_id),
                  var_id.local_id,
                  hcx.def_path_hash(closure_def_id))
-            });
+            };
+            hash_stable_hashmap(hcx, hasher, upvar_capture_map, &upvar_id_key);
+            hash_stable_hashmap(hcx, hasher, upvar_places_map, &upvar_id_key);
 
             closure_kind_origins.hash_stable(hcx, hasher);
             liberated_fn_sigs.hash_stable(hcx, hasher);
@@ -790,6 +810,9 @@ impl<'a, 'gcx> HashStable<StableHashingContext<'a>> for TypeckTables<'gcx> {
             used_trait_imports.hash_stable(hcx, hasher);
             tainted_by_errors.hash_stable(hcx, hasher);
             free_region_map.hash_stable(hcx, hasher);
+            hash_stable_hashmap(hcx, hasher, concrete_existential_types, |def_id, hcx| {
+                hcx.def_path_hash(*def_id)
+            });
         })
     }
 }
@@ -2193,7 +2216,9 @@ direct_interners!('tcx,
             _ => false
         }
     }) -> RegionKind,
-    const_: mk_const(|c: &Const| keep_local(&c.ty) || keep_local(&c.val)) -> Const<'tcx>
+    const_: mk_const(|c: &Const| {
+        super::flags::FlagComputation::for_const(c).intersects(ty::TypeFlags::KEEP_IN_LOCAL_TCX)
+    }) -> Const<'tcx>
 );
 
 macro_rules! slice_interners {
@@ -2314,6 +2339,15 @@ impl<'a, 'gcx, 'tcx> TyCtxt<'a, 'gcx, 'tcx> {
         self.mk_imm_ref(self.types.re_static, self.mk_str())
     }
 
+    /// The type of a caller location, `&'static (&'static str, u32, u32)`,
+    /// as passed implicitly to `#[track_caller]` functions.
+    pub fn caller_location_ty(self) -> Ty<'tcx> {
+        let file_line_col = self.intern_tup(&[self.mk_static_str(),
+                                              self.types.u32,
+                                              self.types.u32]);
+        self.mk_imm_ref(self.types.re_static, file_line_col)
+    }
+
     pub fn mk_adt(self, def: &'tcx AdtDef, substs: &'tcx Substs<'tcx>) -> Ty<'tcx> {
         // take a copy of substs so that we own the vectors inside
         self.mk_ty(TyAdt(def, substs))
@@ -2476,6 +2510,40 @@ impl<'a, 'gcx, 'tcx> TyCtxt<'a, 'gcx, 'tcx> {
         self.mk_param(def.index, def.name)
     }
 
+    pub fn mk_const_param(self,
+                          index: u32,
+                          name: InternedString,
+                          ty: Ty<'tcx>) -> &'tcx Const<'tcx> {
+        self.mk_const(Const {
+            val: ConstVal::Param(ParamConst { idx: index, name: name }),
+            ty,
+        })
+    }
+
+    pub fn mk_const_param_from_def(self, def: &ty::ConstParameterDef) -> &'tcx Const<'tcx> {
+        self.mk_const_param(def.index, def.name, self.type_of(def.def_id))
+    }
+
+    /// A const standing in for one that could not be computed because of an
+    /// error that has already been reported.
+    pub fn mk_const_err(self) -> &'tcx Const<'tcx> {
+        self.mk_const(Const {
+            val: ConstVal::Value(Value::ByVal(PrimVal::Undef)),
+            ty: self.types.err,
+        })
+    }
+
+    pub fn mk_const_var(self, v: ConstVid<'tcx>, ty: Ty<'tcx>) -> &'tcx Const<'tcx> {
+        self.mk_const_infer(ty::InferConst::Var(v), ty)
+    }
+
+    pub fn mk_const_infer(self, ic: ty::InferConst<'tcx>, ty: Ty<'tcx>) -> &'tcx Const<'tcx> {
+        self.mk_const(Const {
+            val: ConstVal::Infer(ic),
+            ty,
+        })
+    }
+
     pub fn mk_anon(self, def_id: DefId, substs: &'tcx Substs<'tcx>) -> Ty<'tcx> {
         self.mk_ty(TyAnon(def_id, substs))
     }
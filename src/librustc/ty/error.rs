@@ -52,6 +52,7 @@ pub enum TypeError<'tcx> {
     ProjectionMismatched(ExpectedFound<DefId>),
     ProjectionBoundsLength(ExpectedFound<usize>),
     ExistentialMismatch(ExpectedFound<&'tcx ty::Slice<ty::ExistentialPredicate<'tcx>>>),
+    ConstMismatch(ExpectedFound<&'tcx ty::Const<'tcx>>),
 
     OldStyleLUB(Box<TypeError<'tcx>>),
 }
@@ -165,6 +166,9 @@ impl<'tcx> fmt::Display for TypeError<'tcx> {
                 report_maybe_different(f, format!("trait `{}`", values.expected),
                                        format!("trait `{}`", values.found))
             }
+            ConstMismatch(ref values) => {
+                write!(f, "expected `{}`, found `{}`", values.expected, values.found)
+            }
             OldStyleLUB(ref err) => {
                 write!(f, "{}", err)
             }
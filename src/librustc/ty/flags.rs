@@ -9,7 +9,7 @@
 // except according to those terms.
 
 use middle::const_val::ConstVal;
-use ty::subst::Substs;
+use ty::subst::{Substs, UnpackedKind};
 use ty::{self, Ty, TypeFlags, TypeFoldable};
 
 #[derive(Debug)]
@@ -31,6 +31,12 @@ impl FlagComputation {
         result
     }
 
+    pub fn for_const(c: &ty::Const) -> TypeFlags {
+        let mut result = FlagComputation::new();
+        result.add_const(c);
+        result.flags
+    }
+
     fn add_flags(&mut self, flags: TypeFlags) {
         self.flags = self.flags | (flags & TypeFlags::NOMINAL_FLAGS);
     }
@@ -228,6 +234,22 @@ impl FlagComputation {
                 self.add_flags(TypeFlags::HAS_PROJECTION);
                 self.add_substs(substs);
             }
+            ConstVal::Param(_) => {
+                self.add_flags(TypeFlags::HAS_LOCAL_NAMES);
+                self.add_flags(TypeFlags::HAS_PARAMS);
+            }
+            ConstVal::Infer(infer) => {
+                self.add_flags(TypeFlags::HAS_LOCAL_NAMES);
+                self.add_flags(TypeFlags::HAS_TY_INFER);
+                match infer {
+                    ty::InferConst::Fresh(_) => {
+                        self.add_flags(TypeFlags::HAS_CANONICAL_VARS);
+                    }
+                    ty::InferConst::Var(_) => {
+                        self.add_flags(TypeFlags::KEEP_IN_LOCAL_TCX);
+                    }
+                }
+            }
         }
     }
 
@@ -241,12 +263,12 @@ impl FlagComputation {
     }
 
     fn add_substs(&mut self, substs: &Substs) {
-        for ty in substs.types() {
-            self.add_ty(ty);
-        }
-
-        for r in substs.regions() {
-            self.add_region(r);
+        for kind in substs {
+            match kind.unpack() {
+                UnpackedKind::Type(ty) => self.add_ty(ty),
+                UnpackedKind::Lifetime(r) => self.add_region(r),
+                UnpackedKind::Const(c) => self.add_const(c),
+            }
         }
     }
 }
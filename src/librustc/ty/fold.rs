@@ -42,6 +42,7 @@
 use middle::const_val::ConstVal;
 use hir::def_id::DefId;
 use ty::{self, Binder, Ty, TyCtxt, TypeFlags};
+use ty::flags::FlagComputation;
 
 use std::fmt;
 use std::collections::BTreeMap;
@@ -621,7 +622,9 @@ impl<'tcx> TypeVisitor<'tcx> for HasTypeFlagsVisitor {
                 return true;
             }
         }
-        c.super_visit_with(self)
+        let flags = FlagComputation::for_const(c);
+        debug!("HasTypeFlagsVisitor: c={:?} c.flags={:?} self.flags={:?}", c, flags, self.flags);
+        flags.intersects(self.flags)
     }
 }
 
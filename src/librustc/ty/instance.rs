@@ -8,6 +8,7 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use hir::TransFnAttrFlags;
 use hir::def_id::DefId;
 use ty::{self, Ty, TypeFoldable, Substs, TyCtxt};
 use ty::subst::Kind;
@@ -28,6 +29,10 @@ pub enum InstanceDef<'tcx> {
     Item(DefId),
     Intrinsic(DefId),
 
+    /// `fn()` pointer or vtable entry for a `#[track_caller]` function,
+    /// which supplies the implicit caller location argument.
+    ReifyShim(DefId),
+
     /// \<fn() as FnTrait>::call_*
     /// def-id is FnTrait::call_*
     FnPtrShim(DefId, Ty<'tcx>),
@@ -67,6 +72,7 @@ impl<'tcx> InstanceDef<'tcx> {
             InstanceDef::FnPtrShim(def_id, _) |
             InstanceDef::Virtual(def_id, _) |
             InstanceDef::Intrinsic(def_id, ) |
+            InstanceDef::ReifyShim(def_id) |
             InstanceDef::ClosureOnceShim { call_once: def_id } |
             InstanceDef::DropGlue(def_id, _) |
             InstanceDef::CloneShim(def_id, _) => def_id
@@ -112,6 +118,20 @@ impl<'tcx> InstanceDef<'tcx> {
         let trans_fn_attrs = tcx.trans_fn_attrs(self.def_id());
         trans_fn_attrs.requests_inline() || tcx.is_const_fn(self.def_id())
     }
+
+    /// True if calls to this instance take the implicit caller location
+    /// argument of `#[track_caller]` functions.
+    pub fn requires_caller_location<'a>(
+        &self,
+        tcx: TyCtxt<'a, 'tcx, 'tcx>
+    ) -> bool {
+        match *self {
+            InstanceDef::Item(def_id) => {
+                tcx.trans_fn_attrs(def_id).flags.contains(TransFnAttrFlags::TRACK_CALLER)
+            }
+            _ => false
+        }
+    }
 }
 
 impl<'tcx> fmt::Display for Instance<'tcx> {
@@ -122,6 +142,9 @@ impl<'tcx> fmt::Display for Instance<'tcx> {
             InstanceDef::Intrinsic(_) => {
                 write!(f, " - intrinsic")
             }
+            InstanceDef::ReifyShim(_) => {
+                write!(f, " - shim(reify)")
+            }
             InstanceDef::Virtual(_, num) => {
                 write!(f, " - shim(#{})", num)
             }
@@ -229,6 +252,26 @@ impl<'a, 'b, 'tcx> Instance<'tcx> {
         result
     }
 
+    /// Like `resolve`, but for an instance whose address is taken, either
+    /// by coercing it to a `fn()` pointer or by placing it in a vtable.
+    /// `#[track_caller]` functions are wrapped in a `ReifyShim`, as their
+    /// callers can no longer be expected to pass a caller location.
+    pub fn resolve_for_fn_ptr(tcx: TyCtxt<'a, 'tcx, 'tcx>,
+                              param_env: ty::ParamEnv<'tcx>,
+                              def_id: DefId,
+                              substs: &'tcx Substs<'tcx>) -> Option<Instance<'tcx>> {
+        Instance::resolve(tcx, param_env, def_id, substs).map(|instance| {
+            if instance.def.requires_caller_location(tcx) {
+                Instance {
+                    def: InstanceDef::ReifyShim(instance.def_id()),
+                    substs: instance.substs
+                }
+            } else {
+                instance
+            }
+        })
+    }
+
     pub fn resolve_closure(
                     tcx: TyCtxt<'a, 'tcx, 'tcx>,
                     def_id: DefId,
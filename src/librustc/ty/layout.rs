@@ -1267,6 +1267,11 @@ impl<'a, 'tcx> LayoutCx<'tcx, TyCtxt<'a, 'tcx, 'tcx>> {
                     }
                 }
 
+                // The length of `[T; N]` is only known after monomorphization.
+                if count.val.to_raw_bits().is_none() {
+                    return Err(LayoutError::Unknown(ty));
+                }
+
                 let element = self.layout_of(element)?;
                 let count = count.val.unwrap_u64();
                 let size = element.size.checked_mul(count, dl)
@@ -1751,12 +1756,14 @@ impl<'a, 'tcx> LayoutCx<'tcx, TyCtxt<'a, 'tcx, 'tcx>> {
     /// layout of each type.
     #[inline]
     fn record_layout_for_printing(self, layout: TyLayout<'tcx>) {
-        // If we are running with `-Zprint-type-sizes`, record layouts for
+        // If we are running with `-Zprint-type-sizes` or
+        // `-Zprint-type-layouts`, record layouts for
         // dumping later. Ignore layouts that are done with non-empty
         // environments or non-monomorphic layouts, as the user only wants
         // to see the stuff resulting from the final trans session.
+        let debugging_opts = &self.tcx.sess.opts.debugging_opts;
         if
-            !self.tcx.sess.opts.debugging_opts.print_type_sizes ||
+            !(debugging_opts.print_type_sizes || debugging_opts.print_type_layouts) ||
             layout.ty.has_param_types() ||
             layout.ty.has_self_ty() ||
             !self.param_env.caller_bounds.is_empty()
@@ -1768,8 +1775,35 @@ impl<'a, 'tcx> LayoutCx<'tcx, TyCtxt<'a, 'tcx, 'tcx>> {
     }
 
     fn record_layout_for_printing_outlined(self, layout: TyLayout<'tcx>) {
+        let scalar_info = |offset: Size, scalar: &Scalar| session::ScalarInfo {
+            offset: offset.bytes(),
+            size: scalar.value.size(self).bytes(),
+            valid_range_start: scalar.valid_range.start,
+            valid_range_end: scalar.valid_range.end,
+        };
+
+        // Only `-Zprint-type-layouts` shows niches, don't look for them
+        // otherwise.
+        let niche = if self.tcx.sess.opts.debugging_opts.print_type_layouts {
+            match layout.find_niche(self, 1) {
+                Ok(Some((offset, scalar, niche_start))) => {
+                    // `find_niche` hands out the first invalid value by
+                    // extending the valid range over it, undo that.
+                    let max_value = !0u128 >> (128 - scalar.value.size(self).bits());
+                    let valid_range_end = niche_start.wrapping_sub(1) & max_value;
+                    Some(session::ScalarInfo {
+                        valid_range_end,
+                        ..scalar_info(offset, &scalar)
+                    })
+                }
+                Ok(None) | Err(_) => None,
+            }
+        } else {
+            None
+        };
+
         // (delay format until we actually need it)
-        let record = |kind, packed, opt_discr_size, variants| {
+        let record = |kind, packed, opt_discr_size, variants, tag| {
             let type_desc = format!("{:?}", layout.ty);
             self.tcx.sess.code_stats.borrow_mut().record_type_size(kind,
                                                                    type_desc,
@@ -1777,7 +1811,9 @@ impl<'a, 'tcx> LayoutCx<'tcx, TyCtxt<'a, 'tcx, 'tcx>> {
                                                                    layout.size,
                                                                    packed,
                                                                    opt_discr_size,
-                                                                   variants);
+                                                                   variants,
+                                                                   tag,
+                                                                   niche.clone());
         };
 
         let adt_def = match layout.ty.sty {
@@ -1788,7 +1824,7 @@ impl<'a, 'tcx> LayoutCx<'tcx, TyCtxt<'a, 'tcx, 'tcx>> {
 
             ty::TyClosure(..) => {
                 debug!("print-type-size t: `{:?}` record closure", layout.ty);
-                record(DataTypeKind::Closure, false, None, vec![]);
+                record(DataTypeKind::Closure, false, None, vec![], None);
                 return;
             }
 
@@ -1856,11 +1892,12 @@ impl<'a, 'tcx> LayoutCx<'tcx, TyCtxt<'a, 'tcx, 'tcx>> {
                            None,
                            vec![build_variant_info(Some(variant_def.name),
                                                    &fields,
-                                                   layout)]);
+                                                   layout)],
+                           None);
                 } else {
                     // (This case arises for *empty* enums; so give it
                     // zero variants.)
-                    record(adt_kind.into(), adt_packed, None, vec![]);
+                    record(adt_kind.into(), adt_packed, None, vec![], None);
                 }
             }
 
@@ -1877,10 +1914,30 @@ impl<'a, 'tcx> LayoutCx<'tcx, TyCtxt<'a, 'tcx, 'tcx>> {
                                             layout.for_variant(self, i))
                     })
                     .collect();
+                // The tag of both encodings is the only field of the enum.
+                let tag = match layout.variants {
+                    Variants::Single { .. } => bug!(),
+                    Variants::Tagged { ref discr, .. } => session::TagEncoding::Direct {
+                        tag: scalar_info(layout.fields.offset(0), discr),
+                    },
+                    Variants::NicheFilling {
+                        dataful_variant,
+                        ref niche_variants,
+                        ref niche,
+                        niche_start,
+                        ..
+                    } => session::TagEncoding::NicheFilling {
+                        dataful_variant: adt_def.variants[dataful_variant].name.to_string(),
+                        niche_variants_start: niche_variants.start as u64,
+                        niche_variants_end: niche_variants.end as u64,
+                        niche: scalar_info(layout.fields.offset(0), niche),
+                        niche_start,
+                    },
+                };
                 record(adt_kind.into(), adt_packed, match layout.variants {
                     Variants::Tagged { ref discr, .. } => Some(discr.value.size(self)),
                     _ => None
-                }, variant_infos);
+                }, variant_infos, Some(tag));
             }
         }
     }
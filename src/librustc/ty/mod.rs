@@ -63,13 +63,14 @@ use hir;
 pub use self::sty::{Binder, CanonicalVar, DebruijnIndex};
 pub use self::sty::{FnSig, GenSig, PolyFnSig, PolyGenSig};
 pub use self::sty::{InferTy, ParamTy, ProjectionTy, ExistentialPredicate};
+pub use self::sty::{InferConst, ParamConst};
 pub use self::sty::{ClosureSubsts, GeneratorInterior, TypeAndMut};
 pub use self::sty::{TraitRef, TypeVariants, PolyTraitRef};
 pub use self::sty::{ExistentialTraitRef, PolyExistentialTraitRef};
 pub use self::sty::{ExistentialProjection, PolyExistentialProjection, Const};
 pub use self::sty::{BoundRegion, EarlyBoundRegion, FreeRegion, Region};
 pub use self::sty::RegionKind;
-pub use self::sty::{TyVid, IntVid, FloatVid, RegionVid, SkolemizedRegionVid};
+pub use self::sty::{TyVid, IntVid, FloatVid, ConstVid, RegionVid, SkolemizedRegionVid};
 pub use self::sty::BoundRegion::*;
 pub use self::sty::InferTy::*;
 pub use self::sty::RegionKind::*;
@@ -694,6 +695,51 @@ pub struct UpvarBorrow<'tcx> {
 
 pub type UpvarCaptureMap<'tcx> = FxHashMap<UpvarId, UpvarCapture<'tcx>>;
 
+/// A step from a captured variable towards a place inside of it.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, RustcEncodable, RustcDecodable)]
+pub enum CaptureProjection {
+    Deref,
+    Field(usize, Name),
+}
+
+/// A place captured by a closure under `#![feature(capture_disjoint_fields)]`.
+/// The place is reached from the captured variable by `projections`, each of
+/// which is paired with the type of the place it leads to.
+#[derive(PartialEq, Clone, Debug, RustcEncodable, RustcDecodable)]
+pub struct CapturedPlace<'tcx> {
+    pub projections: Vec<(CaptureProjection, Ty<'tcx>)>,
+    pub capture: UpvarCapture<'tcx>,
+}
+
+impl<'tcx> CapturedPlace<'tcx> {
+    /// The type of the captured place itself.
+    pub fn place_ty(&self) -> Ty<'tcx> {
+        self.projections.last().expect("captured place without projections").1
+    }
+
+    /// The type in which the place is stored in the closure.
+    pub fn captured_ty<'a, 'gcx>(&self, tcx: TyCtxt<'a, 'gcx, 'tcx>) -> Ty<'tcx> {
+        match self.capture {
+            UpvarCapture::ByValue => self.place_ty(),
+            UpvarCapture::ByRef(borrow) => tcx.mk_ref(borrow.region, TypeAndMut {
+                ty: self.place_ty(),
+                mutbl: borrow.kind.to_mutbl_lossy(),
+            }),
+        }
+    }
+
+    /// Whether this place is `projections`, or contains it.
+    pub fn is_prefix_of(&self, projections: &[CaptureProjection]) -> bool {
+        self.projections.len() <= projections.len() &&
+            self.projections.iter().zip(projections).all(|(&(a, _), &b)| a == b)
+    }
+}
+
+/// The places captured from variables that a closure does not capture as a
+/// whole. A variable listed here is stored in the closure as a tuple with one
+/// element per captured place.
+pub type UpvarPlacesMap<'tcx> = FxHashMap<UpvarId, Vec<CapturedPlace<'tcx>>>;
+
 #[derive(Copy, Clone)]
 pub struct ClosureUpvar<'tcx> {
     pub def: Def,
@@ -738,6 +784,13 @@ pub struct RegionParameterDef {
     pub pure_wrt_drop: bool,
 }
 
+#[derive(Copy, Clone, RustcEncodable, RustcDecodable)]
+pub struct ConstParameterDef {
+    pub name: InternedString,
+    pub def_id: DefId,
+    pub index: u32,
+}
+
 impl RegionParameterDef {
     pub fn to_early_bound_region_data(&self) -> ty::EarlyBoundRegion {
         ty::EarlyBoundRegion {
@@ -763,32 +816,45 @@ impl ty::EarlyBoundRegion {
 ///
 /// Note that in the presence of a `Self` parameter, the ordering here
 /// is different from the ordering in a Substs. Substs are ordered as
-///     Self, *Regions, *Other Type Params, (...child generics)
+///     Self, *Regions, *Other Type Params, *Const Params, (...child generics)
 /// while this struct is ordered as
 ///     regions = Regions
 ///     types = [Self, *Other Type Params]
+///     consts = Const Params
 #[derive(Clone, Debug, RustcEncodable, RustcDecodable)]
 pub struct Generics {
     pub parent: Option<DefId>,
     pub parent_regions: u32,
     pub parent_types: u32,
+    pub parent_consts: u32,
     pub regions: Vec<RegionParameterDef>,
     pub types: Vec<TypeParameterDef>,
+    pub consts: Vec<ConstParameterDef>,
 
     /// Reverse map to each `TypeParameterDef`'s `index` field
     pub type_param_to_index: FxHashMap<DefId, u32>,
 
+    /// Reverse map to each `ConstParameterDef`'s `index` field
+    pub const_param_to_index: FxHashMap<DefId, u32>,
+
     pub has_self: bool,
     pub has_late_bound_regions: Option<Span>,
 }
 
 impl<'a, 'gcx, 'tcx> Generics {
     pub fn parent_count(&self) -> usize {
-        self.parent_regions as usize + self.parent_types as usize
+        self.parent_regions as usize + self.parent_types as usize + self.parent_consts as usize
     }
 
     pub fn own_count(&self) -> usize {
-        self.regions.len() + self.types.len()
+        self.regions.len() + self.types.len() + self.consts.len()
+    }
+
+    /// Whether this item or any of its parents has type or const
+    /// parameters, i.e. whether it must be monomorphized before use.
+    pub fn requires_monomorphization(&self) -> bool {
+        self.parent_types as usize + self.types.len() +
+            self.parent_consts as usize + self.consts.len() > 0
     }
 
     pub fn count(&self) -> usize {
@@ -858,6 +924,20 @@ impl<'a, 'gcx, 'tcx> Generics {
                 .type_param(param, tcx)
         }
     }
+
+    /// Returns the `ConstParameterDef` associated with this `ParamConst`.
+    pub fn const_param(&'tcx self,
+                       param: &ParamConst,
+                       tcx: TyCtxt<'a, 'gcx, 'tcx>)
+                       -> &ConstParameterDef {
+        if let Some(idx) = param.idx.checked_sub(self.parent_count() as u32) {
+            // Const parameters always come after all regions and types.
+            &self.consts[idx as usize - self.regions.len() - self.types.len()]
+        } else {
+            tcx.generics_of(self.parent.expect("parent_count>0 but no parent?"))
+                .const_param(param, tcx)
+        }
+    }
 }
 
 /// Bounds on generics.
@@ -2489,6 +2569,7 @@ impl<'a, 'gcx, 'tcx> TyCtxt<'a, 'gcx, 'tcx> {
                 self.optimized_mir(did)
             }
             ty::InstanceDef::Intrinsic(..) |
+            ty::InstanceDef::ReifyShim(..) |
             ty::InstanceDef::FnPtrShim(..) |
             ty::InstanceDef::Virtual(..) |
             ty::InstanceDef::ClosureOnceShim { .. } |
@@ -91,6 +91,9 @@ pub trait TypeRelation<'a, 'gcx: 'a+'tcx, 'tcx: 'a> : Sized {
     fn regions(&mut self, a: ty::Region<'tcx>, b: ty::Region<'tcx>)
                -> RelateResult<'tcx, ty::Region<'tcx>>;
 
+    fn consts(&mut self, a: &'tcx ty::Const<'tcx>, b: &'tcx ty::Const<'tcx>)
+              -> RelateResult<'tcx, &'tcx ty::Const<'tcx>>;
+
     fn binders<T>(&mut self, a: &ty::Binder<T>, b: &ty::Binder<T>)
                   -> RelateResult<'tcx, ty::Binder<T>>
         where T: Relate<'tcx>;
@@ -467,48 +470,8 @@ pub fn super_relate_tys<'a, 'gcx, 'tcx, R>(relation: &mut R,
             let t = relation.relate(&a_t, &b_t)?;
             assert_eq!(sz_a.ty, tcx.types.usize);
             assert_eq!(sz_b.ty, tcx.types.usize);
-            let to_u64 = |x: &'tcx ty::Const<'tcx>| -> Result<u64, ErrorReported> {
-                match x.val {
-                    ConstVal::Value(Value::ByVal(prim)) => Ok(prim.to_u64().unwrap()),
-                    ConstVal::Unevaluated(def_id, substs) => {
-                        // FIXME(eddyb) get the right param_env.
-                        let param_env = ty::ParamEnv::empty();
-                        match tcx.lift_to_global(&substs) {
-                            Some(substs) => {
-                                let instance = ty::Instance::resolve(
-                                    tcx.global_tcx(),
-                                    param_env,
-                                    def_id,
-                                    substs,
-                                );
-                                if let Some(instance) = instance {
-                                    let cid = GlobalId {
-                                        instance,
-                                        promoted: None
-                                    };
-                                    match tcx.const_eval(param_env.and(cid)) {
-                                        Ok(&ty::Const {
-                                            val: ConstVal::Value(Value::ByVal(PrimVal::Bytes(b))),
-                                            ..
-                                        }) => {
-                                            assert_eq!(b as u64 as u128, b);
-                                            return Ok(b as u64);
-                                        }
-                                        _ => {}
-                                    }
-                                }
-                            },
-                            None => {}
-                        }
-                        tcx.sess.delay_span_bug(tcx.def_span(def_id),
-                            "array length could not be evaluated");
-                        Err(ErrorReported)
-                    }
-                    _ => bug!("arrays should not have {:?} as length", x)
-                }
-            };
-            match (to_u64(sz_a), to_u64(sz_b)) {
-                (Ok(sz_a_u64), Ok(sz_b_u64)) => {
+            match (array_length_to_u64(tcx, sz_a), array_length_to_u64(tcx, sz_b)) {
+                (Some(Ok(sz_a_u64)), Some(Ok(sz_b_u64))) => {
                     if sz_a_u64 == sz_b_u64 {
                         Ok(tcx.mk_ty(ty::TyArray(t, sz_a)))
                     } else {
@@ -517,9 +480,15 @@ pub fn super_relate_tys<'a, 'gcx, 'tcx, R>(relation: &mut R,
                     }
                 }
                 // We reported an error or will ICE, so we can return TyError.
-                (Err(ErrorReported), _) | (_, Err(ErrorReported)) => {
+                (Some(Err(ErrorReported)), _) | (_, Some(Err(ErrorReported))) => {
                     Ok(tcx.types.err)
                 }
+                // At least one of the lengths is a const parameter or
+                // an inference variable, so relate the lengths themselves.
+                _ => {
+                    let sz = relation.relate(&sz_a, &sz_b)?;
+                    Ok(tcx.mk_ty(ty::TyArray(t, sz)))
+                }
             }
         }
 
@@ -683,6 +652,96 @@ impl<'tcx, T: Relate<'tcx>> Relate<'tcx> for Box<T> {
     }
 }
 
+/// Evaluates the length of an array type, returning `None` if the
+/// length is not known until monomorphization or type inference.
+fn array_length_to_u64<'a, 'gcx, 'tcx>(tcx: TyCtxt<'a, 'gcx, 'tcx>,
+                                       x: &'tcx ty::Const<'tcx>)
+                                       -> Option<Result<u64, ErrorReported>> {
+    match x.val {
+        ConstVal::Value(Value::ByVal(prim)) => Some(Ok(prim.to_u64().unwrap())),
+        ConstVal::Unevaluated(def_id, substs) => {
+            // FIXME(eddyb) get the right param_env.
+            let param_env = ty::ParamEnv::empty();
+            match tcx.lift_to_global(&substs) {
+                Some(substs) => {
+                    let instance = ty::Instance::resolve(
+                        tcx.global_tcx(),
+                        param_env,
+                        def_id,
+                        substs,
+                    );
+                    if let Some(instance) = instance {
+                        let cid = GlobalId {
+                            instance,
+                            promoted: None
+                        };
+                        match tcx.const_eval(param_env.and(cid)) {
+                            Ok(&ty::Const {
+                                val: ConstVal::Value(Value::ByVal(PrimVal::Bytes(b))),
+                                ..
+                            }) => {
+                                assert_eq!(b as u64 as u128, b);
+                                return Some(Ok(b as u64));
+                            }
+                            _ => {}
+                        }
+                    }
+                },
+                None => {}
+            }
+            tcx.sess.delay_span_bug(tcx.def_span(def_id),
+                "array length could not be evaluated");
+            Some(Err(ErrorReported))
+        }
+        ConstVal::Param(_) | ConstVal::Infer(_) => None,
+        _ => bug!("arrays should not have {:?} as length", x)
+    }
+}
+
+/// The main "const relation" routine. Like `super_relate_tys`, this
+/// does not handle inference variables, which are left to the caller.
+pub fn super_relate_consts<'a, 'gcx, 'tcx, R>(relation: &mut R,
+                                              a: &'tcx ty::Const<'tcx>,
+                                              b: &'tcx ty::Const<'tcx>)
+                                              -> RelateResult<'tcx, &'tcx ty::Const<'tcx>>
+    where R: TypeRelation<'a, 'gcx, 'tcx>, 'gcx: 'a+'tcx, 'tcx: 'a
+{
+    let tcx = relation.tcx();
+    let is_match = match (a.val, b.val) {
+        (ConstVal::Infer(_), _) | (_, ConstVal::Infer(_)) => {
+            bug!("var types encountered in super_relate_consts: {:?} {:?}", a, b)
+        }
+        (ConstVal::Param(a_p), ConstVal::Param(b_p)) => a_p.idx == b_p.idx,
+        (ConstVal::Value(a_v), ConstVal::Value(b_v)) => a_v == b_v,
+        (ConstVal::Unevaluated(a_def_id, a_substs), ConstVal::Unevaluated(b_def_id, b_substs))
+            if a_def_id == b_def_id =>
+        {
+            let substs = relation.relate_with_variance(ty::Invariant, &a_substs, &b_substs)?;
+            return Ok(tcx.mk_const(ty::Const {
+                val: ConstVal::Unevaluated(a_def_id, substs),
+                ty: a.ty,
+            }));
+        }
+        _ => false,
+    };
+    if is_match {
+        Ok(a)
+    } else {
+        Err(TypeError::ConstMismatch(expected_found(relation, &a, &b)))
+    }
+}
+
+impl<'tcx> Relate<'tcx> for &'tcx ty::Const<'tcx> {
+    fn relate<'a, 'gcx, R>(relation: &mut R,
+                           a: &&'tcx ty::Const<'tcx>,
+                           b: &&'tcx ty::Const<'tcx>)
+                           -> RelateResult<'tcx, &'tcx ty::Const<'tcx>>
+        where R: TypeRelation<'a, 'gcx, 'tcx>, 'gcx: 'a+'tcx, 'tcx: 'a
+    {
+        relation.consts(*a, *b)
+    }
+}
+
 impl<'tcx> Relate<'tcx> for Kind<'tcx> {
     fn relate<'a, 'gcx, R>(
         relation: &mut R,
@@ -699,7 +758,12 @@ impl<'tcx> Relate<'tcx> for Kind<'tcx> {
             (UnpackedKind::Type(a_ty), UnpackedKind::Type(b_ty)) => {
                 Ok(relation.relate(&a_ty, &b_ty)?.into())
             }
-            (UnpackedKind::Lifetime(_), _) | (UnpackedKind::Type(_), _) => bug!()
+            (UnpackedKind::Const(a_ct), UnpackedKind::Const(b_ct)) => {
+                Ok(relation.relate(&a_ct, &b_ct)?.into())
+            }
+            (UnpackedKind::Lifetime(_), _) |
+            (UnpackedKind::Type(_), _) |
+            (UnpackedKind::Const(_), _) => bug!()
         }
     }
 }
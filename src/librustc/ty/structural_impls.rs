@@ -454,7 +454,8 @@ impl<'a, 'tcx> Lift<'tcx> for ty::error::TypeError<'a> {
 
             Sorts(ref x) => return tcx.lift(x).map(Sorts),
             OldStyleLUB(ref x) => return tcx.lift(x).map(OldStyleLUB),
-            ExistentialMismatch(ref x) => return tcx.lift(x).map(ExistentialMismatch)
+            ExistentialMismatch(ref x) => return tcx.lift(x).map(ExistentialMismatch),
+            ConstMismatch(ref x) => return tcx.lift(x).map(ConstMismatch),
         })
     }
 }
@@ -510,6 +511,7 @@ impl<'a, 'tcx> Lift<'tcx> for interpret::EvalError<'a> {
             OverflowingMath => OverflowingMath,
             InvalidChar(c) => InvalidChar(c),
             StackFrameLimitReached => StackFrameLimitReached,
+            StepLimitReached => StepLimitReached,
             OutOfTls => OutOfTls,
             TlsOutOfBounds => TlsOutOfBounds,
             AbiViolation(ref s) => AbiViolation(s.clone()),
@@ -567,6 +569,7 @@ impl<'a, 'tcx> Lift<'tcx> for interpret::EvalError<'a> {
             PathNotFound(ref v) => PathNotFound(v.clone()),
             UnimplementedTraitSelection => UnimplementedTraitSelection,
             TypeckError => TypeckError,
+            TooGeneric => TooGeneric,
             ReferencedConstant => ReferencedConstant,
         };
         Some(interpret::EvalError {
@@ -620,6 +623,8 @@ impl<'a, 'tcx> Lift<'tcx> for ty::InstanceDef<'a> {
                 Some(ty::InstanceDef::Item(def_id)),
             ty::InstanceDef::Intrinsic(def_id) =>
                 Some(ty::InstanceDef::Intrinsic(def_id)),
+            ty::InstanceDef::ReifyShim(def_id) =>
+                Some(ty::InstanceDef::ReifyShim(def_id)),
             ty::InstanceDef::FnPtrShim(def_id, ref ty) =>
                 Some(ty::InstanceDef::FnPtrShim(def_id, tcx.lift(ty)?)),
             ty::InstanceDef::Virtual(def_id, n) =>
@@ -1128,6 +1133,7 @@ EnumTypeFoldableImpl! {
         (ty::error::TypeError::ProjectionBoundsLength)(x),
         (ty::error::TypeError::Sorts)(x),
         (ty::error::TypeError::ExistentialMismatch)(x),
+        (ty::error::TypeError::ConstMismatch)(x),
         (ty::error::TypeError::OldStyleLUB)(x),
     }
 }
@@ -1139,12 +1145,14 @@ impl<'tcx> TypeFoldable<'tcx> for ConstVal<'tcx> {
             ConstVal::Unevaluated(def_id, substs) => {
                 ConstVal::Unevaluated(def_id, substs.fold_with(folder))
             }
+            ConstVal::Param(p) => ConstVal::Param(p),
+            ConstVal::Infer(i) => ConstVal::Infer(i),
         }
     }
 
     fn super_visit_with<V: TypeVisitor<'tcx>>(&self, visitor: &mut V) -> bool {
         match *self {
-            ConstVal::Value(_) => false,
+            ConstVal::Value(_) | ConstVal::Param(_) | ConstVal::Infer(_) => false,
             ConstVal::Unevaluated(_, substs) => substs.visit_with(visitor),
         }
     }
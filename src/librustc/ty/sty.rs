@@ -22,6 +22,7 @@ use util::captures::Captures;
 
 use std::iter;
 use std::cmp::Ordering;
+use std::marker::PhantomData;
 use syntax::abi;
 use syntax::ast::{self, Name};
 use syntax::symbol::{keywords, InternedString};
@@ -885,6 +886,27 @@ impl<'a, 'gcx, 'tcx> ParamTy {
     }
 }
 
+/// A const generic parameter, e.g. `N` in `struct Buf<const N: usize>`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, RustcEncodable, RustcDecodable)]
+pub struct ParamConst {
+    pub idx: u32,
+    pub name: InternedString,
+}
+
+impl<'a, 'gcx, 'tcx> ParamConst {
+    pub fn new(index: u32, name: InternedString) -> ParamConst {
+        ParamConst { idx: index, name: name }
+    }
+
+    pub fn for_def(def: &ty::ConstParameterDef) -> ParamConst {
+        ParamConst::new(def.index, def.name)
+    }
+
+    pub fn to_const(self, tcx: TyCtxt<'a, 'gcx, 'tcx>, ty: Ty<'tcx>) -> &'tcx Const<'tcx> {
+        tcx.mk_const_param(self.idx, self.name, ty)
+    }
+}
+
 /// A [De Bruijn index][dbi] is a standard means of representing
 /// regions (and perhaps later types) in a higher-ranked setting. In
 /// particular, imagine a type like this:
@@ -1067,6 +1089,12 @@ pub struct FloatVid {
     pub index: u32,
 }
 
+#[derive(Clone, Copy, PartialEq, Eq, Hash, RustcEncodable, RustcDecodable)]
+pub struct ConstVid<'tcx> {
+    pub index: u32,
+    pub phantom: PhantomData<&'tcx ()>,
+}
+
 newtype_index!(RegionVid
     {
         pub idx
@@ -1097,6 +1125,18 @@ pub enum InferTy {
 
 newtype_index!(CanonicalVar);
 
+/// An inference variable standing for a const argument, e.g. the length
+/// of an array type whose length is not yet known.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, RustcEncodable, RustcDecodable)]
+pub enum InferConst<'tcx> {
+    Var(ConstVid<'tcx>),
+
+    /// A `Fresh` const is generated as a replacement for an unbound
+    /// const variable, just like `FreshTy`.
+    Fresh(u32),
+}
+
+
 /// A `ProjectionPredicate` for an `ExistentialTraitRef`.
 #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, RustcEncodable, RustcDecodable)]
 pub struct ExistentialProjection<'tcx> {
@@ -11,6 +11,7 @@
 // Type substitutions.
 
 use hir::def_id::DefId;
+use middle::const_val::ConstVal;
 use ty::{self, Lift, Slice, Region, Ty, TyCtxt};
 use ty::fold::{TypeFoldable, TypeFolder, TypeVisitor};
 
@@ -26,24 +27,26 @@ use std::mem;
 use std::num::NonZeroUsize;
 
 /// An entity in the Rust typesystem, which can be one of
-/// several kinds (only types and lifetimes for now).
+/// several kinds (types, lifetimes and constants).
 /// To reduce memory usage, a `Kind` is a interned pointer,
 /// with the lowest 2 bits being reserved for a tag to
-/// indicate the type (`Ty` or `Region`) it points to.
+/// indicate the type (`Ty`, `Region` or `Const`) it points to.
 #[derive(Copy, Clone, PartialEq, Eq, Hash)]
 pub struct Kind<'tcx> {
     ptr: NonZeroUsize,
-    marker: PhantomData<(Ty<'tcx>, ty::Region<'tcx>)>
+    marker: PhantomData<(Ty<'tcx>, ty::Region<'tcx>, &'tcx ty::Const<'tcx>)>
 }
 
 const TAG_MASK: usize = 0b11;
 const TYPE_TAG: usize = 0b00;
 const REGION_TAG: usize = 0b01;
+const CONST_TAG: usize = 0b10;
 
 #[derive(Debug)]
 pub enum UnpackedKind<'tcx> {
     Lifetime(ty::Region<'tcx>),
     Type(Ty<'tcx>),
+    Const(&'tcx ty::Const<'tcx>),
 }
 
 impl<'tcx> UnpackedKind<'tcx> {
@@ -59,6 +62,11 @@ impl<'tcx> UnpackedKind<'tcx> {
                 assert_eq!(mem::align_of_val(ty) & TAG_MASK, 0);
                 (TYPE_TAG, ty as *const _ as usize)
             }
+            UnpackedKind::Const(ct) => {
+                // Ensure we can use the tag bits.
+                assert_eq!(mem::align_of_val(ct) & TAG_MASK, 0);
+                (CONST_TAG, ct as *const _ as usize)
+            }
         };
 
         Kind {
@@ -82,6 +90,12 @@ impl<'tcx> From<Ty<'tcx>> for Kind<'tcx> {
     }
 }
 
+impl<'tcx> From<&'tcx ty::Const<'tcx>> for Kind<'tcx> {
+    fn from(c: &'tcx ty::Const<'tcx>) -> Kind<'tcx> {
+        UnpackedKind::Const(c).pack()
+    }
+}
+
 impl<'tcx> Kind<'tcx> {
     #[inline]
     pub fn unpack(self) -> UnpackedKind<'tcx> {
@@ -90,6 +104,7 @@ impl<'tcx> Kind<'tcx> {
             match ptr & TAG_MASK {
                 REGION_TAG => UnpackedKind::Lifetime(&*((ptr & !TAG_MASK) as *const _)),
                 TYPE_TAG => UnpackedKind::Type(&*((ptr & !TAG_MASK) as *const _)),
+                CONST_TAG => UnpackedKind::Const(&*((ptr & !TAG_MASK) as *const _)),
                 _ => intrinsics::unreachable()
             }
         }
@@ -101,6 +116,7 @@ impl<'tcx> fmt::Debug for Kind<'tcx> {
         match self.unpack() {
             UnpackedKind::Lifetime(lt) => write!(f, "{:?}", lt),
             UnpackedKind::Type(ty) => write!(f, "{:?}", ty),
+            UnpackedKind::Const(ct) => write!(f, "{:?}", ct),
         }
     }
 }
@@ -110,6 +126,7 @@ impl<'tcx> fmt::Display for Kind<'tcx> {
         match self.unpack() {
             UnpackedKind::Lifetime(lt) => write!(f, "{}", lt),
             UnpackedKind::Type(ty) => write!(f, "{}", ty),
+            UnpackedKind::Const(ct) => write!(f, "{}", ct),
         }
     }
 }
@@ -121,6 +138,7 @@ impl<'a, 'tcx> Lift<'tcx> for Kind<'a> {
         match self.unpack() {
             UnpackedKind::Lifetime(a) => a.lift_to_tcx(tcx).map(|a| a.into()),
             UnpackedKind::Type(a) => a.lift_to_tcx(tcx).map(|a| a.into()),
+            UnpackedKind::Const(a) => a.lift_to_tcx(tcx).map(|a| a.into()),
         }
     }
 }
@@ -130,6 +148,7 @@ impl<'tcx> TypeFoldable<'tcx> for Kind<'tcx> {
         match self.unpack() {
             UnpackedKind::Lifetime(lt) => lt.fold_with(folder).into(),
             UnpackedKind::Type(ty) => ty.fold_with(folder).into(),
+            UnpackedKind::Const(ct) => ct.fold_with(folder).into(),
         }
     }
 
@@ -137,6 +156,7 @@ impl<'tcx> TypeFoldable<'tcx> for Kind<'tcx> {
         match self.unpack() {
             UnpackedKind::Lifetime(lt) => lt.visit_with(visitor),
             UnpackedKind::Type(ty) => ty.visit_with(visitor),
+            UnpackedKind::Const(ct) => ct.visit_with(visitor),
         }
     }
 }
@@ -155,6 +175,11 @@ impl<'tcx> Encodable for Kind<'tcx> {
                         e.emit_enum_variant_arg(0, |e| ty.encode(e))
                     })
                 }
+                UnpackedKind::Const(ct) => {
+                    e.emit_enum_variant("Const", CONST_TAG, 1, |e| {
+                        e.emit_enum_variant_arg(0, |e| ct.encode(e))
+                    })
+                }
             }
         })
     }
@@ -163,10 +188,11 @@ impl<'tcx> Encodable for Kind<'tcx> {
 impl<'tcx> Decodable for Kind<'tcx> {
     fn decode<D: Decoder>(d: &mut D) -> Result<Kind<'tcx>, D::Error> {
         d.read_enum("Kind", |d| {
-            d.read_enum_variant(&["Ty", "Region"], |d, tag| {
+            d.read_enum_variant(&["Ty", "Region", "Const"], |d, tag| {
                 match tag {
                     TYPE_TAG => Ty::decode(d).map(Kind::from),
                     REGION_TAG => Region::decode(d).map(Kind::from),
+                    CONST_TAG => <&'tcx ty::Const<'tcx>>::decode(d).map(Kind::from),
                     _ => Err(d.error("invalid Kind tag"))
                 }
             })
@@ -183,64 +209,72 @@ impl<'a, 'gcx, 'tcx> Substs<'tcx> {
                              -> &'tcx Substs<'tcx> {
         Substs::for_item(tcx, def_id, |def, _| {
             tcx.mk_region(ty::ReEarlyBound(def.to_early_bound_region_data()))
-        }, |def, _| tcx.mk_param_from_def(def))
+        }, |def, _| tcx.mk_param_from_def(def), |def, _| tcx.mk_const_param_from_def(def))
     }
 
     /// Creates a Substs for generic parameter definitions,
-    /// by calling closures to obtain each region and type.
+    /// by calling closures to obtain each region, type and const.
     /// The closures get to observe the Substs as they're
     /// being built, which can be used to correctly
     /// substitute defaults of type parameters.
-    pub fn for_item<FR, FT>(tcx: TyCtxt<'a, 'gcx, 'tcx>,
-                            def_id: DefId,
-                            mut mk_region: FR,
-                            mut mk_type: FT)
-                            -> &'tcx Substs<'tcx>
+    pub fn for_item<FR, FT, FC>(tcx: TyCtxt<'a, 'gcx, 'tcx>,
+                                def_id: DefId,
+                                mut mk_region: FR,
+                                mut mk_type: FT,
+                                mut mk_const: FC)
+                                -> &'tcx Substs<'tcx>
     where FR: FnMut(&ty::RegionParameterDef, &[Kind<'tcx>]) -> ty::Region<'tcx>,
-          FT: FnMut(&ty::TypeParameterDef, &[Kind<'tcx>]) -> Ty<'tcx> {
+          FT: FnMut(&ty::TypeParameterDef, &[Kind<'tcx>]) -> Ty<'tcx>,
+          FC: FnMut(&ty::ConstParameterDef, &[Kind<'tcx>]) -> &'tcx ty::Const<'tcx> {
         let defs = tcx.generics_of(def_id);
         let mut substs = Vec::with_capacity(defs.count());
-        Substs::fill_item(&mut substs, tcx, defs, &mut mk_region, &mut mk_type);
+        Substs::fill_item(&mut substs, tcx, defs, &mut mk_region, &mut mk_type, &mut mk_const);
         tcx.intern_substs(&substs)
     }
 
-    pub fn extend_to<FR, FT>(&self,
-                             tcx: TyCtxt<'a, 'gcx, 'tcx>,
-                             def_id: DefId,
-                             mut mk_region: FR,
-                             mut mk_type: FT)
-                             -> &'tcx Substs<'tcx>
+    pub fn extend_to<FR, FT, FC>(&self,
+                                 tcx: TyCtxt<'a, 'gcx, 'tcx>,
+                                 def_id: DefId,
+                                 mut mk_region: FR,
+                                 mut mk_type: FT,
+                                 mut mk_const: FC)
+                                 -> &'tcx Substs<'tcx>
     where FR: FnMut(&ty::RegionParameterDef, &[Kind<'tcx>]) -> ty::Region<'tcx>,
-          FT: FnMut(&ty::TypeParameterDef, &[Kind<'tcx>]) -> Ty<'tcx>
+          FT: FnMut(&ty::TypeParameterDef, &[Kind<'tcx>]) -> Ty<'tcx>,
+          FC: FnMut(&ty::ConstParameterDef, &[Kind<'tcx>]) -> &'tcx ty::Const<'tcx>
     {
         let defs = tcx.generics_of(def_id);
         let mut result = Vec::with_capacity(defs.count());
         result.extend(self[..].iter().cloned());
-        Substs::fill_single(&mut result, defs, &mut mk_region, &mut mk_type);
+        Substs::fill_single(&mut result, defs, &mut mk_region, &mut mk_type, &mut mk_const);
         tcx.intern_substs(&result)
     }
 
-    pub fn fill_item<FR, FT>(substs: &mut Vec<Kind<'tcx>>,
-                             tcx: TyCtxt<'a, 'gcx, 'tcx>,
-                             defs: &ty::Generics,
-                             mk_region: &mut FR,
-                             mk_type: &mut FT)
+    pub fn fill_item<FR, FT, FC>(substs: &mut Vec<Kind<'tcx>>,
+                                 tcx: TyCtxt<'a, 'gcx, 'tcx>,
+                                 defs: &ty::Generics,
+                                 mk_region: &mut FR,
+                                 mk_type: &mut FT,
+                                 mk_const: &mut FC)
     where FR: FnMut(&ty::RegionParameterDef, &[Kind<'tcx>]) -> ty::Region<'tcx>,
-          FT: FnMut(&ty::TypeParameterDef, &[Kind<'tcx>]) -> Ty<'tcx> {
+          FT: FnMut(&ty::TypeParameterDef, &[Kind<'tcx>]) -> Ty<'tcx>,
+          FC: FnMut(&ty::ConstParameterDef, &[Kind<'tcx>]) -> &'tcx ty::Const<'tcx> {
 
         if let Some(def_id) = defs.parent {
             let parent_defs = tcx.generics_of(def_id);
-            Substs::fill_item(substs, tcx, parent_defs, mk_region, mk_type);
+            Substs::fill_item(substs, tcx, parent_defs, mk_region, mk_type, mk_const);
         }
-        Substs::fill_single(substs, defs, mk_region, mk_type)
+        Substs::fill_single(substs, defs, mk_region, mk_type, mk_const)
     }
 
-    fn fill_single<FR, FT>(substs: &mut Vec<Kind<'tcx>>,
-                           defs: &ty::Generics,
-                           mk_region: &mut FR,
-                           mk_type: &mut FT)
+    fn fill_single<FR, FT, FC>(substs: &mut Vec<Kind<'tcx>>,
+                               defs: &ty::Generics,
+                               mk_region: &mut FR,
+                               mk_type: &mut FT,
+                               mk_const: &mut FC)
     where FR: FnMut(&ty::RegionParameterDef, &[Kind<'tcx>]) -> ty::Region<'tcx>,
-          FT: FnMut(&ty::TypeParameterDef, &[Kind<'tcx>]) -> Ty<'tcx> {
+          FT: FnMut(&ty::TypeParameterDef, &[Kind<'tcx>]) -> Ty<'tcx>,
+          FC: FnMut(&ty::ConstParameterDef, &[Kind<'tcx>]) -> &'tcx ty::Const<'tcx> {
         // Handle Self first, before all regions.
         let mut types = defs.types.iter();
         if defs.parent.is_none() && defs.has_self {
@@ -261,6 +295,12 @@ impl<'a, 'gcx, 'tcx> Substs<'tcx> {
             assert_eq!(def.index as usize, substs.len());
             substs.push(Kind::from(ty));
         }
+
+        for def in &defs.consts {
+            let ct = mk_const(def, substs);
+            assert_eq!(def.index as usize, substs.len());
+            substs.push(Kind::from(ct));
+        }
     }
 
     pub fn is_noop(&self) -> bool {
@@ -289,6 +329,17 @@ impl<'a, 'gcx, 'tcx> Substs<'tcx> {
         })
     }
 
+    #[inline]
+    pub fn consts(&'a self) -> impl DoubleEndedIterator<Item=&'tcx ty::Const<'tcx>> + 'a {
+        self.iter().filter_map(|k| {
+            if let UnpackedKind::Const(ct) = k.unpack() {
+                Some(ct)
+            } else {
+                None
+            }
+        })
+    }
+
     #[inline]
     pub fn type_at(&self, i: usize) -> Ty<'tcx> {
         if let UnpackedKind::Type(ty) = self[i].unpack() {
@@ -307,6 +358,15 @@ impl<'a, 'gcx, 'tcx> Substs<'tcx> {
         }
     }
 
+    #[inline]
+    pub fn const_at(&self, i: usize) -> &'tcx ty::Const<'tcx> {
+        if let UnpackedKind::Const(ct) = self[i].unpack() {
+            ct
+        } else {
+            bug!("expected const for param #{} in {:?}", i, self);
+        }
+    }
+
     #[inline]
     pub fn type_for_def(&self, ty_param_def: &ty::TypeParameterDef) -> Ty<'tcx> {
         self.type_at(ty_param_def.index as usize)
@@ -317,6 +377,11 @@ impl<'a, 'gcx, 'tcx> Substs<'tcx> {
         self.region_at(def.index as usize)
     }
 
+    #[inline]
+    pub fn const_for_def(&self, def: &ty::ConstParameterDef) -> &'tcx ty::Const<'tcx> {
+        self.const_at(def.index as usize)
+    }
+
     /// Transform from substitutions for a child of `source_ancestor`
     /// (e.g. a trait or impl) to substitutions for the same child
     /// in a different item, with `target_substs` as the base for
@@ -480,6 +545,18 @@ impl<'a, 'gcx, 'tcx> TypeFolder<'gcx, 'tcx> for SubstFolder<'a, 'gcx, 'tcx> {
 
         return t1;
     }
+
+    fn fold_const(&mut self, c: &'tcx ty::Const<'tcx>) -> &'tcx ty::Const<'tcx> {
+        if !c.needs_subst() {
+            return c;
+        }
+
+        if let ConstVal::Param(p) = c.val {
+            self.const_for_param(p, c)
+        } else {
+            c.super_fold_with(self)
+        }
+    }
 }
 
 impl<'a, 'gcx, 'tcx> SubstFolder<'a, 'gcx, 'tcx> {
@@ -505,6 +582,29 @@ impl<'a, 'gcx, 'tcx> SubstFolder<'a, 'gcx, 'tcx> {
         self.shift_regions_through_binders(ty)
     }
 
+    fn const_for_param(&self,
+                       p: ty::ParamConst,
+                       source_ct: &'tcx ty::Const<'tcx>)
+                       -> &'tcx ty::Const<'tcx> {
+        // Look up the const in the substitutions. It really should be in there.
+        let opt_ct = self.substs.get(p.idx as usize).map(|k| k.unpack());
+        match opt_ct {
+            Some(UnpackedKind::Const(ct)) => ct,
+            _ => {
+                let span = self.span.unwrap_or(DUMMY_SP);
+                span_bug!(
+                    span,
+                    "Const parameter `{:?}` ({:?}/{}) out of range \
+                         when substituting (root type={:?}) substs={:?}",
+                    p,
+                    source_ct,
+                    p.idx,
+                    self.root_ty,
+                    self.substs);
+            }
+        }
+    }
+
     /// It is sometimes necessary to adjust the debruijn indices during substitution. This occurs
     /// when we are substituting a type with escaping regions into a context where we have passed
     /// through region binders. That's quite a mouthful. Let's see an example:
@@ -511,6 +511,11 @@ impl<'a, 'gcx, 'tcx> TyCtxt<'a, 'gcx, 'tcx> {
                         // as an error.
                         false
                     }
+                    UnpackedKind::Const(_) => {
+                        // consts cannot contain references, so they
+                        // never need to outlive the destructor.
+                        false
+                    }
                 }
             }).map(|(&item_param, _)| item_param).collect();
         debug!("destructor_constraint({:?}) = {:?}", def.did, result);
@@ -572,6 +577,8 @@ impl<'a, 'gcx, 'tcx> TyCtxt<'a, 'gcx, 'tcx> {
                              |_, _| self.types.re_erased,
                              |_, _| {
             bug!("empty_substs_for_def_id: {:?} has type parameters", item_def_id)
+        }, |_, _| {
+            bug!("empty_substs_for_def_id: {:?} has const parameters", item_def_id)
         })
     }
 
@@ -661,6 +668,7 @@ impl<'a, 'gcx, 'tcx, W> TypeVisitor<'tcx> for TypeIdHasher<'a, 'gcx, 'tcx, W>
                 match n.val {
                     ConstVal::Value(Value::ByVal(PrimVal::Bytes(b))) => self.hash(b),
                     ConstVal::Unevaluated(def_id, _) => self.def_id(def_id),
+                    ConstVal::Param(p) => self.hash(p.idx),
                     _ => bug!("arrays should not have {:?} as length", n)
                 }
             }
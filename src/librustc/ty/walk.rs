@@ -140,7 +140,7 @@ fn push_subtypes<'tcx>(stack: &mut TypeWalkerStack<'tcx>, parent_ty: Ty<'tcx>) {
 
 fn push_const<'tcx>(stack: &mut TypeWalkerStack<'tcx>, constant: &'tcx ty::Const<'tcx>) {
     match constant.val {
-        ConstVal::Value(_) => {}
+        ConstVal::Value(_) | ConstVal::Param(_) | ConstVal::Infer(_) => {}
         ConstVal::Unevaluated(_, substs) => {
             stack.extend(substs.types().rev());
         }
@@ -259,6 +259,7 @@ impl PrintContext {
         let mut has_self = false;
         let mut num_regions = 0;
         let mut num_types = 0;
+        let mut num_consts = 0;
         let mut is_value_path = false;
         let fn_trait_kind = ty::tls::with(|tcx| {
             // Unfortunately, some kinds of items (e.g., closures) don't have
@@ -286,6 +287,7 @@ impl PrintContext {
                     DefPathData::MacroDef(_) |
                     DefPathData::ClosureExpr |
                     DefPathData::TypeParam(_) |
+                    DefPathData::ConstParam(_) |
                     DefPathData::LifetimeDef(_) |
                     DefPathData::Field(_) |
                     DefPathData::StructCtor |
@@ -315,6 +317,7 @@ impl PrintContext {
                 generics = tcx.generics_of(def_id);
                 num_regions = generics.regions.len();
                 num_types = generics.types.len();
+                num_consts = generics.consts.len();
 
                 if has_self {
                     print!(f, self, write("<"), print_display(substs.type_at(0)), write(" as "))?;
@@ -331,6 +334,7 @@ impl PrintContext {
                     // Types and traits.
                     num_regions = generics.regions.len();
                     num_types = generics.types.len();
+                    num_consts = generics.consts.len();
                 }
             }
 
@@ -412,6 +416,11 @@ impl PrintContext {
             ty.print_display(f, self)?;
         }
 
+        for ct in substs.consts().take(num_consts) {
+            start_or_continue(f, "<", ", ")?;
+            ct.print_display(f, self)?;
+        }
+
         for projection in projections {
             start_or_continue(f, "<", ", ")?;
             ty::tls::with(|tcx|
@@ -444,6 +453,11 @@ impl PrintContext {
                 ty.print_display(f, self)?;
             }
 
+            for ct in substs.consts().skip(num_consts) {
+                start_or_continue(f, "::<", ", ")?;
+                ct.print_display(f, self)?;
+            }
+
             start_or_continue(f, "", ">")?;
         }
 
@@ -889,6 +903,21 @@ impl fmt::Debug for ty::IntVid {
     }
 }
 
+impl<'tcx> fmt::Debug for ty::ConstVid<'tcx> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "_#{}c", self.index)
+    }
+}
+
+impl<'tcx> fmt::Debug for ty::InferConst<'tcx> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ty::InferConst::Var(vid) => write!(f, "{:?}", vid),
+            ty::InferConst::Fresh(v) => write!(f, "FreshConst({:?})", v),
+        }
+    }
+}
+
 impl fmt::Debug for ty::FloatVid {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "_#{}f", self.index)
@@ -1193,9 +1222,13 @@ define_print! {
                         ConstVal::Value(Value::ByVal(PrimVal::Bytes(sz))) => {
                             write!(f, "{}", sz)?;
                         }
-                        ConstVal::Unevaluated(_def_id, _substs) => {
+                        ConstVal::Unevaluated(_def_id, _substs) |
+                        ConstVal::Infer(_) => {
                             write!(f, "_")?;
                         }
+                        ConstVal::Param(p) => {
+                            write!(f, "{}", p.name)?;
+                        }
                         _ => {
                             write!(f, "{:?}", sz)?;
                         }
@@ -1232,6 +1265,43 @@ define_print! {
     }
 }
 
+define_print! {
+    () ty::ParamConst, (self, f, cx) {
+        display {
+            write!(f, "{}", self.name)
+        }
+        debug {
+            write!(f, "{}/#{}", self.name, self.idx)
+        }
+    }
+}
+
+define_print! {
+    ('tcx) ty::Const<'tcx>, (self, f, cx) {
+        display {
+            match (self.val, &self.ty.sty) {
+                (ConstVal::Value(Value::ByVal(PrimVal::Bytes(0))), &TyBool) => write!(f, "false"),
+                (ConstVal::Value(Value::ByVal(PrimVal::Bytes(1))), &TyBool) => write!(f, "true"),
+                (ConstVal::Value(Value::ByVal(PrimVal::Bytes(bits))), &TyChar) => {
+                    write!(f, "{:?}", ::std::char::from_u32(bits as u32).unwrap())
+                }
+                (ConstVal::Value(Value::ByVal(PrimVal::Bytes(bits))), &TyInt(i)) => {
+                    let size = ty::tls::with(|tcx| {
+                        i.bit_width().unwrap_or(tcx.data_layout.pointer_size.bits() as usize)
+                    });
+                    let shift = 128 - size;
+                    write!(f, "{}", ((bits << shift) as i128) >> shift)
+                }
+                (ConstVal::Value(Value::ByVal(PrimVal::Bytes(bits))), _) => {
+                    write!(f, "{}", bits)
+                }
+                (ConstVal::Param(p), _) => write!(f, "{}", p.name),
+                _ => write!(f, "_"),
+            }
+        }
+    }
+}
+
 define_print! {
     ('tcx, T: Print + fmt::Debug, U: Print + fmt::Debug) ty::OutlivesPredicate<T, U>,
     (self, f, cx) {
@@ -12,7 +12,7 @@ use rustc::middle::allocator::AllocatorKind;
 use rustc_errors;
 use syntax::abi::Abi;
 use syntax::ast::{Attribute, Crate, LitKind, StrStyle};
-use syntax::ast::{Arg, Constness, Generics, Mac, Mutability, Ty, Unsafety};
+use syntax::ast::{Arg, Constness, Generics, IsAsync, Mac, Mutability, Ty, Unsafety};
 use syntax::ast::{self, Expr, Ident, Item, ItemKind, TyKind, VisibilityKind};
 use syntax::attr;
 use syntax::codemap::{dummy_spanned, respan};
@@ -152,6 +152,7 @@ impl<'a> AllocFnFactory<'a> {
         let kind = ItemKind::Fn(
             self.cx.fn_decl(abi_args, ast::FunctionRetTy::Ty(output_ty)),
             Unsafety::Unsafe,
+            IsAsync::NotAsync,
             dummy_spanned(Constness::NotConst),
             Abi::Rust,
             Generics::default(),
@@ -56,7 +56,7 @@ use syntax::ext::base::ExtCtxt;
 use syntax::fold::Folder;
 use syntax::parse::{self, PResult};
 use syntax::util::node_count::NodeCounter;
-use syntax_pos::FileName;
+use syntax_pos::{hygiene, FileName};
 use syntax_ext;
 
 use derive_registrar;
@@ -292,6 +292,10 @@ pub fn compile_input(
         sess.code_stats.borrow().print_type_sizes();
     }
 
+    if sess.opts.debugging_opts.print_type_layouts {
+        sess.code_stats.borrow().print_type_layouts();
+    }
+
     trans.join_trans_and_link(ongoing_trans, sess, &dep_graph, &outputs)?;
 
     if sess.opts.debugging_opts.perf_stats {
@@ -544,6 +548,8 @@ pub fn phase_1_parse_input<'a>(
         profile::begin(sess);
     }
 
+    hygiene::set_default_edition(sess.edition());
+
     let krate = time(sess, "parsing", || match *input {
         Input::File(ref file) => parse::parse_crate_from_file(file, &sess.parse_sess),
         Input::Str {
@@ -258,6 +258,26 @@ impl Diagnostic {
         self
     }
 
+    /// Prints out a message with a suggested edit of the code that touches
+    /// several disjoint parts of the source, all of which are applied together.
+    ///
+    /// See `CodeSuggestion` for more information.
+    pub fn multipart_suggestion(&mut self, msg: &str,
+                                suggestion: Vec<(Span, String)>) -> &mut Self {
+        self.suggestions.push(CodeSuggestion {
+            substitutions: vec![Substitution {
+                parts: suggestion.into_iter().map(|(span, snippet)| SubstitutionPart {
+                    snippet,
+                    span,
+                }).collect(),
+            }],
+            msg: msg.to_owned(),
+            show_code_when_inline: true,
+            approximate: false,
+        });
+        self
+    }
+
     /// Prints out a message with multiple suggested edits of the code.
     pub fn span_suggestions(&mut self, sp: Span, msg: &str, suggestions: Vec<String>) -> &mut Self {
         self.suggestions.push(CodeSuggestion {
@@ -182,6 +182,10 @@ impl<'a> DiagnosticBuilder<'a> {
                                     msg: &str,
                                     suggestion: String)
                                     -> &mut Self);
+    forward!(pub fn multipart_suggestion(&mut self,
+                                         msg: &str,
+                                         suggestion: Vec<(Span, String)>)
+                                         -> &mut Self);
     forward!(pub fn span_suggestions(&mut self,
                                      sp: Span,
                                      msg: &str,
@@ -398,6 +398,15 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for NonUpperCaseGlobals {
         }
     }
 
+    fn check_generic_param(&mut self, cx: &LateContext, param: &hir::GenericParam) {
+        if let hir::GenericParam::Const(ref const_param) = *param {
+            NonUpperCaseGlobals::check_upper_case(cx,
+                                                  "const parameter",
+                                                  const_param.name,
+                                                  const_param.span);
+        }
+    }
+
     fn check_pat(&mut self, cx: &LateContext, p: &hir::Pat) {
         // Lint for constants that look like binding identifiers (#7526)
         if let PatKind::Path(hir::QPath::Resolved(None, ref path)) = p.node {
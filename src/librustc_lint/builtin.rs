@@ -33,6 +33,7 @@ use rustc::hir::def_id::DefId;
 use rustc::cfg;
 use rustc::ty::subst::Substs;
 use rustc::ty::{self, Ty};
+use rustc::ty::layout::{self, LayoutOf};
 use rustc::traits;
 use rustc::hir::map as hir_map;
 use util::nodemap::NodeSet;
@@ -1423,6 +1424,7 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for TypeAliasBounds {
             let spans : Vec<_> = match param {
                 &hir::GenericParam::Lifetime(ref l) => l.bounds.iter().map(|b| b.span).collect(),
                 &hir::GenericParam::Type(ref ty) => ty.bounds.iter().map(|b| b.span()).collect(),
+                &hir::GenericParam::Const(_) => Vec::new(),
             };
             if !spans.is_empty() {
                 let mut err = cx.struct_span_lint(
@@ -1509,3 +1511,244 @@ impl<'a, 'tcx> LateLintPass<'a, 'tcx> for UnusedBrokenConst {
         }
     }
 }
+
+declare_lint! {
+    pub INVALID_VALUE,
+    Deny,
+    "an invalid value is being created (such as a null reference)"
+}
+
+/// Detects `mem::zeroed` and `mem::uninitialized` being used to create
+/// values of types that do not permit an all-zero or uninitialized bit
+/// pattern, which is immediate undefined behavior.
+#[derive(Copy, Clone)]
+pub struct InvalidValue;
+
+impl LintPass for InvalidValue {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(INVALID_VALUE)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum InitKind {
+    Zeroed,
+    Uninit,
+}
+
+/// Why a type does not admit a given kind of initialization.
+struct InitError {
+    message: String,
+    /// The struct field in which the problem was found, if it is local.
+    span: Option<Span>,
+    /// Whether `message` already says where the problem is.
+    located: bool,
+}
+
+impl InitError {
+    fn new(message: &str) -> InitError {
+        InitError { message: message.to_string(), span: None, located: false }
+    }
+
+    fn in_field<'tcx>(mut self, cx: &LateContext, field: &ty::FieldDef, ty: Ty<'tcx>) -> Self {
+        if !self.located {
+            self.message = format!("{} (in field `{}` of `{}`)", self.message, field.name, ty);
+            self.span = cx.tcx.hir.span_if_local(field.did);
+            self.located = true;
+        }
+        self
+    }
+}
+
+impl InvalidValue {
+    fn init_kind(cx: &LateContext, def_id: DefId) -> Option<InitKind> {
+        use syntax::abi::Abi::RustIntrinsic;
+
+        let name = cx.tcx.item_name(def_id);
+        if cx.tcx.fn_sig(def_id).abi() == RustIntrinsic {
+            return match &*name {
+                "init" => Some(InitKind::Zeroed),
+                "uninit" => Some(InitKind::Uninit),
+                _ => None,
+            };
+        }
+
+        // `mem::zeroed` and `mem::uninitialized` are defined in libcore and
+        // re-exported by libstd.
+        if cx.tcx.crate_name(def_id.krate) != "core" {
+            return None;
+        }
+        let path = cx.tcx.def_path(def_id);
+        let is_in_mem = path.data.len() == 2 &&
+            path.data[0].data.get_opt_name().map_or(false, |name| name == "mem");
+        if !is_in_mem {
+            return None;
+        }
+        match &*name {
+            "zeroed" => Some(InitKind::Zeroed),
+            "uninitialized" => Some(InitKind::Uninit),
+            _ => None,
+        }
+    }
+
+    /// Finds the reason, if any, why `ty` cannot be initialized with `init`.
+    fn find_init_error<'a, 'tcx>(cx: &LateContext<'a, 'tcx>,
+                                 ty: Ty<'tcx>,
+                                 init: InitKind)
+                                 -> Option<InitError> {
+        match ty.sty {
+            ty::TyRef(..) => Some(InitError::new("references must be non-null")),
+            ty::TyFnPtr(..) => Some(InitError::new("function pointers must be non-null")),
+            ty::TyNever => Some(InitError::new("the `!` type has no valid value")),
+            ty::TyAdt(adt_def, _) if adt_def.is_box() => {
+                Some(InitError::new("`Box` must be non-null"))
+            }
+            ty::TyBool if init == InitKind::Uninit => {
+                Some(InitError::new("booleans must be either `true` or `false`"))
+            }
+            ty::TyChar if init == InitKind::Uninit => {
+                Some(InitError::new("characters must be a valid Unicode codepoint"))
+            }
+            ty::TyTuple(tys) => {
+                tys.iter().filter_map(|&ty| Self::find_init_error(cx, ty, init)).next()
+            }
+            ty::TyArray(elem, _) => Self::find_init_error(cx, elem, init),
+            ty::TyAdt(adt_def, substs) if adt_def.is_enum() => {
+                Self::find_enum_init_error(cx, ty, adt_def, substs, init)
+            }
+            ty::TyAdt(adt_def, substs) if !adt_def.is_union() => {
+                let variant = adt_def.non_enum_variant();
+                Self::find_variant_init_error(cx, ty, variant, substs, init)
+                    .or_else(|| Self::find_layout_init_error(cx, ty, init))
+            }
+            _ => None,
+        }
+    }
+
+    fn find_variant_init_error<'a, 'tcx>(cx: &LateContext<'a, 'tcx>,
+                                         ty: Ty<'tcx>,
+                                         variant: &ty::VariantDef,
+                                         substs: &'tcx Substs<'tcx>,
+                                         init: InitKind)
+                                         -> Option<InitError> {
+        variant.fields.iter().filter_map(|field| {
+            Self::find_init_error(cx, field.ty(cx.tcx, substs), init)
+                .map(|err| err.in_field(cx, field, ty))
+        }).next()
+    }
+
+    fn find_enum_init_error<'a, 'tcx>(cx: &LateContext<'a, 'tcx>,
+                                      ty: Ty<'tcx>,
+                                      adt_def: &'tcx ty::AdtDef,
+                                      substs: &'tcx Substs<'tcx>,
+                                      init: InitKind)
+                                      -> Option<InitError> {
+        if adt_def.variants.is_empty() {
+            return Some(InitError::new("enums with no variants have no valid value"));
+        }
+        let layout = match cx.layout_of(ty) {
+            Ok(layout) => layout,
+            Err(_) => return None,
+        };
+        // Find the variant that an all-zero (or arbitrary) bit pattern would
+        // be decoded as, and check its fields.
+        let variant_index = match layout.variants {
+            layout::Variants::Single { index } => index,
+            _ if init == InitKind::Uninit => {
+                return Some(InitError::new("enums have to be initialized to a variant"));
+            }
+            layout::Variants::Tagged { .. } => {
+                match adt_def.discriminants(cx.tcx).position(|discr| discr.val == 0) {
+                    Some(index) => index,
+                    None => {
+                        return Some(InitError::new(
+                            "enums with no variant with discriminant 0 are not valid when zeroed"));
+                    }
+                }
+            }
+            layout::Variants::NicheFilling { dataful_variant, ref niche_variants,
+                                             niche_start, .. } => {
+                if niche_start == 0 {
+                    niche_variants.start
+                } else {
+                    dataful_variant
+                }
+            }
+        };
+        Self::find_variant_init_error(cx, ty, &adt_def.variants[variant_index], substs, init)
+    }
+
+    /// Catches types like `NonNull<T>` whose fields are valid on their own,
+    /// but whose layout excludes some bit patterns.
+    fn find_layout_init_error<'a, 'tcx>(cx: &LateContext<'a, 'tcx>,
+                                        ty: Ty<'tcx>,
+                                        init: InitKind)
+                                        -> Option<InitError> {
+        let layout = match cx.layout_of(ty) {
+            Ok(layout) => layout,
+            Err(_) => return None,
+        };
+        let scalar_is_invalid = |scalar: &layout::Scalar| {
+            let range = &scalar.valid_range;
+            let contains_zero = range.start == 0 || range.start > range.end;
+            let bits = scalar.value.size(cx.tcx).bits();
+            let is_full = range.start == range.end.wrapping_add(1) ||
+                (range.start == 0 && range.end == !0 >> (128 - bits));
+            match init {
+                InitKind::Zeroed => !contains_zero,
+                InitKind::Uninit => !is_full,
+            }
+        };
+        let invalid = match layout.abi {
+            layout::Abi::Uninhabited => true,
+            layout::Abi::Scalar(ref scalar) => scalar_is_invalid(scalar),
+            layout::Abi::ScalarPair(ref a, ref b) => scalar_is_invalid(a) || scalar_is_invalid(b),
+            layout::Abi::Vector { .. } |
+            layout::Abi::Aggregate { .. } => false,
+        };
+        if !invalid {
+            return None;
+        }
+        Some(InitError::new(&match init {
+            InitKind::Zeroed => format!("`{}` must be non-null", ty),
+            InitKind::Uninit => {
+                format!("`{}` must be initialized inside its custom valid range", ty)
+            }
+        }))
+    }
+}
+
+impl<'a, 'tcx> LateLintPass<'a, 'tcx> for InvalidValue {
+    fn check_expr(&mut self, cx: &LateContext<'a, 'tcx>, expr: &hir::Expr) {
+        let callee = match expr.node {
+            hir::ExprCall(ref callee, ref args) if args.is_empty() => callee,
+            _ => return,
+        };
+        let def_id = match callee.node {
+            hir::ExprPath(ref qpath) => match cx.tables.qpath_def(qpath, callee.hir_id) {
+                Def::Fn(def_id) => def_id,
+                _ => return,
+            },
+            _ => return,
+        };
+        let init = match Self::init_kind(cx, def_id) {
+            Some(init) => init,
+            None => return,
+        };
+
+        let ty = cx.tables.expr_ty(expr);
+        if let Some(err) = Self::find_init_error(cx, ty, init) {
+            let msg = format!("the type `{}` does not permit {}", ty, match init {
+                InitKind::Zeroed => "zero-initialization",
+                InitKind::Uninit => "being left uninitialized",
+            });
+            let mut diag = cx.struct_span_lint(INVALID_VALUE, expr.span, &msg);
+            diag.span_label(expr.span, "this code causes undefined behavior when executed");
+            match err.span {
+                Some(span) => diag.span_note(span, &err.message),
+                None => diag.note(&err.message),
+            };
+            diag.emit();
+        }
+    }
+}
@@ -51,11 +51,13 @@ use lint::FutureIncompatibleInfo;
 
 mod bad_style;
 mod builtin;
+mod non_ascii_idents;
 mod types;
 mod unused;
 
 use bad_style::*;
 use builtin::*;
+use non_ascii_idents::*;
 use types::*;
 use unused::*;
 
@@ -106,6 +108,7 @@ pub fn register_builtins(store: &mut lint::LintStore, sess: Option<&Session>) {
                        UnusedImportBraces,
                        AnonymousParameters,
                        UnusedDocComment,
+                       NonAsciiIdents,
                        );
 
     add_early_builtin_with_new!(sess,
@@ -137,6 +140,7 @@ pub fn register_builtins(store: &mut lint::LintStore, sess: Option<&Session>) {
                  UnreachablePub,
                  TypeAliasBounds,
                  UnusedBrokenConst,
+                 InvalidValue,
                  );
 
     add_builtin_with_new!(sess,
@@ -0,0 +1,383 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Lints guarding against homoglyph attacks in non-ASCII identifiers.
+//!
+//! The lexer records every identifier it sees in the session's symbol
+//! gallery. Once the crate has been parsed, we look at that table as a whole
+//! to find identifiers that mix scripts, contain codepoints that are not
+//! recommended for identifiers, or that share a confusable "skeleton" (in
+//! the sense of Unicode Technical Standard #39) with another identifier.
+//!
+//! The Unicode tables below only cover the scripts and homoglyphs that are
+//! most commonly abused; everything else falls back to a catch-all script.
+
+use lint::{EarlyContext, LintContext, LintArray};
+use lint::{LintPass, EarlyLintPass};
+use rustc::util::nodemap::FxHashMap;
+
+use syntax::ast;
+use syntax::symbol::Symbol;
+use syntax_pos::Span;
+
+use std::cmp::Ordering;
+
+declare_lint! {
+    pub MIXED_SCRIPT_IDENTS,
+    Warn,
+    "detects identifiers that mix characters from several Unicode scripts"
+}
+
+declare_lint! {
+    pub CONFUSABLE_IDENTS,
+    Warn,
+    "detects pairs of identifiers that are visually confusable"
+}
+
+declare_lint! {
+    pub UNCOMMON_CODEPOINTS,
+    Warn,
+    "detects identifiers containing codepoints not recommended for use in identifiers"
+}
+
+#[derive(Copy, Clone)]
+pub struct NonAsciiIdents;
+
+impl LintPass for NonAsciiIdents {
+    fn get_lints(&self) -> LintArray {
+        lint_array!(MIXED_SCRIPT_IDENTS, CONFUSABLE_IDENTS, UNCOMMON_CODEPOINTS)
+    }
+}
+
+impl EarlyLintPass for NonAsciiIdents {
+    fn check_crate(&mut self, cx: &EarlyContext, _: &ast::Crate) {
+        let mut symbols: Vec<(Symbol, Span)> = cx.sess.parse_sess.symbol_gallery
+            .borrow()
+            .iter()
+            .map(|(&name, &span)| (name, span))
+            .collect();
+
+        // Nothing to guard against in a purely ASCII crate.
+        if symbols.iter().all(|&(name, _)| name.as_str().is_ascii()) {
+            return;
+        }
+
+        // Report in source order rather than in hash map order.
+        symbols.sort_by_key(|&(_, span)| span.lo());
+
+        let mut skeletons: FxHashMap<String, (Symbol, Span)> = FxHashMap();
+        for &(name, span) in &symbols {
+            let string = name.as_str();
+            let is_ascii = string.is_ascii();
+
+            if !is_ascii {
+                check_uncommon_codepoints(cx, &string, span);
+                check_mixed_script(cx, &string, span);
+            }
+
+            let skeleton = skeleton(&string);
+            match skeletons.get(&skeleton) {
+                Some(&(other, other_span)) if !is_ascii || !other.as_str().is_ascii() => {
+                    let msg = format!("identifier pair considered confusable between `{}` and `{}`",
+                                      string, other);
+                    cx.struct_span_lint(CONFUSABLE_IDENTS, span, &msg)
+                      .span_label(other_span, "this is where the previous identifier occurred")
+                      .emit();
+                    continue;
+                }
+                Some(_) => continue,
+                None => {}
+            }
+            skeletons.insert(skeleton, (name, span));
+        }
+    }
+}
+
+fn check_uncommon_codepoints(cx: &EarlyContext, ident: &str, span: Span) {
+    if let Some(c) = ident.chars().find(|&c| is_uncommon_codepoint(c)) {
+        let msg = format!("identifier contains an uncommon Unicode codepoint: `{}` (U+{:04X})",
+                          c, c as u32);
+        cx.span_lint(UNCOMMON_CODEPOINTS, span, &msg);
+    }
+}
+
+fn check_mixed_script(cx: &EarlyContext, ident: &str, span: Span) {
+    // Following UTS #39, characters in the Common and Inherited scripts are
+    // compatible with every script.
+    let mut allowed = !0u32;
+    let mut seen = 0u32;
+    for c in ident.chars() {
+        let script = script_of(c);
+        match script {
+            Script::Common | Script::Inherited => continue,
+            _ => {}
+        }
+        seen |= script.bit();
+        allowed &= script.augmented();
+    }
+
+    if allowed == 0 {
+        let scripts = SCRIPT_NAMES.iter()
+            .filter(|&&(script, _)| seen & script.bit() != 0)
+            .map(|&(_, name)| name)
+            .collect::<Vec<_>>()
+            .join(", ");
+        let msg = format!("identifier `{}` contains characters from multiple scripts: {}",
+                          ident, scripts);
+        cx.span_lint(MIXED_SCRIPT_IDENTS, span, &msg);
+    }
+}
+
+/// Computes a simplified confusable skeleton for `ident`: every character is
+/// replaced by the prototype it is visually confusable with.
+fn skeleton(ident: &str) -> String {
+    ident.chars().map(|c| {
+        match CONFUSABLES.binary_search_by_key(&c, |&(from, _)| from) {
+            Ok(i) => CONFUSABLES[i].1,
+            Err(_) => c,
+        }
+    }).collect()
+}
+
+fn is_uncommon_codepoint(c: char) -> bool {
+    let c = c as u32;
+    UNCOMMON_RANGES.iter().any(|&(lo, hi)| lo <= c && c <= hi)
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+enum Script {
+    Common,
+    Inherited,
+    Latin,
+    Greek,
+    Cyrillic,
+    Armenian,
+    Hebrew,
+    Arabic,
+    Devanagari,
+    Bengali,
+    Thai,
+    Georgian,
+    Hangul,
+    Hiragana,
+    Katakana,
+    Bopomofo,
+    Han,
+    Other,
+}
+
+impl Script {
+    fn bit(self) -> u32 {
+        1 << (self as u32)
+    }
+
+    /// The set of scripts this script may be mixed with. The Han script is
+    /// written alongside Japanese kana, Korean Hangul and Bopomofo, which
+    /// UTS #39 models with the extra `Jpan`, `Kore` and `Hanb` sets.
+    fn augmented(self) -> u32 {
+        match self {
+            Script::Han => self.bit() | JPAN | KORE | HANB,
+            Script::Hiragana | Script::Katakana => self.bit() | JPAN,
+            Script::Hangul => self.bit() | KORE,
+            Script::Bopomofo => self.bit() | HANB,
+            _ => self.bit(),
+        }
+    }
+}
+
+const JPAN: u32 = 1 << 29;
+const KORE: u32 = 1 << 30;
+const HANB: u32 = 1 << 31;
+
+const SCRIPT_NAMES: &[(Script, &str)] = &[
+    (Script::Latin, "Latin"),
+    (Script::Greek, "Greek"),
+    (Script::Cyrillic, "Cyrillic"),
+    (Script::Armenian, "Armenian"),
+    (Script::Hebrew, "Hebrew"),
+    (Script::Arabic, "Arabic"),
+    (Script::Devanagari, "Devanagari"),
+    (Script::Bengali, "Bengali"),
+    (Script::Thai, "Thai"),
+    (Script::Georgian, "Georgian"),
+    (Script::Hangul, "Hangul"),
+    (Script::Hiragana, "Hiragana"),
+    (Script::Katakana, "Katakana"),
+    (Script::Bopomofo, "Bopomofo"),
+    (Script::Han, "Han"),
+    (Script::Other, "other"),
+];
+
+fn script_of(c: char) -> Script {
+    let c = c as u32;
+    match SCRIPT_RANGES.binary_search_by(|&(lo, hi, _)| {
+        if hi < c {
+            Ordering::Less
+        } else if lo > c {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }) {
+        Ok(i) => SCRIPT_RANGES[i].2,
+        Err(_) => Script::Other,
+    }
+}
+
+// Sorted, non-overlapping ranges of codepoints that may appear in
+// identifiers, and the script they belong to.
+const SCRIPT_RANGES: &[(u32, u32, Script)] = &[
+    (0x0030, 0x0039, Script::Common),
+    (0x0041, 0x005A, Script::Latin),
+    (0x005F, 0x005F, Script::Common),
+    (0x0061, 0x007A, Script::Latin),
+    (0x00AA, 0x00AA, Script::Latin),
+    (0x00B5, 0x00B5, Script::Common),
+    (0x00BA, 0x00BA, Script::Latin),
+    (0x00C0, 0x00D6, Script::Latin),
+    (0x00D8, 0x00F6, Script::Latin),
+    (0x00F8, 0x02AF, Script::Latin),
+    (0x02B0, 0x02FF, Script::Common),
+    (0x0300, 0x036F, Script::Inherited),
+    (0x0370, 0x03FF, Script::Greek),
+    (0x0400, 0x052F, Script::Cyrillic),
+    (0x0531, 0x058F, Script::Armenian),
+    (0x0591, 0x05FF, Script::Hebrew),
+    (0x0600, 0x06FF, Script::Arabic),
+    (0x0750, 0x077F, Script::Arabic),
+    (0x0900, 0x097F, Script::Devanagari),
+    (0x0980, 0x09FF, Script::Bengali),
+    (0x0E00, 0x0E7F, Script::Thai),
+    (0x10A0, 0x10FF, Script::Georgian),
+    (0x1100, 0x11FF, Script::Hangul),
+    (0x1C80, 0x1C8F, Script::Cyrillic),
+    (0x1D00, 0x1D7F, Script::Latin),
+    (0x1E00, 0x1EFF, Script::Latin),
+    (0x1F00, 0x1FFF, Script::Greek),
+    (0x2C60, 0x2C7F, Script::Latin),
+    (0x2DE0, 0x2DFF, Script::Cyrillic),
+    (0x3005, 0x3007, Script::Han),
+    (0x3041, 0x309F, Script::Hiragana),
+    (0x30A0, 0x30FB, Script::Katakana),
+    (0x30FC, 0x30FC, Script::Common),
+    (0x30FD, 0x30FF, Script::Katakana),
+    (0x3105, 0x312F, Script::Bopomofo),
+    (0x3130, 0x318F, Script::Hangul),
+    (0x31A0, 0x31BF, Script::Bopomofo),
+    (0x31F0, 0x31FF, Script::Katakana),
+    (0x3400, 0x4DBF, Script::Han),
+    (0x4E00, 0x9FFF, Script::Han),
+    (0xA640, 0xA69F, Script::Cyrillic),
+    (0xA720, 0xA7FF, Script::Latin),
+    (0xAC00, 0xD7AF, Script::Hangul),
+    (0xF900, 0xFAFF, Script::Han),
+    (0xFB00, 0xFB06, Script::Latin),
+    (0xFF21, 0xFF3A, Script::Latin),
+    (0xFF41, 0xFF5A, Script::Latin),
+    (0xFF66, 0xFF9D, Script::Katakana),
+    (0xFFA0, 0xFFDC, Script::Hangul),
+    (0x20000, 0x2FA1F, Script::Han),
+];
+
+// Ranges of codepoints whose identifier status in UTS #39 is not "Allowed":
+// obsolete and limited-use scripts, compatibility forms and technical
+// symbols that are rarely if ever needed in identifiers.
+const UNCOMMON_RANGES: &[(u32, u32)] = &[
+    (0x00AA, 0x00AA),   // feminine ordinal indicator
+    (0x00B5, 0x00B5),   // micro sign
+    (0x00BA, 0x00BA),   // masculine ordinal indicator
+    (0x01C4, 0x01CC),   // digraphs such as DŽ and Lj
+    (0x01F1, 0x01F3),
+    (0x0250, 0x02FF),   // IPA extensions and spacing modifier letters
+    (0x1680, 0x169F),   // Ogham
+    (0x16A0, 0x16FF),   // Runic
+    (0x1D00, 0x1DBF),   // phonetic extensions
+    (0x2070, 0x209F),   // superscripts and subscripts
+    (0x2100, 0x214F),   // letterlike symbols
+    (0x2160, 0x2188),   // Roman numerals
+    (0xFB00, 0xFB4F),   // alphabetic presentation forms
+    (0xFE70, 0xFEFF),   // Arabic presentation forms-B
+    (0xFF00, 0xFFEF),   // halfwidth and fullwidth forms
+    (0x10330, 0x1034F), // Gothic
+    (0x10400, 0x1044F), // Deseret
+    (0x1D400, 0x1D7FF), // mathematical alphanumeric symbols
+];
+
+// A subset of the UTS #39 confusables table, sorted by source character.
+// Only single-character prototypes are listed.
+const CONFUSABLES: &[(char, char)] = &[
+    ('0', 'O'),
+    ('1', 'l'),
+    ('I', 'l'),
+    ('\u{0391}', 'A'), // Greek capital alpha
+    ('\u{0392}', 'B'), // Greek capital beta
+    ('\u{0395}', 'E'), // Greek capital epsilon
+    ('\u{0396}', 'Z'), // Greek capital zeta
+    ('\u{0397}', 'H'), // Greek capital eta
+    ('\u{0399}', 'l'), // Greek capital iota
+    ('\u{039A}', 'K'), // Greek capital kappa
+    ('\u{039C}', 'M'), // Greek capital mu
+    ('\u{039D}', 'N'), // Greek capital nu
+    ('\u{039F}', 'O'), // Greek capital omicron
+    ('\u{03A1}', 'P'), // Greek capital rho
+    ('\u{03A4}', 'T'), // Greek capital tau
+    ('\u{03A5}', 'Y'), // Greek capital upsilon
+    ('\u{03A7}', 'X'), // Greek capital chi
+    ('\u{03B1}', 'a'), // Greek small alpha
+    ('\u{03B3}', 'y'), // Greek small gamma
+    ('\u{03B9}', 'i'), // Greek small iota
+    ('\u{03BD}', 'v'), // Greek small nu
+    ('\u{03BF}', 'o'), // Greek small omicron
+    ('\u{03C1}', 'p'), // Greek small rho
+    ('\u{03C3}', 'o'), // Greek small sigma
+    ('\u{03C5}', 'u'), // Greek small upsilon
+    ('\u{0405}', 'S'), // Cyrillic capital dze
+    ('\u{0406}', 'l'), // Cyrillic capital byelorussian-ukrainian i
+    ('\u{0408}', 'J'), // Cyrillic capital je
+    ('\u{0410}', 'A'), // Cyrillic capital a
+    ('\u{0412}', 'B'), // Cyrillic capital ve
+    ('\u{0415}', 'E'), // Cyrillic capital ie
+    ('\u{041A}', 'K'), // Cyrillic capital ka
+    ('\u{041C}', 'M'), // Cyrillic capital em
+    ('\u{041D}', 'H'), // Cyrillic capital en
+    ('\u{041E}', 'O'), // Cyrillic capital o
+    ('\u{0420}', 'P'), // Cyrillic capital er
+    ('\u{0421}', 'C'), // Cyrillic capital es
+    ('\u{0422}', 'T'), // Cyrillic capital te
+    ('\u{0425}', 'X'), // Cyrillic capital ha
+    ('\u{0430}', 'a'), // Cyrillic small a
+    ('\u{0435}', 'e'), // Cyrillic small ie
+    ('\u{043E}', 'o'), // Cyrillic small o
+    ('\u{0440}', 'p'), // Cyrillic small er
+    ('\u{0441}', 'c'), // Cyrillic small es
+    ('\u{0443}', 'y'), // Cyrillic small u
+    ('\u{0445}', 'x'), // Cyrillic small ha
+    ('\u{0455}', 's'), // Cyrillic small dze
+    ('\u{0456}', 'i'), // Cyrillic small byelorussian-ukrainian i
+    ('\u{0458}', 'j'), // Cyrillic small je
+    ('\u{04BB}', 'h'), // Cyrillic small shha
+    ('\u{0501}', 'd'), // Cyrillic small komi de
+    ('\u{051B}', 'q'), // Cyrillic small qa
+    ('\u{051D}', 'w'), // Cyrillic small we
+    ('\u{0555}', 'O'), // Armenian capital oh
+    ('\u{0578}', 'n'), // Armenian small vo
+    ('\u{057D}', 'u'), // Armenian small seh
+    ('\u{0585}', 'o'), // Armenian small oh
+    ('\u{2160}', 'l'), // Roman numeral one
+    ('\u{2164}', 'V'), // Roman numeral five
+    ('\u{2169}', 'X'), // Roman numeral ten
+    ('\u{216C}', 'L'), // Roman numeral fifty
+    ('\u{216D}', 'C'), // Roman numeral one hundred
+    ('\u{216E}', 'D'), // Roman numeral five hundred
+    ('\u{216F}', 'M'), // Roman numeral one thousand
+    ('\u{FF41}', 'a'), // fullwidth small a
+    ('\u{FF45}', 'e'), // fullwidth small e
+    ('\u{FF4F}', 'o'), // fullwidth small o
+];
@@ -358,6 +358,18 @@ pub struct ThinLTOModule {
     pub len: usize,
 }
 
+/// LLVMRustCounterMappingRegion
+#[repr(C)]
+#[derive(Copy, Clone, Debug)]
+pub struct CounterRegion {
+    pub counter_id: u32,
+    pub file_id: u32,
+    pub line_start: u32,
+    pub column_start: u32,
+    pub line_end: u32,
+    pub column_end: u32,
+}
+
 /// LLVMThreadLocalMode
 #[derive(Copy, Clone)]
 #[repr(C)]
@@ -767,7 +779,9 @@ extern "C" {
     // Operations on instructions
     pub fn LLVMGetInstructionParent(Inst: ValueRef) -> BasicBlockRef;
     pub fn LLVMGetFirstBasicBlock(Fn: ValueRef) -> BasicBlockRef;
+    pub fn LLVMGetNextBasicBlock(BB: BasicBlockRef) -> BasicBlockRef;
     pub fn LLVMGetFirstInstruction(BB: BasicBlockRef) -> ValueRef;
+    pub fn LLVMGetNextInstruction(Inst: ValueRef) -> ValueRef;
     pub fn LLVMInstructionEraseFromParent(Inst: ValueRef);
 
     // Operations on call sites
@@ -1639,7 +1653,8 @@ extern "C" {
                                        FunctionSections: bool,
                                        DataSections: bool,
                                        TrapUnreachable: bool,
-                                       Singlethread: bool)
+                                       Singlethread: bool,
+                                       EmitStackSizeSection: bool)
                                        -> TargetMachineRef;
     pub fn LLVMRustDisposeTargetMachine(T: TargetMachineRef);
     pub fn LLVMRustAddAnalysisPasses(T: TargetMachineRef, PM: PassManagerRef, M: ModuleRef);
@@ -1687,6 +1702,10 @@ extern "C" {
     pub fn LLVMRustDestroyArchive(AR: ArchiveRef);
 
     pub fn LLVMRustGetSectionName(SI: SectionIteratorRef, data: *mut *const c_char) -> size_t;
+    pub fn LLVMRustWriteStackSizesToString(Data: *const c_char,
+                                           Len: size_t,
+                                           Str: RustStringRef)
+                                           -> bool;
 
     pub fn LLVMRustWriteTwineToString(T: TwineRef, s: RustStringRef);
 
@@ -1791,6 +1810,21 @@ extern "C" {
     pub fn LLVMRustThinLTOPatchDICompileUnit(M: ModuleRef, CU: *mut c_void);
     pub fn LLVMRustThinLTORemoveAvailableExternally(M: ModuleRef);
 
+    pub fn LLVMRustCoverageAvailable() -> bool;
+    pub fn LLVMRustCoverageWriteFilenamesSectionToBuffer(Filenames: *const *const c_char,
+                                                         FilenamesLen: size_t,
+                                                         BufferOut: RustStringRef);
+    pub fn LLVMRustCoverageWriteMappingToBuffer(VirtualFileMappingIds: *const c_uint,
+                                                NumVirtualFileMappingIds: c_uint,
+                                                Regions: *const CounterRegion,
+                                                NumRegions: c_uint,
+                                                BufferOut: RustStringRef);
+    pub fn LLVMRustCoverageCreatePGOFuncNameVar(F: ValueRef, FuncName: *const c_char) -> ValueRef;
+    pub fn LLVMRustCoverageHashFunctionName(FuncName: *const c_char) -> u64;
+    pub fn LLVMRustCoverageWriteSectionNameToString(M: ModuleRef, Str: RustStringRef);
+    pub fn LLVMRustCoverageWriteMappingVarNameToString(Str: RustStringRef);
+    pub fn LLVMRustCoverageMappingVersion() -> u32;
+
     pub fn LLVMRustLinkerNew(M: ModuleRef) -> LinkerRef;
     pub fn LLVMRustLinkerAdd(linker: LinkerRef,
                              bytecode: *const c_char,
@@ -801,7 +801,8 @@ impl<'a> CrateLoader<'a> {
 
     fn inject_profiler_runtime(&mut self) {
         if self.sess.opts.debugging_opts.profile ||
-            self.sess.opts.debugging_opts.pgo_gen.is_some()
+            self.sess.opts.debugging_opts.pgo_gen.is_some() ||
+            self.sess.opts.debugging_opts.instrument_coverage
         {
             info!("loading profiler");
 
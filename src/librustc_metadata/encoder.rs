@@ -929,9 +929,9 @@ impl<'a, 'b: 'a, 'tcx: 'b> IsolatedEncoder<'a, 'b, 'tcx> {
                 hir::ImplItemKind::Const(..) => true,
                 hir::ImplItemKind::Method(ref sig, _) => {
                     let generics = self.tcx.generics_of(def_id);
-                    let types = generics.parent_types as usize + generics.types.len();
                     let needs_inline =
-                        (types > 0 || tcx.trans_fn_attrs(def_id).requests_inline())
+                        (generics.requires_monomorphization() ||
+                         tcx.trans_fn_attrs(def_id).requests_inline())
                             && !self.metadata_output_only();
                     let is_const_fn = sig.constness == hir::Constness::Const;
                     let always_encode_mir = self.tcx.sess.opts.debugging_opts.always_encode_mir;
@@ -1224,7 +1224,8 @@ impl<'a, 'b: 'a, 'tcx: 'b> IsolatedEncoder<'a, 'b, 'tcx> {
                 }
                 hir::ItemConst(..) => self.encode_optimized_mir(def_id),
                 hir::ItemFn(_, _, constness, _, ref generics, _) => {
-                    let has_tps = generics.ty_params().next().is_some();
+                    let has_tps = generics.ty_params().next().is_some() ||
+                                  generics.const_params().next().is_some();
                     let needs_inline = (has_tps || tcx.trans_fn_attrs(def_id).requests_inline()) &&
                         !self.metadata_output_only();
                     let always_encode_mir = self.tcx.sess.opts.debugging_opts.always_encode_mir;
@@ -209,15 +209,18 @@ impl<'a, 'gcx, 'tcx> Builder<'a, 'gcx, 'tcx> {
         };
 
         // Simplify the candidate. Since the pattern is irrefutable, this should
-        // always convert all match-pairs into bindings.
+        // always convert all match-pairs into bindings, except for or-patterns.
         unpack!(block = self.simplify_candidate(block, &mut candidate));
 
-        if !candidate.match_pairs.is_empty() {
-            span_bug!(candidate.match_pairs[0].pattern.span,
+        if let Some(match_pair) = candidate.match_pairs.iter().find(|mp| !mp.is_or_pattern()) {
+            span_bug!(match_pair.pattern.span,
                       "match pairs {:?} remaining after simplifying \
                        irrefutable pattern",
                       candidate.match_pairs);
         }
+        if !candidate.match_pairs.is_empty() {
+            return self.place_into_or_pattern(block, candidate);
+        }
 
         // now apply the bindings, which will also declare the variables
         self.bind_matched_candidate(block, candidate.bindings);
@@ -225,6 +228,38 @@ impl<'a, 'gcx, 'tcx> Builder<'a, 'gcx, 'tcx> {
         block.unit()
     }
 
+    /// Binds an irrefutable pattern containing or-patterns, e.g. `let (Ok(x) | Err(x)) = r;`,
+    /// given its simplified candidate. This is matched as the only pattern of a `match` arm,
+    /// whose block is where the bindings of all the alternatives rejoin.
+    fn place_into_or_pattern<'pat>(&mut self,
+                                   block: BasicBlock,
+                                   mut candidate: Candidate<'pat, 'tcx>)
+                                   -> BlockAnd<()> {
+        let span = candidate.span;
+        let join_block = self.cfg.start_new_block();
+        let mut arm_blocks = ArmBlocks {
+            blocks: vec![join_block],
+        };
+
+        candidate.pre_binding_block = self.cfg.start_new_block();
+        candidate.next_candidate_pre_binding_block = self.cfg.start_new_block();
+        let source_info = self.source_info(span);
+        self.cfg.terminate(candidate.next_candidate_pre_binding_block,
+                           source_info, TerminatorKind::Unreachable);
+
+        let mut otherwise = self.match_candidates(span, &mut arm_blocks, vec![candidate], block);
+
+        // The pattern is irrefutable, so the tips of the decision tree that
+        // match none of the alternatives can't be reached.
+        otherwise.sort();
+        otherwise.dedup();
+        for block in otherwise {
+            self.cfg.terminate(block, source_info, TerminatorKind::Unreachable);
+        }
+
+        join_block.unit()
+    }
+
     /// Declares the bindings of the given pattern and returns the visibility scope
     /// for the bindings in this patterns, if such a scope had to be created.
     /// NOTE: Declaring the bindings should always be done in their drop scope.
@@ -306,6 +341,10 @@ impl<'a, 'gcx, 'tcx> Builder<'a, 'gcx, 'tcx> {
                     self.visit_bindings(&subpattern.pattern, f);
                 }
             }
+            PatternKind::Or { ref pats } => {
+                // All the alternatives bind the same variables.
+                self.visit_bindings(&pats[0], f);
+            }
         }
     }
 }
@@ -503,6 +542,12 @@ impl<'a, 'gcx, 'tcx> Builder<'a, 'gcx, 'tcx> {
             return vec![block];
         }
 
+        // Or-patterns are matched one alternative at a time.
+        if let Some(index) = unmatched_candidates[0].match_pairs.iter()
+                                                   .position(|mp| mp.is_or_pattern()) {
+            return self.match_or_pattern(span, arm_blocks, unmatched_candidates, index, block);
+        }
+
         // Test candidates where possible.
         let (otherwise, tested_candidates) =
             self.test_candidates(span, arm_blocks, &unmatched_candidates, block);
@@ -522,6 +567,90 @@ impl<'a, 'gcx, 'tcx> Builder<'a, 'gcx, 'tcx> {
         self.match_candidates(span, arm_blocks, untested_candidates, join_block)
     }
 
+    /// Matches the `index`th match pair of the first candidate, which is an
+    /// or-pattern. Each alternative is lowered once, rather than expanding
+    /// every combination of the or-patterns of a candidate up front.
+    ///
+    /// If the or-pattern has no bindings, its alternatives are matched in a
+    /// nested decision tree of their own, after which the candidate carries on
+    /// with its other match pairs. Otherwise each alternative binds different
+    /// places, so the candidate is instead split into one candidate per
+    /// alternative, which go on to their shared arm separately.
+    fn match_or_pattern<'pat>(&mut self,
+                              span: Span,
+                              arm_blocks: &mut ArmBlocks,
+                              mut candidates: Vec<Candidate<'pat, 'tcx>>,
+                              index: usize,
+                              block: BasicBlock)
+                              -> Vec<BasicBlock>
+    {
+        let mut candidate = candidates.remove(0);
+        let match_pair = candidate.match_pairs.remove(index);
+        let pattern: &'pat Pattern<'tcx> = match_pair.pattern;
+        let pats = match *pattern.kind {
+            PatternKind::Or { ref pats } => pats,
+            _ => span_bug!(pattern.span, "expected an or-pattern"),
+        };
+
+        let mut has_bindings = false;
+        self.visit_bindings(pattern, &mut |_, _, _, _, _, _| has_bindings = true);
+        if has_bindings {
+            let mut pre_binding_block = candidate.pre_binding_block;
+            let alternatives: Vec<_> = pats.iter().enumerate().map(|(i, pat)| {
+                let next_candidate_pre_binding_block = if i + 1 == pats.len() {
+                    candidate.next_candidate_pre_binding_block
+                } else {
+                    self.cfg.start_new_block()
+                };
+                let mut alternative = candidate.clone();
+                alternative.match_pairs.insert(index, MatchPair::new(match_pair.place.clone(),
+                                                                     pat));
+                alternative.pre_binding_block = pre_binding_block;
+                alternative.next_candidate_pre_binding_block = next_candidate_pre_binding_block;
+                pre_binding_block = next_candidate_pre_binding_block;
+                alternative
+            }).collect();
+            let candidates = alternatives.into_iter().chain(candidates).collect();
+            return self.match_candidates(span, arm_blocks, candidates, block);
+        }
+
+        let matched_block = self.cfg.start_new_block();
+        let mut alternative_blocks = ArmBlocks {
+            blocks: vec![matched_block],
+        };
+        let pre_binding_blocks: Vec<_> = (0..pats.len() + 1)
+            .map(|_| self.cfg.start_new_block()).collect();
+        let alternatives: Vec<_> =
+            pats.iter()
+                .zip(pre_binding_blocks.iter().zip(pre_binding_blocks.iter().skip(1)))
+                .map(|(pat, (pre_binding_block, next_candidate_pre_binding_block))| {
+                    Candidate {
+                        span: pat.span,
+                        match_pairs: vec![MatchPair::new(match_pair.place.clone(), pat)],
+                        bindings: vec![],
+                        guard: None,
+                        arm_index: 0,
+                        pre_binding_block: *pre_binding_block,
+                        next_candidate_pre_binding_block: *next_candidate_pre_binding_block,
+                    }
+                })
+                .collect();
+        let source_info = self.source_info(pattern.span);
+        self.cfg.terminate(*pre_binding_blocks.last().unwrap(),
+                           source_info, TerminatorKind::Unreachable);
+
+        // As in `test_candidates`, the candidates after this one are only
+        // tried once it has failed, wherever that happens.
+        let mut otherwise =
+            self.match_candidates(span, &mut alternative_blocks, alternatives, block);
+        otherwise.extend(self.match_candidates(span, arm_blocks, vec![candidate], matched_block));
+        if candidates.is_empty() {
+            return otherwise;
+        }
+        let join_block = self.join_otherwise_blocks(span, otherwise);
+        self.match_candidates(span, arm_blocks, candidates, join_block)
+    }
+
     fn join_otherwise_blocks(&mut self,
                              span: Span,
                              mut otherwise: Vec<BasicBlock>)
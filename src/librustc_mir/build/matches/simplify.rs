@@ -148,6 +148,11 @@ impl<'a, 'gcx, 'tcx> Builder<'a, 'gcx, 'tcx> {
                 candidate.match_pairs.push(MatchPair::new(place, subpattern));
                 Ok(())
             }
+
+            PatternKind::Or { .. } => {
+                // matched one alternative at a time, see `match_or_pattern`
+                Err(match_pair)
+            }
         }
     }
 }
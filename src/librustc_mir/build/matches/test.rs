@@ -101,7 +101,8 @@ impl<'a, 'gcx, 'tcx> Builder<'a, 'gcx, 'tcx> {
             PatternKind::Wild |
             PatternKind::Binding { .. } |
             PatternKind::Leaf { .. } |
-            PatternKind::Deref { .. } => {
+            PatternKind::Deref { .. } |
+            PatternKind::Or { .. } => {
                 self.error_simplifyable(match_pair)
             }
         }
@@ -141,7 +142,8 @@ impl<'a, 'gcx, 'tcx> Builder<'a, 'gcx, 'tcx> {
             PatternKind::Wild |
             PatternKind::Binding { .. } |
             PatternKind::Leaf { .. } |
-            PatternKind::Deref { .. } => {
+            PatternKind::Deref { .. } |
+            PatternKind::Or { .. } => {
                 // don't know how to add these patterns to a switch
                 false
             }
@@ -85,4 +85,11 @@ impl<'pat, 'tcx> MatchPair<'pat, 'tcx> {
             slice_len_checked: false,
         }
     }
+
+    pub fn is_or_pattern(&self) -> bool {
+        match *self.pattern.kind {
+            PatternKind::Or { .. } => true,
+            _ => false,
+        }
+    }
 }
@@ -453,14 +453,17 @@ fn construct_fn<'a, 'gcx, 'tcx, A>(hir: Cx<'a, 'gcx, 'tcx>,
             let var_id = fv.var_id();
             let var_hir_id = tcx.hir.node_to_hir_id(var_id);
             let closure_expr_id = tcx.hir.local_def_id(fn_id);
-            let capture = hir.tables().upvar_capture(ty::UpvarId {
+            let upvar_id = ty::UpvarId {
                 var_id: var_hir_id,
                 closure_expr_id: LocalDefId::from_def_id(closure_expr_id),
-            });
-            let by_ref = match capture {
-                ty::UpvarCapture::ByValue => false,
-                ty::UpvarCapture::ByRef(..) => true
             };
+            // A variable captured through its places is stored as a tuple of
+            // them, which is not a reference itself.
+            let by_ref = hir.tables().upvar_places(upvar_id).is_none() &&
+                match hir.tables().upvar_capture(upvar_id) {
+                    ty::UpvarCapture::ByValue => false,
+                    ty::UpvarCapture::ByRef(..) => true
+                };
             let mut decl = UpvarDecl {
                 debug_name: keywords::Invalid.name(),
                 by_ref,
@@ -15,6 +15,7 @@ use hair::cx::block;
 use hair::cx::to_ref::ToRef;
 use rustc::hir::def::{Def, CtorKind};
 use rustc::middle::const_val::ConstVal;
+use rustc::middle::mem_categorization as mc;
 use rustc::mir::interpret::{GlobalId, Value, PrimVal};
 use rustc::ty::{self, AdtKind, Ty};
 use rustc::ty::adjustment::{Adjustment, Adjust, AutoBorrow, AutoBorrowMutability};
@@ -199,6 +200,10 @@ fn make_mirror_unadjusted<'a, 'gcx, 'tcx>(cx: &mut Cx<'a, 'gcx, 'tcx>,
     let expr_ty = cx.tables().expr_ty(expr);
     let temp_lifetime = cx.region_scope_tree.temporary_scope(expr.hir_id.local_id);
 
+    if let Some(place) = convert_captured_place(cx, expr) {
+        return Expr { ty: expr_ty, ..place };
+    }
+
     let kind = match expr.node {
         // Here comes the interesting stuff:
         hir::ExprMethodCall(.., ref args) => {
@@ -779,6 +784,23 @@ fn convert_path_expr<'a, 'gcx, 'tcx>(cx: &mut Cx<'a, 'gcx, 'tcx>,
             },
         },
 
+        Def::ConstParam(def_id) => {
+            let node_id = cx.tcx.hir.as_local_node_id(def_id).unwrap();
+            let item_id = cx.tcx.hir.get_parent_node(node_id);
+            let item_def_id = cx.tcx.hir.local_def_id(item_id);
+            let generics = cx.tcx.generics_of(item_def_id);
+            let index = generics.const_param_to_index[&def_id];
+            ExprKind::Literal {
+                literal: Literal::Value {
+                    value: cx.tcx.mk_const_param(
+                        index,
+                        cx.tcx.hir.name(node_id).as_str(),
+                        cx.tables().node_id_to_type(expr.hir_id),
+                    ),
+                },
+            }
+        }
+
         Def::StructCtor(def_id, CtorKind::Const) |
         Def::VariantCtor(def_id, CtorKind::Const) => {
             match cx.tables().node_id_to_type(expr.hir_id).sty {
@@ -822,86 +844,9 @@ fn convert_var<'a, 'gcx, 'tcx>(cx: &mut Cx<'a, 'gcx, 'tcx>,
             let var_hir_id = cx.tcx.hir.node_to_hir_id(var_id);
             let var_ty = cx.tables().node_id_to_type(var_hir_id);
 
-            // FIXME free regions in closures are not right
-            let closure_ty = cx.tables()
-                               .node_id_to_type(cx.tcx.hir.node_to_hir_id(closure_expr_id));
-
-            // FIXME we're just hard-coding the idea that the
-            // signature will be &self or &mut self and hence will
-            // have a bound region with number 0
-            let closure_def_id = cx.tcx.hir.local_def_id(closure_expr_id);
-            let region = ty::ReFree(ty::FreeRegion {
-                scope: closure_def_id,
-                bound_region: ty::BoundRegion::BrAnon(0),
-            });
-            let region = cx.tcx.mk_region(region);
-
-            let self_expr = if let ty::TyClosure(_, closure_substs) = closure_ty.sty {
-                match cx.infcx.closure_kind(closure_def_id, closure_substs).unwrap() {
-                    ty::ClosureKind::Fn => {
-                        let ref_closure_ty = cx.tcx.mk_ref(region,
-                                                           ty::TypeAndMut {
-                                                               ty: closure_ty,
-                                                               mutbl: hir::MutImmutable,
-                                                           });
-                        Expr {
-                            ty: closure_ty,
-                            temp_lifetime: temp_lifetime,
-                            span: expr.span,
-                            kind: ExprKind::Deref {
-                                arg: Expr {
-                                    ty: ref_closure_ty,
-                                    temp_lifetime,
-                                    span: expr.span,
-                                    kind: ExprKind::SelfRef,
-                                }
-                                .to_ref(),
-                            },
-                        }
-                    }
-                    ty::ClosureKind::FnMut => {
-                        let ref_closure_ty = cx.tcx.mk_ref(region,
-                                                           ty::TypeAndMut {
-                                                               ty: closure_ty,
-                                                               mutbl: hir::MutMutable,
-                                                           });
-                        Expr {
-                            ty: closure_ty,
-                            temp_lifetime,
-                            span: expr.span,
-                            kind: ExprKind::Deref {
-                                arg: Expr {
-                                    ty: ref_closure_ty,
-                                    temp_lifetime,
-                                    span: expr.span,
-                                    kind: ExprKind::SelfRef,
-                                }.to_ref(),
-                            },
-                        }
-                    }
-                    ty::ClosureKind::FnOnce => {
-                        Expr {
-                            ty: closure_ty,
-                            temp_lifetime,
-                            span: expr.span,
-                            kind: ExprKind::SelfRef,
-                        }
-                    }
-                }
-            } else {
-                Expr {
-                    ty: closure_ty,
-                    temp_lifetime,
-                    span: expr.span,
-                    kind: ExprKind::SelfRef,
-                }
-            };
-
             // at this point we have `self.n`, which loads up the upvar
-            let field_kind = ExprKind::Field {
-                lhs: self_expr.to_ref(),
-                name: Field::new(index),
-            };
+            let field_kind = closure_env_field(cx, expr, closure_expr_id, index);
+            let closure_def_id = cx.tcx.hir.local_def_id(closure_expr_id);
 
             // ...but the upvar might be an `&T` or `&mut T` capture, at which
             // point we need an implicit deref
@@ -933,6 +878,220 @@ fn convert_var<'a, 'gcx, 'tcx>(cx: &mut Cx<'a, 'gcx, 'tcx>,
 }
 
 
+/// Loads the upvar with the given index from the environment of a closure,
+/// i.e. `self.n`.
+fn closure_env_field<'a, 'gcx, 'tcx>(cx: &mut Cx<'a, 'gcx, 'tcx>,
+                                     expr: &'tcx hir::Expr,
+                                     closure_expr_id: ast::NodeId,
+                                     index: usize)
+                                     -> ExprKind<'tcx> {
+    let temp_lifetime = cx.region_scope_tree.temporary_scope(expr.hir_id.local_id);
+
+    // FIXME free regions in closures are not right
+    let closure_ty = cx.tables()
+                       .node_id_to_type(cx.tcx.hir.node_to_hir_id(closure_expr_id));
+
+    // FIXME we're just hard-coding the idea that the
+    // signature will be &self or &mut self and hence will
+    // have a bound region with number 0
+    let closure_def_id = cx.tcx.hir.local_def_id(closure_expr_id);
+    let region = ty::ReFree(ty::FreeRegion {
+        scope: closure_def_id,
+        bound_region: ty::BoundRegion::BrAnon(0),
+    });
+    let region = cx.tcx.mk_region(region);
+
+    let self_expr = if let ty::TyClosure(_, closure_substs) = closure_ty.sty {
+        match cx.infcx.closure_kind(closure_def_id, closure_substs).unwrap() {
+            ty::ClosureKind::Fn => {
+                let ref_closure_ty = cx.tcx.mk_ref(region,
+                                                   ty::TypeAndMut {
+                                                       ty: closure_ty,
+                                                       mutbl: hir::MutImmutable,
+                                                   });
+                Expr {
+                    ty: closure_ty,
+                    temp_lifetime: temp_lifetime,
+                    span: expr.span,
+                    kind: ExprKind::Deref {
+                        arg: Expr {
+                            ty: ref_closure_ty,
+                            temp_lifetime,
+                            span: expr.span,
+                            kind: ExprKind::SelfRef,
+                        }
+                        .to_ref(),
+                    },
+                }
+            }
+            ty::ClosureKind::FnMut => {
+                let ref_closure_ty = cx.tcx.mk_ref(region,
+                                                   ty::TypeAndMut {
+                                                       ty: closure_ty,
+                                                       mutbl: hir::MutMutable,
+                                                   });
+                Expr {
+                    ty: closure_ty,
+                    temp_lifetime,
+                    span: expr.span,
+                    kind: ExprKind::Deref {
+                        arg: Expr {
+                            ty: ref_closure_ty,
+                            temp_lifetime,
+                            span: expr.span,
+                            kind: ExprKind::SelfRef,
+                        }.to_ref(),
+                    },
+                }
+            }
+            ty::ClosureKind::FnOnce => {
+                Expr {
+                    ty: closure_ty,
+                    temp_lifetime,
+                    span: expr.span,
+                    kind: ExprKind::SelfRef,
+                }
+            }
+        }
+    } else {
+        Expr {
+            ty: closure_ty,
+            temp_lifetime,
+            span: expr.span,
+            kind: ExprKind::SelfRef,
+        }
+    };
+
+    ExprKind::Field {
+        lhs: self_expr.to_ref(),
+        name: Field::new(index),
+    }
+}
+
+/// Converts the place reached from the variable `def` refers to by
+/// `projections`. In a closure that captures places of the variable rather
+/// than the variable itself, this goes through the captured place that
+/// contains it.
+fn convert_place<'a, 'gcx, 'tcx>(cx: &mut Cx<'a, 'gcx, 'tcx>,
+                                 expr: &'tcx hir::Expr,
+                                 def: Def,
+                                 projections: &[(ty::CaptureProjection, Ty<'tcx>)])
+                                 -> Expr<'tcx> {
+    let temp_lifetime = cx.region_scope_tree.temporary_scope(expr.hir_id.local_id);
+
+    let captured_places = match def {
+        Def::Upvar(var_id, index, closure_expr_id) => {
+            let upvar_id = ty::UpvarId {
+                var_id: cx.tcx.hir.node_to_hir_id(var_id),
+                closure_expr_id: cx.tcx.hir.local_def_id(closure_expr_id).to_local(),
+            };
+            cx.tables().upvar_places(upvar_id).map(|places| (places, index, closure_expr_id))
+        }
+        _ => None,
+    };
+
+    let (mut place, rest) = match captured_places {
+        Some((places, index, closure_expr_id)) => {
+            let path: Vec<_> = projections.iter().map(|&(projection, _)| projection).collect();
+            let (field, captured) = places.iter()
+                .enumerate()
+                .find(|&(_, place)| place.is_prefix_of(&path))
+                .unwrap_or_else(|| span_bug!(expr.span, "uncaptured place of upvar: {:?}", path));
+
+            // The upvar is a tuple of its captured places...
+            let upvar = Expr {
+                temp_lifetime,
+                ty: cx.tcx.mk_tup(places.iter().map(|place| place.captured_ty(cx.tcx))),
+                span: expr.span,
+                kind: closure_env_field(cx, expr, closure_expr_id, index),
+            };
+            let mut place = Expr {
+                temp_lifetime,
+                ty: captured.captured_ty(cx.tcx),
+                span: expr.span,
+                kind: ExprKind::Field {
+                    lhs: upvar.to_ref(),
+                    name: Field::new(field),
+                },
+            };
+            // ...each of which might be captured by reference.
+            if let ty::UpvarCapture::ByRef(..) = captured.capture {
+                place = Expr {
+                    temp_lifetime,
+                    ty: captured.place_ty(),
+                    span: expr.span,
+                    kind: ExprKind::Deref { arg: place.to_ref() },
+                };
+            }
+            (place, &projections[captured.projections.len()..])
+        }
+        None => {
+            let var_id = match def {
+                Def::Local(id) | Def::Upvar(id, ..) => id,
+                _ => span_bug!(expr.span, "place rooted at non-variable {:?}", def),
+            };
+            let var = Expr {
+                temp_lifetime,
+                ty: cx.tables().node_id_to_type(cx.tcx.hir.node_to_hir_id(var_id)),
+                span: expr.span,
+                kind: convert_var(cx, expr, def),
+            };
+            (var, projections)
+        }
+    };
+
+    for &(projection, ty) in rest {
+        let kind = match projection {
+            ty::CaptureProjection::Deref => ExprKind::Deref { arg: place.to_ref() },
+            ty::CaptureProjection::Field(index, _) => {
+                ExprKind::Field {
+                    lhs: place.to_ref(),
+                    name: Field::new(index),
+                }
+            }
+        };
+        place = Expr {
+            temp_lifetime,
+            ty,
+            span: expr.span,
+            kind,
+        };
+    }
+    place
+}
+
+/// Converts `expr` through the captured places of a closure if it is a place
+/// rooted at a variable the closure captures places of. The expressions
+/// handled here are the ones that upvar inference made sure to capture.
+fn convert_captured_place<'a, 'gcx, 'tcx>(cx: &mut Cx<'a, 'gcx, 'tcx>,
+                                          expr: &'tcx hir::Expr)
+                                          -> Option<Expr<'tcx>> {
+    if cx.tables().upvar_places_map.is_empty() {
+        return None;
+    }
+
+    let place = {
+        let mc = mc::MemCategorizationContext::new(cx.tcx.global_tcx(),
+                                                   &cx.region_scope_tree,
+                                                   cx.tables(),
+                                                   None);
+        mc.cat_expr_unadjusted(expr).ok()?.upvar_place()?
+    };
+    if !place.complete || cx.tables().upvar_places(place.upvar_id).is_none() {
+        return None;
+    }
+
+    let var_id = cx.tcx.hir.hir_to_node_id(place.upvar_id.var_id);
+    let closure_def_id = place.upvar_id.closure_expr_id.to_def_id();
+    let closure_expr_id = cx.tcx.hir.as_local_node_id(closure_def_id).unwrap();
+    let index = cx.tcx.with_freevars(closure_expr_id, |freevars| {
+        freevars.iter().position(|freevar| freevar.var_id() == var_id)
+    }).unwrap();
+    let def = Def::Upvar(var_id, index, closure_expr_id);
+    Some(convert_place(cx, expr, def, &place.projections))
+}
+
+
 fn bin_op(op: hir::BinOp_) -> BinOp {
     match op {
         hir::BinOp_::BiAdd => BinOp::Add,
@@ -1023,8 +1182,24 @@ fn capture_freevar<'a, 'gcx, 'tcx>(cx: &mut Cx<'a, 'gcx, 'tcx>,
         var_id: var_hir_id,
         closure_expr_id: cx.tcx.hir.local_def_id(closure_expr.id).to_local(),
     };
-    let upvar_capture = cx.tables().upvar_capture(upvar_id);
     let temp_lifetime = cx.region_scope_tree.temporary_scope(closure_expr.hir_id.local_id);
+
+    // A variable captured through its places becomes a tuple of them.
+    if let Some(places) = cx.tables().upvar_places(upvar_id) {
+        let fields = places.iter().map(|place| {
+            let captured_place = convert_place(cx, closure_expr, freevar.def, &place.projections);
+            let captured_ty = place.captured_ty(cx.tcx);
+            capture_place(closure_expr, temp_lifetime, captured_place, place.capture, captured_ty)
+        }).collect();
+        return Expr {
+            temp_lifetime,
+            ty: freevar_ty,
+            span: closure_expr.span,
+            kind: ExprKind::Tuple { fields },
+        }.to_ref();
+    }
+
+    let upvar_capture = cx.tables().upvar_capture(upvar_id);
     let var_ty = cx.tables().node_id_to_type(var_hir_id);
     let captured_var = Expr {
         temp_lifetime,
@@ -1032,8 +1207,17 @@ fn capture_freevar<'a, 'gcx, 'tcx>(cx: &mut Cx<'a, 'gcx, 'tcx>,
         span: closure_expr.span,
         kind: convert_var(cx, closure_expr, freevar.def),
     };
-    match upvar_capture {
-        ty::UpvarCapture::ByValue => captured_var.to_ref(),
+    capture_place(closure_expr, temp_lifetime, captured_var, upvar_capture, freevar_ty)
+}
+
+fn capture_place<'tcx>(closure_expr: &'tcx hir::Expr,
+                       temp_lifetime: Option<region::Scope>,
+                       place: Expr<'tcx>,
+                       capture: ty::UpvarCapture<'tcx>,
+                       captured_ty: Ty<'tcx>)
+                       -> ExprRef<'tcx> {
+    match capture {
+        ty::UpvarCapture::ByValue => place.to_ref(),
         ty::UpvarCapture::ByRef(upvar_borrow) => {
             let borrow_kind = match upvar_borrow.kind {
                 ty::BorrowKind::ImmBorrow => BorrowKind::Shared,
@@ -1042,12 +1226,12 @@ fn capture_freevar<'a, 'gcx, 'tcx>(cx: &mut Cx<'a, 'gcx, 'tcx>,
             };
             Expr {
                 temp_lifetime,
-                ty: freevar_ty,
+                ty: captured_ty,
                 span: closure_expr.span,
                 kind: ExprKind::Borrow {
                     region: upvar_borrow.region,
                     borrow_kind,
-                    arg: captured_var.to_ref(),
+                    arg: place.to_ref(),
                 },
             }.to_ref()
         }
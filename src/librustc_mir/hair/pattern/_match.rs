@@ -23,11 +23,14 @@ use super::{PatternFoldable, PatternFolder, compare_const_vals};
 use rustc::hir::def_id::DefId;
 use rustc::hir::RangeEnd;
 use rustc::ty::{self, Ty, TyCtxt, TypeFoldable};
+use rustc::ty::layout::Integer;
 
 use rustc::mir::Field;
 use rustc::mir::interpret::{Value, PrimVal};
 use rustc::util::common::ErrorReported;
 
+use syntax::ast::{IntTy, UintTy};
+use syntax::attr::{SignedInt, UnsignedInt};
 use syntax_pos::{Span, DUMMY_SP};
 
 use arena::TypedArena;
@@ -35,6 +38,7 @@ use arena::TypedArena;
 use std::cmp::{self, Ordering};
 use std::fmt;
 use std::iter::{FromIterator, IntoIterator, repeat};
+use std::u128;
 
 pub fn expand_pattern<'a, 'tcx>(cx: &MatchCheckCtxt<'a, 'tcx>, pat: Pattern<'tcx>)
                                 -> &'a Pattern<'tcx>
@@ -84,7 +88,19 @@ impl<'a, 'tcx> Matrix<'a, 'tcx> {
         Matrix(vec![])
     }
 
+    /// Pushes a row, expanding an or-pattern in its first column into one row
+    /// per alternative, so that the head of a row is never an or-pattern.
     pub fn push(&mut self, row: Vec<&'a Pattern<'tcx>>) {
+        if let Some(&head) = row.first() {
+            if let PatternKind::Or { ref pats } = *head.kind {
+                for pat in pats {
+                    let mut row = row.clone();
+                    row[0] = pat;
+                    self.push(row);
+                }
+                return;
+            }
+        }
         self.0.push(row)
     }
 }
@@ -136,7 +152,11 @@ impl<'a, 'tcx> fmt::Debug for Matrix<'a, 'tcx> {
 impl<'a, 'tcx> FromIterator<Vec<&'a Pattern<'tcx>>> for Matrix<'a, 'tcx> {
     fn from_iter<T: IntoIterator<Item=Vec<&'a Pattern<'tcx>>>>(iter: T) -> Self
     {
-        Matrix(iter.into_iter().collect())
+        let mut matrix = Matrix::empty();
+        for row in iter {
+            matrix.push(row);
+        }
+        matrix
     }
 }
 
@@ -281,6 +301,164 @@ impl<'tcx> Constructor<'tcx> {
     }
 }
 
+/// Whether the values of `ty` are matched exhaustively by ranges, rather
+/// than by assuming that some value is always left over. `isize` and `usize`
+/// are left out, as their range depends on the target.
+fn treats_ranges_exhaustively<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>, ty: Ty<'tcx>) -> bool {
+    tcx.features().exhaustive_integer_patterns && match ty.sty {
+        ty::TyChar => true,
+        ty::TyInt(ity) => ity != IntTy::Isize,
+        ty::TyUint(uty) => uty != UintTy::Usize,
+        _ => false,
+    }
+}
+
+/// An inclusive range of `char` or integer values.
+///
+/// The endpoints are encoded so that `0` is the smallest value of the type,
+/// whatever its signedness: `-128i8...127i8` is stored as `0...255`. This
+/// keeps comparisons and arithmetic on the endpoints unsigned.
+#[derive(Clone, Debug)]
+pub struct IntRange<'tcx> {
+    lo: u128,
+    hi: u128,
+    ty: Ty<'tcx>,
+}
+
+impl<'tcx> IntRange<'tcx> {
+    /// The size in bits of the values of `ty`, and the bias that is XORed into
+    /// them to encode or decode an endpoint.
+    fn size_and_bias<'a>(tcx: TyCtxt<'a, 'tcx, 'tcx>, ty: Ty<'tcx>) -> Option<(u64, u128)> {
+        match ty.sty {
+            ty::TyChar => Some((32, 0)),
+            ty::TyInt(ity) => {
+                let size = Integer::from_attr(tcx, SignedInt(ity)).size().bits();
+                Some((size, 1 << (size - 1)))
+            }
+            ty::TyUint(uty) => {
+                Some((Integer::from_attr(tcx, UnsignedInt(uty)).size().bits(), 0))
+            }
+            _ => None,
+        }
+    }
+
+    fn encode<'a>(tcx: TyCtxt<'a, 'tcx, 'tcx>, value: &ty::Const<'tcx>) -> Option<u128> {
+        let (size, bias) = IntRange::size_and_bias(tcx, value.ty)?;
+        match value.val {
+            // Signed literals are stored sign-extended, so mask them first.
+            ConstVal::Value(Value::ByVal(PrimVal::Bytes(bits))) => {
+                Some((bits & (u128::MAX >> (128 - size))) ^ bias)
+            }
+            _ => None,
+        }
+    }
+
+    fn decode<'a>(tcx: TyCtxt<'a, 'tcx, 'tcx>, ty: Ty<'tcx>, value: u128)
+                  -> &'tcx ty::Const<'tcx> {
+        let (size, bias) = IntRange::size_and_bias(tcx, ty).unwrap();
+        let mut bits = value ^ bias;
+        if bias != 0 {
+            // Sign-extend, as literals are.
+            let shift = 128 - size;
+            bits = (((bits << shift) as i128) >> shift) as u128;
+        }
+        tcx.mk_const(ty::Const {
+            val: ConstVal::Value(Value::ByVal(PrimVal::Bytes(bits))),
+            ty,
+        })
+    }
+
+    fn from_ctor<'a>(tcx: TyCtxt<'a, 'tcx, 'tcx>, ctor: &Constructor<'tcx>)
+                     -> Option<IntRange<'tcx>> {
+        match *ctor {
+            ConstantValue(value) => {
+                let value_bits = IntRange::encode(tcx, value)?;
+                Some(IntRange { lo: value_bits, hi: value_bits, ty: value.ty })
+            }
+            ConstantRange(lo, hi, end) => {
+                let (lo_bits, hi_bits) = (IntRange::encode(tcx, lo)?, IntRange::encode(tcx, hi)?);
+                if lo_bits > hi_bits || (lo_bits == hi_bits && end == RangeEnd::Excluded) {
+                    return None;
+                }
+                let hi_bits = if end == RangeEnd::Excluded { hi_bits - 1 } else { hi_bits };
+                Some(IntRange { lo: lo_bits, hi: hi_bits, ty: lo.ty })
+            }
+            Single | Variant(_) | Slice(_) => None,
+        }
+    }
+
+    /// The range matched by a constant or range pattern of `char` or integer
+    /// type.
+    pub fn from_pat<'a>(tcx: TyCtxt<'a, 'tcx, 'tcx>, pat: &Pattern<'tcx>)
+                        -> Option<IntRange<'tcx>> {
+        match *pat.kind {
+            PatternKind::Constant { value } => IntRange::from_ctor(tcx, &ConstantValue(value)),
+            PatternKind::Range { lo, hi, end } => {
+                IntRange::from_ctor(tcx, &ConstantRange(lo, hi, end))
+            }
+            _ => None,
+        }
+    }
+
+    fn into_ctor<'a>(self, tcx: TyCtxt<'a, 'tcx, 'tcx>) -> Constructor<'tcx> {
+        let lo = IntRange::decode(tcx, self.ty, self.lo);
+        if self.lo == self.hi {
+            ConstantValue(lo)
+        } else {
+            ConstantRange(lo, IntRange::decode(tcx, self.ty, self.hi), RangeEnd::Included)
+        }
+    }
+
+    /// A pattern matching exactly this range, for use in diagnostics.
+    pub fn into_pattern<'a>(self, tcx: TyCtxt<'a, 'tcx, 'tcx>) -> Pattern<'tcx> {
+        let ty = self.ty;
+        let kind = match self.into_ctor(tcx) {
+            ConstantValue(value) => PatternKind::Constant { value },
+            ConstantRange(lo, hi, end) => PatternKind::Range { lo, hi, end },
+            _ => bug!(),
+        };
+        Pattern { ty, span: DUMMY_SP, kind: box kind }
+    }
+
+    pub fn intersection(&self, other: &IntRange<'tcx>) -> Option<IntRange<'tcx>> {
+        if self.lo <= other.hi && other.lo <= self.hi {
+            Some(IntRange {
+                lo: cmp::max(self.lo, other.lo),
+                hi: cmp::min(self.hi, other.hi),
+                ty: self.ty,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn is_subrange(&self, other: &IntRange<'tcx>) -> bool {
+        other.lo <= self.lo && self.hi <= other.hi
+    }
+
+    /// Returns the constructors covering the values of `ranges` that are not
+    /// covered by `self`.
+    fn subtract_from<'a>(&self,
+                         tcx: TyCtxt<'a, 'tcx, 'tcx>,
+                         ranges: Vec<Constructor<'tcx>>)
+                         -> Vec<Constructor<'tcx>> {
+        let mut remaining = vec![];
+        for range in ranges.iter().filter_map(|r| IntRange::from_ctor(tcx, r)) {
+            if self.intersection(&range).is_none() {
+                remaining.push(range);
+                continue;
+            }
+            if range.lo < self.lo {
+                remaining.push(IntRange { lo: range.lo, hi: self.lo - 1, ty: self.ty });
+            }
+            if self.hi < range.hi {
+                remaining.push(IntRange { lo: self.hi + 1, hi: range.hi, ty: self.ty });
+            }
+        }
+        remaining.into_iter().map(|range| range.into_ctor(tcx)).collect()
+    }
+}
+
 #[derive(Clone)]
 pub enum Usefulness<'tcx> {
     Useful,
@@ -404,6 +582,7 @@ impl<'tcx> Witness<'tcx> {
                 _ => {
                     match *ctor {
                         ConstantValue(value) => PatternKind::Constant { value },
+                        ConstantRange(lo, hi, end) => PatternKind::Range { lo, hi, end },
                         _ => PatternKind::Wild,
                     }
                 }
@@ -429,6 +608,10 @@ impl<'tcx> Witness<'tcx> {
 /// non-booleans, because we currently assume that there is always a
 /// "non-standard constant" that matches. See issue #12483.
 ///
+/// With `exhaustive_integer_patterns`, the values of `char` and of the
+/// fixed-size integer types are instead listed as a few large ranges, which
+/// are split up as needed by `split_grouped_constructors`.
+///
 /// We make sure to omit constructors that are statically impossible. eg for
 /// Option<!> we do not include Some(_) in the returned list of constructors.
 fn all_constructors<'a, 'tcx: 'a>(cx: &mut MatchCheckCtxt<'a, 'tcx>,
@@ -468,6 +651,18 @@ fn all_constructors<'a, 'tcx: 'a>(cx: &mut MatchCheckCtxt<'a, 'tcx>,
                 .map(|v| Variant(v.did))
                 .collect()
         }
+        ty::TyChar if treats_ranges_exhaustively(cx.tcx, pcx.ty) => {
+            // The valid Unicode scalar values, skipping the surrogates.
+            vec![
+                IntRange { lo: 0, hi: 0xD7FF, ty: pcx.ty }.into_ctor(cx.tcx),
+                IntRange { lo: 0xE000, hi: 0x10FFFF, ty: pcx.ty }.into_ctor(cx.tcx),
+            ]
+        }
+        ty::TyInt(_) | ty::TyUint(_) if treats_ranges_exhaustively(cx.tcx, pcx.ty) => {
+            let (size, _) = IntRange::size_and_bias(cx.tcx, pcx.ty).unwrap();
+            let max = u128::MAX >> (128 - size);
+            vec![IntRange { lo: 0, hi: max, ty: pcx.ty }.into_ctor(cx.tcx)]
+        }
         _ => {
             if cx.is_uninhabited(pcx.ty) {
                 vec![]
@@ -634,6 +829,16 @@ pub fn is_useful<'p, 'a: 'p, 'tcx: 'a>(cx: &mut MatchCheckCtxt<'a, 'tcx>,
 
     assert!(rows.iter().all(|r| r.len() == v.len()));
 
+    // An or-pattern is useful if any of its alternatives is. The rows of the
+    // matrix have already had theirs expanded by `Matrix::push`.
+    if let PatternKind::Or { ref pats } = *v[0].kind {
+        return pats.iter().map(|pat| {
+            let mut v = v.to_vec();
+            v[0] = pat;
+            is_useful(cx, matrix, &v, witness)
+        }).find(|result| result.is_useful()).unwrap_or(NotUseful);
+    }
+
     let pcx = PatternContext {
         // TyErr is used to represent the type of wildcard patterns matching
         // against inaccessible (private) fields of structs, so that we won't
@@ -663,7 +868,7 @@ pub fn is_useful<'p, 'a: 'p, 'tcx: 'a>(cx: &mut MatchCheckCtxt<'a, 'tcx>,
 
     if let Some(constructors) = pat_constructors(cx, v[0], pcx) {
         debug!("is_useful - expanding constructors: {:#?}", constructors);
-        constructors.into_iter().map(|c|
+        split_grouped_constructors(cx.tcx, constructors, matrix, pcx.ty).into_iter().map(|c|
             is_useful_specialized(cx, matrix, v, c.clone(), pcx.ty, witness)
         ).find(|result| result.is_useful()).unwrap_or(NotUseful)
     } else {
@@ -675,9 +880,7 @@ pub fn is_useful<'p, 'a: 'p, 'tcx: 'a>(cx: &mut MatchCheckCtxt<'a, 'tcx>,
         debug!("used_ctors = {:#?}", used_ctors);
         let all_ctors = all_constructors(cx, pcx);
         debug!("all_ctors = {:#?}", all_ctors);
-        let missing_ctors: Vec<Constructor> = all_ctors.iter().filter(|c| {
-            !used_ctors.contains(*c)
-        }).cloned().collect();
+        let missing_ctors = compute_missing_ctors(cx.tcx, &all_ctors, &used_ctors, pcx.ty);
 
         // `missing_ctors` is the set of constructors from the same type as the
         // first column of `matrix` that are matched only by wildcard patterns
@@ -710,7 +913,7 @@ pub fn is_useful<'p, 'a: 'p, 'tcx: 'a>(cx: &mut MatchCheckCtxt<'a, 'tcx>,
         let is_non_exhaustive = is_privately_empty || is_declared_nonexhaustive;
 
         if missing_ctors.is_empty() && !is_non_exhaustive {
-            all_ctors.into_iter().map(|c| {
+            split_grouped_constructors(cx.tcx, all_ctors, matrix, pcx.ty).into_iter().map(|c| {
                 is_useful_specialized(cx, matrix, v, c.clone(), pcx.ty, witness)
             }).find(|result| result.is_useful()).unwrap_or(NotUseful)
         } else {
@@ -794,6 +997,108 @@ pub fn is_useful<'p, 'a: 'p, 'tcx: 'a>(cx: &mut MatchCheckCtxt<'a, 'tcx>,
     }
 }
 
+/// Returns the constructors in `all_ctors` that are not covered by any of
+/// `used_ctors`. Ranges of integers and `char`s may be partially covered, in
+/// which case the values that are left over are returned as smaller ranges.
+fn compute_missing_ctors<'a, 'tcx: 'a>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
+                                       all_ctors: &[Constructor<'tcx>],
+                                       used_ctors: &[Constructor<'tcx>],
+                                       ty: Ty<'tcx>)
+                                       -> Vec<Constructor<'tcx>>
+{
+    if !treats_ranges_exhaustively(tcx, ty) {
+        return all_ctors.iter().filter(|c| !used_ctors.contains(*c)).cloned().collect();
+    }
+
+    let mut missing_ctors = vec![];
+    for ctor in all_ctors {
+        let mut remaining = vec![ctor.clone()];
+        for used_ctor in used_ctors {
+            if let Some(used_range) = IntRange::from_ctor(tcx, used_ctor) {
+                remaining = used_range.subtract_from(tcx, remaining);
+            }
+            if remaining.is_empty() {
+                break;
+            }
+        }
+        missing_ctors.extend(remaining);
+    }
+    missing_ctors
+}
+
+/// Splits the integer and `char` ranges among `ctors` at the boundaries of
+/// the ranges in the first column of `matrix`, so that each of the resulting
+/// ranges is either entirely covered by a row's first pattern, or not at all.
+/// That is what `specialize` relies on when it checks a range constructor
+/// against a range pattern.
+///
+/// For example, with the rows `0...5` and `3...10`, the constructor `0...255`
+/// is split into `0...2`, `3...5`, `6...10` and `11...255`.
+fn split_grouped_constructors<'p, 'a: 'p, 'tcx: 'a>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
+                                                   ctors: Vec<Constructor<'tcx>>,
+                                                   &Matrix(ref m): &Matrix<'p, 'tcx>,
+                                                   ty: Ty<'tcx>)
+                                                   -> Vec<Constructor<'tcx>>
+{
+    if !treats_ranges_exhaustively(tcx, ty) {
+        return ctors;
+    }
+
+    /// A border just before a value, or after the largest value of the type,
+    /// which cannot be written as `JustBefore(u128::MAX + 1)`.
+    #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+    enum Border {
+        JustBefore(u128),
+        AfterMax,
+    }
+
+    fn range_borders(range: &IntRange) -> [Border; 2] {
+        let after_hi = match range.hi.checked_add(1) {
+            Some(value) => Border::JustBefore(value),
+            None => Border::AfterMax,
+        };
+        [Border::JustBefore(range.lo), after_hi]
+    }
+
+    let mut split_ctors = vec![];
+    for ctor in ctors {
+        let ctor_range = match ctor {
+            ConstantRange(..) => IntRange::from_ctor(tcx, &ctor),
+            _ => None,
+        };
+        let ctor_range = match ctor_range {
+            Some(ctor_range) => ctor_range,
+            None => {
+                split_ctors.push(ctor);
+                continue;
+            }
+        };
+
+        let mut borders = range_borders(&ctor_range).to_vec();
+        for row in m {
+            if let Some(row_range) = IntRange::from_pat(tcx, row[0]) {
+                if let Some(overlap) = ctor_range.intersection(&row_range) {
+                    borders.extend_from_slice(&range_borders(&overlap));
+                }
+            }
+        }
+        borders.sort_unstable();
+        borders.dedup();
+
+        // Each pair of consecutive borders delimits one of the new ranges.
+        for window in borders.windows(2) {
+            let hi = match window[1] {
+                Border::JustBefore(value) => value - 1,
+                Border::AfterMax => u128::MAX,
+            };
+            if let Border::JustBefore(lo) = window[0] {
+                split_ctors.push(IntRange { lo, hi, ty: ctor_range.ty }.into_ctor(tcx));
+            }
+        }
+    }
+    split_ctors
+}
+
 fn is_useful_specialized<'p, 'a:'p, 'tcx: 'a>(
     cx: &mut MatchCheckCtxt<'a, 'tcx>,
     &Matrix(ref m): &Matrix<'p, 'tcx>,
@@ -812,9 +1117,9 @@ fn is_useful_specialized<'p, 'a:'p, 'tcx: 'a>(
         }
     }).collect();
     let wild_patterns: Vec<_> = wild_patterns_owned.iter().collect();
-    let matrix = Matrix(m.iter().flat_map(|r| {
+    let matrix: Matrix = m.iter().flat_map(|r| {
         specialize(cx, &r, &ctor, &wild_patterns)
-    }).collect());
+    }).collect();
     match specialize(cx, v, &ctor, &wild_patterns) {
         Some(v) => match is_useful(cx, &matrix, &v, witness) {
             UsefulWithWitness(witnesses) => UsefulWithWitness(
@@ -866,6 +1171,7 @@ fn pat_constructors<'tcx>(_cx: &mut MatchCheckCtxt,
                 Some(vec![Slice(pat_len)])
             }
         }
+        PatternKind::Or { .. } => span_bug!(pat.span, "unexpanded or-pattern {:?}", pat),
     }
 }
 
@@ -1165,6 +1471,8 @@ fn specialize<'p, 'a: 'p, 'tcx: 'a>(
                     "unexpected ctor {:?} for slice pat", constructor)
             }
         }
+
+        PatternKind::Or { .. } => span_bug!(pat.span, "unexpanded or-pattern {:?}", pat),
     };
     debug!("specialize({:#?}, {:#?}) = {:#?}", r[0], wild_patterns, head);
 
@@ -8,7 +8,7 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use super::_match::{MatchCheckCtxt, Matrix, expand_pattern, is_useful};
+use super::_match::{IntRange, MatchCheckCtxt, Matrix, expand_pattern, is_useful};
 use super::_match::Usefulness::*;
 use super::_match::WitnessPreference::*;
 
@@ -357,11 +357,20 @@ fn check_arms<'a, 'tcx>(cx: &mut MatchCheckCtxt<'a, 'tcx>,
                         source: hir::MatchSource)
 {
     let mut seen = Matrix::empty();
+    let mut seen_ranges: Vec<(IntRange, Span)> = vec![];
     let mut catchall = None;
     let mut printed_if_let_err = false;
     for (arm_index, &(ref pats, guard)) in arms.iter().enumerate() {
         for &(pat, hir_pat) in pats {
             let v = vec![pat];
+            let range = match *pat.kind {
+                PatternKind::Range { .. } => IntRange::from_pat(cx.tcx, pat),
+                _ => None,
+            };
+
+            if let Some(ref range) = range {
+                lint_overlapping_ranges(cx, range, &seen_ranges, hir_pat.id, pat.span);
+            }
 
             match is_useful(cx, &seen, &v, LeaveOutWitness) {
                 NotUseful => {
@@ -428,6 +437,16 @@ fn check_arms<'a, 'tcx>(cx: &mut MatchCheckCtxt<'a, 'tcx>,
                         // Unreachable patterns in try expressions occur when one of the arms
                         // are an uninhabited type. Which is OK.
                         hir::MatchSource::TryDesugar => {}
+
+                        // The wildcard arm holding the `else` block, which can't run
+                        // as the user's pattern always matches.
+                        hir::MatchSource::LetElseDesugar => {
+                            let span = arms[0].0[0].0.span;
+                            cx.tcx.lint_node(lint::builtin::UNREACHABLE_PATTERNS,
+                                             hir_pat.id,
+                                             span,
+                                             "irrefutable `let...else` pattern");
+                        }
                     }
                 }
                 Useful => (),
@@ -435,6 +454,9 @@ fn check_arms<'a, 'tcx>(cx: &mut MatchCheckCtxt<'a, 'tcx>,
             }
             if guard.is_none() {
                 seen.push(v);
+                if let Some(range) = range {
+                    seen_ranges.push((range, pat.span));
+                }
                 if catchall.is_none() && pat_is_catchall(hir_pat) {
                     catchall = Some(pat.span);
                 }
@@ -443,6 +465,39 @@ fn check_arms<'a, 'tcx>(cx: &mut MatchCheckCtxt<'a, 'tcx>,
     }
 }
 
+/// Warns about a range pattern that shares some, but not all, of its values
+/// with the range of an earlier arm. Nested ranges are left alone: an inner
+/// range coming first is a special case, and coming last is unreachable.
+fn lint_overlapping_ranges<'a, 'tcx>(cx: &MatchCheckCtxt<'a, 'tcx>,
+                                     range: &IntRange<'tcx>,
+                                     seen_ranges: &[(IntRange<'tcx>, Span)],
+                                     id: ast::NodeId,
+                                     span: Span) {
+    let overlaps: Vec<_> = seen_ranges.iter().filter_map(|&(ref seen, seen_span)| {
+        if range.is_subrange(seen) || seen.is_subrange(range) {
+            None
+        } else {
+            seen.intersection(range).map(|overlap| (overlap, seen_span))
+        }
+    }).collect();
+    if overlaps.is_empty() {
+        return;
+    }
+
+    let mut err = cx.tcx.struct_span_lint_node(
+        lint::builtin::OVERLAPPING_PATTERNS,
+        id,
+        span,
+        "multiple patterns covering the same range",
+    );
+    err.span_label(span, "overlapping patterns");
+    for (overlap, seen_span) in overlaps {
+        err.span_label(seen_span, format!("this range overlaps on `{}`",
+                                          overlap.into_pattern(cx.tcx)));
+    }
+    err.emit();
+}
+
 fn check_exhaustive<'a, 'tcx>(cx: &mut MatchCheckCtxt<'a, 'tcx>,
                               scrut_ty: Ty<'tcx>,
                               sp: Span,
@@ -120,12 +120,19 @@ pub enum PatternKind<'tcx> {
         slice: Option<Pattern<'tcx>>,
         suffix: Vec<Pattern<'tcx>>,
     },
+
+    /// A | B | C, nested inside another pattern
+    Or {
+        pats: Vec<Pattern<'tcx>>,
+    },
 }
 
 fn print_const_val(value: &ty::Const, f: &mut fmt::Formatter) -> fmt::Result {
     match value.val {
         ConstVal::Value(v) => print_miri_value(v, value.ty, f),
-        ConstVal::Unevaluated(..) => bug!("{:?} not printable in a pattern", value)
+        ConstVal::Unevaluated(..) |
+        ConstVal::Param(_) |
+        ConstVal::Infer(_) => bug!("{:?} not printable in a pattern", value)
     }
 }
 
@@ -254,7 +261,7 @@ impl<'tcx> fmt::Display for Pattern<'tcx> {
             PatternKind::Range { lo, hi, end } => {
                 print_const_val(lo, f)?;
                 match end {
-                    RangeEnd::Included => write!(f, "...")?,
+                    RangeEnd::Included => write!(f, "..=")?,
                     RangeEnd::Excluded => write!(f, "..")?,
                 }
                 print_const_val(hi, f)
@@ -280,6 +287,17 @@ impl<'tcx> fmt::Display for Pattern<'tcx> {
                 }
                 write!(f, "]")
             }
+            PatternKind::Or { ref pats } => {
+                let mut first = true;
+                for p in pats {
+                    if !first {
+                        write!(f, " | ")?;
+                    }
+                    first = false;
+                    write!(f, "{}", p)?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -446,6 +464,10 @@ impl<'a, 'tcx> PatternContext<'a, 'tcx> {
                 }
             }
 
+            PatKind::Or(ref pats) => {
+                PatternKind::Or { pats: self.lower_patterns(pats) }
+            }
+
             PatKind::Tuple(ref subpatterns, ddpos) => {
                 let ty = self.tables.node_id_to_type(pat.hir_id);
                 match ty.sty {
@@ -849,7 +871,9 @@ impl<'a, 'tcx> PatternContext<'a, 'tcx> {
                             subpatterns,
                         }
                     },
-                    ConstVal::Unevaluated(..) =>
+                    ConstVal::Unevaluated(..) |
+                    ConstVal::Param(_) |
+                    ConstVal::Infer(_) =>
                         span_bug!(span, "{:#?} is not a valid enum constant", cv),
                 }
             },
@@ -1043,6 +1067,11 @@ impl<'tcx> PatternFoldable<'tcx> for PatternKind<'tcx> {
                 slice: slice.fold_with(folder),
                 suffix: suffix.fold_with(folder)
             },
+            PatternKind::Or {
+                ref pats,
+            } => PatternKind::Or {
+                pats: pats.fold_with(folder),
+            },
         }
     }
 }
@@ -42,10 +42,14 @@ pub struct EvalContext<'a, 'mir, 'tcx: 'a + 'mir, M: Machine<'mir, 'tcx>> {
     /// The maximum number of stack frames allowed
     pub(crate) stack_limit: usize,
 
+    /// The number of terminators that may be evaluated before the
+    /// `long_running_const_eval` lint reports that evaluation is taking a long time.
+    pub(crate) terminators_remaining: usize,
+
     /// The maximum number of terminators that may be evaluated.
     /// This prevents infinite loops and huge computations from freezing up const eval.
     /// Remove once halting problem is solved.
-    pub(crate) terminators_remaining: usize,
+    pub(crate) steps_remaining: usize,
 }
 
 /// A stack frame.
@@ -120,7 +124,9 @@ impl<'tcx> ValTy<'tcx> {
     pub fn from(val: &ty::Const<'tcx>) -> Option<Self> {
         match val.val {
             ConstVal::Value(value) => Some(ValTy { value, ty: val.ty }),
-            ConstVal::Unevaluated { .. } => None,
+            ConstVal::Unevaluated { .. } |
+            ConstVal::Param(_) |
+            ConstVal::Infer(_) => None,
         }
     }
 }
@@ -195,7 +201,8 @@ impl<'a, 'mir, 'tcx: 'mir, M: Machine<'mir, 'tcx>> EvalContext<'a, 'mir, 'tcx, M
             memory: Memory::new(tcx, memory_data),
             stack: Vec::new(),
             stack_limit: tcx.sess.const_eval_stack_frame_limit,
-            terminators_remaining: 1_000_000,
+            terminators_remaining: step_limit(*tcx.sess.const_eval_warn_limit.get()),
+            steps_remaining: step_limit(*tcx.sess.const_eval_limit.get()),
         }
     }
 
@@ -243,6 +250,18 @@ impl<'a, 'mir, 'tcx: 'mir, M: Machine<'mir, 'tcx>> EvalContext<'a, 'mir, 'tcx, M
                 }, ty)
             }
             ConstVal::Value(val) => Ok(val),
+            ConstVal::Param(_) => {
+                let substituted = self.tcx.subst_and_normalize_erasing_regions(
+                    self.substs(),
+                    self.param_env,
+                    &self.tcx.mk_const(ty::Const { val: *const_val, ty }),
+                );
+                match substituted.val {
+                    ConstVal::Param(_) => err!(TooGeneric),
+                    ref val => self.const_to_value(val, ty),
+                }
+            }
+            ConstVal::Infer(_) => bug!("const_to_value: unexpected inference const"),
         }
     }
 
@@ -538,7 +557,7 @@ impl<'a, 'mir, 'tcx: 'mir, M: Machine<'mir, 'tcx>> EvalContext<'a, 'mir, 'tcx, M
             }
 
             Aggregate(ref kind, ref operands) => {
-                self.inc_step_counter_and_check_limit(operands.len());
+                self.inc_step_counter_and_check_limit(operands.len())?;
 
                 let (dest, active_field_index) = match **kind {
                     mir::AggregateKind::Adt(adt_def, variant_index, _, active_field_index) => {
@@ -1749,3 +1768,13 @@ impl<'mir, 'tcx> Frame<'mir, 'tcx> {
         self.locals[local].take()
     }
 }
+
+/// Turns a configured step limit into a number of remaining steps,
+/// where a limit of zero means there is no limit.
+fn step_limit(limit: usize) -> usize {
+    if limit == 0 {
+        usize::max_value()
+    } else {
+        limit
+    }
+}
@@ -2,19 +2,76 @@
 //!
 //! The main entry point is the `step` method.
 
-use rustc::mir;
+use rustc::lint::builtin::LONG_RUNNING_CONST_EVAL;
+use rustc::middle::const_val::FrameInfo;
+use rustc::mir::{self, ClearCrossCrate};
 
 use rustc::mir::interpret::EvalResult;
 use super::{EvalContext, Machine};
 
 impl<'a, 'mir, 'tcx, M: Machine<'mir, 'tcx>> EvalContext<'a, 'mir, 'tcx, M> {
-    pub fn inc_step_counter_and_check_limit(&mut self, n: usize) {
-        self.terminators_remaining = self.terminators_remaining.saturating_sub(n);
-        if self.terminators_remaining == 0 {
-            // FIXME(#49980): make this warning a lint
-            self.tcx.sess.span_warn(self.frame().span, "Constant evaluating a complex constant, this might take some time");
-            self.terminators_remaining = 1_000_000;
+    pub fn inc_step_counter_and_check_limit(&mut self, n: usize) -> EvalResult<'tcx> {
+        self.steps_remaining = self.steps_remaining.saturating_sub(n);
+        if self.steps_remaining == 0 {
+            return err!(StepLimitReached);
         }
+
+        if self.terminators_remaining != 0 {
+            self.terminators_remaining = self.terminators_remaining.saturating_sub(n);
+            if self.terminators_remaining == 0 {
+                self.lint_long_running();
+            }
+        }
+        Ok(())
+    }
+
+    /// Reports the `long_running_const_eval` lint, along with the
+    /// current const eval call stack.
+    fn lint_long_running(&self) {
+        // Report the lint on the outermost frame which carries lint levels,
+        // so that it can be allowed on the constant being evaluated.
+        let lint_root = self.stack().iter().filter_map(|frame| {
+            let scope_info = match frame.mir.visibility_scope_info {
+                ClearCrossCrate::Set(ref scope_info) => scope_info,
+                ClearCrossCrate::Clear => return None,
+            };
+            let block = &frame.mir.basic_blocks()[frame.block];
+            let source_info = if frame.stmt < block.statements.len() {
+                block.statements[frame.stmt].source_info
+            } else {
+                block.terminator().source_info
+            };
+            Some(scope_info[source_info.scope].lint_root)
+        }).next();
+        let lint_root = match lint_root {
+            Some(lint_root) => lint_root,
+            None => return,
+        };
+
+        // Point at the constant being evaluated, then at the calls it is in.
+        let span = self.tcx.def_span(self.stack()[0].instance.def_id());
+        let (frames, _) = self.generate_stacktrace(None);
+        let mut err = self.tcx.struct_span_lint_node(
+            LONG_RUNNING_CONST_EVAL,
+            lint_root,
+            span,
+            &format!("constant evaluation took more than {} steps",
+                     self.tcx.sess.const_eval_warn_limit.get()),
+        );
+        for FrameInfo { span, location } in frames {
+            err.span_note(span, &format!("inside call to `{}`", location));
+        }
+        // A limit of 0 means evaluation is never aborted.
+        let limit = self.tcx.sess.const_eval_limit.get();
+        if limit != 0 {
+            err.note(&format!("evaluation is aborted after {} steps, the limits can be \
+                               customized with the `const_eval_warn_limit` and \
+                               `const_eval_limit` attributes",
+                              limit));
+        } else {
+            err.note("the limit can be customized with the `const_eval_warn_limit` attribute");
+        }
+        err.emit();
     }
 
     /// Returns true as long as there are more things to do.
@@ -36,7 +93,7 @@ impl<'a, 'mir, 'tcx, M: Machine<'mir, 'tcx>> EvalContext<'a, 'mir, 'tcx, M> {
             return Ok(true);
         }
 
-        self.inc_step_counter_and_check_limit(1);
+        self.inc_step_counter_and_check_limit(1)?;
 
         let terminator = basic_block.terminator();
         assert_eq!(old_frames, self.cur_frame());
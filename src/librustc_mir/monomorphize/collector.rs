@@ -200,7 +200,8 @@ use rustc::ty::subst::{Substs, Kind};
 use rustc::ty::{self, TypeFoldable, Ty, TyCtxt};
 use rustc::ty::adjustment::CustomCoerceUnsized;
 use rustc::session::config;
-use rustc::mir::{self, Location, Promoted};
+use rustc::lint::builtin::LARGE_ASSIGNMENTS;
+use rustc::mir::{self, ClearCrossCrate, Location, Promoted};
 use rustc::mir::visit::Visitor as MirVisitor;
 use rustc::mir::mono::MonoItem;
 use rustc::mir::interpret::GlobalId;
@@ -212,6 +213,9 @@ use monomorphize::item::{MonoItemExt, DefPathBasedNames, InstantiationMode};
 
 use rustc_data_structures::bitvec::BitVector;
 
+use syntax::attr;
+use syntax_pos::Span;
+
 #[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
 pub enum MonoItemCollectionMode {
     Eager,
@@ -513,12 +517,67 @@ struct MirNeighborCollector<'a, 'tcx: 'a> {
     param_substs: &'tcx Substs<'tcx>,
 }
 
+impl<'a, 'tcx> MirNeighborCollector<'a, 'tcx> {
+    /// Reports `operand` through the `large_assignments` lint if moving or
+    /// copying it exceeds the move size limit.
+    fn check_move_size(&self, operand: &mir::Operand<'tcx>, location: Location) {
+        let limit = *self.tcx.sess.move_size_limit.get();
+        if limit == 0 {
+            return;
+        }
+        let (place, verb) = match *operand {
+            mir::Operand::Copy(ref place) => (place, "copied"),
+            mir::Operand::Move(ref place) => (place, "moved"),
+            mir::Operand::Constant(_) => return,
+        };
+        let ty = place.ty(self.mir, self.tcx).to_ty(self.tcx);
+        let ty = self.tcx.subst_and_normalize_erasing_regions(
+            self.param_substs,
+            ty::ParamEnv::reveal_all(),
+            &ty,
+        );
+        let layout = match self.tcx.layout_of(ty::ParamEnv::reveal_all().and(ty)) {
+            Ok(layout) => layout,
+            Err(_) => return,
+        };
+        let size = layout.size.bytes();
+        if size <= limit as u64 {
+            return;
+        }
+        // MIR from other crates does not carry lint levels, so we can only
+        // report large assignments in the local crate.
+        let source_info = self.mir.source_info(location);
+        let lint_root = match self.mir.visibility_scope_info {
+            ClearCrossCrate::Set(ref scope_info) => scope_info[source_info.scope].lint_root,
+            ClearCrossCrate::Clear => return,
+        };
+        let mut diag = self.tcx.struct_span_lint_node(LARGE_ASSIGNMENTS,
+                                                      lint_root,
+                                                      source_info.span,
+                                                      &format!("moving {} bytes", size));
+        diag.span_label(source_info.span, format!("value of type `{}` {} from here", ty, verb));
+        if attr::contains_name(self.tcx.hir.krate_attrs(), "move_size_limit") {
+            diag.note(&format!("the current maximum size is {}, but it can be customized with \
+                                the `move_size_limit` attribute: `#![move_size_limit = \"...\"]`",
+                               limit));
+        } else {
+            diag.note(&format!("the current maximum size is {}, as set by \
+                                `-Z move-size-limit`",
+                               limit));
+        }
+        diag.emit();
+    }
+}
+
 impl<'a, 'tcx> MirVisitor<'tcx> for MirNeighborCollector<'a, 'tcx> {
 
     fn visit_rvalue(&mut self, rvalue: &mir::Rvalue<'tcx>, location: Location) {
         debug!("visiting rvalue {:?}", *rvalue);
 
         match *rvalue {
+            mir::Rvalue::Use(ref operand) => {
+                self.check_move_size(operand, location);
+            }
             // When doing an cast from a regular pointer to a fat pointer, we
             // have to instantiate all methods of the trait being cast to, so we
             // can build the appropriate vtable.
@@ -607,7 +666,7 @@ impl<'a, 'tcx> MirVisitor<'tcx> for MirNeighborCollector<'a, 'tcx> {
 
         let tcx = self.tcx;
         match *kind {
-            mir::TerminatorKind::Call { ref func, .. } => {
+            mir::TerminatorKind::Call { ref func, ref args, .. } => {
                 let callee_ty = func.ty(self.mir, tcx);
                 let callee_ty = tcx.subst_and_normalize_erasing_regions(
                     self.param_substs,
@@ -615,6 +674,16 @@ impl<'a, 'tcx> MirVisitor<'tcx> for MirNeighborCollector<'a, 'tcx> {
                     &callee_ty,
                 );
                 visit_fn_use(self.tcx, callee_ty, true, &mut self.output);
+
+                // Arguments are usually moved out of temporaries, whose
+                // assignment has already been checked.
+                for arg in args {
+                    match *arg {
+                        mir::Operand::Move(mir::Place::Local(local))
+                            if self.mir.local_kind(local) == mir::LocalKind::Temp => {}
+                        _ => self.check_move_size(arg, location),
+                    }
+                }
             }
             mir::TerminatorKind::Drop { ref location, .. } |
             mir::TerminatorKind::DropAndReplace { ref location, .. } => {
@@ -674,10 +743,12 @@ fn visit_fn_use<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
                           output: &mut Vec<MonoItem<'tcx>>)
 {
     if let ty::TyFnDef(def_id, substs) = ty.sty {
-        let instance = ty::Instance::resolve(tcx,
-                                             ty::ParamEnv::reveal_all(),
-                                             def_id,
-                                             substs).unwrap();
+        let param_env = ty::ParamEnv::reveal_all();
+        let instance = if is_direct_call {
+            ty::Instance::resolve(tcx, param_env, def_id, substs)
+        } else {
+            ty::Instance::resolve_for_fn_ptr(tcx, param_env, def_id, substs)
+        }.unwrap();
         visit_instance_use(tcx, instance, is_direct_call, output);
     }
 }
@@ -710,6 +781,7 @@ fn visit_instance_use<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
         }
         ty::InstanceDef::ClosureOnceShim { .. } |
         ty::InstanceDef::Item(..) |
+        ty::InstanceDef::ReifyShim(..) |
         ty::InstanceDef::FnPtrShim(..) |
         ty::InstanceDef::CloneShim(..) => {
             output.push(create_fn_mono_item(instance));
@@ -717,6 +789,74 @@ fn visit_instance_use<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
     }
 }
 
+/// The calls made by a monomorphized function.
+pub struct CallSites<'tcx> {
+    /// Functions called directly, including drop glue.
+    pub direct: Vec<Instance<'tcx>>,
+    /// Calls through function pointers and trait objects, whose callee is
+    /// not known statically.
+    pub indirect: Vec<Span>,
+}
+
+/// Collects the calls made by `instance`, resolving callees the same way
+/// the mono item graph does. Unlike the graph, this only follows calls and
+/// drops, and keeps the calls whose callee is unknown.
+pub fn call_sites<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
+                            instance: Instance<'tcx>)
+                            -> CallSites<'tcx> {
+    let mir = tcx.instance_mir(instance.def);
+    let param_env = ty::ParamEnv::reveal_all();
+    let mut sites = CallSites {
+        direct: vec![],
+        indirect: vec![],
+    };
+
+    for data in mir.basic_blocks() {
+        let terminator = data.terminator();
+        match terminator.kind {
+            mir::TerminatorKind::Call { ref func, .. } => {
+                let callee_ty = tcx.subst_and_normalize_erasing_regions(
+                    instance.substs,
+                    param_env,
+                    &func.ty(mir, tcx),
+                );
+                let callee = match callee_ty.sty {
+                    ty::TyFnDef(def_id, substs) => {
+                        ty::Instance::resolve(tcx, param_env, def_id, substs).unwrap()
+                    }
+                    _ => {
+                        sites.indirect.push(terminator.source_info.span);
+                        continue;
+                    }
+                };
+                match callee.def {
+                    ty::InstanceDef::Intrinsic(_) => {}
+                    ty::InstanceDef::Virtual(..) => {
+                        sites.indirect.push(terminator.source_info.span);
+                    }
+                    _ => sites.direct.push(callee),
+                }
+            }
+            mir::TerminatorKind::Drop { ref location, .. } |
+            mir::TerminatorKind::DropAndReplace { ref location, .. } => {
+                let ty = tcx.subst_and_normalize_erasing_regions(
+                    instance.substs,
+                    param_env,
+                    &location.ty(mir, tcx).to_ty(tcx),
+                );
+                let callee = monomorphize::resolve_drop_in_place(tcx, ty);
+                match callee.def {
+                    ty::InstanceDef::DropGlue(_, None) => {}
+                    _ => sites.direct.push(callee),
+                }
+            }
+            _ => {}
+        }
+    }
+
+    sites
+}
+
 // Returns true if we should translate an instance in the local crate.
 // Returns false if we can just link to the upstream crate and therefore don't
 // need a mono item.
@@ -726,6 +866,7 @@ fn should_monomorphize_locally<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>, instance:
         ty::InstanceDef::Item(def_id) => def_id,
         ty::InstanceDef::ClosureOnceShim { .. } |
         ty::InstanceDef::Virtual(..) |
+        ty::InstanceDef::ReifyShim(..) |
         ty::InstanceDef::FnPtrShim(..) |
         ty::InstanceDef::DropGlue(..) |
         ty::InstanceDef::Intrinsic(_) |
@@ -907,7 +1048,7 @@ fn create_mono_items_for_vtable_methods<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
             // Walk all methods of the trait, including those of its supertraits
             let methods = tcx.vtable_methods(poly_trait_ref);
             let methods = methods.iter().cloned().filter_map(|method| method)
-                .map(|(def_id, substs)| ty::Instance::resolve(
+                .map(|(def_id, substs)| ty::Instance::resolve_for_fn_ptr(
                         tcx,
                         ty::ParamEnv::reveal_all(),
                         def_id,
@@ -1075,8 +1216,7 @@ impl<'b, 'a, 'v> RootCollector<'b, 'a, 'v> {
 }
 
 fn item_has_type_parameters<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>, def_id: DefId) -> bool {
-    let generics = tcx.generics_of(def_id);
-    generics.parent_types as usize + generics.types.len() > 0
+    tcx.generics_of(def_id).requires_monomorphization()
 }
 
 fn create_mono_items_for_default_impls<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
@@ -1108,14 +1248,16 @@ fn create_mono_items_for_default_impls<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
                         continue;
                     }
 
-                    if !tcx.generics_of(method.def_id).types.is_empty() {
+                    let method_generics = tcx.generics_of(method.def_id);
+                    if !method_generics.types.is_empty() || !method_generics.consts.is_empty() {
                         continue;
                     }
 
                     let substs = Substs::for_item(tcx,
                                                   method.def_id,
                                                   |_, _| tcx.types.re_erased,
-                                                  |def, _| trait_ref.substs.type_for_def(def));
+                                                  |def, _| trait_ref.substs.type_for_def(def),
+                                                  |def, _| trait_ref.substs.const_for_def(def));
 
                     let instance = ty::Instance::resolve(tcx,
                                                          ty::ParamEnv::reveal_all(),
@@ -171,6 +171,7 @@ pub trait CodegenUnitExt<'tcx> {
                             tcx.hir.as_local_node_id(def_id)
                         }
                         InstanceDef::Intrinsic(..) |
+                        InstanceDef::ReifyShim(..) |
                         InstanceDef::FnPtrShim(..) |
                         InstanceDef::Virtual(..) |
                         InstanceDef::ClosureOnceShim { .. } |
@@ -430,6 +431,7 @@ fn place_root_translation_items<'a, 'tcx, I>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
                                 }
                             }
                             InstanceDef::FnPtrShim(..) |
+                            InstanceDef::ReifyShim(..) |
                             InstanceDef::Virtual(..) |
                             InstanceDef::Intrinsic(..) |
                             InstanceDef::ClosureOnceShim { .. } |
@@ -681,6 +683,7 @@ fn characteristic_def_id_of_trans_item<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
             let def_id = match instance.def {
                 ty::InstanceDef::Item(def_id) => def_id,
                 ty::InstanceDef::FnPtrShim(..) |
+                ty::InstanceDef::ReifyShim(..) |
                 ty::InstanceDef::ClosureOnceShim { .. } |
                 ty::InstanceDef::Intrinsic(..) |
                 ty::InstanceDef::DropGlue(..) |
@@ -81,6 +81,17 @@ fn make_shim<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
                 None
             )
         }
+        ty::InstanceDef::ReifyShim(def_id) => {
+            // A direct call to the `#[track_caller]` function, which passes
+            // the location of the shim itself as the caller location.
+            build_call_shim(
+                tcx,
+                def_id,
+                Adjustment::Identity,
+                CallKind::Direct(def_id),
+                None
+            )
+        }
         ty::InstanceDef::ClosureOnceShim { call_once } => {
             let fn_mut = tcx.lang_items().fn_mut_trait().unwrap();
             let call_mut = tcx.global_tcx()
@@ -433,7 +444,8 @@ impl<'a, 'tcx> CloneShimBuilder<'a, 'tcx> {
             tcx,
             self.def_id,
             |_, _| tcx.types.re_erased,
-            |_, _| ty
+            |_, _| ty,
+            |_, _| bug!("`Clone::clone` has no const parameters")
         );
 
         // `func == Clone::clone(&ty) -> ty`
@@ -145,11 +145,14 @@ impl<'b, 'a, 'tcx:'b> ConstPropagator<'b, 'a, 'tcx> {
                     };
                     self.const_eval(cid, c.span)
                 },
+                // FIXME: can't handle code with const generics
+                ConstVal::Param(_) => None,
+                ConstVal::Infer(_) => bug!("unexpected inference const in MIR: {:?}", value),
             },
             // evaluate the promoted and replace the constant with the evaluated result
             Literal::Promoted { index } => {
                 let generics = self.tcx.generics_of(self.source.def_id);
-                if generics.parent_types as usize + generics.types.len() > 0 {
+                if generics.requires_monomorphization() {
                     // FIXME: can't handle code with generics
                     return None;
                 }
@@ -253,7 +256,7 @@ impl<'b, 'a, 'tcx:'b> ConstPropagator<'b, 'a, 'tcx> {
                     self.source.def_id
                 };
                 let generics = self.tcx.generics_of(def_id);
-                if generics.parent_types as usize + generics.types.len() > 0 {
+                if generics.requires_monomorphization() {
                     // FIXME: can't handle code with generics
                     return None;
                 }
@@ -281,7 +284,7 @@ impl<'b, 'a, 'tcx:'b> ConstPropagator<'b, 'a, 'tcx> {
                     self.source.def_id
                 };
                 let generics = self.tcx.generics_of(def_id);
-                let has_generics = generics.parent_types as usize + generics.types.len() > 0;
+                let has_generics = generics.requires_monomorphization();
                 if has_generics {
                     // FIXME: can't handle code with generics
                     return None;
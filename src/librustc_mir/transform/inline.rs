@@ -86,6 +86,12 @@ impl<'a, 'tcx> Inliner<'a, 'tcx> {
         let id = self.tcx.hir.as_local_node_id(self.source.def_id).unwrap();
         let body_owner_kind = self.tcx.hir.body_owner_kind(id);
         if let (hir::BodyOwnerKind::Fn, None) = (body_owner_kind, self.source.promoted) {
+            // Calls and panics inlined into a `#[track_caller]` function would
+            // report the location of its caller instead of their own.
+            let caller_attrs = self.tcx.trans_fn_attrs(self.source.def_id);
+            if caller_attrs.flags.contains(TransFnAttrFlags::TRACK_CALLER) {
+                return;
+            }
 
             for (bb, bb_data) in caller_mir.basic_blocks().iter_enumerated() {
                 // Don't inline calls that are in cleanup blocks.
@@ -220,6 +226,13 @@ impl<'a, 'tcx> Inliner<'a, 'tcx> {
 
         let trans_fn_attrs = tcx.trans_fn_attrs(callsite.callee);
 
+        // The caller location of a `#[track_caller]` function is an
+        // implicit argument, which is only passed by trans.
+        if trans_fn_attrs.flags.contains(TransFnAttrFlags::TRACK_CALLER) {
+            debug!("    #[track_caller] present - not inlining");
+            return false;
+        }
+
         let hinted = match trans_fn_attrs.inline {
             // Just treat inline(always) as a hint for now,
             // there are cases that prevent inlining that we
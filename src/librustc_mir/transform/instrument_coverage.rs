@@ -0,0 +1,259 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! This pass assigns a coverage counter to every basic block of a function
+//! when `-Z instrument-coverage` is given, along with the source regions the
+//! counter stands for: the spans of the statements and calls of the block.
+//! Translation increments the counter on entry to the block and emits the
+//! regions as LLVM coverage mapping records, which is what `llvm-cov` uses to
+//! report region and line coverage from the profile written by the
+//! `profiler_builtins` runtime.
+//!
+//! Working on MIR rather than on debuginfo line tables means that closures
+//! are instrumented as the separate functions they are, and that code
+//! expanded from a macro is attributed to the macro invocation in the
+//! function being instrumented. The paths out of `expr?` are told apart by
+//! giving the error path the span of the `?` operator, and blocks without
+//! code of their own, like an empty match arm or the panic of an `assert!`,
+//! fall back to a span that stands for them.
+//!
+//! FIXME: Branch coverage needs the branch regions of a newer coverage
+//! mapping format than the one of the LLVM in this tree. Until then, how
+//! often each side of a condition was taken only shows in the counts of the
+//! code each side leads to.
+
+use rustc::hir;
+use rustc::mir::*;
+use rustc::ty::{self, TyCtxt};
+use rustc_data_structures::fx::{FxHashSet, FxHasher};
+use std::cmp;
+use std::hash::{Hash, Hasher};
+use syntax_pos::{BytePos, CompilerDesugaringKind, Span, SyntaxContext};
+use transform::{MirPass, MirSource};
+
+pub struct InstrumentCoverage;
+
+impl MirPass for InstrumentCoverage {
+    fn run_pass<'a, 'tcx>(&self,
+                          tcx: TyCtxt<'a, 'tcx, 'tcx>,
+                          source: MirSource,
+                          mir: &mut Mir<'tcx>) {
+        if !tcx.sess.opts.debugging_opts.instrument_coverage {
+            return;
+        }
+
+        // Promoted constants are evaluated at compile time, so there is
+        // nothing to count for them.
+        if source.promoted.is_some() {
+            return;
+        }
+
+        let node_id = tcx.hir.as_local_node_id(source.def_id).unwrap();
+        match tcx.hir.body_owner_kind(node_id) {
+            hir::BodyOwnerKind::Fn => {}
+            hir::BodyOwnerKind::Const | hir::BodyOwnerKind::Static(_) => return,
+        }
+
+        // Functions generated by a macro (e.g. by a `#[derive]`) have no
+        // source of their own to map counters to.
+        let body_span = mir.span;
+        if body_span.ctxt() != SyntaxContext::empty() || body_span.lo() == body_span.hi() {
+            return;
+        }
+
+        mir.coverage_info = Some(coverage_info(tcx, mir, body_span));
+    }
+}
+
+fn coverage_info<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
+                           mir: &Mir<'tcx>,
+                           body_span: Span)
+                           -> CoverageInfo {
+    let mut counters = vec![];
+    let mut seen_regions = FxHashSet();
+    let mut uncovered_blocks = vec![];
+
+    for (bb, data) in mir.basic_blocks().iter_enumerated() {
+        if data.is_cleanup {
+            continue;
+        }
+
+        let raw_spans: Vec<_> = data.statements.iter()
+            .filter(|statement| is_counted(statement))
+            .map(|statement| statement.source_info.span)
+            .chain(data.terminator.as_ref().and_then(|t| terminator_span(tcx, t)))
+            .collect();
+        let expansion_ends: Vec<_> = raw_spans.iter()
+            .filter_map(|&span| expansion_end_in_body(span, body_span))
+            .collect();
+        let mut spans: Vec<_> = raw_spans.into_iter()
+            .filter_map(|span| span_in_body(span, body_span))
+            .collect();
+        spans.sort_by_key(|span| (span.lo(), cmp::Reverse(span.hi())));
+
+        // Spans come from the expression tree, so two of them are either
+        // disjoint or nested. A span nested in another span of the same
+        // block adds nothing, while a span that is already the region of an
+        // earlier block would give two counts for the same code.
+        let mut regions: Vec<Span> = vec![];
+        for span in spans {
+            if regions.last().map_or(false, |region| region.contains(span)) {
+                continue;
+            }
+            if seen_regions.insert(span) {
+                regions.push(span);
+            }
+        }
+
+        if !regions.is_empty() {
+            counters.push(CoverageCounter { block: bb, regions });
+        } else {
+            uncovered_blocks.push((bb, unit_spans(data, body_span), expansion_ends));
+        }
+    }
+
+    // A block left without a region either does no work of its own, like
+    // an empty match arm, or runs code of a macro invocation or desugaring
+    // (e.g. the panic of an `assert!`) that an earlier block already maps
+    // to. The former gets the span of the `()` or `{}` it evaluates, unless
+    // that span takes in the code of other blocks, as it does for the `()`
+    // of an `if` without `else`. The latter gets the last character of the
+    // invocation, so that each path through it shows up: one more path at
+    // most, since that character then is a region too.
+    for (bb, unit_spans, expansion_ends) in uncovered_blocks {
+        let unit_span = unit_spans.into_iter().find(|&span| {
+            !seen_regions.iter().any(|&region| overlaps(span, region))
+        });
+        let region = unit_span.or_else(|| {
+            expansion_ends.into_iter().find(|span| !seen_regions.contains(span))
+        });
+        if let Some(region) = region {
+            seen_regions.insert(region);
+            counters.push(CoverageCounter { block: bb, regions: vec![region] });
+        }
+    }
+    counters.sort_by_key(|counter| counter.block);
+
+    let mut hasher = FxHasher::default();
+    counters.len().hash(&mut hasher);
+    for counter in &counters {
+        counter.block.hash(&mut hasher);
+        counter.regions.len().hash(&mut hasher);
+    }
+
+    CoverageInfo {
+        hash: hasher.finish(),
+        counters,
+    }
+}
+
+/// Whether the span of `statement` is that of the code it runs. Assigning
+/// `()` does no work, and is given the span of the whole `if` or loop it
+/// completes, while the discriminant a `match` tests is read with the span
+/// of the pattern in the first arm.
+fn is_counted(statement: &Statement) -> bool {
+    match statement.kind {
+        StatementKind::Assign(_, Rvalue::Aggregate(box AggregateKind::Tuple, ref operands)) =>
+            !operands.is_empty(),
+        StatementKind::Assign(_, Rvalue::Discriminant(_)) |
+        StatementKind::StorageLive(_) |
+        StatementKind::StorageDead(_) |
+        StatementKind::EndRegion(_) |
+        StatementKind::Validate(..) |
+        StatementKind::Nop => false,
+        _ => true,
+    }
+}
+
+/// The spans of the `()` assigned by the statements of `data`, which
+/// `is_counted` leaves out.
+fn unit_spans(data: &BasicBlockData, body_span: Span) -> Vec<Span> {
+    data.statements.iter()
+        .filter(|statement| match statement.kind {
+            StatementKind::Assign(_, Rvalue::Aggregate(box AggregateKind::Tuple, ref operands)) =>
+                operands.is_empty(),
+            _ => false,
+        })
+        .filter_map(|statement| span_in_body(statement.source_info.span, body_span))
+        .collect()
+}
+
+/// The span of the code run by `terminator`, if any. A `SwitchInt` or `Drop`
+/// carries the span of the whole `match`, `if` or scope it belongs to, most
+/// of which is accounted for by other blocks.
+fn terminator_span<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
+                             terminator: &Terminator<'tcx>)
+                             -> Option<Span> {
+    let span = terminator.source_info.span;
+    match terminator.kind {
+        // Both paths out of `expr?` have the span of the whole expression.
+        // The error path is the one calling `Try::from_error`, and is given
+        // the span of the `?` operator.
+        TerminatorKind::Call { func: Operand::Constant(ref func), .. }
+                if is_question_mark_from_error(tcx, func) && span.lo() < span.hi() => {
+            Some(span.with_lo(span.hi() - BytePos(1)))
+        }
+        TerminatorKind::DropAndReplace { .. } |
+        TerminatorKind::Call { .. } |
+        TerminatorKind::Assert { .. } |
+        TerminatorKind::Yield { .. } => Some(terminator.source_info.span),
+        TerminatorKind::Goto { .. } |
+        TerminatorKind::SwitchInt { .. } |
+        TerminatorKind::Resume |
+        TerminatorKind::Abort |
+        TerminatorKind::Return |
+        TerminatorKind::Unreachable |
+        TerminatorKind::Drop { .. } |
+        TerminatorKind::GeneratorDrop |
+        TerminatorKind::FalseEdges { .. } |
+        TerminatorKind::FalseUnwind { .. } => None,
+    }
+}
+
+fn is_question_mark_from_error<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
+                                         func: &Constant<'tcx>)
+                                         -> bool {
+    if !func.span.is_compiler_desugaring(CompilerDesugaringKind::QuestionMark) {
+        return false;
+    }
+    match func.ty.sty {
+        ty::TyFnDef(def_id, _) => tcx.item_name(def_id) == "from_error",
+        _ => false,
+    }
+}
+
+fn overlaps(a: Span, b: Span) -> bool {
+    a.lo() < b.hi() && b.lo() < a.hi()
+}
+
+/// The last character of the macro invocation or desugared expression in
+/// the function body that `span` was expanded from, if it was.
+fn expansion_end_in_body(span: Span, body_span: Span) -> Option<Span> {
+    if span.ctxt() == body_span.ctxt() {
+        return None;
+    }
+    let span = span_in_body(span, body_span)?;
+    Some(span.with_lo(span.hi() - BytePos(1)))
+}
+
+/// Maps `span` to a non-empty span within the function body, walking out of
+/// macro expansions and desugarings until it reaches the invocation in the
+/// body. Spans that belong to code inlined from elsewhere are not part of the
+/// body at all.
+fn span_in_body(mut span: Span, body_span: Span) -> Option<Span> {
+    while span.ctxt() != body_span.ctxt() {
+        span = span.ctxt().outer().expn_info()?.call_site;
+    }
+    if body_span.contains(span) && span.lo() < span.hi() {
+        Some(span)
+    } else {
+        None
+    }
+}
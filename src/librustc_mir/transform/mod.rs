@@ -44,6 +44,7 @@ pub mod copy_prop;
 pub mod const_prop;
 pub mod generator;
 pub mod inline;
+pub mod instrument_coverage;
 pub mod lower_128bit;
 pub mod uniform_array_move_out;
 
@@ -277,6 +278,8 @@ fn optimized_mir<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>, def_id: DefId) -> &'tcx
         simplify::SimplifyLocals,
 
         add_call_guards::CriticalCallEdges,
+        // Coverage counters are assigned to the final set of basic blocks.
+        instrument_coverage::InstrumentCoverage,
         dump_mir::Marker("PreTrans"),
     ];
     tcx.alloc_mir(mir)
@@ -124,6 +124,9 @@ struct Qualifier<'a, 'gcx: 'a+'tcx, 'tcx: 'a> {
     return_qualif: Option<Qualif>,
     qualif: Qualif,
     const_fn_arg_vars: BitVector,
+    /// Whether branches, loops and local variables are allowed,
+    /// i.e. `#![feature(const_control_flow)]` in a constant context.
+    control_flow: bool,
     temp_promotion_state: IndexVec<Local, TempState>,
     promotion_candidates: Vec<Candidate>
 }
@@ -159,6 +162,7 @@ impl<'a, 'tcx> Qualifier<'a, 'tcx, 'tcx> {
             return_qualif: None,
             qualif: Qualif::empty(),
             const_fn_arg_vars: BitVector::new(mir.local_decls.len()),
+            control_flow: mode != Mode::Fn && tcx.features().const_control_flow,
             temp_promotion_state: temps,
             promotion_candidates: vec![]
         }
@@ -168,6 +172,16 @@ impl<'a, 'tcx> Qualifier<'a, 'tcx, 'tcx> {
     // categories, but enabling full miri would make that
     // slightly pointless (even with feature-gating).
     fn not_const(&mut self) {
+        self.report_not_const(false);
+    }
+
+    /// Error about a branch or loop in a constant, which is allowed
+    /// with `#![feature(const_control_flow)]`.
+    fn control_flow_not_const(&mut self) {
+        self.report_not_const(true);
+    }
+
+    fn report_not_const(&mut self, is_control_flow: bool) {
         self.add(Qualif::NOT_CONST);
         if self.mode != Mode::Fn {
             let mut err = struct_span_err!(
@@ -183,6 +197,11 @@ impl<'a, 'tcx> Qualifier<'a, 'tcx, 'tcx> {
                 err.note("Remember: you can't use a function call inside a const's initialization \
                           expression! However, you can use it anywhere else.");
             }
+            if is_control_flow {
+                help!(&mut err,
+                      "in Nightly builds, add `#![feature(const_control_flow)]` \
+                       to the crate attributes to enable");
+            }
             err.emit();
         }
     }
@@ -268,11 +287,15 @@ impl<'a, 'tcx> Qualifier<'a, 'tcx, 'tcx> {
     fn assign(&mut self, dest: &Place<'tcx>, location: Location) {
         let qualif = self.qualif;
         let span = self.span;
+        let control_flow = self.control_flow;
         let store = |slot: &mut Option<Qualif>| {
-            if slot.is_some() {
-                span_bug!(span, "multiple assignments to {:?}", dest);
+            match *slot {
+                // With branches and loops, a local can be assigned on several
+                // paths, so it has the qualifications of all of them.
+                Some(old) if control_flow => *slot = Some(old | qualif),
+                Some(_) => span_bug!(span, "multiple assignments to {:?}", dest),
+                None => *slot = Some(qualif),
             }
-            *slot = Some(qualif);
         };
 
         // Only handle promotable temps in non-const functions.
@@ -296,6 +319,16 @@ impl<'a, 'tcx> Qualifier<'a, 'tcx, 'tcx> {
                 debug!("store to return place {:?}", index);
                 store(&mut self.return_qualif)
             }
+            Place::Local(index) if self.control_flow
+                && self.mir.local_kind(index) == LocalKind::Var => {
+                debug!("store to var {:?}", index);
+                store(&mut self.temp_qualif[index])
+            }
+
+            // Assignment to a field or element of a variable, e.g. `a[i] = x`.
+            Place::Projection(_) if self.control_flow && self.is_var_place(dest) => {
+                self.visit_place(dest, PlaceContext::Store, location);
+            }
 
             Place::Projection(box Projection {
                 base: Place::Local(index),
@@ -318,10 +351,72 @@ impl<'a, 'tcx> Qualifier<'a, 'tcx, 'tcx> {
         }
     }
 
-    /// Qualify a whole const, static initializer or const fn.
-    fn qualify_const(&mut self) -> (Qualif, Lrc<IdxSetBuf<Local>>) {
-        debug!("qualifying {} {:?}", self.mode, self.def_id);
+    /// Check if the given place is a local variable, or a field or element
+    /// of one, without going through any dereference.
+    fn is_var_place(&self, place: &Place<'tcx>) -> bool {
+        match *place {
+            Place::Local(index) => self.mir.local_kind(index) == LocalKind::Var,
+            Place::Projection(ref proj) => match proj.elem {
+                ProjectionElem::Deref => false,
+                _ => self.is_var_place(&proj.base),
+            },
+            Place::Static(_) => false,
+        }
+    }
+
+    /// Check for unused values. This usually means
+    /// there are extra statements in the AST.
+    fn check_unused_temps(&mut self) {
+        let mir = self.mir;
+        for temp in mir.temps_iter() {
+            if self.temp_qualif[temp].is_none() {
+                continue;
+            }
+
+            let state = self.temp_promotion_state[temp];
+            if let TempState::Defined { location, uses: 0 } = state {
+                let data = &mir[location.block];
+                let stmt_idx = location.statement_index;
+
+                // Get the span for the initialization.
+                let source_info = if stmt_idx < data.statements.len() {
+                    data.statements[stmt_idx].source_info
+                } else {
+                    data.terminator().source_info
+                };
+                self.span = source_info.span;
+
+                // Treat this as a statement in the AST.
+                self.statement_like();
+            }
+        }
+    }
+
+    /// Qualify all the blocks reachable on the non-unwinding paths, for
+    /// constants which can contain branches and loops. Blocks are visited in
+    /// reverse postorder, so every local is assigned before it is used,
+    /// except for assignments coming from a later iteration of a loop.
+    fn qualify_control_flow(&mut self) {
+        let mir = self.mir;
+        for (bb, data) in traversal::reverse_postorder(mir) {
+            if data.is_cleanup {
+                continue;
+            }
+
+            self.visit_basic_block_data(bb, data);
+
+            if let TerminatorKind::DropAndReplace { .. } = data.terminator().kind {
+                // Assigning a new value would run the destructor of the old one.
+                self.not_const();
+            }
+        }
 
+        self.check_unused_temps();
+    }
+
+    /// Qualify the blocks along the single path through a constant
+    /// without branches or loops, erroring on anything else.
+    fn qualify_straight_line(&mut self) {
         let mir = self.mir;
 
         let mut seen_blocks = BitVector::new(mir.basic_blocks().len());
@@ -355,30 +450,7 @@ impl<'a, 'tcx> Qualifier<'a, 'tcx, 'tcx> {
                 TerminatorKind::FalseUnwind { .. } => None,
 
                 TerminatorKind::Return => {
-                    // Check for unused values. This usually means
-                    // there are extra statements in the AST.
-                    for temp in mir.temps_iter() {
-                        if self.temp_qualif[temp].is_none() {
-                            continue;
-                        }
-
-                        let state = self.temp_promotion_state[temp];
-                        if let TempState::Defined { location, uses: 0 } = state {
-                            let data = &mir[location.block];
-                            let stmt_idx = location.statement_index;
-
-                            // Get the span for the initialization.
-                            let source_info = if stmt_idx < data.statements.len() {
-                                data.statements[stmt_idx].source_info
-                            } else {
-                                data.terminator().source_info
-                            };
-                            self.span = source_info.span;
-
-                            // Treat this as a statement in the AST.
-                            self.statement_like();
-                        }
-                    }
+                    self.check_unused_temps();
 
                     // Make sure there are no extra unassigned variables.
                     self.qualif = Qualif::NOT_CONST;
@@ -401,12 +473,34 @@ impl<'a, 'tcx> Qualifier<'a, 'tcx, 'tcx> {
                 Some(target) if !seen_blocks.contains(target.index()) => {
                     bb = target;
                 }
-                _ => {
-                    self.not_const();
+                Some(_) => {
+                    self.control_flow_not_const();
+                    break;
+                }
+                None => {
+                    match mir[bb].terminator().kind {
+                        TerminatorKind::SwitchInt { .. } |
+                        TerminatorKind::FalseEdges { .. } |
+                        TerminatorKind::FalseUnwind { .. } => self.control_flow_not_const(),
+                        _ => self.not_const(),
+                    }
                     break;
                 }
             }
         }
+    }
+
+    /// Qualify a whole const, static initializer or const fn.
+    fn qualify_const(&mut self) -> (Qualif, Lrc<IdxSetBuf<Local>>) {
+        debug!("qualifying {} {:?}", self.mode, self.def_id);
+
+        let mir = self.mir;
+
+        if self.control_flow {
+            self.qualify_control_flow();
+        } else {
+            self.qualify_straight_line();
+        }
 
         self.qualif = self.return_qualif.unwrap_or(Qualif::NOT_CONST);
 
@@ -453,6 +547,16 @@ impl<'a, 'tcx> Visitor<'tcx> for Qualifier<'a, 'tcx, 'tcx> {
             LocalKind::ReturnPointer => {
                 self.not_const();
             }
+            LocalKind::Var if self.control_flow => {
+                // A variable can be assigned again later in a loop,
+                // so only its type tells what it may contain.
+                self.add(Qualif::NOT_PROMOTABLE);
+                if self.mode == Mode::ConstFn {
+                    self.add(Qualif::FN_ARGUMENT);
+                }
+                let ty = self.mir.local_decls[local].ty;
+                self.add_type(ty);
+            }
             LocalKind::Var => {
                 self.add(Qualif::NOT_CONST);
             }
@@ -566,6 +670,13 @@ impl<'a, 'tcx> Visitor<'tcx> for Qualifier<'a, 'tcx, 'tcx> {
                             this.qualif.restrict(ty, this.tcx, this.param_env);
                         }
 
+                        ProjectionElem::ConstantIndex {..} |
+                        ProjectionElem::Subslice {..} |
+                        ProjectionElem::Downcast(..) if this.control_flow => {
+                            let ty = place.ty(this.mir, this.tcx).to_ty(this.tcx);
+                            this.qualif.restrict(ty, this.tcx, this.param_env);
+                        }
+
                         ProjectionElem::ConstantIndex {..} |
                         ProjectionElem::Subslice {..} |
                         ProjectionElem::Downcast(..) => {
@@ -690,7 +801,7 @@ impl<'a, 'tcx> Visitor<'tcx> for Qualifier<'a, 'tcx, 'tcx> {
                             _ => false
                         }
                     } else if let ty::TyArray(_, len) = ty.sty {
-                        len.val.unwrap_u64() == 0 &&
+                        len.val.to_raw_bits() == Some(0) &&
                             self.mode == Mode::Fn
                     } else {
                         false
@@ -1039,9 +1150,11 @@ This does not pose a problem by itself because they can't be accessed directly."
                     location: Location) {
         self.visit_rvalue(rvalue, location);
 
-        // Check the allowed const fn argument forms.
+        // Check the allowed const fn argument forms. With control flow,
+        // variables are allowed, so any argument binding is too.
         if let (Mode::ConstFn, &Place::Local(index)) = (self.mode, dest) {
-            if self.mir.local_kind(index) == LocalKind::Var &&
+            if !self.control_flow &&
+               self.mir.local_kind(index) == LocalKind::Var &&
                self.const_fn_arg_vars.insert(index.index()) {
 
                 // Direct use of an argument is permitted.
@@ -146,6 +146,9 @@ fn dump_matched_mir_node<'a, 'gcx, 'tcx, F>(
         if let Some(ref layout) = mir.generator_layout {
             writeln!(file, "// generator_layout = {:?}", layout)?;
         }
+        if let Some(ref coverage) = mir.coverage_info {
+            writeln!(file, "// coverage_info = {:?}", coverage)?;
+        }
         writeln!(file, "")?;
         extra_data(PassWhere::BeforeCFG, &mut file)?;
         write_mir_fn(tcx, source, mir, &mut extra_data, &mut file)?;
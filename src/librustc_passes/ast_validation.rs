@@ -89,6 +89,15 @@ impl<'a> AstValidator<'a> {
         }
     }
 
+    fn check_trait_fn_not_async(&self, span: Span, asyncness: IsAsync) {
+        if asyncness.is_async() {
+            struct_span_err!(self.session, span, E0706,
+                             "trait fns cannot be declared `async`")
+                .span_label(span, "`async` trait fns are not supported")
+                .emit();
+        }
+    }
+
     fn check_trait_fn_not_const(&self, constness: Spanned<Constness>) {
         match constness.node {
             Constness::Const => {
@@ -146,6 +155,7 @@ impl<'a> AstValidator<'a> {
             .filter_map(|param| match *param {
                 GenericParam::Lifetime(_) => None,
                 GenericParam::Type(ref t) => Some(t.ident.span),
+                GenericParam::Const(ref c) => Some(c.ident.span),
             }).collect();
         if !non_lifetime_param_spans.is_empty() {
             self.err_handler().span_err(non_lifetime_param_spans,
@@ -162,7 +172,7 @@ impl<'a> AstValidator<'a> {
                             "lifetime bounds cannot be used in this context");
                     }
                 }
-                GenericParam::Type(_) => {}
+                GenericParam::Type(_) | GenericParam::Const(_) => {}
             }
         }
     }
@@ -260,6 +270,7 @@ impl<'a> Visitor<'a> for AstValidator<'a> {
                     self.invalid_visibility(&impl_item.vis, None);
                     if let ImplItemKind::Method(ref sig, _) = impl_item.node {
                         self.check_trait_fn_not_const(sig.constness);
+                        self.check_trait_fn_not_async(impl_item.span, sig.asyncness);
                     }
                 }
             }
@@ -312,6 +323,7 @@ impl<'a> Visitor<'a> for AstValidator<'a> {
                 for trait_item in trait_items {
                     if let TraitItemKind::Method(ref sig, ref block) = trait_item.node {
                         self.check_trait_fn_not_const(sig.constness);
+                        self.check_trait_fn_not_async(trait_item.span, sig.asyncness);
                         if block.is_none() {
                             self.check_decl_no_pat(&sig.decl, |span, mut_ident| {
                                 if mut_ident {
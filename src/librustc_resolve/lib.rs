@@ -52,10 +52,10 @@ use syntax::util::lev_distance::find_best_match_for_name;
 
 use syntax::visit::{self, FnKind, Visitor};
 use syntax::attr;
-use syntax::ast::{Arm, BindingMode, Block, Crate, Expr, ExprKind};
+use syntax::ast::{Arm, BindingMode, Block, ChainCond, Crate, Expr, ExprKind};
 use syntax::ast::{FnDecl, ForeignItem, ForeignItemKind, GenericParam, Generics};
-use syntax::ast::{Item, ItemKind, ImplItem, ImplItemKind};
-use syntax::ast::{Label, Local, Mutability, Pat, PatKind, Path};
+use syntax::ast::{IsAsync, Item, ItemKind, ImplItem, ImplItemKind};
+use syntax::ast::{Label, Local, Mutability, Pat, PatKind, Path, PathParameters};
 use syntax::ast::{QSelf, TraitItemKind, TraitRef, Ty, TyKind};
 use syntax::feature_gate::{feature_err, GateIssue};
 use syntax::parse::token;
@@ -198,14 +198,20 @@ fn resolve_struct_error<'sess, 'a>(resolver: &'sess Resolver,
                         err.span_label(typaram_span, "type variable from outer function");
                     }
                 },
+                Def::ConstParam(const_param_defid) => {
+                    if let Some(const_param_span) =
+                            resolver.definitions.opt_span(const_param_defid) {
+                        err.span_label(const_param_span, "const parameter from outer function");
+                    }
+                },
                 Def::Mod(..) | Def::Struct(..) | Def::Union(..) | Def::Enum(..) | Def::Variant(..) |
                 Def::Trait(..) | Def::TyAlias(..) | Def::TyForeign(..) | Def::TraitAlias(..) |
                 Def::AssociatedTy(..) | Def::PrimTy(..) | Def::Fn(..) | Def::Const(..) |
                 Def::Static(..) | Def::StructCtor(..) | Def::VariantCtor(..) | Def::Method(..) |
                 Def::AssociatedConst(..) | Def::Local(..) | Def::Upvar(..) | Def::Label(..) |
                 Def::Macro(..) | Def::GlobalAsm(..) | Def::Err =>
-                    bug!("TypeParametersFromOuterFunction should only be used with Def::SelfTy or \
-                         Def::TyParam")
+                    bug!("TypeParametersFromOuterFunction should only be used with Def::SelfTy, \
+                         Def::TyParam or Def::ConstParam")
             }
 
             // Try to retrieve the span of the function signature and generate a new message with
@@ -617,7 +623,8 @@ impl<'a> PathSource<'a> {
                 Def::StructCtor(_, CtorKind::Const) | Def::StructCtor(_, CtorKind::Fn) |
                 Def::VariantCtor(_, CtorKind::Const) | Def::VariantCtor(_, CtorKind::Fn) |
                 Def::Const(..) | Def::Static(..) | Def::Local(..) | Def::Upvar(..) |
-                Def::Fn(..) | Def::Method(..) | Def::AssociatedConst(..) => true,
+                Def::Fn(..) | Def::Method(..) | Def::AssociatedConst(..) |
+                Def::ConstParam(..) => true,
                 _ => false,
             },
             PathSource::Pat => match def {
@@ -792,6 +799,62 @@ impl<'tcx> Visitor<'tcx> for UsePlacementFinder {
     }
 }
 
+/// The or-patterns nested inside a pattern, along with the alternatives enclosing each of
+/// its bindings as `(or-pattern id, alternative index)` pairs, outermost first.
+struct OrPatterns<'a> {
+    or_pats: Vec<&'a [P<Pat>]>,
+    enclosing: Vec<(NodeId, usize)>,
+    bindings: NodeMap<Vec<(NodeId, usize)>>,
+}
+
+impl<'a> OrPatterns<'a> {
+    fn collect(pat: &'a Pat) -> OrPatterns<'a> {
+        let mut collector = OrPatterns {
+            or_pats: Vec::new(),
+            enclosing: Vec::new(),
+            bindings: NodeMap(),
+        };
+        collector.visit_pat(pat);
+        collector
+    }
+}
+
+impl<'a> Visitor<'a> for OrPatterns<'a> {
+    fn visit_pat(&mut self, pat: &'a Pat) {
+        match pat.node {
+            PatKind::Or(ref alternatives) => {
+                self.or_pats.push(alternatives);
+                for (i, alternative) in alternatives.iter().enumerate() {
+                    self.enclosing.push((pat.id, i));
+                    self.visit_pat(alternative);
+                    self.enclosing.pop();
+                }
+            }
+            PatKind::Ident(..) if !self.enclosing.is_empty() => {
+                self.bindings.insert(pat.id, self.enclosing.clone());
+                visit::walk_pat(self, pat);
+            }
+            _ => visit::walk_pat(self, pat),
+        }
+    }
+}
+
+/// Whether two bindings of the same pattern sit in different alternatives of a common
+/// or-pattern, like the two `a`s in `Some(A(a) | B(a))`.
+fn in_different_alternatives(alternatives: &NodeMap<Vec<(NodeId, usize)>>,
+                             a: NodeId,
+                             b: NodeId)
+                             -> bool {
+    match (alternatives.get(&a), alternatives.get(&b)) {
+        (Some(path_a), Some(path_b)) => {
+            path_a.iter().zip(path_b)
+                .find(|&(alt_a, alt_b)| alt_a != alt_b)
+                .map_or(false, |(&(or_a, _), &(or_b, _))| or_a == or_b)
+        }
+        _ => false,
+    }
+}
+
 /// This thing walks the whole crate in DFS manner, visiting each item, resolving names as it goes.
 impl<'a, 'tcx> Visitor<'tcx> for Resolver<'a> {
     fn visit_item(&mut self, item: &'tcx Item) {
@@ -831,6 +894,39 @@ impl<'a, 'tcx> Visitor<'tcx> for Resolver<'a> {
         }
         visit::walk_ty(self, ty);
     }
+    fn visit_path_parameters(&mut self, path_span: Span, parameters: &'tcx PathParameters) {
+        let data = match *parameters {
+            PathParameters::AngleBracketed(ref data) => data,
+            PathParameters::Parenthesized(..) => {
+                return visit::walk_path_parameters(self, path_span, parameters);
+            }
+        };
+        for ty in &data.types {
+            // A path naming a const parameter or a constant rather than a type is
+            // a const argument that was parsed as a type, e.g. the `N` in `Buf<N>`.
+            if let TyKind::Path(None, ref path) = ty.node {
+                if path.segments.len() == 1 && path.segments[0].parameters.is_none() {
+                    let ident = path.segments[0].ident;
+                    let is_type = self.resolve_ident_in_lexical_scope(ident, TypeNS, false,
+                                                                      path.span).is_some();
+                    let is_const = !is_type &&
+                        self.resolve_ident_in_lexical_scope(ident, ValueNS, false, path.span)
+                            .map_or(false, |binding| match binding.def() {
+                                Def::ConstParam(..) | Def::Const(..) => true,
+                                _ => false,
+                            });
+                    if is_const {
+                        self.smart_resolve_path(ty.id, None, path, PathSource::Expr(None));
+                        continue;
+                    }
+                }
+            }
+            self.visit_ty(ty);
+        }
+        walk_list!(self, visit_expr, &data.consts);
+        walk_list!(self, visit_lifetime, &data.lifetimes);
+        walk_list!(self, visit_assoc_type_binding, &data.bindings);
+    }
     fn visit_poly_trait_ref(&mut self,
                             tref: &'tcx ast::PolyTraitRef,
                             m: &'tcx ast::TraitBoundModifier) {
@@ -874,14 +970,14 @@ impl<'a, 'tcx> Visitor<'tcx> for Resolver<'a> {
                 declaration: &'tcx FnDecl,
                 _: Span,
                 node_id: NodeId) {
-        let rib_kind = match function_kind {
-            FnKind::ItemFn(..) => {
-                ItemRibKind
+        let (rib_kind, asyncness) = match function_kind {
+            FnKind::ItemFn(_, _, asyncness, ..) => {
+                (FnItemRibKind, asyncness)
             }
-            FnKind::Method(_, _, _, _) => {
-                TraitOrImplItemRibKind
+            FnKind::Method(_, sig, _, _) => {
+                (TraitOrImplItemRibKind, sig.asyncness)
             }
-            FnKind::Closure(_) => ClosureRibKind(node_id),
+            FnKind::Closure(_) => (ClosureRibKind(node_id), IsAsync::NotAsync),
         };
 
         // Create a value rib for the function.
@@ -901,6 +997,14 @@ impl<'a, 'tcx> Visitor<'tcx> for Resolver<'a> {
         }
         visit::walk_fn_ret_ty(self, &declaration.output);
 
+        // The body of an async fn is the body of the generator it is lowered
+        // to, which captures the arguments it uses.
+        if let IsAsync::Async { closure_id, .. } = asyncness {
+            let rib_kind = ClosureRibKind(closure_id);
+            self.ribs[ValueNS].push(Rib::new(rib_kind));
+            self.label_ribs.push(Rib::new(rib_kind));
+        }
+
         // Resolve the function body.
         match function_kind {
             FnKind::ItemFn(.., body) |
@@ -912,6 +1016,11 @@ impl<'a, 'tcx> Visitor<'tcx> for Resolver<'a> {
             }
         };
 
+        if asyncness.is_async() {
+            self.label_ribs.pop();
+            self.ribs[ValueNS].pop();
+        }
+
         debug!("(resolving function) leaving function");
 
         self.label_ribs.pop();
@@ -946,6 +1055,9 @@ impl<'a, 'tcx> Visitor<'tcx> for Resolver<'a> {
                     // Allow all following defaults to refer to this type parameter.
                     default_ban_rib.bindings.remove(&Ident::with_empty_ctxt(ty_param.ident.name));
                 }
+                GenericParam::Const(ref const_param) => {
+                    self.visit_ty(&const_param.ty);
+                }
             }
         }
         for p in &generics.where_clause.predicates { self.visit_where_predicate(p); }
@@ -982,6 +1094,10 @@ enum RibKind<'a> {
     /// We passed through an item scope. Disallow upvars.
     ItemRibKind,
 
+    /// We passed through a function definition. Disallow upvars.
+    /// Permit only those const parameters that are specified in the function's generics.
+    FnItemRibKind,
+
     /// We're in a constant item. Can't refer to dynamic stuff.
     ConstantItemRibKind,
 
@@ -2264,33 +2380,55 @@ impl<'a> Resolver<'a> {
     fn with_type_parameter_rib<'b, F>(&'b mut self, type_parameters: TypeParameters<'a, 'b>, f: F)
         where F: FnOnce(&mut Resolver)
     {
+        // Const parameters live in the value namespace, their rib is only pushed
+        // when there are any so that other values keep resolving as before.
+        let mut pushed_const_rib = false;
         match type_parameters {
             HasTypeParameters(generics, rib_kind) => {
                 let mut function_type_rib = Rib::new(rib_kind);
+                let mut function_value_rib = Rib::new(rib_kind);
                 let mut seen_bindings = FxHashMap();
                 for param in &generics.params {
-                    if let GenericParam::Type(ref type_parameter) = *param {
-                        let ident = type_parameter.ident.modern();
-                        debug!("with_type_parameter_rib: {}", type_parameter.id);
-
-                        if seen_bindings.contains_key(&ident) {
-                            let span = seen_bindings.get(&ident).unwrap();
-                            let err = ResolutionError::NameAlreadyUsedInTypeParameterList(
-                                ident.name,
-                                span,
-                            );
-                            resolve_error(self, type_parameter.ident.span, err);
+                    let (ident, id) = match *param {
+                        GenericParam::Type(ref type_parameter) => {
+                            (type_parameter.ident, type_parameter.id)
                         }
-                        seen_bindings.entry(ident).or_insert(type_parameter.ident.span);
-
-                        // plain insert (no renaming)
-                        let def_id = self.definitions.local_def_id(type_parameter.id);
-                        let def = Def::TyParam(def_id);
-                        function_type_rib.bindings.insert(ident, def);
-                        self.record_def(type_parameter.id, PathResolution::new(def));
+                        GenericParam::Const(ref const_parameter) => {
+                            (const_parameter.ident, const_parameter.id)
+                        }
+                        GenericParam::Lifetime(_) => continue,
+                    };
+                    let modern_ident = ident.modern();
+                    debug!("with_type_parameter_rib: {}", id);
+
+                    if seen_bindings.contains_key(&modern_ident) {
+                        let span = seen_bindings.get(&modern_ident).unwrap();
+                        let err = ResolutionError::NameAlreadyUsedInTypeParameterList(
+                            modern_ident.name,
+                            span,
+                        );
+                        resolve_error(self, ident.span, err);
                     }
+                    seen_bindings.entry(modern_ident).or_insert(ident.span);
+
+                    // plain insert (no renaming)
+                    let def_id = self.definitions.local_def_id(id);
+                    let def = if param.is_const_param() {
+                        let def = Def::ConstParam(def_id);
+                        function_value_rib.bindings.insert(modern_ident, def);
+                        def
+                    } else {
+                        let def = Def::TyParam(def_id);
+                        function_type_rib.bindings.insert(modern_ident, def);
+                        def
+                    };
+                    self.record_def(id, PathResolution::new(def));
                 }
                 self.ribs[TypeNS].push(function_type_rib);
+                if !function_value_rib.bindings.is_empty() {
+                    self.ribs[ValueNS].push(function_value_rib);
+                    pushed_const_rib = true;
+                }
             }
 
             NoTypeParameters => {
@@ -2303,6 +2441,9 @@ impl<'a> Resolver<'a> {
         if let HasTypeParameters(..) = type_parameters {
             self.ribs[TypeNS].pop();
         }
+        if pushed_const_rib {
+            self.ribs[ValueNS].pop();
+        }
     }
 
     fn with_label_rib<F>(&mut self, f: F)
@@ -2481,6 +2622,9 @@ impl<'a> Resolver<'a> {
         // Resolve the initializer.
         walk_list!(self, visit_expr, &local.init);
 
+        // Resolve the `else` block, which doesn't see the pattern's bindings.
+        walk_list!(self, visit_block, &local.els);
+
         // Resolve the pattern.
         self.resolve_pattern(&local.pat, PatternSource::Let, &mut FxHashMap());
     }
@@ -2594,6 +2738,31 @@ impl<'a> Resolver<'a> {
         self.ribs[ValueNS].pop();
     }
 
+    /// Resolves the conditions of an `if let` or `while let` chain. The
+    /// bindings of each `let` are in scope for the following conditions and
+    /// the body, so one rib is left pushed per `let`; returns their number.
+    fn resolve_chain_conds(&mut self, conds: &[ChainCond], source: PatternSource) -> usize {
+        let mut ribs = 0;
+        for cond in conds {
+            match *cond {
+                ChainCond::Let(ref pats, ref subexpression) => {
+                    self.visit_expr(subexpression);
+
+                    self.ribs[ValueNS].push(Rib::new(NormalRibKind));
+                    ribs += 1;
+                    let mut bindings_list = FxHashMap();
+                    for pat in pats {
+                        self.resolve_pattern(pat, source, &mut bindings_list);
+                    }
+                    // This has to happen *after* we determine which pat_idents are variants
+                    self.check_consistent_bindings(pats);
+                }
+                ChainCond::Expr(ref subexpression) => self.visit_expr(subexpression),
+            }
+        }
+        ribs
+    }
+
     fn resolve_block(&mut self, block: &Block) {
         debug!("(resolving block) entering block");
         // Move down in the graph, if there's an anonymous module rooted here.
@@ -2643,7 +2812,8 @@ impl<'a> Resolver<'a> {
                      pat_id: NodeId,
                      outer_pat_id: NodeId,
                      pat_src: PatternSource,
-                     bindings: &mut FxHashMap<Ident, NodeId>)
+                     bindings: &mut FxHashMap<Ident, NodeId>,
+                     or_alternatives: &NodeMap<Vec<(NodeId, usize)>>)
                      -> PathResolution {
         // Add the binding to the local ribs, if it
         // doesn't already exist in the bindings map. (We
@@ -2653,13 +2823,24 @@ impl<'a> Resolver<'a> {
         let mut def = Def::Local(pat_id);
         match bindings.get(&ident).cloned() {
             Some(id) if id == outer_pat_id => {
-                // `Variant(a, a)`, error
-                resolve_error(
-                    self,
-                    ident.span,
-                    ResolutionError::IdentifierBoundMoreThanOnceInSamePattern(
-                        &ident.name.as_str())
-                );
+                let first_def = self.ribs[ValueNS].last().unwrap().bindings[&ident];
+                match first_def {
+                    Def::Local(first_id)
+                        if in_different_alternatives(or_alternatives, first_id, pat_id) => {
+                        // `Some(Variant1(a) | Variant2(a))`, ok
+                        // Reuse definition from the first `a`.
+                        def = Def::Local(first_id);
+                    }
+                    _ => {
+                        // `Variant(a, a)`, error
+                        resolve_error(
+                            self,
+                            ident.span,
+                            ResolutionError::IdentifierBoundMoreThanOnceInSamePattern(
+                                &ident.name.as_str())
+                        );
+                    }
+                }
             }
             Some(..) if pat_src == PatternSource::FnParam => {
                 // `fn f(a: u8, a: u8)`, error
@@ -2701,6 +2882,7 @@ impl<'a> Resolver<'a> {
                        bindings: &mut FxHashMap<Ident, NodeId>) {
         // Visit all direct subpatterns of this pattern.
         let outer_pat_id = pat.id;
+        let or_patterns = OrPatterns::collect(pat);
         pat.walk(&mut |pat| {
             match pat.node {
                 PatKind::Ident(bmode, ident, ref opt_pat) => {
@@ -2747,7 +2929,8 @@ impl<'a> Resolver<'a> {
                             }
                         }
                     }).unwrap_or_else(|| {
-                        self.fresh_binding(ident, pat.id, outer_pat_id, pat_src, bindings)
+                        self.fresh_binding(ident, pat.id, outer_pat_id, pat_src, bindings,
+                                           &or_patterns.bindings)
                     });
 
                     self.record_def(pat.id, resolution);
@@ -2770,6 +2953,11 @@ impl<'a> Resolver<'a> {
             true
         });
 
+        // This has to happen *after* we determine which pat_idents are variants
+        for pats in or_patterns.or_pats {
+            self.check_consistent_bindings(pats);
+        }
+
         visit::walk_pat(self, pat);
     }
 
@@ -3447,7 +3635,7 @@ impl<'a> Resolver<'a> {
                                 seen.insert(node_id, depth);
                             }
                         }
-                        ItemRibKind | TraitOrImplItemRibKind => {
+                        ItemRibKind | FnItemRibKind | TraitOrImplItemRibKind => {
                             // This was an attempt to access an upvar inside a
                             // named function item. This is not allowed, so we
                             // report an error.
@@ -3468,12 +3656,12 @@ impl<'a> Resolver<'a> {
                     }
                 }
             }
-            Def::TyParam(..) | Def::SelfTy(..) => {
+            Def::TyParam(..) | Def::SelfTy(..) | Def::ConstParam(..) => {
                 for rib in ribs {
                     match rib.kind {
                         NormalRibKind | TraitOrImplItemRibKind | ClosureRibKind(..) |
                         ModuleRibKind(..) | MacroDefinition(..) | ForwardTyParamBanRibKind |
-                        ConstantItemRibKind => {
+                        ConstantItemRibKind | FnItemRibKind => {
                             // Nothing to do. Continue.
                         }
                         ItemRibKind => {
@@ -3701,6 +3889,16 @@ impl<'a> Resolver<'a> {
                 optional_else.as_ref().map(|expr| self.visit_expr(expr));
             }
 
+            ExprKind::IfLetChain(ref conds, ref if_block, ref optional_else) => {
+                let ribs = self.resolve_chain_conds(conds, PatternSource::IfLet);
+                self.visit_block(if_block);
+                for _ in 0..ribs {
+                    self.ribs[ValueNS].pop();
+                }
+
+                optional_else.as_ref().map(|expr| self.visit_expr(expr));
+            }
+
             ExprKind::Loop(ref block, label) => self.resolve_labeled_block(label, expr.id, &block),
 
             ExprKind::While(ref subexpression, ref block, label) => {
@@ -3725,6 +3923,16 @@ impl<'a> Resolver<'a> {
                 });
             }
 
+            ExprKind::WhileLetChain(ref conds, ref block, label) => {
+                self.with_resolved_label(label, expr.id, |this| {
+                    let ribs = this.resolve_chain_conds(conds, PatternSource::WhileLet);
+                    this.visit_block(block);
+                    for _ in 0..ribs {
+                        this.ribs[ValueNS].pop();
+                    }
+                });
+            }
+
             ExprKind::ForLoop(ref pattern, ref subexpression, ref block, label) => {
                 self.visit_expr(subexpression);
                 self.ribs[ValueNS].push(Rib::new(NormalRibKind));
@@ -3765,6 +3973,16 @@ impl<'a> Resolver<'a> {
                 visit::walk_expr(self, expr);
                 self.current_type_ascription.pop();
             }
+            // The body of an async block is the body of the generator it is
+            // lowered to, so it captures the locals it uses like a closure.
+            ExprKind::Async(_, async_closure_id, ref block) => {
+                let rib_kind = ClosureRibKind(async_closure_id);
+                self.ribs[ValueNS].push(Rib::new(rib_kind));
+                self.label_ribs.push(Rib::new(rib_kind));
+                self.visit_block(&block);
+                self.label_ribs.pop();
+                self.ribs[ValueNS].pop();
+            }
             _ => {
                 visit::walk_expr(self, expr);
             }
@@ -331,6 +331,7 @@ impl<'l, 'tcx: 'l, 'll, O: DumpOutput + 'll> DumpVisitor<'l, 'tcx, 'll, O> {
             HirDef::SelfTy(..) |
             HirDef::Label(_) |
             HirDef::TyParam(..) |
+            HirDef::ConstParam(..) |
             HirDef::Method(..) |
             HirDef::AssociatedTy(..) |
             HirDef::AssociatedConst(..) |
@@ -1025,6 +1026,18 @@ impl<'l, 'tcx: 'l, 'll, O: DumpOutput + 'll> DumpVisitor<'l, 'tcx, 'll, O> {
         }
     }
 
+    fn process_chain_conds(&mut self, conds: &'l [ast::ChainCond]) {
+        for cond in conds {
+            match *cond {
+                ast::ChainCond::Let(ref pats, ref subexpression) => {
+                    self.process_var_decl_multi(pats);
+                    self.visit_expr(subexpression);
+                }
+                ast::ChainCond::Expr(ref subexpression) => self.visit_expr(subexpression),
+            }
+        }
+    }
+
     fn process_var_decl_multi(&mut self, pats: &'l [P<ast::Pat>]) {
         let mut collector = PathCollector::new();
         for pattern in pats {
@@ -1573,6 +1586,9 @@ impl<'l, 'tcx: 'l, 'll, O: DumpOutput + 'll> Visitor<'l> for DumpVisitor<'l, 'tc
                     self.visit_ty(&ty);
                 }
             }
+            if let ast::GenericParam::Const(ref const_param) = *param {
+                self.visit_ty(&const_param.ty);
+            }
         }
     }
 
@@ -1671,6 +1687,15 @@ impl<'l, 'tcx: 'l, 'll, O: DumpOutput + 'll> Visitor<'l> for DumpVisitor<'l, 'tc
                 visit::walk_block(self, block);
                 opt_else.as_ref().map(|el| self.visit_expr(el));
             }
+            ast::ExprKind::IfLetChain(ref conds, ref block, ref opt_else) => {
+                self.process_chain_conds(conds);
+                visit::walk_block(self, block);
+                opt_else.as_ref().map(|el| self.visit_expr(el));
+            }
+            ast::ExprKind::WhileLetChain(ref conds, ref block, _) => {
+                self.process_chain_conds(conds);
+                visit::walk_block(self, block);
+            }
             ast::ExprKind::Repeat(ref element, ref count) => {
                 self.visit_expr(element);
                 self.nest_tables(count.id, |v| v.visit_expr(count));
@@ -1717,9 +1742,10 @@ impl<'l, 'tcx: 'l, 'll, O: DumpOutput + 'll> Visitor<'l> for DumpVisitor<'l, 'tc
             .unwrap_or(String::new());
         self.process_var_decl(&l.pat, value);
 
-        // Just walk the initialiser and type (don't want to walk the pattern again).
+        // Just walk the initialiser, else block and type (don't want to walk the pattern again).
         walk_list!(self, visit_ty, &l.ty);
         walk_list!(self, visit_expr, &l.init);
+        walk_list!(self, visit_block, &l.els);
     }
 
     fn visit_foreign_item(&mut self, item: &'l ast::ForeignItem) {
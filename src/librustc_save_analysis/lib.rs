@@ -722,6 +722,7 @@ impl<'l, 'tcx: 'l> SaveContext<'l, 'tcx> {
             }
             HirDef::Static(..) |
             HirDef::Const(..) |
+            HirDef::ConstParam(..) |
             HirDef::AssociatedConst(..) |
             HirDef::VariantCtor(..) => {
                 let span = self.span_from_span(sub_span);
@@ -939,6 +940,7 @@ fn make_signature(decl: &ast::FnDecl, generics: &ast::Generics) -> String {
             .map(|param| match *param {
                 ast::GenericParam::Lifetime(ref l) => l.lifetime.ident.name.to_string(),
                 ast::GenericParam::Type(ref t) => t.ident.to_string(),
+                ast::GenericParam::Const(ref c) => format!("const {}", c.ident),
             })
             .collect::<Vec<_>>()
             .join(", "));
@@ -380,11 +380,14 @@ impl Sig for ast::Item {
 
                 Ok(extend_sig(ty, text, defs, vec![]))
             }
-            ast::ItemKind::Fn(ref decl, unsafety, constness, abi, ref generics, _) => {
+            ast::ItemKind::Fn(ref decl, unsafety, asyncness, constness, abi, ref generics, _) => {
                 let mut text = String::new();
                 if constness.node == ast::Constness::Const {
                     text.push_str("const ");
                 }
+                if asyncness.is_async() {
+                    text.push_str("async ");
+                }
                 if unsafety == ast::Unsafety::Unsafe {
                     text.push_str("unsafe ");
                 }
@@ -655,6 +658,19 @@ impl Sig for ast::Generics {
                     text.push_str(&t_text);
                     text.push(',');
                 }
+                ast::GenericParam::Const(ref c) => {
+                    let c_text = c.ident.to_string();
+                    text.push_str("const ");
+                    defs.push(SigElement {
+                        id: id_from_node_id(c.id, scx),
+                        start: offset + text.len(),
+                        end: offset + text.len() + c_text.len(),
+                    });
+                    text.push_str(&c_text);
+                    text.push_str(": ");
+                    text.push_str(&pprust::ty_to_string(&c.ty));
+                    text.push(',');
+                }
             }
         }
 
@@ -928,6 +944,9 @@ fn make_method_signature(
     if m.constness.node == ast::Constness::Const {
         text.push_str("const ");
     }
+    if m.asyncness.is_async() {
+        text.push_str("async ");
+    }
     if m.unsafety == ast::Unsafety::Unsafe {
         text.push_str("unsafe ");
     }
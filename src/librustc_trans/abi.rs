@@ -652,7 +652,18 @@ impl<'a, 'tcx> FnType<'tcx> {
         let fn_ty = instance.ty(cx.tcx);
         let sig = ty_fn_sig(cx, fn_ty);
         let sig = cx.tcx.normalize_erasing_late_bound_regions(ty::ParamEnv::reveal_all(), &sig);
-        FnType::new(cx, sig, &[])
+        let mut fn_ty = FnType::new(cx, sig, &[]);
+        if instance.def.requires_caller_location(cx.tcx) {
+            fn_ty.push_caller_location(cx);
+        }
+        fn_ty
+    }
+
+    /// Append the implicit caller location argument of `#[track_caller]`
+    /// functions, which is passed after all the declared arguments.
+    pub fn push_caller_location(&mut self, cx: &CodegenCx<'a, 'tcx>) {
+        let layout = cx.layout_of(cx.tcx.caller_location_ty());
+        self.args.push(ArgType::new(layout));
     }
 
     pub fn new(cx: &CodegenCx<'a, 'tcx>,
@@ -92,8 +92,11 @@ pub fn set_probestack(cx: &CodegenCx, llfn: ValueRef) {
         _ => {}
     }
 
-    // probestack doesn't play nice either with pgo-gen.
-    if cx.sess().opts.debugging_opts.pgo_gen.is_some() {
+    // probestack doesn't play nice either with pgo-gen or with coverage
+    // instrumentation.
+    if cx.sess().opts.debugging_opts.pgo_gen.is_some() ||
+        cx.sess().opts.debugging_opts.instrument_coverage
+    {
         return;
     }
 
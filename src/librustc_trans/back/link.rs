@@ -1124,7 +1124,9 @@ fn link_args(cmd: &mut Linker,
         cmd.build_static_executable();
     }
 
-    if sess.opts.debugging_opts.pgo_gen.is_some() {
+    if sess.opts.debugging_opts.pgo_gen.is_some() ||
+        sess.opts.debugging_opts.instrument_coverage
+    {
         cmd.pgo_gen();
     }
 
@@ -0,0 +1,276 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The worst-case stack usage report of `-Z stack-size-report`.
+//!
+//! The call graph of the crate is taken from MIR while the crate is being
+//! translated, and the frame size of every function from the `.stack_sizes`
+//! section LLVM emits into the object files. The bound of a function is its
+//! own frame plus the largest bound among its callees. There is no bound for
+//! a function that is recursive, that makes calls through function pointers
+//! or trait objects, that calls a function outside of this crate, or that
+//! LLVM gave no frame size, which it doesn't for frames of dynamic size. A
+//! function with no code in the objects was inlined into all of its callers,
+//! and counts as a frame of size 0 that makes its calls.
+//!
+//! The bounds leave out the frames of the calls LLVM inserts itself, such as
+//! the calls to `memcpy` or `memset` that copy or clear large values. These
+//! are listed for each function in `llvm_calls`, along with any other symbol
+//! from outside of the object its code refers to but MIR doesn't call.
+
+use llvm;
+use rustc::hir::def_id::LOCAL_CRATE;
+use rustc::mir::mono::MonoItem;
+use rustc::session::Session;
+use rustc::session::config::OutputFilenames;
+use rustc::ty::TyCtxt;
+use rustc::util::nodemap::{FxHashMap, FxHashSet};
+use rustc_mir::monomorphize::collector;
+use serialize::json::Json;
+use CompiledModule;
+
+use libc::{c_char, size_t};
+use std::cmp;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::io::Write;
+
+/// The monomorphized call graph of the crate, keyed by symbol name.
+pub struct CallGraph {
+    functions: FxHashMap<String, FunctionNode>,
+}
+
+struct FunctionNode {
+    /// The human readable name of the function.
+    name: String,
+    callees: Vec<String>,
+    /// Locations of the calls whose callee is not known statically.
+    indirect_calls: Vec<String>,
+}
+
+impl CallGraph {
+    pub fn new<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>) -> CallGraph {
+        let mut functions = FxHashMap();
+        let (_, codegen_units) = tcx.collect_and_partition_translation_items(LOCAL_CRATE);
+        for cgu in codegen_units.iter() {
+            for (&item, _) in cgu.items() {
+                let instance = match item {
+                    MonoItem::Fn(instance) => instance,
+                    MonoItem::Static(..) | MonoItem::GlobalAsm(..) => continue,
+                };
+                let symbol = tcx.symbol_name(instance).name.to_string();
+                if functions.contains_key(&symbol) {
+                    continue;
+                }
+
+                let sites = collector::call_sites(tcx, instance);
+                let mut callees: Vec<_> = sites.direct.iter().map(|&callee| {
+                    tcx.symbol_name(callee).name.to_string()
+                }).collect();
+                callees.sort();
+                callees.dedup();
+                functions.insert(symbol, FunctionNode {
+                    name: instance.to_string(),
+                    callees,
+                    indirect_calls: sites.indirect.iter().map(|&span| {
+                        tcx.sess.codemap().span_to_string(span)
+                    }).collect(),
+                });
+            }
+        }
+        CallGraph { functions }
+    }
+}
+
+/// Reads the stack sizes out of the objects of `modules` and writes the
+/// report next to the other outputs.
+pub fn write_report(sess: &Session,
+                    call_graph: &CallGraph,
+                    modules: &[CompiledModule],
+                    outputs: &OutputFilenames) {
+    let mut stack_sizes = FxHashMap();
+    let mut defined = FxHashSet();
+    let mut external_calls = FxHashMap();
+    for module in modules {
+        let object = match module.object {
+            Some(ref object) => object,
+            None => {
+                sess.warn("no object files were emitted, cannot write the stack size report");
+                return;
+            }
+        };
+        let data = match fs::read(object) {
+            Ok(data) => data,
+            Err(e) => {
+                sess.err(&format!("failed to read `{}`: {}", object.display(), e));
+                return;
+            }
+        };
+
+        let mut ok = false;
+        // See `LLVMRustWriteStackSizesToString` for the format.
+        let sizes = llvm::build_string(|s| unsafe {
+            ok = llvm::LLVMRustWriteStackSizesToString(data.as_ptr() as *const c_char,
+                                                        data.len() as size_t,
+                                                        s);
+        }).expect("non-UTF8 symbol name in object file");
+        if !ok {
+            let msg = llvm::last_error().unwrap_or("unknown error".to_string());
+            sess.warn(&format!("failed to read stack sizes from `{}`: {}",
+                               object.display(), msg));
+            return;
+        }
+
+        for line in sizes.lines() {
+            let mut parts = line.split(' ');
+            match (parts.next(), parts.next(), parts.next()) {
+                (Some("function"), Some(symbol), None) => {
+                    defined.insert(strip_llvm_suffix(symbol).to_string());
+                }
+                // Functions with internal linkage may be defined in several
+                // codegen units, keep the largest of their frames.
+                (Some("size"), Some(size), Some(symbol)) => {
+                    let size: u64 = size.parse().unwrap();
+                    let entry = stack_sizes.entry(strip_llvm_suffix(symbol).to_string())
+                                           .or_insert(size);
+                    *entry = cmp::max(*entry, size);
+                }
+                (Some("call"), Some(caller), Some(callee)) => {
+                    external_calls.entry(strip_llvm_suffix(caller).to_string())
+                                  .or_insert_with(BTreeSet::new)
+                                  .insert(strip_llvm_suffix(callee).to_string());
+                }
+                _ => bug!("unexpected line `{}` in the stack sizes of `{}`",
+                          line, object.display()),
+            }
+        }
+    }
+
+    let mut analysis = Analysis {
+        call_graph,
+        stack_sizes: &stack_sizes,
+        defined: &defined,
+        bounds: FxHashMap(),
+        stack: vec![],
+        recursive: FxHashSet(),
+    };
+    let mut symbols: Vec<_> = call_graph.functions.keys().collect();
+    symbols.sort();
+    for symbol in &symbols {
+        analysis.bound(symbol);
+    }
+
+    let functions = symbols.iter().map(|&symbol| {
+        let node = &call_graph.functions[symbol];
+        let mut function = BTreeMap::new();
+        function.insert("symbol".to_string(), Json::String(symbol.clone()));
+        function.insert("name".to_string(), Json::String(node.name.clone()));
+        function.insert("stack_size".to_string(),
+                        stack_sizes.get(symbol).map_or(Json::Null, |&size| Json::U64(size)));
+        function.insert("inlined".to_string(), Json::Boolean(!defined.contains(symbol)));
+        function.insert("max_stack".to_string(),
+                        analysis.bounds[symbol].map_or(Json::Null, Json::U64));
+        function.insert("recursive".to_string(),
+                        Json::Boolean(analysis.recursive.contains(symbol)));
+        let strings = |strings: Vec<&String>| {
+            Json::Array(strings.into_iter().map(|s| Json::String(s.clone())).collect())
+        };
+        function.insert("callees".to_string(), strings(node.callees.iter().collect()));
+        function.insert("unknown_callees".to_string(), strings(node.callees.iter().filter(|callee| {
+            !call_graph.functions.contains_key(*callee)
+        }).collect()));
+        function.insert("indirect_calls".to_string(),
+                        strings(node.indirect_calls.iter().collect()));
+        let llvm_calls = external_calls.get(symbol).map_or(vec![], |callees| {
+            callees.iter().filter(|callee| node.callees.binary_search(*callee).is_err()).collect()
+        });
+        function.insert("llvm_calls".to_string(), strings(llvm_calls));
+        Json::Object(function)
+    }).collect();
+    let mut report = BTreeMap::new();
+    report.insert("functions".to_string(), Json::Array(functions));
+
+    let path = outputs.with_extension("stack_sizes.json");
+    let result = fs::File::create(&path).and_then(|mut file| {
+        write!(file, "{}", Json::Object(report).pretty())
+    });
+    if let Err(e) = result {
+        sess.err(&format!("failed to write `{}`: {}", path.display(), e));
+    }
+}
+
+/// Removes the `.llvm.<hash>` suffix ThinLTO gives to the local symbols it
+/// promotes to global ones, which MIR doesn't know about.
+fn strip_llvm_suffix(symbol: &str) -> &str {
+    match symbol.find(".llvm.") {
+        Some(i) => &symbol[..i],
+        None => symbol,
+    }
+}
+
+struct Analysis<'a> {
+    call_graph: &'a CallGraph,
+    stack_sizes: &'a FxHashMap<String, u64>,
+    /// The functions the objects have code for.
+    defined: &'a FxHashSet<String>,
+    /// The worst-case stack usage of every function visited so far, `None`
+    /// if it has no bound.
+    bounds: FxHashMap<String, Option<u64>>,
+    /// The functions currently being visited, in call order.
+    stack: Vec<String>,
+    recursive: FxHashSet<String>,
+}
+
+impl<'a> Analysis<'a> {
+    fn bound(&mut self, symbol: &str) -> Option<u64> {
+        if let Some(&bound) = self.bounds.get(symbol) {
+            return bound;
+        }
+        if let Some(position) = self.stack.iter().position(|s| s == symbol) {
+            for function in &self.stack[position..] {
+                self.recursive.insert(function.clone());
+            }
+            return None;
+        }
+
+        let call_graph = self.call_graph;
+        let node = match call_graph.functions.get(symbol) {
+            Some(node) => node,
+            None => return None,
+        };
+        let own_size = match self.stack_sizes.get(symbol) {
+            Some(&size) => Some(size),
+            // A function of this crate without code of its own has been
+            // inlined into all of its callers, which then also make its
+            // calls.
+            None if !self.defined.contains(symbol) => Some(0),
+            None => None,
+        };
+
+        self.stack.push(symbol.to_string());
+        let mut bound = if node.indirect_calls.is_empty() {
+            own_size
+        } else {
+            None
+        };
+        let mut deepest_callee = 0;
+        for callee in &node.callees {
+            match self.bound(callee) {
+                Some(callee_bound) => deepest_callee = cmp::max(deepest_callee, callee_bound),
+                None => bound = None,
+            }
+        }
+        self.stack.pop();
+
+        let bound = bound.map(|size| size + deepest_callee);
+        self.bounds.insert(symbol.to_string(), bound);
+        bound
+    }
+}
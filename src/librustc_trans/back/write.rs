@@ -13,6 +13,7 @@ use back::lto::{self, ModuleBuffer, ThinBuffer};
 use back::link::{self, get_linker, remove};
 use back::command::Command;
 use back::linker::LinkerInfo;
+use back::stack_sizes::{self, CallGraph};
 use back::symbol_export::ExportedSymbols;
 use base;
 use consts;
@@ -206,6 +207,8 @@ pub fn target_machine_factory(sess: &Session, find_features: bool)
     let features = CString::new(target_feature(sess).as_bytes()).unwrap();
     let is_pie_binary = !find_features && is_pie_binary(sess);
     let trap_unreachable = sess.target.target.options.trap_unreachable;
+    let emit_stack_size_section = sess.opts.debugging_opts.emit_stack_sizes ||
+                                  sess.opts.debugging_opts.stack_size_report;
 
     Arc::new(move || {
         let tm = unsafe {
@@ -220,6 +223,7 @@ pub fn target_machine_factory(sess: &Session, find_features: bool)
                 fdata_sections,
                 trap_unreachable,
                 singlethread,
+                emit_stack_size_section,
             )
         };
 
@@ -245,6 +249,7 @@ pub struct ModuleConfig {
 
     pgo_gen: Option<String>,
     pgo_use: String,
+    instrument_coverage: bool,
 
     // Flags indicating which outputs to produce.
     emit_no_opt_bc: bool,
@@ -282,6 +287,7 @@ impl ModuleConfig {
 
             pgo_gen: None,
             pgo_use: String::new(),
+            instrument_coverage: false,
 
             emit_no_opt_bc: false,
             emit_bc: false,
@@ -566,6 +572,10 @@ unsafe fn optimize(cgcx: &CodegenContext,
         };
 
         if !config.no_verify { assert!(addpass("verify")); }
+        // The counter increments have to be lowered before any optimization
+        // gets a chance to delete the functions they belong to, otherwise
+        // their coverage records would refer to functions without counters.
+        if config.instrument_coverage { assert!(addpass("instrprof")); }
         if !config.no_prepopulate_passes {
             llvm::LLVMRustAddAnalysisPasses(tm, fpm, llmod);
             llvm::LLVMRustAddAnalysisPasses(tm, mpm, llmod);
@@ -948,6 +958,7 @@ pub fn start_async_translation(tcx: TyCtxt,
 
     modules_config.pgo_gen = sess.opts.debugging_opts.pgo_gen.clone();
     modules_config.pgo_use = sess.opts.debugging_opts.pgo_use.clone();
+    modules_config.instrument_coverage = sess.opts.debugging_opts.instrument_coverage;
 
     modules_config.opt_level = Some(get_llvm_opt_level(sess.opts.optimize));
     modules_config.opt_size = Some(get_llvm_opt_size(sess.opts.optimize));
@@ -1007,6 +1018,12 @@ pub fn start_async_translation(tcx: TyCtxt,
     metadata_config.time_passes = false;
     allocator_config.time_passes = false;
 
+    let call_graph = if sess.opts.debugging_opts.stack_size_report {
+        Some(CallGraph::new(tcx))
+    } else {
+        None
+    };
+
     let (shared_emitter, shared_emitter_main) = SharedEmitter::new();
     let (trans_worker_send, trans_worker_receive) = channel();
 
@@ -1036,6 +1053,7 @@ pub fn start_async_translation(tcx: TyCtxt,
         shared_emitter_main,
         future: coordinator_thread,
         output_filenames: tcx.output_filenames(LOCAL_CRATE),
+        call_graph,
     }
 }
 
@@ -2246,6 +2264,7 @@ pub struct OngoingCrateTranslation {
     shared_emitter_main: SharedEmitterMain,
     future: thread::JoinHandle<Result<CompiledModules, ()>>,
     output_filenames: Arc<OutputFilenames>,
+    call_graph: Option<CallGraph>,
 }
 
 impl OngoingCrateTranslation {
@@ -2271,6 +2290,15 @@ impl OngoingCrateTranslation {
         copy_module_artifacts_into_incr_comp_cache(sess,
                                                    dep_graph,
                                                    &compiled_modules);
+
+        // The report reads the object files, so it has to be written before
+        // the temporary ones are removed.
+        if let Some(ref call_graph) = self.call_graph {
+            stack_sizes::write_report(sess,
+                                      call_graph,
+                                      &compiled_modules.modules,
+                                      &self.output_filenames);
+        }
         produce_final_output_artifacts(sess,
                                        &compiled_modules,
                                        &self.output_filenames);
@@ -37,7 +37,7 @@ use metadata;
 use rustc::hir::def_id::{CrateNum, DefId, LOCAL_CRATE};
 use rustc::middle::lang_items::StartFnLangItem;
 use rustc::middle::weak_lang_items;
-use rustc::mir::mono::{Linkage, Visibility, Stats};
+use rustc::mir::mono::{InstanceStats, Linkage, Visibility, Stats};
 use rustc::middle::cstore::{EncodedMetadata};
 use rustc::ty::{self, Ty, TyCtxt};
 use rustc::ty::layout::{self, Align, TyLayout, LayoutOf};
@@ -59,6 +59,7 @@ use common::{C_bool, C_bytes_in_context, C_i32, C_usize};
 use rustc_mir::monomorphize::collector::{self, MonoItemCollectionMode};
 use common::{self, C_struct_in_context, C_array, val_ty};
 use consts;
+use coverageinfo;
 use context::{self, CodegenCx};
 use debuginfo;
 use declare;
@@ -498,6 +499,31 @@ pub fn trans_instance<'a, 'tcx>(cx: &CodegenCx<'a, 'tcx>, instance: Instance<'tc
 
     let mir = cx.tcx.instance_mir(instance.def);
     mir::trans_mir(cx, lldecl, &mir, instance, sig);
+
+    if cx.sess().opts.debugging_opts.print_generic_bloat {
+        cx.stats.borrow_mut().instance_stats.push(InstanceStats {
+            def_id: instance.def_id(),
+            instance: instance.to_string(),
+            codegen_unit: cx.codegen_unit.name().to_string(),
+            llvm_insns: count_insns(lldecl),
+        });
+    }
+}
+
+fn count_insns(llfn: ValueRef) -> usize {
+    let mut insns = 0;
+    unsafe {
+        let mut llbb = llvm::LLVMGetFirstBasicBlock(llfn);
+        while !llbb.is_null() {
+            let mut llinst = llvm::LLVMGetFirstInstruction(llbb);
+            while !llinst.is_null() {
+                insns += 1;
+                llinst = llvm::LLVMGetNextInstruction(llinst);
+            }
+            llbb = llvm::LLVMGetNextBasicBlock(llbb);
+        }
+    }
+    insns
 }
 
 pub fn set_link_section(cx: &CodegenCx,
@@ -722,6 +748,12 @@ pub fn trans_crate<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
         tcx.sess.fatal("this compiler's LLVM does not support PGO");
     }
 
+    if tcx.sess.opts.debugging_opts.instrument_coverage &&
+        unsafe { !llvm::LLVMRustCoverageAvailable() }
+    {
+        tcx.sess.fatal("this compiler's LLVM does not support coverage instrumentation");
+    }
+
     let crate_hash = tcx.crate_hash(LOCAL_CRATE);
     let link_meta = link::build_link_meta(crate_hash);
 
@@ -933,12 +965,54 @@ pub fn trans_crate<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
         }
     }
 
+    if tcx.sess.opts.debugging_opts.print_generic_bloat {
+        print_generic_bloat(tcx, &all_stats.instance_stats);
+    }
+
     ongoing_translation.check_for_errors(tcx.sess);
 
     assert_and_save_dep_graph(tcx);
     ongoing_translation
 }
 
+/// Prints the functions translated into this crate grouped by the definition
+/// they are instances of, the definitions that account for the most LLVM IR
+/// first. An instance is counted once for every codegen unit it was
+/// translated into, as inlined copies add to the size of the crate as well.
+fn print_generic_bloat<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>, instance_stats: &[InstanceStats]) {
+    let mut definitions = FxHashMap();
+    for stats in instance_stats {
+        definitions.entry(stats.def_id)
+                   .or_insert_with(BTreeMap::new)
+                   .entry(&stats.instance[..])
+                   .or_insert_with(Vec::new)
+                   .push((&stats.codegen_unit[..], stats.llvm_insns));
+    }
+
+    let total = |copies: &[(&str, usize)]| copies.iter().map(|&(_, insns)| insns).sum::<usize>();
+    let mut definitions: Vec<_> = definitions.into_iter().map(|(def_id, instances)| {
+        let mut instances: Vec<_> = instances.into_iter().collect();
+        instances.sort_by_key(|&(_, ref copies)| cmp::Reverse(total(copies)));
+        let insns = instances.iter().map(|&(_, ref copies)| total(copies)).sum::<usize>();
+        (insns, tcx.item_path_str(def_id), instances)
+    }).collect();
+    definitions.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.1.cmp(&b.1)));
+
+    for (insns, path, instances) in definitions {
+        let cgus: FxHashSet<_> = instances.iter().flat_map(|&(_, ref copies)| {
+            copies.iter().map(|&(cgu, _)| cgu)
+        }).collect();
+        println!("print-generic-bloat: {} insns, {} instances, {} codegen units: {}",
+                 insns, instances.len(), cgus.len(), path);
+        for (instance, mut copies) in instances {
+            copies.sort();
+            let cgus: Vec<_> = copies.iter().map(|&(cgu, _)| cgu).collect();
+            println!("print-generic-bloat:     {} insns: {} @@ {}",
+                     total(&copies), instance, cgus.join(" "));
+        }
+    }
+}
+
 fn assert_and_save_dep_graph<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>) {
     time(tcx.sess,
          "assert dep graph",
@@ -1236,6 +1310,10 @@ fn compile_codegen_unit<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
                 }
             }
 
+            // Emit the coverage mapping records, which also have to be
+            // kept alive through the llvm.used variable
+            coverageinfo::finalize(&cx);
+
             // Create the llvm.used variable
             // This variable has type [N x i8*] and is stored in the llvm.metadata section
             if !cx.used_statics.borrow().is_empty() {
@@ -1394,7 +1472,9 @@ fn fetch_wasm_section(tcx: TyCtxt, id: DefId) -> (String, Vec<u8>) {
 
     let val = match val.val {
         ConstVal::Value(val) => val,
-        ConstVal::Unevaluated(..) => bug!("should be evaluated"),
+        ConstVal::Unevaluated(..) |
+        ConstVal::Param(_) |
+        ConstVal::Infer(_) => bug!("should be evaluated"),
     };
     let val = match val {
         Value::ByRef(ptr, _align) => ptr.into_inner_primval(),
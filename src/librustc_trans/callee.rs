@@ -14,6 +14,7 @@
 //! and methods are represented as just a fn ptr and not a full
 //! closure.
 
+use abi::FnType;
 use attributes;
 use common::{self, CodegenCx};
 use consts;
@@ -56,8 +57,13 @@ pub fn get_fn<'a, 'tcx>(cx: &CodegenCx<'a, 'tcx>,
     debug!("get_fn({:?}: {:?}) => {}", instance, fn_ty, sym);
 
     // Create a fn pointer with the substituted signature.
-    let fn_ptr_ty = tcx.mk_fn_ptr(common::ty_fn_sig(cx, fn_ty));
-    let llptrty = cx.layout_of(fn_ptr_ty).llvm_type(cx);
+    let llptrty = if instance.def.requires_caller_location(tcx) {
+        // The implicit caller location argument isn't part of the signature.
+        FnType::of_instance(cx, &instance).llvm_type(cx).ptr_to()
+    } else {
+        let fn_ptr_ty = tcx.mk_fn_ptr(common::ty_fn_sig(cx, fn_ty));
+        cx.layout_of(fn_ptr_ty).llvm_type(cx)
+    };
 
     let llfn = if let Some(llfn) = declare::get_declared_value(cx, &sym) {
         // This is subtle and surprising, but sometimes we have to bitcast
@@ -91,7 +97,7 @@ pub fn get_fn<'a, 'tcx>(cx: &CodegenCx<'a, 'tcx>,
             llfn
         }
     } else {
-        let llfn = declare::declare_fn(cx, &sym, fn_ty);
+        let llfn = declare::declare_instance(cx, &sym, instance);
         assert_eq!(common::val_ty(llfn), llptrty);
         debug!("get_fn: not casting pointer!");
 
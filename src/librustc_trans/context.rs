@@ -15,6 +15,7 @@ use rustc::dep_graph::DepGraphSafe;
 use rustc::hir;
 use rustc::hir::def_id::DefId;
 use debuginfo;
+use coverageinfo;
 use callee;
 use base;
 use declare;
@@ -96,6 +97,8 @@ pub struct CodegenCx<'a, 'tcx: 'a> {
 
     pub dbg_cx: Option<debuginfo::CrateDebugContext<'tcx>>,
 
+    pub coverage_cx: Option<coverageinfo::CrateCoverageContext>,
+
     eh_personality: Cell<Option<ValueRef>>,
     eh_unwind_resume: Cell<Option<ValueRef>>,
     pub rust_try_fn: Cell<Option<ValueRef>>,
@@ -279,6 +282,12 @@ impl<'a, 'tcx> CodegenCx<'a, 'tcx> {
                 None
             };
 
+            let coverage_cx = if tcx.sess.opts.debugging_opts.instrument_coverage {
+                Some(coverageinfo::CrateCoverageContext::new())
+            } else {
+                None
+            };
+
             let mut cx = CodegenCx {
                 tcx,
                 check_overflow,
@@ -301,6 +310,7 @@ impl<'a, 'tcx> CodegenCx<'a, 'tcx> {
                 pointee_infos: RefCell::new(FxHashMap()),
                 isize_ty: Type::from_ref(ptr::null_mut()),
                 dbg_cx,
+                coverage_cx,
                 eh_personality: Cell::new(None),
                 eh_unwind_resume: Cell::new(None),
                 rust_try_fn: Cell::new(None),
@@ -650,6 +660,10 @@ fn declare_intrinsic(cx: &CodegenCx, key: &str) -> Option<ValueRef> {
     ifn!("llvm.assume", fn(i1) -> void);
     ifn!("llvm.prefetch", fn(i8p, t_i32, t_i32, t_i32) -> void);
 
+    if cx.sess().opts.debugging_opts.instrument_coverage {
+        ifn!("llvm.instrprof.increment", fn(i8p, t_i64, t_i32, t_i32) -> void);
+    }
+
     if cx.sess().opts.debuginfo != NoDebugInfo {
         ifn!("llvm.dbg.declare", fn(Type::metadata(cx), Type::metadata(cx)) -> void);
         ifn!("llvm.dbg.value", fn(Type::metadata(cx), t_i64, Type::metadata(cx)) -> void);
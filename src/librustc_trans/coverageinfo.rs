@@ -0,0 +1,238 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Source-based code coverage (`-Z instrument-coverage`).
+//!
+//! The `InstrumentCoverage` MIR pass assigns counters to basic blocks. Here
+//! every counter becomes a call to `llvm.instrprof.increment` at the start of
+//! its block, and the source region of every counter is recorded. Once the
+//! codegen unit is complete, `finalize` writes the regions out as LLVM
+//! coverage mapping records (the `__llvm_covmap` section) which is what
+//! `llvm-cov` reads. The `instrprof` LLVM pass then lowers the increments to
+//! counters that the `profiler_builtins` runtime writes out when the program
+//! exits.
+
+use builder::Builder;
+use common::{C_array, C_bytes, C_struct, C_u32, C_u64, CodegenCx, val_ty};
+use consts;
+use declare;
+use llvm::{self, ValueRef};
+use monomorphize::Instance;
+use rustc::hir::def_id::LOCAL_CRATE;
+use rustc::mir::{self, Mir};
+use rustc_data_structures::fx::FxHashMap;
+use syntax_pos::{BytePos, FileName};
+use type_::Type;
+
+use libc::c_uint;
+use std::cell::RefCell;
+use std::ffi::CString;
+
+/// Coverage mapping data collected while translating a codegen unit.
+pub struct CrateCoverageContext {
+    filenames: RefCell<Vec<CString>>,
+    filename_indices: RefCell<FxHashMap<String, u32>>,
+    function_records: RefCell<Vec<FunctionRecord>>,
+}
+
+struct FunctionRecord {
+    /// The MD5 hash of the function's profile name.
+    name_hash: u64,
+    /// The hash of the counter layout, see `mir::CoverageInfo`.
+    hash: u64,
+    /// The encoded mapping regions of the function.
+    mapping: Vec<u8>,
+}
+
+impl CrateCoverageContext {
+    pub fn new() -> CrateCoverageContext {
+        CrateCoverageContext {
+            filenames: RefCell::new(Vec::new()),
+            filename_indices: RefCell::new(FxHashMap()),
+            function_records: RefCell::new(Vec::new()),
+        }
+    }
+
+    fn filename_index(&self, filename: String) -> u32 {
+        let mut filenames = self.filenames.borrow_mut();
+        *self.filename_indices.borrow_mut().entry(filename.clone()).or_insert_with(|| {
+            filenames.push(CString::new(filename).unwrap());
+            (filenames.len() - 1) as u32
+        })
+    }
+}
+
+/// The counters of the function being translated.
+pub struct FunctionCoverage {
+    /// The `__profn_` variable naming the function in the profile.
+    name: ValueRef,
+    hash: u64,
+    num_counters: u32,
+    counters: FxHashMap<mir::BasicBlock, u32>,
+}
+
+pub fn create_function_coverage<'a, 'tcx>(cx: &CodegenCx<'a, 'tcx>,
+                                          instance: Instance<'tcx>,
+                                          llfn: ValueRef,
+                                          mir: &Mir<'tcx>)
+                                          -> Option<FunctionCoverage> {
+    let coverage_cx = cx.coverage_cx.as_ref()?;
+    let info = mir.coverage_info.as_ref()?;
+
+    let symbol_name = CString::new(&cx.tcx.symbol_name(instance).name[..]).unwrap();
+    let (llname, name_hash) = unsafe {
+        (llvm::LLVMRustCoverageCreatePGOFuncNameVar(llfn, symbol_name.as_ptr()),
+         llvm::LLVMRustCoverageHashFunctionName(symbol_name.as_ptr()))
+    };
+
+    // All counter regions lie within the function body, so a single file
+    // covers the whole function.
+    let codemap = cx.sess().codemap();
+    let file_id = {
+        let file = codemap.lookup_char_pos(mir.span.lo()).file;
+        let filename = match file.name {
+            FileName::Real(ref path) if instance.def_id().krate == LOCAL_CRATE => {
+                cx.sess().working_dir.0.join(path).to_string_lossy().into_owned()
+            }
+            ref name => name.to_string(),
+        };
+        coverage_cx.filename_index(filename)
+    };
+
+    let line_and_column = |pos: BytePos| {
+        let line = codemap.lookup_line(pos).unwrap_or_else(|_| {
+            bug!("coverage region at {:?} has no line", pos)
+        });
+        let (line_start, _) = line.fm.line_bounds(line.line);
+        (line.line as u32 + 1, (pos - line_start).0 + 1)
+    };
+    let line_and_column = &line_and_column;
+    let regions: Vec<_> = info.counters.iter().enumerate().flat_map(|(index, counter)| {
+        counter.regions.iter().map(move |span| {
+            let (line_start, column_start) = line_and_column(span.lo());
+            let (line_end, column_end) = line_and_column(span.hi());
+            llvm::CounterRegion {
+                counter_id: index as u32,
+                file_id: 0,
+                line_start,
+                column_start,
+                line_end,
+                column_end,
+            }
+        })
+    }).collect();
+
+    let mapping = llvm::build_byte_buffer(|s| unsafe {
+        llvm::LLVMRustCoverageWriteMappingToBuffer(&file_id,
+                                                   1,
+                                                   regions.as_ptr(),
+                                                   regions.len() as c_uint,
+                                                   s);
+    });
+    coverage_cx.function_records.borrow_mut().push(FunctionRecord {
+        name_hash,
+        hash: info.hash,
+        mapping,
+    });
+
+    Some(FunctionCoverage {
+        name: consts::ptrcast(llname, Type::i8p(cx)),
+        hash: info.hash,
+        num_counters: info.counters.len() as u32,
+        counters: info.counters.iter().enumerate().map(|(index, counter)| {
+            (counter.block, index as u32)
+        }).collect(),
+    })
+}
+
+/// Increments the counter of `bb`, if it has one.
+pub fn increment_counter(bx: &Builder, coverage: &FunctionCoverage, bb: mir::BasicBlock) {
+    if let Some(&index) = coverage.counters.get(&bb) {
+        let cx = bx.cx;
+        let llfn = cx.get_intrinsic("llvm.instrprof.increment");
+        bx.call(llfn, &[
+            coverage.name,
+            C_u64(cx, coverage.hash),
+            C_u32(cx, coverage.num_counters),
+            C_u32(cx, index),
+        ], None);
+    }
+}
+
+/// Emits the coverage mapping records of every function instrumented in
+/// this codegen unit. This mirrors the layout clang uses: a header, an array
+/// of function records, and the encoded filenames followed by the encoded
+/// mappings of every function, padded to a multiple of 8 bytes.
+pub fn finalize(cx: &CodegenCx) {
+    let coverage_cx = match cx.coverage_cx {
+        Some(ref coverage_cx) => coverage_cx,
+        None => return,
+    };
+    let records = coverage_cx.function_records.borrow();
+    if records.is_empty() {
+        return;
+    }
+
+    let filenames = coverage_cx.filenames.borrow();
+    let filename_ptrs: Vec<_> = filenames.iter().map(|filename| filename.as_ptr()).collect();
+    let mut data = llvm::build_byte_buffer(|s| unsafe {
+        llvm::LLVMRustCoverageWriteFilenamesSectionToBuffer(filename_ptrs.as_ptr(),
+                                                            filename_ptrs.len(),
+                                                            s);
+    });
+    let filenames_size = data.len();
+    for record in records.iter() {
+        data.extend_from_slice(&record.mapping);
+    }
+    while data.len() % 8 != 0 {
+        data.push(0);
+    }
+    let coverage_size = data.len() - filenames_size;
+
+    let llrecords: Vec<_> = records.iter().map(|record| {
+        C_struct(cx, &[
+            C_u64(cx, record.name_hash),
+            C_u32(cx, record.mapping.len() as u32),
+            C_u64(cx, record.hash),
+        ], true)
+    }).collect();
+    let version = unsafe { llvm::LLVMRustCoverageMappingVersion() };
+    let header = C_struct(cx, &[
+        C_u32(cx, records.len() as u32),
+        C_u32(cx, filenames_size as u32),
+        C_u32(cx, coverage_size as u32),
+        C_u32(cx, version),
+    ], false);
+    let llcovmap = C_struct(cx, &[
+        header,
+        C_array(val_ty(llrecords[0]), &llrecords),
+        C_bytes(cx, &data),
+    ], false);
+
+    let name = llvm::build_string(|s| unsafe {
+        llvm::LLVMRustCoverageWriteMappingVarNameToString(s);
+    }).expect("non-UTF8 coverage mapping variable name");
+    let section = llvm::build_string(|s| unsafe {
+        llvm::LLVMRustCoverageWriteSectionNameToString(cx.llmod, s);
+    }).expect("non-UTF8 coverage mapping section name");
+    let section = CString::new(section).unwrap();
+
+    unsafe {
+        let g = declare::define_global(cx, &name, val_ty(llcovmap)).unwrap_or_else(|| {
+            bug!("symbol `{}` is already defined", name);
+        });
+        llvm::LLVMSetInitializer(g, llcovmap);
+        llvm::LLVMSetGlobalConstant(g, llvm::True);
+        llvm::LLVMRustSetLinkage(g, llvm::Linkage::InternalLinkage);
+        llvm::LLVMSetSection(g, section.as_ptr());
+        llvm::LLVMSetAlignment(g, 8);
+        cx.used_statics.borrow_mut().push(consts::ptrcast(g, Type::i8p(cx)));
+    }
+}
@@ -22,7 +22,7 @@
 
 use llvm::{self, ValueRef};
 use llvm::AttributePlace::Function;
-use rustc::ty::{self, Ty};
+use rustc::ty::{self, Instance, Ty};
 use rustc::session::config::Sanitizer;
 use rustc_back::PanicStrategy;
 use abi::{Abi, FnType};
@@ -130,7 +130,25 @@ pub fn declare_fn<'a, 'tcx>(cx: &CodegenCx<'a, 'tcx>, name: &str,
     let sig = cx.tcx.normalize_erasing_late_bound_regions(ty::ParamEnv::reveal_all(), &sig);
     debug!("declare_rust_fn (after region erasure) sig={:?}", sig);
 
-    let fty = FnType::new(cx, sig, &[]);
+    declare_rust_fn(cx, name, sig, FnType::new(cx, sig, &[]))
+}
+
+
+/// Declare the Rust function implementing `instance`.
+///
+/// Unlike `declare_fn`, this accounts for the implicit arguments of the
+/// instance, such as the caller location of `#[track_caller]` functions.
+pub fn declare_instance<'a, 'tcx>(cx: &CodegenCx<'a, 'tcx>, name: &str,
+                                  instance: Instance<'tcx>) -> ValueRef {
+    debug!("declare_instance(name={:?}, instance={:?})", name, instance);
+    let sig = common::ty_fn_sig(cx, instance.ty(cx.tcx));
+    let sig = cx.tcx.normalize_erasing_late_bound_regions(ty::ParamEnv::reveal_all(), &sig);
+
+    declare_rust_fn(cx, name, sig, FnType::of_instance(cx, &instance))
+}
+
+fn declare_rust_fn<'a, 'tcx>(cx: &CodegenCx<'a, 'tcx>, name: &str,
+                             sig: ty::FnSig<'tcx>, fty: FnType<'tcx>) -> ValueRef {
     let llfn = declare_raw_fn(cx, name, fty.cconv, fty.llvm_type(cx));
 
     // FIXME(canndrew): This is_never should really be an is_uninhabited
@@ -92,6 +92,7 @@ mod back {
     pub mod linker;
     pub mod link;
     mod lto;
+    mod stack_sizes;
     pub mod symbol_export;
     pub mod write;
     mod rpath;
@@ -126,6 +127,7 @@ mod callee;
 mod common;
 mod consts;
 mod context;
+mod coverageinfo;
 mod debuginfo;
 mod declare;
 mod glue;
@@ -98,7 +98,12 @@ pub fn get_vtable<'a, 'tcx>(cx: &CodegenCx<'a, 'tcx>,
         let methods = tcx.vtable_methods(trait_ref);
         let methods = methods.iter().cloned().map(|opt_mth| {
             opt_mth.map_or(nullptr, |(def_id, substs)| {
-                callee::resolve_and_get_fn(cx, def_id, substs)
+                callee::get_fn(cx, ty::Instance::resolve_for_fn_ptr(
+                    cx.tcx,
+                    ty::ParamEnv::reveal_all(),
+                    def_id,
+                    substs
+                ).unwrap())
             })
         });
         components.extend(methods);
@@ -19,13 +19,14 @@ use callee;
 use builder::Builder;
 use common::{self, C_bool, C_str_slice, C_struct, C_u32, C_uint_big, C_undef};
 use consts;
+use coverageinfo;
 use meth;
 use monomorphize;
 use type_of::LayoutLlvmExt;
 use type_::Type;
 
 use syntax::symbol::Symbol;
-use syntax_pos::Pos;
+use syntax_pos::{Pos, Span};
 
 use super::{FunctionCx, LocalRef};
 use super::place::PlaceRef;
@@ -39,6 +40,10 @@ impl<'a, 'tcx> FunctionCx<'a, 'tcx> {
 
         debug!("trans_block({:?}={:?})", bb, data);
 
+        if let Some(ref coverage) = self.coverage {
+            coverageinfo::increment_counter(&bx, coverage, bb);
+        }
+
         for statement in &data.statements {
             bx = self.trans_statement(bx, statement);
         }
@@ -358,11 +363,19 @@ impl<'a, 'tcx> FunctionCx<'a, 'tcx> {
                         let len = self.trans_operand(&mut bx, len).immediate();
                         let index = self.trans_operand(&mut bx, index).immediate();
 
-                        let file_line_col = C_struct(bx.cx, &[filename, line, col], false);
-                        let file_line_col = consts::addr_of(bx.cx,
-                                                            file_line_col,
-                                                            align,
-                                                            "panic_bounds_check_loc");
+                        // Blame the caller of a `#[track_caller]` function.
+                        let file_line_col = match self.caller_location {
+                            Some(caller_location) => caller_location,
+                            None => {
+                                let file_line_col = C_struct(bx.cx,
+                                                             &[filename, line, col],
+                                                             false);
+                                consts::addr_of(bx.cx,
+                                                file_line_col,
+                                                align,
+                                                "panic_bounds_check_loc")
+                            }
+                        };
                         (lang_items::PanicBoundsCheckFnLangItem,
                          vec![file_line_col, index, len])
                     }
@@ -460,7 +473,7 @@ impl<'a, 'tcx> FunctionCx<'a, 'tcx> {
                     self.monomorphize(&op_ty)
                 }).collect::<Vec<_>>();
 
-                let fn_ty = match def {
+                let mut fn_ty = match def {
                     Some(ty::InstanceDef::Virtual(..)) => {
                         FnType::new_vtable(bx.cx, sig, &extra_args)
                     }
@@ -473,6 +486,15 @@ impl<'a, 'tcx> FunctionCx<'a, 'tcx> {
                     _ => FnType::new(bx.cx, sig, &extra_args)
                 };
 
+                // `#[track_caller]` functions take the location of the call
+                // as an implicit last argument.
+                let requires_caller_location = def.map_or(false, |def| {
+                    def.requires_caller_location(bx.tcx())
+                });
+                if requires_caller_location {
+                    fn_ty.push_caller_location(bx.cx);
+                }
+
                 // The arguments we'll be passing. Plus one to account for outptr, if used.
                 let arg_count = fn_ty.args.len() + fn_ty.ret.is_indirect() as usize;
                 let mut llargs = Vec::with_capacity(arg_count);
@@ -528,9 +550,14 @@ impl<'a, 'tcx> FunctionCx<'a, 'tcx> {
                     }).collect();
 
 
-                    let callee_ty = instance.as_ref().unwrap().ty(bx.cx.tcx);
-                    trans_intrinsic_call(&bx, callee_ty, &fn_ty, &args, dest,
-                                         terminator.source_info.span);
+                    if intrinsic == Some("caller_location") {
+                        let location = self.get_caller_location(&bx, span);
+                        bx.store(location, dest, fn_ty.ret.layout.align);
+                    } else {
+                        let callee_ty = instance.as_ref().unwrap().ty(bx.cx.tcx);
+                        trans_intrinsic_call(&bx, callee_ty, &fn_ty, &args, dest,
+                                             terminator.source_info.span);
+                    }
 
                     if let ReturnDest::IndirectOperand(dst, _) = ret_dest {
                         self.store_return(&bx, ret_dest, &fn_ty.ret, dst.llval);
@@ -583,6 +610,10 @@ impl<'a, 'tcx> FunctionCx<'a, 'tcx> {
                         &fn_ty.args[first_args.len()..])
                 }
 
+                if requires_caller_location {
+                    llargs.push(self.get_caller_location(&bx, span));
+                }
+
                 let fn_ptr = match (llfn, instance) {
                     (Some(llfn), _) => llfn,
                     (None, Some(instance)) => callee::get_fn(bx.cx, instance),
@@ -600,6 +631,31 @@ impl<'a, 'tcx> FunctionCx<'a, 'tcx> {
         }
     }
 
+    /// The location to pass to a `#[track_caller]` function called at
+    /// `span`: our own caller location if we are `#[track_caller]` too,
+    /// otherwise the location of the call itself.
+    fn get_caller_location(&self, bx: &Builder<'a, 'tcx>, span: Span) -> ValueRef {
+        if let Some(caller_location) = self.caller_location {
+            return caller_location;
+        }
+
+        // Like `line!()`, point at the outermost macro invocation.
+        let tcx = bx.tcx();
+        let loc = bx.sess().codemap().lookup_char_pos(span.source_callsite().lo());
+        let filename = Symbol::intern(&loc.file.name.to_string()).as_str();
+        let filename = C_str_slice(bx.cx, filename);
+        let line = C_u32(bx.cx, loc.line as u32);
+        let col = C_u32(bx.cx, loc.col.to_usize() as u32 + 1);
+        let align = tcx.data_layout.aggregate_align
+            .max(tcx.data_layout.i32_align)
+            .max(tcx.data_layout.pointer_align);
+
+        let file_line_col = C_struct(bx.cx, &[filename, line, col], false);
+        let file_line_col = consts::addr_of(bx.cx, file_line_col, align, "caller_location");
+        let llty = bx.cx.layout_of(tcx.caller_location_ty()).llvm_type(bx.cx);
+        consts::ptrcast(file_line_col, llty)
+    }
+
     fn trans_argument(&mut self,
                       bx: &Builder<'a, 'tcx>,
                       op: OperandRef<'tcx>,
@@ -160,6 +160,9 @@ impl<'a, 'tcx> FunctionCx<'a, 'tcx> {
                 self.const_to_miri_value(bx, c)
             },
             ConstVal::Value(miri_val) => Ok(miri_val),
+            ConstVal::Param(_) | ConstVal::Infer(_) => {
+                bug!("const_to_miri_value: {:?} should have been monomorphized", constant)
+            }
         }
     }
 
@@ -20,6 +20,7 @@ use rustc::session::config::FullDebugInfo;
 use base;
 use builder::Builder;
 use common::{CodegenCx, Funclet};
+use coverageinfo::{self, FunctionCoverage};
 use debuginfo::{self, declare_local, VariableAccess, VariableKind, FunctionDebugContext};
 use monomorphize::Instance;
 use abi::{ArgAttribute, FnType, PassMode};
@@ -49,6 +50,9 @@ pub struct FunctionCx<'a, 'tcx:'a> {
 
     debug_context: debuginfo::FunctionDebugContext,
 
+    /// The coverage counters of this function, if it is instrumented.
+    coverage: Option<FunctionCoverage>,
+
     llfn: ValueRef,
 
     cx: &'a CodegenCx<'a, 'tcx>,
@@ -103,6 +107,9 @@ pub struct FunctionCx<'a, 'tcx:'a> {
 
     /// If this function is being monomorphized, this contains the type substitutions used.
     param_substs: &'tcx Substs<'tcx>,
+
+    /// The implicit caller location argument, if this is a `#[track_caller]` function.
+    caller_location: Option<ValueRef>,
 }
 
 impl<'a, 'tcx> FunctionCx<'a, 'tcx> {
@@ -204,10 +211,11 @@ pub fn trans_mir<'a, 'tcx: 'a>(
     instance: Instance<'tcx>,
     sig: ty::FnSig<'tcx>,
 ) {
-    let fn_ty = FnType::new(cx, sig, &[]);
+    let fn_ty = FnType::of_instance(cx, &instance);
     debug!("fn_ty: {:?}", fn_ty);
     let debug_context =
         debuginfo::create_function_debug_context(cx, instance, sig, llfn, mir);
+    let coverage = coverageinfo::create_function_coverage(cx, instance, llfn, mir);
     let bx = Builder::new_block(cx, llfn, "start");
 
     if mir.basic_blocks().iter().any(|bb| bb.is_cleanup) {
@@ -231,6 +239,14 @@ pub fn trans_mir<'a, 'tcx: 'a>(
     let scopes = debuginfo::create_mir_scopes(cx, mir, &debug_context);
     let (landing_pads, funclets) = create_funclets(mir, &bx, &cleanup_kinds, &block_bxs);
 
+    // The caller location is passed after all the declared arguments.
+    let caller_location = if instance.def.requires_caller_location(cx.tcx) {
+        let llarg_count = unsafe { llvm::LLVMCountParams(llfn) };
+        Some(llvm::get_param(llfn, llarg_count - 1))
+    } else {
+        None
+    };
+
     let mut fx = FunctionCx {
         instance,
         mir,
@@ -246,10 +262,12 @@ pub fn trans_mir<'a, 'tcx: 'a>(
         scopes,
         locals: IndexVec::new(),
         debug_context,
+        coverage,
         param_substs: {
             assert!(!instance.substs.needs_infer());
             instance.substs
         },
+        caller_location,
     };
 
     let memory_locals = analyze::non_ssa_locals(&fx);
@@ -197,8 +197,14 @@ impl<'a, 'tcx> FunctionCx<'a, 'tcx> {
                                     bug!("reifying a fn ptr that requires \
                                           const arguments");
                                 }
-                                OperandValue::Immediate(
-                                    callee::resolve_and_get_fn(bx.cx, def_id, substs))
+                                OperandValue::Immediate(callee::get_fn(
+                                    bx.cx,
+                                    ty::Instance::resolve_for_fn_ptr(
+                                        bx.cx.tcx,
+                                        ty::ParamEnv::reveal_all(),
+                                        def_id,
+                                        substs
+                                    ).unwrap()))
                             }
                             _ => {
                                 bug!("{} cannot be reified to a fn ptr", operand.layout.ty)
@@ -160,7 +160,7 @@ fn predefine_fn<'a, 'tcx>(cx: &CodegenCx<'a, 'tcx>,
 
     let mono_ty = instance.ty(cx.tcx);
     let attrs = instance.def.attrs(cx.tcx);
-    let lldecl = declare::declare_fn(cx, symbol_name, mono_ty);
+    let lldecl = declare::declare_instance(cx, symbol_name, instance);
     unsafe { llvm::LLVMRustSetLinkage(lldecl, base::linkage_to_llvm(linkage)) };
     base::set_link_section(cx, lldecl, &attrs);
     if linkage == Linkage::LinkOnceODR ||
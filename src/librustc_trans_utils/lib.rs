@@ -44,6 +44,7 @@ use rustc::ty::TyCtxt;
 pub mod link;
 pub mod trans_crate;
 pub mod symbol_names;
+mod symbol_names_v0;
 pub mod symbol_names_test;
 
 /// check for the #[rustc_error] annotation, which forces an
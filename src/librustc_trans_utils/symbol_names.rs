@@ -100,7 +100,7 @@
 use rustc::middle::weak_lang_items;
 use rustc_mir::monomorphize::Instance;
 use rustc_mir::monomorphize::item::{MonoItem, MonoItemExt, InstantiationMode};
-use rustc::hir::def_id::{DefId, LOCAL_CRATE};
+use rustc::hir::def_id::{CrateNum, DefId, LOCAL_CRATE};
 use rustc::hir::map as hir_map;
 use rustc::ty::{self, Ty, TyCtxt, TypeFoldable};
 use rustc::ty::fold::TypeVisitor;
@@ -108,6 +108,7 @@ use rustc::ty::item_path::{self, ItemPathBuffer, RootMode};
 use rustc::ty::maps::Providers;
 use rustc::ty::subst::Substs;
 use rustc::hir::map::definitions::DefPathData;
+use rustc::session::config::SymbolManglingVersion;
 use rustc::util::common::record_time;
 
 use syntax::attr;
@@ -115,6 +116,8 @@ use syntax_pos::symbol::Symbol;
 
 use std::fmt::Write;
 
+use symbol_names_v0;
+
 pub fn provide(providers: &mut Providers) {
     *providers = Providers {
         def_symbol_name,
@@ -170,43 +173,13 @@ fn get_symbol_hash<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
         assert!(!substs.needs_subst());
         substs.visit_with(&mut hasher);
 
-        let is_generic = substs.types().next().is_some();
-        let avoid_cross_crate_conflicts =
-            // If this is an instance of a generic function, we also hash in
-            // the ID of the instantiating crate. This avoids symbol conflicts
-            // in case the same instances is emitted in two crates of the same
-            // project.
-            is_generic ||
-
-            // If we're dealing with an instance of a function that's inlined from
-            // another crate but we're marking it as globally shared to our
-            // compliation (aka we're not making an internal copy in each of our
-            // codegen units) then this symbol may become an exported (but hidden
-            // visibility) symbol. This means that multiple crates may do the same
-            // and we want to be sure to avoid any symbol conflicts here.
-            match MonoItem::Fn(instance).instantiation_mode(tcx) {
-                InstantiationMode::GloballyShared { may_conflict: true } => true,
-                _ => false,
-            };
-
-        if avoid_cross_crate_conflicts {
-            let instantiating_crate = if is_generic {
-                if !def_id.is_local() && tcx.share_generics() {
-                    // If we are re-using a monomorphization from another crate,
-                    // we have to compute the symbol hash accordingly.
-                    let upstream_monomorphizations =
-                        tcx.upstream_monomorphizations_for(def_id);
-
-                    upstream_monomorphizations.and_then(|monos| monos.get(&substs)
-                                                                     .cloned())
-                                              .unwrap_or(LOCAL_CRATE)
-                } else {
-                    LOCAL_CRATE
-                }
-            } else {
-                LOCAL_CRATE
-            };
+        // A reify shim wraps the very instance it is named after, so keep
+        // the two symbols apart.
+        if let ty::InstanceDef::ReifyShim(..) = instance.def {
+            hasher.hash("reify-shim");
+        }
 
+        if let Some(instantiating_crate) = instantiating_crate(tcx, def_id, instance, substs) {
             hasher.hash(&tcx.original_crate_name(instantiating_crate).as_str()[..]);
             hasher.hash(&tcx.crate_disambiguator(instantiating_crate));
         }
@@ -216,6 +189,49 @@ fn get_symbol_hash<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
     hasher.finish()
 }
 
+/// The crate whose ID has to be part of the symbol name of `instance`, if
+/// the symbol could otherwise conflict with the same instance emitted by
+/// another crate.
+fn instantiating_crate<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
+                                 def_id: DefId,
+                                 instance: Instance<'tcx>,
+                                 substs: &'tcx Substs<'tcx>)
+                                 -> Option<CrateNum> {
+    let is_generic = substs.types().next().is_some();
+    let avoid_cross_crate_conflicts =
+        // If this is an instance of a generic function, we also hash in
+        // the ID of the instantiating crate. This avoids symbol conflicts
+        // in case the same instances is emitted in two crates of the same
+        // project.
+        is_generic ||
+
+        // If we're dealing with an instance of a function that's inlined from
+        // another crate but we're marking it as globally shared to our
+        // compliation (aka we're not making an internal copy in each of our
+        // codegen units) then this symbol may become an exported (but hidden
+        // visibility) symbol. This means that multiple crates may do the same
+        // and we want to be sure to avoid any symbol conflicts here.
+        match MonoItem::Fn(instance).instantiation_mode(tcx) {
+            InstantiationMode::GloballyShared { may_conflict: true } => true,
+            _ => false,
+        };
+
+    if !avoid_cross_crate_conflicts {
+        return None;
+    }
+
+    if is_generic && !def_id.is_local() && tcx.share_generics() {
+        // If we are re-using a monomorphization from another crate,
+        // we have to compute the symbol hash accordingly.
+        let upstream_monomorphizations = tcx.upstream_monomorphizations_for(def_id);
+
+        Some(upstream_monomorphizations.and_then(|monos| monos.get(&substs).cloned())
+                                       .unwrap_or(LOCAL_CRATE))
+    } else {
+        Some(LOCAL_CRATE)
+    }
+}
+
 fn def_symbol_name<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>, def_id: DefId)
                              -> ty::SymbolName
 {
@@ -277,16 +293,30 @@ fn compute_symbol_name<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>, instance: Instance
         return tcx.item_name(def_id).to_string();
     }
 
+    // The explicit symbol names below belong to the function itself, not
+    // to a shim reifying it.
+    let is_reify_shim = match instance.def {
+        ty::InstanceDef::ReifyShim(..) => true,
+        _ => false,
+    };
+
     if let Some(name) = tcx.trans_fn_attrs(def_id).export_name {
-        // Use provided name
-        return name.to_string();
+        if !is_reify_shim {
+            // Use provided name
+            return name.to_string();
+        }
     }
 
-    if attr::contains_name(&attrs, "no_mangle") {
+    if attr::contains_name(&attrs, "no_mangle") && !is_reify_shim {
         // Don't mangle
         return tcx.item_name(def_id).to_string();
     }
 
+    if tcx.sess.opts.debugging_opts.symbol_mangling_version == SymbolManglingVersion::V0 {
+        let instantiating_crate = instantiating_crate(tcx, def_id, instance, substs);
+        return symbol_names_v0::mangle(tcx, instance, instantiating_crate);
+    }
+
     // We want to compute the "type" of this item. Unfortunately, some
     // kinds of items (e.g., closures) don't have an entry in the
     // item-type array. So walk back up the find the closest parent
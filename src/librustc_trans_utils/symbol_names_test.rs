@@ -15,7 +15,9 @@
 //! paths etc in all kinds of annoying scenarios.
 
 use rustc::hir;
+use rustc::session::config::SymbolManglingVersion;
 use rustc::ty::TyCtxt;
+use rustc::ty::subst::Substs;
 use syntax::ast;
 
 use rustc_mir::monomorphize::Instance;
@@ -48,8 +50,17 @@ impl<'a, 'tcx> SymbolNamesTest<'a, 'tcx> {
         let def_id = tcx.hir.local_def_id(node_id);
         for attr in tcx.get_attrs(def_id).iter() {
             if attr.check_name(SYMBOL_NAME) {
-                // for now, can only use on monomorphic names
-                let instance = Instance::mono(tcx, def_id);
+                // The legacy scheme can only name monomorphic items, but
+                // the v0 scheme prints the parameters of a generic item
+                // as placeholders.
+                let v0 = tcx.sess.opts.debugging_opts.symbol_mangling_version ==
+                    SymbolManglingVersion::V0;
+                let instance = if v0 {
+                    let substs = Substs::identity_for_item(tcx, def_id);
+                    Instance::new(def_id, tcx.erase_regions(&substs))
+                } else {
+                    Instance::mono(tcx, def_id)
+                };
                 let name = self.tcx.symbol_name(instance);
                 tcx.sess.span_err(attr.span, &format!("symbol-name({})", name));
             } else if attr.check_name(ITEM_PATH) {
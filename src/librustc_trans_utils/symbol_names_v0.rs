@@ -0,0 +1,649 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The `v0` symbol mangling scheme, selected with
+//! `-Z symbol-mangling-version=v0`.
+//!
+//! Unlike the legacy scheme, which flattens the item path and appends an
+//! opaque hash, `v0` symbols encode everything that distinguishes one symbol
+//! from another, so they can be demangled back into a readable Rust path
+//! (see `demangle` in `libstd/sys_common/backtrace.rs`):
+//!
+//! ```text
+//! symbol        = "_R" path [instantiating-crate]
+//! path          = "C" [disambiguator] identifier           // crate root
+//!               | "M" impl-path type                       // <T>
+//!               | "X" impl-path type path                  // <T as Trait>
+//!               | "Y" type path                            // <T as Trait>
+//!               | "N" namespace path [disambiguator] identifier
+//!               | "I" path {generic-arg} "E"               // path<...>
+//!               | backref
+//! impl-path     = [disambiguator] path
+//! namespace     = "t" | "v"                                // type or value
+//!               | "C" | "S" | "A"                          // closure, shim, anonymous
+//! generic-arg   = type | "K" const
+//! type          = basic-type | path | backref
+//!               | "A" type const                           // [T; N]
+//!               | "S" type                                 // [T]
+//!               | "T" {type} "E"                           // (T, U, ...)
+//!               | "R" [lifetime] type                    // &T
+//!               | "Q" [lifetime] type                      // &mut T
+//!               | "P" type | "O" type                      // *const T, *mut T
+//!               | "F" [binder] ["U"] ["K" abi] {type} "E" type // fn(...) -> ...
+//!               | "D" [binder] {path {"p" identifier type}} "E" lifetime // dyn Trait
+//! generic-arg   = lifetime | type | "K" const
+//! lifetime      = "L" base-62-number
+//! binder        = "G" base-62-number                       // for<'a, ...>
+//! const         = type ["n"] {hex-digit} "_" | "p" | backref
+//! backref       = "B" base-62-number
+//! disambiguator = "s" base-62-number
+//! identifier    = ["u"] decimal ["_"] bytes
+//! ```
+//!
+//! Basic types are single lowercase letters (`a` = `i8`, `h` = `u8`, ...),
+//! and `p` stands for a generic parameter (or a const depending on one)
+//! that is not known, which only happens in the self type of an impl that
+//! is part of a non-generic path.
+//!
+//! Free regions never reach the mangler: the substs of an instance are
+//! already erased, and the self type and trait of an impl are normalized
+//! with their regions erased. An erased lifetime is only encoded where one
+//! is mandatory, as `L_` for the lifetime of a trait object. Late-bound
+//! regions are kept, so that `for<'a> fn(&'a u8)` and `fn(&'static u8)` get
+//! different symbols: a binder gives the number of lifetimes it binds, and
+//! `L` followed by `i > 0` refers to the `i`-th innermost of all the
+//! lifetimes bound by the enclosing binders.
+//!
+//! A path, type or const that was already printed is replaced with a
+//! backref to the position it was printed at, counted from after the `_R`,
+//! unless it refers to lifetimes bound outside of it.
+//!
+//! Identifiers containing non-ASCII characters are encoded with Punycode
+//! (RFC 3492), using `_` instead of `-` as the delimiter, and flagged with
+//! `u`; `gödel` is mangled as `u8gdel_5qa`.
+
+use rustc::hir::def_id::{CrateNum, DefId};
+use rustc::hir::map::definitions::DefPathData;
+use rustc::middle::const_val::ConstVal;
+use rustc::ty::{self, Ty, TyCtxt, TypeFoldable};
+use rustc::ty::subst::{Subst, Substs, UnpackedKind};
+use rustc_data_structures::fx::FxHashMap;
+use rustc_mir::monomorphize::Instance;
+
+use syntax::abi::Abi;
+use syntax::ast;
+
+use std::fmt::Write;
+use std::ops::Range;
+
+pub fn mangle<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
+                        instance: Instance<'tcx>,
+                        instantiating_crate: Option<CrateNum>)
+                        -> String {
+    let mut cx = SymbolMangler {
+        tcx,
+        out: String::from("_R"),
+        binders: vec![],
+        paths: FxHashMap(),
+        types: FxHashMap(),
+        consts: FxHashMap(),
+    };
+
+    // Shims are named after the item they wrap, in their own namespace.
+    match instance.def {
+        ty::InstanceDef::ReifyShim(..) => {
+            cx.out.push_str("NS");
+            cx.print_def_path(instance.def_id(), instance.substs);
+            cx.push_ident("reify");
+        }
+        _ => cx.print_def_path(instance.def_id(), instance.substs),
+    }
+
+    if let Some(cnum) = instantiating_crate {
+        cx.print_crate_root(cnum);
+    }
+
+    cx.out
+}
+
+struct SymbolMangler<'a, 'tcx: 'a> {
+    tcx: TyCtxt<'a, 'tcx, 'tcx>,
+    out: String,
+    binders: Vec<BinderLevel>,
+
+    // The positions in `out` of what can be referred to with a backref.
+    paths: FxHashMap<(DefId, &'tcx Substs<'tcx>), usize>,
+    types: FxHashMap<Ty<'tcx>, usize>,
+    consts: FxHashMap<&'tcx ty::Const<'tcx>, usize>,
+}
+
+struct BinderLevel {
+    /// The range of the lifetimes bound by this binder, counting all the
+    /// lifetimes bound by the enclosing binders before it.
+    lifetime_depths: Range<u32>,
+}
+
+impl<'a, 'tcx> SymbolMangler<'a, 'tcx> {
+    fn push_integer_62(&mut self, x: u64) {
+        if let Some(x) = x.checked_sub(1) {
+            let mut digits = vec![];
+            let mut x = x;
+            loop {
+                let d = (x % 62) as u8;
+                digits.push(match d {
+                    0...9 => b'0' + d,
+                    10...35 => b'a' + (d - 10),
+                    _ => b'A' + (d - 36),
+                });
+                x /= 62;
+                if x == 0 {
+                    break;
+                }
+            }
+            self.out.extend(digits.into_iter().rev().map(|d| d as char));
+        }
+        self.out.push('_');
+    }
+
+    fn push_disambiguator(&mut self, dis: u64) {
+        if let Some(dis) = dis.checked_sub(1) {
+            self.out.push('s');
+            self.push_integer_62(dis);
+        }
+    }
+
+    fn push_ident(&mut self, ident: &str) {
+        let punycode;
+        let ident = if ident.is_ascii() {
+            ident
+        } else {
+            self.out.push('u');
+            punycode = punycode_encode(ident).unwrap_or_else(|| {
+                bug!("symbol_names_v0: can't encode identifier `{}`", ident)
+            });
+            &punycode[..]
+        };
+
+        let _ = write!(self.out, "{}", ident.len());
+
+        // Write a separating `_` if the identifier would otherwise run
+        // into the decimal length.
+        match ident.chars().next() {
+            Some('_') | Some('0'...'9') => self.out.push('_'),
+            _ => {}
+        }
+
+        self.out.push_str(ident);
+    }
+
+    fn print_backref(&mut self, i: usize) {
+        self.out.push('B');
+        self.push_integer_62((i - "_R".len()) as u64);
+    }
+
+    fn print_crate_root(&mut self, cnum: CrateNum) {
+        self.out.push('C');
+        let fingerprint = self.tcx.crate_disambiguator(cnum).to_fingerprint();
+        self.push_disambiguator(fingerprint.to_smaller_hash());
+        let name = self.tcx.original_crate_name(cnum).as_str();
+        self.push_ident(&name);
+    }
+
+    fn print_def_path(&mut self, def_id: DefId, substs: &'tcx Substs<'tcx>) {
+        if let Some(&i) = self.paths.get(&(def_id, substs)) {
+            return self.print_backref(i);
+        }
+        let start = self.out.len();
+        self.print_def_path_uncached(def_id, substs);
+        if !substs.has_escaping_regions() {
+            self.paths.insert((def_id, substs), start);
+        }
+    }
+
+    fn print_def_path_uncached(&mut self, def_id: DefId, substs: &'tcx Substs<'tcx>) {
+        let tcx = self.tcx;
+        let key = tcx.def_key(def_id);
+        let parent_def_id = key.parent.map(|index| DefId { krate: def_id.krate, index });
+
+        let ns = match key.disambiguated_data.data {
+            DefPathData::CrateRoot => return self.print_crate_root(def_id.krate),
+            DefPathData::Impl => return self.print_impl_path(def_id, substs),
+
+            DefPathData::ClosureExpr => 'C',
+
+            DefPathData::Trait(_) |
+            DefPathData::AssocTypeInTrait(_) |
+            DefPathData::AssocTypeInImpl(_) |
+            DefPathData::TypeNs(_) |
+            DefPathData::Module(_) |
+            DefPathData::MacroDef(_) |
+            DefPathData::GlobalMetaData(_) => 't',
+
+            DefPathData::ValueNs(_) |
+            DefPathData::EnumVariant(_) |
+            DefPathData::Field(_) |
+            DefPathData::StructCtor => 'v',
+
+            DefPathData::Misc |
+            DefPathData::Initializer |
+            DefPathData::ImplTrait |
+            DefPathData::Typeof |
+            DefPathData::TypeParam(_) |
+            DefPathData::ConstParam(_) |
+            DefPathData::LifetimeDef(_) => 'A',
+        };
+
+        // Split the substs between this item and its parent. Closures and
+        // generators don't print their own (synthetic) parameters, and the
+        // `Self` parameter of a trait is printed as the type in
+        // `<Self as Trait>`, not as an argument.
+        let (parent_substs, own_substs) = if substs.is_empty() {
+            (substs, &substs[..])
+        } else {
+            let generics = tcx.generics_of(def_id);
+            let parent_count = generics.parent_count();
+            let own_start = if generics.has_self && parent_count == 0 { 1 } else { parent_count };
+            let own_substs = if ns == 'C' {
+                &[][..]
+            } else {
+                &substs[own_start..generics.count()]
+            };
+            (tcx.intern_substs(&substs[..parent_count]), own_substs)
+        };
+
+        let has_own_args = own_substs.iter().any(|k| match k.unpack() {
+            UnpackedKind::Lifetime(r) => *r != ty::ReErased,
+            _ => true,
+        });
+        if has_own_args {
+            self.out.push('I');
+        }
+
+        self.out.push('N');
+        self.out.push(ns);
+
+        let parent_def_id = parent_def_id.unwrap_or_else(|| {
+            bug!("print_def_path: {:?} has no parent", def_id)
+        });
+        match tcx.trait_of_item(def_id) {
+            // Items of traits are printed as `<Self as Trait>::item` when
+            // we know the `Self` type.
+            Some(trait_def_id) if trait_def_id == parent_def_id && !parent_substs.is_empty() => {
+                self.out.push('Y');
+                self.print_type(parent_substs.type_at(0));
+                self.print_def_path(trait_def_id, parent_substs);
+            }
+            _ => self.print_def_path(parent_def_id, parent_substs),
+        }
+
+        self.push_disambiguator(key.disambiguated_data.disambiguator as u64);
+        match key.disambiguated_data.data.get_opt_name() {
+            Some(name) => self.push_ident(&name),
+            None => self.push_ident(""),
+        }
+
+        if has_own_args {
+            self.print_generic_args(own_substs);
+            self.out.push('E');
+        }
+    }
+
+    fn print_impl_path(&mut self, impl_def_id: DefId, substs: &'tcx Substs<'tcx>) {
+        let tcx = self.tcx;
+        let key = tcx.def_key(impl_def_id);
+        let parent_def_id = DefId {
+            krate: impl_def_id.krate,
+            index: key.parent.unwrap(),
+        };
+
+        // Without substs (e.g. for an item nested in a generic impl) the
+        // impl's parameters show up as placeholders, which are normalized
+        // under the impl's own where clauses.
+        let substs = if substs.is_empty() {
+            Substs::identity_for_item(tcx, impl_def_id)
+        } else {
+            substs
+        };
+        let param_env = if substs.needs_subst() {
+            tcx.param_env(impl_def_id).with_reveal_all()
+        } else {
+            ty::ParamEnv::reveal_all()
+        };
+
+        // Erase the regions of e.g. `impl Foo for &'static str`, and
+        // normalize projections and array lengths in the self type and
+        // the trait, which are stored as written.
+        let self_ty = tcx.type_of(impl_def_id).subst(tcx, substs);
+        let self_ty = tcx.normalize_erasing_regions(param_env, self_ty);
+        let trait_ref = tcx.impl_trait_ref(impl_def_id).map(|trait_ref| {
+            tcx.normalize_erasing_regions(param_env, trait_ref.subst(tcx, substs))
+        });
+
+        self.out.push(if trait_ref.is_some() { 'X' } else { 'M' });
+        self.push_disambiguator(key.disambiguated_data.disambiguator as u64);
+        self.print_def_path(parent_def_id, Substs::empty());
+        self.print_type(self_ty);
+        if let Some(trait_ref) = trait_ref {
+            self.print_def_path(trait_ref.def_id, trait_ref.substs);
+        }
+    }
+
+    fn print_generic_args(&mut self, args: &[ty::subst::Kind<'tcx>]) {
+        for arg in args {
+            match arg.unpack() {
+                UnpackedKind::Lifetime(r) => {
+                    if *r != ty::ReErased {
+                        self.print_region(r);
+                    }
+                }
+                UnpackedKind::Type(ty) => self.print_type(ty),
+                UnpackedKind::Const(c) => {
+                    self.out.push('K');
+                    self.print_const(c);
+                }
+            }
+        }
+    }
+
+    fn print_type(&mut self, ty: Ty<'tcx>) {
+        let basic_type = match ty.sty {
+            ty::TyBool => "b",
+            ty::TyChar => "c",
+            ty::TyStr => "e",
+            ty::TyTuple(tys) if tys.is_empty() => "u",
+            ty::TyInt(ast::IntTy::I8) => "a",
+            ty::TyInt(ast::IntTy::I16) => "s",
+            ty::TyInt(ast::IntTy::I32) => "l",
+            ty::TyInt(ast::IntTy::I64) => "x",
+            ty::TyInt(ast::IntTy::I128) => "n",
+            ty::TyInt(ast::IntTy::Isize) => "i",
+            ty::TyUint(ast::UintTy::U8) => "h",
+            ty::TyUint(ast::UintTy::U16) => "t",
+            ty::TyUint(ast::UintTy::U32) => "m",
+            ty::TyUint(ast::UintTy::U64) => "y",
+            ty::TyUint(ast::UintTy::U128) => "o",
+            ty::TyUint(ast::UintTy::Usize) => "j",
+            ty::TyFloat(ast::FloatTy::F32) => "f",
+            ty::TyFloat(ast::FloatTy::F64) => "d",
+            ty::TyNever => "z",
+            ty::TyParam(_) => "p",
+            _ => "",
+        };
+        if !basic_type.is_empty() {
+            self.out.push_str(basic_type);
+            return;
+        }
+
+        if let Some(&i) = self.types.get(&ty) {
+            return self.print_backref(i);
+        }
+        let start = self.out.len();
+
+        match ty.sty {
+            ty::TyRef(r, mt) => {
+                self.out.push(match mt.mutbl {
+                    ast::Mutability::Immutable => 'R',
+                    ast::Mutability::Mutable => 'Q',
+                });
+                if *r != ty::ReErased {
+                    self.print_region(r);
+                }
+                self.print_type(mt.ty);
+            }
+            ty::TyRawPtr(mt) => {
+                self.out.push(match mt.mutbl {
+                    ast::Mutability::Immutable => 'P',
+                    ast::Mutability::Mutable => 'O',
+                });
+                self.print_type(mt.ty);
+            }
+            ty::TyArray(ty, len) => {
+                self.out.push('A');
+                self.print_type(ty);
+                self.print_const(len);
+            }
+            ty::TySlice(ty) => {
+                self.out.push('S');
+                self.print_type(ty);
+            }
+            ty::TyTuple(tys) => {
+                self.out.push('T');
+                for &ty in tys {
+                    self.print_type(ty);
+                }
+                self.out.push('E');
+            }
+            ty::TyAdt(def, substs) => self.print_def_path(def.did, substs),
+            // Only left unnormalized when it depends on placeholders, and
+            // printed as `<T as Trait>::Assoc`.
+            ty::TyProjection(data) => self.print_def_path(data.item_def_id, data.substs),
+            ty::TyAnon(def_id, substs) => self.print_def_path(def_id, substs),
+            ty::TyForeign(def_id) => self.print_def_path(def_id, Substs::empty()),
+            ty::TyFnDef(def_id, substs) |
+            ty::TyClosure(def_id, ty::ClosureSubsts { substs }) |
+            ty::TyGenerator(def_id, ty::ClosureSubsts { substs }, _) => {
+                self.print_def_path(def_id, substs);
+            }
+            ty::TyFnPtr(sig) => {
+                self.out.push('F');
+                self.in_binder(&sig, |cx, sig| {
+                    if sig.unsafety == ast::Unsafety::Unsafe {
+                        cx.out.push('U');
+                    }
+                    match sig.abi {
+                        Abi::Rust => {}
+                        Abi::C => cx.out.push_str("KC"),
+                        abi => {
+                            cx.out.push('K');
+                            cx.push_ident(&abi.name().replace('-', "_"));
+                        }
+                    }
+                    for &ty in sig.inputs() {
+                        cx.print_type(ty);
+                    }
+                    if sig.variadic {
+                        cx.out.push('v');
+                    }
+                    cx.out.push('E');
+                    cx.print_type(sig.output());
+                });
+            }
+            ty::TyDynamic(predicates, r) => {
+                self.out.push('D');
+                self.in_binder(&predicates, |cx, predicates| {
+                    let dummy_self = cx.tcx.types.err;
+                    for predicate in predicates.iter() {
+                        match *predicate {
+                            ty::ExistentialPredicate::Trait(trait_ref) => {
+                                let trait_ref = trait_ref.with_self_ty(cx.tcx, dummy_self);
+                                cx.print_def_path(trait_ref.def_id, trait_ref.substs);
+                            }
+                            ty::ExistentialPredicate::Projection(projection) => {
+                                let name = cx.tcx.associated_item(projection.item_def_id).name;
+                                cx.out.push('p');
+                                cx.push_ident(&name.as_str());
+                                cx.print_type(projection.ty);
+                            }
+                            ty::ExistentialPredicate::AutoTrait(def_id) => {
+                                cx.print_def_path(def_id, Substs::empty());
+                            }
+                        }
+                    }
+                });
+                self.out.push('E');
+                self.print_region(r);
+            }
+            _ => bug!("symbol_names_v0: unexpected type `{:?}`", ty),
+        }
+
+        if !ty.has_escaping_regions() {
+            self.types.insert(ty, start);
+        }
+    }
+
+    fn in_binder<T, F>(&mut self, value: &ty::Binder<T>, print_value: F)
+        where T: TypeFoldable<'tcx>,
+              F: FnOnce(&mut Self, &T)
+    {
+        // Number the lifetimes in order of appearance, from 1, so that the
+        // symbol doesn't depend on their names.
+        let value = self.tcx.anonymize_late_bound_regions(value);
+        let lifetimes = self.tcx.collect_referenced_late_bound_regions(&value)
+            .into_iter()
+            .map(|br| match br {
+                ty::BrAnon(i) => i,
+                _ => bug!("symbol_names_v0: unexpected bound region `{:?}`", br),
+            })
+            .max()
+            .unwrap_or(0);
+
+        if let Some(lifetimes) = (lifetimes as u64).checked_sub(1) {
+            self.out.push('G');
+            self.push_integer_62(lifetimes);
+        }
+
+        let start = self.binders.last().map_or(0, |b| b.lifetime_depths.end);
+        self.binders.push(BinderLevel {
+            lifetime_depths: start..start + lifetimes,
+        });
+        print_value(self, value.skip_binder());
+        self.binders.pop();
+    }
+
+    fn print_region(&mut self, region: ty::Region<'tcx>) {
+        let i = match *region {
+            ty::ReErased => 0,
+            ty::ReLateBound(debruijn, ty::BrAnon(i)) => {
+                let binder = &self.binders[self.binders.len() - debruijn.depth as usize];
+                let depth = binder.lifetime_depths.start + i - 1;
+                1 + (self.binders.last().unwrap().lifetime_depths.end - 1 - depth)
+            }
+            _ => bug!("symbol_names_v0: unexpected region `{:?}`", region),
+        };
+        self.out.push('L');
+        self.push_integer_62(i as u64);
+    }
+
+    fn print_const(&mut self, c: &'tcx ty::Const<'tcx>) {
+        match c.val {
+            // A length that depends on placeholders can't be evaluated.
+            ConstVal::Param(_) | ConstVal::Unevaluated(..) => {
+                self.out.push('p');
+                return;
+            }
+            _ => {}
+        }
+
+        if let Some(&i) = self.consts.get(&c) {
+            return self.print_backref(i);
+        }
+        let start = self.out.len();
+
+        self.print_type(c.ty);
+        let bits = c.val.to_raw_bits().unwrap_or_else(|| {
+            bug!("symbol_names_v0: unexpected const `{:?}`", c)
+        });
+        let bits = match c.ty.sty {
+            ty::TyInt(int_ty) => {
+                let size = int_ty.bit_width().unwrap_or_else(|| {
+                    self.tcx.data_layout.pointer_size.bits() as usize
+                });
+                let shift = 128 - size;
+                let value = ((bits << shift) as i128) >> shift;
+                if value < 0 {
+                    self.out.push('n');
+                    (value as u128).wrapping_neg()
+                } else {
+                    value as u128
+                }
+            }
+            _ => bits,
+        };
+        let _ = write!(self.out, "{:x}_", bits);
+        self.consts.insert(c, start);
+    }
+}
+
+// Parameters of the Punycode encoding, from RFC 3492.
+const PUNYCODE_BASE: u32 = 36;
+const PUNYCODE_TMIN: u32 = 1;
+const PUNYCODE_TMAX: u32 = 26;
+const PUNYCODE_SKEW: u32 = 38;
+const PUNYCODE_DAMP: u32 = 700;
+const PUNYCODE_INITIAL_BIAS: u32 = 72;
+const PUNYCODE_INITIAL_N: u32 = 128;
+
+fn punycode_adapt(delta: u32, num_points: u32, first_time: bool) -> u32 {
+    let mut delta = if first_time { delta / PUNYCODE_DAMP } else { delta / 2 };
+    delta += delta / num_points;
+    let mut k = 0;
+    while delta > ((PUNYCODE_BASE - PUNYCODE_TMIN) * PUNYCODE_TMAX) / 2 {
+        delta /= PUNYCODE_BASE - PUNYCODE_TMIN;
+        k += PUNYCODE_BASE;
+    }
+    k + (PUNYCODE_BASE - PUNYCODE_TMIN + 1) * delta / (delta + PUNYCODE_SKEW)
+}
+
+/// Encodes `input` with Punycode, except that the delimiter between the
+/// ASCII characters and the encoded rest is `_` rather than `-`, which
+/// can't appear in a symbol. Returns `None` on overflow.
+fn punycode_encode(input: &str) -> Option<String> {
+    fn digit(d: u32) -> char {
+        (if d < 26 { b'a' + d as u8 } else { b'0' + (d - 26) as u8 }) as char
+    }
+
+    let chars: Vec<u32> = input.chars().map(|c| c as u32).collect();
+    let mut out: String = input.chars().filter(|c| c.is_ascii()).collect();
+    let basic = out.len() as u32;
+    if basic > 0 {
+        out.push('_');
+    }
+
+    let mut n = PUNYCODE_INITIAL_N;
+    let mut delta: u32 = 0;
+    let mut bias = PUNYCODE_INITIAL_BIAS;
+    let mut handled = basic;
+    while (handled as usize) < chars.len() {
+        let m = chars.iter().cloned().filter(|&c| c >= n).min()?;
+        delta = delta.checked_add((m - n).checked_mul(handled + 1)?)?;
+        n = m;
+        for &c in &chars {
+            if c < n {
+                delta = delta.checked_add(1)?;
+            }
+            if c == n {
+                let mut q = delta;
+                let mut k = PUNYCODE_BASE;
+                loop {
+                    let t = if k <= bias {
+                        PUNYCODE_TMIN
+                    } else if k >= bias + PUNYCODE_TMAX {
+                        PUNYCODE_TMAX
+                    } else {
+                        k - bias
+                    };
+                    if q < t {
+                        break;
+                    }
+                    out.push(digit(t + (q - t) % (PUNYCODE_BASE - t)));
+                    q = (q - t) / (PUNYCODE_BASE - t);
+                    k += PUNYCODE_BASE;
+                }
+                out.push(digit(q));
+                bias = punycode_adapt(delta, handled + 1, handled == basic);
+                delta = 0;
+                handled += 1;
+            }
+        }
+        delta = delta.checked_add(1)?;
+        n = n.checked_add(1)?;
+    }
+    Some(out)
+}
@@ -13,6 +13,7 @@
 //! is parameterized by an instance of `AstConv`.
 
 use rustc::middle::const_val::ConstVal;
+use rustc::mir::interpret::{GlobalId, PrimVal, Value};
 use rustc_data_structures::accumulate_vec::AccumulateVec;
 use hir;
 use hir::def::Def;
@@ -56,6 +57,11 @@ pub trait AstConv<'gcx, 'tcx> {
         self.ty_infer(span)
     }
 
+    /// What const should we use when a const argument is omitted?
+    fn ct_infer_for_def(&self,
+                        def: &ty::ConstParameterDef,
+                        span: Span) -> &'tcx ty::Const<'tcx>;
+
     /// Projecting an associated type from a (potentially)
     /// higher-ranked trait reference is more complicated, because of
     /// the possibility of late-bound regions appearing in the
@@ -226,6 +232,16 @@ impl<'o, 'gcx: 'tcx, 'tcx> AstConv<'gcx, 'tcx>+'o {
             check_type_argument_count(tcx, span, num_types_provided, ty_param_defs);
         }
 
+        // Check the number of const parameters supplied by the user.
+        let num_consts_provided = parameters.consts.len();
+        let expected_num_consts = decl_generics.consts.len();
+        if (!infer_types || num_consts_provided > expected_num_consts) &&
+            num_consts_provided != expected_num_consts {
+            tcx.sess.span_err(span, &format!(
+                "wrong number of const arguments: expected {}, found {}",
+                expected_num_consts, num_consts_provided));
+        }
+
         let is_object = self_ty.map_or(false, |ty| ty.sty == TRAIT_OBJECT_DUMMY_SELF);
         let default_needs_object_self = |p: &ty::TypeParameterDef| {
             if is_object && p.has_default {
@@ -295,6 +311,17 @@ impl<'o, 'gcx: 'tcx, 'tcx> AstConv<'gcx, 'tcx>+'o {
                 // We've already errored above about the mismatch.
                 tcx.types.err
             }
+        }, |def, _| {
+            let i = def.index as usize - decl_generics.regions.len() - decl_generics.types.len();
+            if let Some(arg) = parameters.consts.get(i) {
+                // A provided const parameter.
+                self.ast_const_to_const(arg, tcx.type_of(def.def_id))
+            } else if infer_types {
+                self.ct_infer_for_def(def, span)
+            } else {
+                // We've already errored above about the mismatch.
+                tcx.mk_const_err()
+            }
         });
 
         let assoc_bindings = parameters.bindings.iter().map(|binding| {
@@ -933,6 +960,13 @@ impl<'o, 'gcx: 'tcx, 'tcx> AstConv<'gcx, 'tcx>+'o {
                     self.prohibit_projection(binding.span);
                     break;
                 }
+                for ct in &parameters.consts {
+                    self.tcx().sess.struct_span_err(ct.span,
+                                                    "const parameters are not allowed on this type")
+                        .span_label(ct.span, "const parameter not allowed")
+                        .emit();
+                    break;
+                }
             })
         }
     }
@@ -1084,12 +1118,23 @@ impl<'o, 'gcx: 'tcx, 'tcx> AstConv<'gcx, 'tcx>+'o {
                 self.associated_path_def_to_ty(ast_ty.id, ast_ty.span, ty, def, segment).0
             }
             hir::TyArray(ref ty, length) => {
-                let length_def_id = tcx.hir.body_owner_def_id(length);
-                let substs = Substs::identity_for_item(tcx, length_def_id);
-                let length = tcx.mk_const(ty::Const {
-                    val: ConstVal::Unevaluated(length_def_id, substs),
-                    ty: tcx.types.usize
-                });
+                let length = match tcx.hir.body(length).value.node {
+                    // A length that is just a const parameter, as in `[T; N]`.
+                    hir::ExprPath(hir::QPath::Resolved(None, ref path)) if match path.def {
+                        Def::ConstParam(..) => true,
+                        _ => false,
+                    } => {
+                        self.ast_const_path_to_const(path, tcx.types.usize)
+                    }
+                    _ => {
+                        let length_def_id = tcx.hir.body_owner_def_id(length);
+                        let substs = Substs::identity_for_item(tcx, length_def_id);
+                        tcx.mk_const(ty::Const {
+                            val: ConstVal::Unevaluated(length_def_id, substs),
+                            ty: tcx.types.usize
+                        })
+                    }
+                };
                 let array_ty = tcx.mk_ty(ty::TyArray(self.ast_ty_to_ty(&ty), length));
                 self.normalize_ty(ast_ty.span, array_ty)
             }
@@ -1117,6 +1162,81 @@ impl<'o, 'gcx: 'tcx, 'tcx> AstConv<'gcx, 'tcx>+'o {
         result_ty
     }
 
+    /// Converts a const argument `arg` given for a const parameter of type `ty`.
+    pub fn ast_const_to_const(&self,
+                              arg: &hir::ConstArg,
+                              ty: Ty<'tcx>)
+                              -> &'tcx ty::Const<'tcx> {
+        let tcx = self.tcx();
+        let path = match arg.node {
+            hir::ConstArgLit(ref lit, neg) => {
+                return match lit_to_const_bits(tcx, &lit.node, ty, neg) {
+                    Some(bits) => tcx.mk_const(ty::Const {
+                        val: ConstVal::Value(Value::ByVal(PrimVal::Bytes(bits))),
+                        ty,
+                    }),
+                    None => {
+                        tcx.sess.span_err(arg.span, &format!(
+                            "this const argument is not a valid value of type `{}`", ty));
+                        tcx.mk_const_err()
+                    }
+                };
+            }
+            hir::ConstArgPath(ref path) => path,
+        };
+        self.ast_const_path_to_const(path, ty)
+    }
+
+    /// Converts a path used as a const argument, which must resolve to a
+    /// const parameter or a constant of type `ty`.
+    fn ast_const_path_to_const(&self, path: &hir::Path, ty: Ty<'tcx>) -> &'tcx ty::Const<'tcx> {
+        let tcx = self.tcx();
+        self.prohibit_type_params(&path.segments);
+
+        let ct = match path.def {
+            Def::ConstParam(did) => {
+                let node_id = tcx.hir.as_local_node_id(did).unwrap();
+                let item_id = tcx.hir.get_parent_node(node_id);
+                let item_def_id = tcx.hir.local_def_id(item_id);
+                let generics = tcx.generics_of(item_def_id);
+                let index = generics.const_param_to_index[&did];
+                tcx.mk_const_param(index, tcx.hir.name(node_id).as_str(), tcx.type_of(did))
+            }
+            Def::Const(did) => {
+                let param_env = ty::ParamEnv::empty();
+                let cid = GlobalId {
+                    instance: ty::Instance::mono(tcx.global_tcx(), did),
+                    promoted: None
+                };
+                match tcx.const_eval(param_env.and(cid)) {
+                    Ok(ct) => ct,
+                    Err(err) => {
+                        err.report(tcx, path.span, "const argument");
+                        return tcx.mk_const_err();
+                    }
+                }
+            }
+            Def::Err => {
+                self.set_tainted_by_errors();
+                return tcx.mk_const_err();
+            }
+            _ => {
+                tcx.sess.span_err(path.span,
+                                  "const arguments must be literals, const parameters \
+                                   or constants");
+                return tcx.mk_const_err();
+            }
+        };
+
+        if ct.ty != ty && !ct.ty.references_error() {
+            tcx.sess.span_err(path.span, &format!(
+                "mismatched types: expected a const argument of type `{}`, found `{}`",
+                ty, ct.ty));
+            return tcx.mk_const_err();
+        }
+        ct
+    }
+
     pub fn impl_trait_ty_to_ty(&self, def_id: DefId, lifetimes: &[hir::Lifetime]) -> Ty<'tcx> {
         debug!("impl_trait_ty_to_ty(def_id={:?}, lifetimes={:?})", def_id, lifetimes);
         let tcx = self.tcx();
@@ -1131,7 +1251,8 @@ impl<'o, 'gcx: 'tcx, 'tcx> AstConv<'gcx, 'tcx>+'o {
                 &mut substs, tcx, parent_generics,
                 &mut |def, _| tcx.mk_region(
                     ty::ReEarlyBound(def.to_early_bound_region_data())),
-                &mut |def, _| tcx.mk_param_from_def(def)
+                &mut |def, _| tcx.mk_param_from_def(def),
+                &mut |def, _| tcx.mk_const_param_from_def(def)
             );
 
             // Replace all lifetimes with 'static
@@ -1366,6 +1487,48 @@ fn report_lifetime_number_error(tcx: TyCtxt, span: Span, number: usize, expected
         .emit();
 }
 
+/// Returns the bits of the value of the literal `lit`, negated if `neg` is
+/// set, as a const of type `ty`, or `None` if it is not a valid value of `ty`.
+fn lit_to_const_bits(tcx: TyCtxt, lit: &ast::LitKind, ty: Ty, neg: bool) -> Option<u128> {
+    let pointer_width = tcx.data_layout.pointer_size.bits() as usize;
+    match (lit, &ty.sty) {
+        (&ast::LitKind::Bool(b), &ty::TyBool) if !neg => Some(b as u128),
+        (&ast::LitKind::Char(c), &ty::TyChar) if !neg => Some(c as u128),
+        (&ast::LitKind::Byte(b), &ty::TyUint(ast::UintTy::U8)) if !neg => Some(b as u128),
+        (&ast::LitKind::Int(n, suffix), &ty::TyUint(uty)) if !neg => {
+            match suffix {
+                ast::LitIntType::Unsuffixed => {}
+                ast::LitIntType::Unsigned(t) if t == uty => {}
+                _ => return None,
+            }
+            let width = uty.bit_width().unwrap_or(pointer_width);
+            if width < 128 && n >> width != 0 {
+                None
+            } else {
+                Some(n)
+            }
+        }
+        (&ast::LitKind::Int(n, suffix), &ty::TyInt(ity)) => {
+            match suffix {
+                ast::LitIntType::Unsuffixed => {}
+                ast::LitIntType::Signed(t) if t == ity => {}
+                _ => return None,
+            }
+            // Signed values are stored sign-extended to 128 bits.
+            let width = ity.bit_width().unwrap_or(pointer_width);
+            let max = 1u128 << (width - 1);
+            if n > max || n == max && !neg {
+                None
+            } else if neg {
+                Some((n as i128).wrapping_neg() as u128)
+            } else {
+                Some(n)
+            }
+        }
+        _ => None,
+    }
+}
+
 // A helper struct for conveniently grouping a set of bounds which we pass to
 // and return from functions in multiple places.
 #[derive(PartialEq, Eq, Clone, Debug)]
@@ -66,7 +66,8 @@ impl<'a, 'gcx, 'tcx> FnCtxt<'a, 'gcx, 'tcx> {
             }
             PatKind::Wild |
             PatKind::Binding(..) |
-            PatKind::Ref(..) => false,
+            PatKind::Ref(..) |
+            PatKind::Or(..) => false,
         };
         if is_non_ref_pat {
             debug!("pattern is non reference pattern");
@@ -376,6 +377,12 @@ impl<'a, 'gcx, 'tcx> FnCtxt<'a, 'gcx, 'tcx> {
             PatKind::Slice(ref before, ref slice, ref after) => {
                 let expected_ty = self.structurally_resolved_type(pat.span, expected);
                 let (inner_ty, slice_ty) = match expected_ty.sty {
+                    ty::TyArray(inner_ty, size) if size.val.to_raw_bits().is_none() => {
+                        tcx.sess.span_err(pat.span, &format!(
+                            "cannot pattern-match on an array without a fixed length: `{}`",
+                            expected_ty));
+                        (inner_ty, tcx.types.err)
+                    }
                     ty::TyArray(inner_ty, size) => {
                         let size = size.val.unwrap_u64();
                         let min_len = before.len() as u64 + after.len() as u64;
@@ -437,6 +444,14 @@ impl<'a, 'gcx, 'tcx> FnCtxt<'a, 'gcx, 'tcx> {
                 }
                 expected_ty
             }
+            PatKind::Or(ref alternatives) => {
+                // Every alternative is checked against the same type and binding mode;
+                // the bindings they have in common are unified through their canonical IDs.
+                for alternative in alternatives {
+                    self.check_pat_walk(&alternative, expected, def_bm, is_arg);
+                }
+                expected
+            }
         };
 
         self.write_ty(pat.hir_id, ty);
@@ -673,6 +688,18 @@ https://doc.rust-lang.org/reference/types.html#trait-objects");
             let arm_ty = self.check_expr_with_expectation(&arm.body, expected);
             all_arms_diverge &= self.diverges.get();
 
+            // The `else` block of a desugared `let...else` must not fall
+            // through to the code using the bindings of the pattern.
+            if match_src == hir::MatchSource::LetElseDesugar && i == arms.len() - 1 &&
+               !self.diverges.get().always() {
+                struct_span_err!(tcx.sess, arm.body.span, E0912,
+                                 "`else` clause of `let...else` does not diverge")
+                    .span_label(arm.body.span, format!("expected `!`, found `{}`", arm_ty))
+                    .help("try adding a diverging expression, such as `return` or `panic!(..)`")
+                    .emit();
+                continue;
+            }
+
             // Handle the fallback arm of a desugared if-let like a missing else.
             let is_if_let_fallback = match match_src {
                 hir::MatchSource::IfLetDesugar { contains_else_clause: false } => {
@@ -112,6 +112,7 @@ impl<'a, 'gcx, 'tcx> FnCtxt<'a, 'gcx, 'tcx> {
                 self.infcx
                     .next_ty_var(TypeVariableOrigin::ClosureSynthetic(expr.span))
             },
+            |_, _| span_bug!(expr.span, "closure has const param"),
         );
         let substs = ty::ClosureSubsts { substs };
         let closure_type = self.tcx.mk_closure(expr_def_id, substs);
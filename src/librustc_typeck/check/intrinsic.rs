@@ -150,6 +150,7 @@ pub fn check_intrinsic_type<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
             "needs_drop" => (1, Vec::new(), tcx.types.bool),
 
             "type_name" => (1, Vec::new(), tcx.mk_static_str()),
+            "caller_location" => (0, Vec::new(), tcx.caller_location_ty()),
             "type_id" => (1, Vec::new(), tcx.types.u64),
             "offset" | "arith_offset" => {
               (1,
@@ -339,6 +339,20 @@ impl<'a, 'gcx, 'tcx> ConfirmContext<'a, 'gcx, 'tcx> {
             } else {
                 self.type_var_for_def(self.span, def)
             }
+        }, |def, _cur_substs| {
+            let i = def.index as usize;
+            if i < parent_substs.len() {
+                parent_substs.const_at(i)
+            } else if let Some(ct)
+                = provided.as_ref().and_then(|p| {
+                    p.consts.get(i - parent_substs.len() - method_generics.regions.len() -
+                                 method_generics.types.len())
+                })
+            {
+                AstConv::ast_const_to_const(self.fcx, ct, self.tcx.type_of(def.def_id))
+            } else {
+                self.const_var_for_def(def)
+            }
         })
     }
 
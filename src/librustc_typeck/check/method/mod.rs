@@ -264,7 +264,7 @@ impl<'a, 'gcx, 'tcx> FnCtxt<'a, 'gcx, 'tcx> {
             } else {
                 self.type_var_for_def(span, def)
             }
-        });
+        }, |def, _| self.const_var_for_def(def));
 
         let trait_ref = ty::TraitRef::new(trait_def_id, substs);
 
@@ -1380,12 +1380,13 @@ impl<'a, 'gcx, 'tcx> ProbeContext<'a, 'gcx, 'tcx> {
         let generics = self.tcx.generics_of(method);
         assert_eq!(substs.types().count(), generics.parent_types as usize);
         assert_eq!(substs.regions().count(), generics.parent_regions as usize);
+        assert_eq!(substs.consts().count(), generics.parent_consts as usize);
 
         // Erase any late-bound regions from the method and substitute
         // in the values from the substitution.
         let xform_fn_sig = self.erase_late_bound_regions(&fn_sig);
 
-        if generics.types.is_empty() && generics.regions.is_empty() {
+        if generics.types.is_empty() && generics.regions.is_empty() && generics.consts.is_empty() {
             xform_fn_sig.subst(self.tcx, substs)
         } else {
             let substs = Substs::for_item(self.tcx, method, |def, _| {
@@ -1404,6 +1405,13 @@ impl<'a, 'gcx, 'tcx> ProbeContext<'a, 'gcx, 'tcx> {
                 } else {
                     self.type_var_for_def(self.span, def)
                 }
+            }, |def, _cur_substs| {
+                let i = def.index as usize;
+                if i < substs.len() {
+                    substs.const_at(i)
+                } else {
+                    self.const_var_for_def(def)
+                }
             });
             xform_fn_sig.subst(self.tcx, substs)
         }
@@ -1420,7 +1428,8 @@ impl<'a, 'gcx, 'tcx> ProbeContext<'a, 'gcx, 'tcx> {
                          |_, _| self.tcx.types.re_erased,
                          |_, _| self.next_ty_var(
                              TypeVariableOrigin::SubstitutionPlaceholder(
-                                 self.tcx.def_span(def_id))))
+                                 self.tcx.def_span(def_id))),
+                         |def, _| self.const_var_for_def(def))
     }
 
     /// Replace late-bound-regions bound by `value` with `'static` using
@@ -962,6 +962,16 @@ impl<'a, 'gcx, 'tcx> Visitor<'gcx> for GatherLocalsVisitor<'a, 'gcx, 'tcx> {
             Some(ref ty) => {
                 let o_ty = self.fcx.to_ty(&ty);
 
+                // The concrete type of `let x: impl Trait = ..;` is inferred
+                // from the initializer, like that of a returned `impl Trait`.
+                let o_ty = match ty.node {
+                    hir::TyImplTraitExistential(..) => {
+                        let item_id = self.fcx.tcx.hir.get_parent(local.id);
+                        self.fcx.instantiate_anon_types_from_value(item_id, &o_ty)
+                    }
+                    _ => o_ty,
+                };
+
                 let (c_ty, _orig_values) = self.fcx.inh.infcx.canonicalize_response(&o_ty);
                 debug!("visit_local: ty.hir_id={:?} o_ty={:?} c_ty={:?}", ty.hir_id, o_ty, c_ty);
                 self.fcx.tables.borrow_mut().user_provided_tys_mut().insert(ty.hir_id, c_ty);
@@ -1038,7 +1048,7 @@ fn check_fn<'a, 'gcx, 'tcx>(inherited: &'a Inherited<'a, 'gcx, 'tcx>,
 
     let ret_ty = fn_sig.output();
     fcx.require_type_is_sized(ret_ty, decl.output.span(), traits::SizedReturnType);
-    let ret_ty = fcx.instantiate_anon_types_from_return_value(fn_id, &ret_ty);
+    let ret_ty = fcx.instantiate_anon_types_from_value(fn_id, &ret_ty);
     fcx.ret_coercion = Some(RefCell::new(CoerceMany::new(ret_ty)));
     fn_sig = fcx.tcx.mk_fn_sig(
         fn_sig.inputs().iter().cloned(),
@@ -1754,6 +1764,12 @@ impl<'a, 'gcx, 'tcx> AstConv<'gcx, 'tcx> for FnCtxt<'a, 'gcx, 'tcx> {
         self.type_var_for_def(span, ty_param_def)
     }
 
+    fn ct_infer_for_def(&self,
+                        def: &ty::ConstParameterDef,
+                        _span: Span) -> &'tcx ty::Const<'tcx> {
+        self.const_var_for_def(def)
+    }
+
     fn projected_ty_from_poly_trait_ref(&self,
                                         span: Span,
                                         item_def_id: DefId,
@@ -2036,17 +2052,17 @@ impl<'a, 'gcx, 'tcx> FnCtxt<'a, 'gcx, 'tcx> {
     }
 
     /// Replace the anonymized types from the return value of the
-    /// function with type variables and records the `AnonTypeMap` for
-    /// later use during writeback. See
-    /// `InferCtxt::instantiate_anon_types` for more details.
-    fn instantiate_anon_types_from_return_value<T: TypeFoldable<'tcx>>(
+    /// function (or the type of a `let` binding in it) with type
+    /// variables and records the `AnonTypeMap` for later use during
+    /// writeback. See `InferCtxt::instantiate_anon_types` for more details.
+    fn instantiate_anon_types_from_value<T: TypeFoldable<'tcx>>(
         &self,
         fn_id: ast::NodeId,
         value: &T,
     ) -> T {
         let fn_def_id = self.tcx.hir.local_def_id(fn_id);
         debug!(
-            "instantiate_anon_types_from_return_value(fn_def_id={:?}, value={:?})",
+            "instantiate_anon_types_from_value(fn_def_id={:?}, value={:?})",
             fn_def_id,
             value
         );
@@ -4714,8 +4730,8 @@ impl<'a, 'gcx, 'tcx> FnCtxt<'a, 'gcx, 'tcx> {
                 fn_segment = Some((segments.last().unwrap(), generics));
             }
 
-            // Case 4. Local variable, no generics.
-            Def::Local(..) | Def::Upvar(..) => {}
+            // Case 4. Local variable or const parameter, no generics.
+            Def::Local(..) | Def::Upvar(..) | Def::ConstParam(..) => {}
 
             _ => bug!("unexpected definition: {:?}", def),
         }
@@ -4737,6 +4753,11 @@ impl<'a, 'gcx, 'tcx> FnCtxt<'a, 'gcx, 'tcx> {
                 self.write_ty(self.tcx.hir.node_to_hir_id(node_id), ty);
                 return ty;
             }
+            Def::ConstParam(def_id) => {
+                let ty = self.tcx.type_of(def_id);
+                self.write_ty(self.tcx.hir.node_to_hir_id(node_id), ty);
+                return ty;
+            }
             _ => {}
         }
 
@@ -4820,6 +4841,30 @@ impl<'a, 'gcx, 'tcx> FnCtxt<'a, 'gcx, 'tcx> {
                 // TyError to let type inference recover somewhat.
                 self.type_var_for_def(span, def)
             }
+        }, |def, _| {
+            let mut i = def.index as usize;
+
+            let segment = if i < fn_start {
+                type_segment
+            } else {
+                i -= fn_start;
+                fn_segment
+            };
+            let consts = segment.map_or(&[][..], |(s, _)| {
+                s.parameters.as_ref().map_or(&[][..], |p| &p.consts[..])
+            });
+
+            // Skip over the lifetimes and types in the same segment.
+            if let Some((_, generics)) = segment {
+                i -= generics.regions.len() + generics.types.len();
+            }
+
+            if let Some(ct) = consts.get(i) {
+                // A provided const parameter.
+                AstConv::ast_const_to_const(self, ct, self.tcx.type_of(def.def_id))
+            } else {
+                self.const_var_for_def(def)
+            }
         });
 
         // The things we are substituting into the type should not contain
@@ -4953,6 +4998,22 @@ impl<'a, 'gcx, 'tcx> FnCtxt<'a, 'gcx, 'tcx> {
                 .emit();
         }
 
+        // Check provided const parameters.
+        let consts = segment.map_or(&[][..], |(s, _)| {
+            s.parameters.as_ref().map_or(&[][..], |p| &p.consts[..])
+        });
+        let const_defs = segment.map_or(&[][..], |(_, generics)| &generics.consts[..]);
+        if consts.len() > const_defs.len() ||
+           consts.len() < const_defs.len() && !infer_types && !supress_mismatch_error {
+            self.tcx.sess.span_err(span, &format!(
+                "wrong number of const arguments: expected {}, found {}",
+                const_defs.len(), consts.len()));
+            if consts.len() > const_defs.len() {
+                // As above, don't use the extra const arguments.
+                *segment = None;
+            }
+        }
+
         if !bindings.is_empty() {
             AstConv::prohibit_projection(self, bindings[0].span);
         }
@@ -39,6 +39,20 @@
 //! `ty::InferBorrow(upvar_id)` or something like that, but this would
 //! then mean that all later passes would have to check for these figments
 //! and report an error, and it just seems like more mess in the end.)
+//!
+//! ### Capturing disjoint fields
+//!
+//! Under `#![feature(capture_disjoint_fields)]`, closures (but not
+//! generators) capture the places they use instead of whole variables, so
+//! that `|| self.b.len()` only borrows `self.b`. Alongside the borrow kinds
+//! above, every use of a place rooted at an upvar is recorded together with
+//! the capture it needs, and the uses are then merged into the captured
+//! places of each variable (see `compute_upvar_places`). Paths are cut short
+//! where a place could not be captured on its own: at derefs of raw
+//! pointers and at fields of unions and packed structs, as well as at any
+//! deref and at fields of types with a destructor for places captured by
+//! value. A variable that ends up captured through several places is stored
+//! in the closure as a tuple of them.
 
 use super::FnCtxt;
 
@@ -53,6 +67,7 @@ use syntax_pos::Span;
 use rustc::hir;
 use rustc::hir::def_id::LocalDefId;
 use rustc::hir::intravisit::{self, NestedVisitorMap, Visitor};
+use rustc::util::nodemap::FxHashMap;
 
 impl<'a, 'gcx, 'tcx> FnCtxt<'a, 'gcx, 'tcx> {
     pub fn closure_analyze(&self, body: &'gcx hir::Body) {
@@ -155,6 +170,8 @@ impl<'a, 'gcx, 'tcx> FnCtxt<'a, 'gcx, 'tcx> {
             }
         });
 
+        let capture_places = gen.is_none() && self.tcx.features().capture_disjoint_fields;
+
         let body_owner_def_id = self.tcx.hir.body_owner_def_id(body.id());
         let region_scope_tree = &self.tcx.region_scope_tree(body_owner_def_id);
         let mut delegate = InferBorrowKind {
@@ -163,6 +180,7 @@ impl<'a, 'gcx, 'tcx> FnCtxt<'a, 'gcx, 'tcx> {
             current_closure_kind: ty::ClosureKind::LATTICE_BOTTOM,
             current_origin: None,
             adjust_upvar_captures: ty::UpvarCaptureMap::default(),
+            place_uses: if capture_places { Some(FxHashMap()) } else { None },
         };
         euv::ExprUseVisitor::with_infer(
             &mut delegate,
@@ -172,6 +190,26 @@ impl<'a, 'gcx, 'tcx> FnCtxt<'a, 'gcx, 'tcx> {
             &self.tables.borrow(),
         ).consume_body(body);
 
+        if capture_places {
+            // Not every place the body mentions is reported to the delegate
+            // (e.g. `let _ = x.a;`), but all of them have to be covered by a
+            // captured place when the body is lowered to MIR.
+            let mentioned_places = {
+                let tables = self.tables.borrow();
+                let mut visitor = UpvarPlaceVisitor {
+                    mc: mc::MemCategorizationContext::with_infer(&self.infcx,
+                                                                 region_scope_tree,
+                                                                 &tables),
+                    places: vec![],
+                };
+                visitor.visit_body(body);
+                visitor.places
+            };
+            for cmt in mentioned_places {
+                delegate.record_place_use(&cmt, PlaceAccess::Borrow(ty::ImmBorrow));
+            }
+        }
+
         if infer_kind {
             // Unify the (as yet unbound) type variable in the closure
             // substs with the kind we inferred.
@@ -193,6 +231,10 @@ impl<'a, 'gcx, 'tcx> FnCtxt<'a, 'gcx, 'tcx> {
             .upvar_capture_map
             .extend(delegate.adjust_upvar_captures);
 
+        if let Some(place_uses) = delegate.place_uses {
+            self.compute_upvar_places(place_uses);
+        }
+
         // Now that we've analyzed the closure, we know how each
         // variable is borrowed, and we know what traits the closure
         // implements (Fn vs FnMut etc). We now have some updates to do
@@ -228,6 +270,41 @@ impl<'a, 'gcx, 'tcx> FnCtxt<'a, 'gcx, 'tcx> {
         }
     }
 
+    /// Merges the place uses recorded for each upvar into the places the
+    /// closure captures. A use of a place contained in another used place
+    /// is covered by capturing the latter, with the strongest capture that
+    /// either of them needs. An upvar whose merged place is the variable
+    /// itself is captured as a whole, exactly as without
+    /// `capture_disjoint_fields`.
+    fn compute_upvar_places(&self,
+                            place_uses: FxHashMap<ty::UpvarId, Vec<ty::CapturedPlace<'tcx>>>) {
+        for (upvar_id, mut uses) in place_uses {
+            // Shorter places first, so that a place is always merged into
+            // the places containing it.
+            uses.sort_by_key(|place_use| place_use.projections.len());
+
+            let mut places: Vec<ty::CapturedPlace<'tcx>> = vec![];
+            for place_use in uses {
+                let projections: Vec<_> = place_use.projections
+                    .iter()
+                    .map(|&(projection, _)| projection)
+                    .collect();
+                match places.iter_mut().find(|place| place.is_prefix_of(&projections)) {
+                    Some(place) => {
+                        place.capture = stronger_capture(place.capture, place_use.capture);
+                    }
+                    None => places.push(place_use),
+                }
+            }
+
+            debug!("compute_upvar_places: upvar_id={:?} places={:?}", upvar_id, places);
+
+            if places.iter().all(|place| !place.projections.is_empty()) {
+                self.tables.borrow_mut().upvar_places_map.insert(upvar_id, places);
+            }
+        }
+    }
+
     // Returns a list of `ClosureUpvar`s for each upvar.
     fn final_upvar_tys(&self, closure_id: ast::NodeId) -> Vec<Ty<'tcx>> {
         // Presently an unboxed closure type cannot "escape" out of a
@@ -249,6 +326,9 @@ impl<'a, 'gcx, 'tcx> FnCtxt<'a, 'gcx, 'tcx> {
                         var_id: var_hir_id,
                         closure_expr_id: LocalDefId::from_def_id(closure_def_index),
                     };
+                    if let Some(places) = self.tables.borrow().upvar_places(upvar_id) {
+                        return tcx.mk_tup(places.iter().map(|place| place.captured_ty(tcx)));
+                    }
                     let capture = self.tables.borrow().upvar_capture(upvar_id);
 
                     debug!(
@@ -295,9 +375,99 @@ struct InferBorrowKind<'a, 'gcx: 'a + 'tcx, 'tcx: 'a> {
     // For each upvar that we access, we track the minimal kind of
     // access we need (ref, ref mut, move, etc).
     adjust_upvar_captures: ty::UpvarCaptureMap<'tcx>,
+
+    // When capturing disjoint fields, the places rooted at each upvar that
+    // are used, along with the capture each use needs.
+    place_uses: Option<FxHashMap<ty::UpvarId, Vec<ty::CapturedPlace<'tcx>>>>,
+}
+
+#[derive(Copy, Clone, Debug)]
+enum PlaceAccess {
+    Move,
+    Borrow(ty::BorrowKind),
 }
 
 impl<'a, 'gcx, 'tcx> InferBorrowKind<'a, 'gcx, 'tcx> {
+    /// Records that the place `cmt` is used in a way that requires
+    /// `access`, if it is rooted at an upvar of the closure whose captures
+    /// are being inferred and that closure captures places.
+    fn record_place_use(&mut self, cmt: &mc::cmt<'tcx>, access: PlaceAccess) {
+        let fcx = self.fcx;
+        let closure_def_id = self.closure_def_id;
+        let place_uses = match self.place_uses {
+            Some(ref mut place_uses) => place_uses,
+            None => return,
+        };
+        let place = match cmt.upvar_place() {
+            Some(place) => place,
+            None => return,
+        };
+        if place.upvar_id.closure_expr_id.to_def_id() != closure_def_id {
+            return;
+        }
+
+        let base_ty = |i: usize| {
+            let ty = if i == 0 { place.var_ty } else { place.projections[i - 1].1 };
+            fcx.resolve_type_vars_if_possible(&ty)
+        };
+        let derefs_borrowed_ptr = (0..place.projections.len()).any(|i| {
+            place.projections[i].0 == ty::CaptureProjection::Deref && !base_ty(i).is_box()
+        });
+
+        // The upvar was seeded with the capture of a `move` closure or with a
+        // shared borrow, so this tells us which kind of closure this is.
+        let mut capture = match (fcx.tables.borrow().upvar_capture(place.upvar_id), access) {
+            (ty::UpvarCapture::ByValue, _) => ty::UpvarCapture::ByValue,
+            (ty::UpvarCapture::ByRef(borrow), PlaceAccess::Move) => {
+                if derefs_borrowed_ptr {
+                    // Moving out of borrowed content is reported by
+                    // borrowck; all we need is to be able to see the place.
+                    ty::UpvarCapture::ByRef(ty::UpvarBorrow { kind: ty::ImmBorrow, ..borrow })
+                } else {
+                    ty::UpvarCapture::ByValue
+                }
+            }
+            (ty::UpvarCapture::ByRef(borrow), PlaceAccess::Borrow(kind)) => {
+                ty::UpvarCapture::ByRef(ty::UpvarBorrow { kind, ..borrow })
+            }
+        };
+        let by_value = match capture {
+            ty::UpvarCapture::ByValue => true,
+            ty::UpvarCapture::ByRef(..) => false,
+        };
+
+        let tcx = fcx.tcx;
+        let cut = (0..place.projections.len()).find(|&i| {
+            match (place.projections[i].0, &base_ty(i).sty) {
+                (ty::CaptureProjection::Deref, &ty::TyRawPtr(..)) => true,
+                (ty::CaptureProjection::Deref, _) => by_value,
+                (ty::CaptureProjection::Field(..), &ty::TyAdt(def, _)) => {
+                    def.is_union() || def.repr.packed() || (by_value && def.has_dtor(tcx))
+                }
+                (ty::CaptureProjection::Field(..), _) => false,
+            }
+        });
+
+        let mut projections = place.projections.clone();
+        if let Some(cut) = cut {
+            if let ty::TyRawPtr(..) = base_ty(cut).sty {
+                // Only the pointer has to be read to go through it.
+                if let ty::UpvarCapture::ByRef(ref mut borrow) = capture {
+                    borrow.kind = ty::ImmBorrow;
+                }
+            }
+            projections.truncate(cut);
+        }
+
+        debug!("record_place_use(cmt={:?}, access={:?}): projections={:?} capture={:?}",
+               cmt, access, projections, capture);
+
+        place_uses.entry(place.upvar_id).or_insert(vec![]).push(ty::CapturedPlace {
+            projections,
+            capture,
+        });
+    }
+
     fn adjust_upvar_borrow_kind_for_consume(&mut self, cmt: mc::cmt<'tcx>, mode: euv::ConsumeMode) {
         debug!(
             "adjust_upvar_borrow_kind_for_consume(cmt={:?}, mode={:?})",
@@ -585,14 +755,17 @@ impl<'a, 'gcx, 'tcx> euv::Delegate<'tcx> for InferBorrowKind<'a, 'gcx, 'tcx> {
         mode: euv::ConsumeMode,
     ) {
         debug!("consume(cmt={:?},mode={:?})", cmt, mode);
+        self.record_place_use(&cmt, consume_access(mode));
         self.adjust_upvar_borrow_kind_for_consume(cmt, mode);
     }
 
-    fn matched_pat(&mut self, _matched_pat: &hir::Pat, _cmt: mc::cmt<'tcx>, _mode: euv::MatchMode) {
+    fn matched_pat(&mut self, _matched_pat: &hir::Pat, cmt: mc::cmt<'tcx>, _mode: euv::MatchMode) {
+        self.record_place_use(&cmt, PlaceAccess::Borrow(ty::ImmBorrow));
     }
 
     fn consume_pat(&mut self, _consume_pat: &hir::Pat, cmt: mc::cmt<'tcx>, mode: euv::ConsumeMode) {
         debug!("consume_pat(cmt={:?},mode={:?})", cmt, mode);
+        self.record_place_use(&cmt, consume_access(mode));
         self.adjust_upvar_borrow_kind_for_consume(cmt, mode);
     }
 
@@ -612,6 +785,8 @@ impl<'a, 'gcx, 'tcx> euv::Delegate<'tcx> for InferBorrowKind<'a, 'gcx, 'tcx> {
             bk
         );
 
+        self.record_place_use(&cmt, PlaceAccess::Borrow(bk));
+
         match bk {
             ty::ImmBorrow => {}
             ty::UniqueImmBorrow => {
@@ -634,10 +809,62 @@ impl<'a, 'gcx, 'tcx> euv::Delegate<'tcx> for InferBorrowKind<'a, 'gcx, 'tcx> {
     ) {
         debug!("mutate(assignee_cmt={:?})", assignee_cmt);
 
+        self.record_place_use(&assignee_cmt, PlaceAccess::Borrow(ty::MutBorrow));
         self.adjust_upvar_borrow_kind_for_mut(assignee_cmt);
     }
 }
 
+fn consume_access(mode: euv::ConsumeMode) -> PlaceAccess {
+    match mode {
+        euv::Copy => PlaceAccess::Borrow(ty::ImmBorrow),
+        euv::Move(_) => PlaceAccess::Move,
+    }
+}
+
+/// Returns the capture that allows for the uses permitted by both `a` and
+/// `b`. Both captures are of the same upvar, so they share its region.
+fn stronger_capture<'tcx>(a: ty::UpvarCapture<'tcx>,
+                          b: ty::UpvarCapture<'tcx>)
+                          -> ty::UpvarCapture<'tcx> {
+    match (a, b) {
+        (ty::UpvarCapture::ByValue, _) |
+        (_, ty::UpvarCapture::ByValue) => ty::UpvarCapture::ByValue,
+        (ty::UpvarCapture::ByRef(a_borrow), ty::UpvarCapture::ByRef(b_borrow)) => {
+            let kind = match (a_borrow.kind, b_borrow.kind) {
+                (ty::MutBorrow, _) | (_, ty::MutBorrow) => ty::MutBorrow,
+                (ty::UniqueImmBorrow, _) | (_, ty::UniqueImmBorrow) => ty::UniqueImmBorrow,
+                (ty::ImmBorrow, ty::ImmBorrow) => ty::ImmBorrow,
+            };
+            ty::UpvarCapture::ByRef(ty::UpvarBorrow { kind, ..a_borrow })
+        }
+    }
+}
+
+/// Collects the place expressions in a closure body that are rooted at one
+/// of its upvars, without descending into them. These are exactly the
+/// expressions that MIR construction lowers through the captured places.
+struct UpvarPlaceVisitor<'a, 'gcx: 'a + 'tcx, 'tcx: 'a> {
+    mc: mc::MemCategorizationContext<'a, 'gcx, 'tcx>,
+    places: Vec<mc::cmt<'tcx>>,
+}
+
+impl<'a, 'gcx, 'tcx, 'v> Visitor<'v> for UpvarPlaceVisitor<'a, 'gcx, 'tcx> {
+    fn nested_visit_map<'this>(&'this mut self) -> NestedVisitorMap<'this, 'v> {
+        NestedVisitorMap::None
+    }
+
+    fn visit_expr(&mut self, expr: &'v hir::Expr) {
+        if let Ok(cmt) = self.mc.cat_expr_unadjusted(expr) {
+            if cmt.upvar_place().map_or(false, |place| place.complete) {
+                self.places.push(cmt);
+                return;
+            }
+        }
+
+        intravisit::walk_expr(self, expr);
+    }
+}
+
 fn var_name(tcx: TyCtxt, var_hir_id: hir::HirId) -> ast::Name {
     let var_node_id = tcx.hir.hir_to_node_id(var_hir_id);
     tcx.hir.name(var_node_id)
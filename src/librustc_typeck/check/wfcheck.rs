@@ -409,6 +409,9 @@ fn check_where_clauses<'a, 'gcx, 'fcx, 'tcx>(tcx: TyCtxt<'a, 'gcx, 'gcx>,
             }
             // Mark unwanted params as err.
             fcx.tcx.types.err
+        }, |def, _| {
+            // All consts are identity.
+            fcx.tcx.mk_const_param_from_def(def)
         });
     // Now we build the substituted predicates.
     for &pred in predicates.predicates.iter() {
@@ -612,6 +615,7 @@ fn check_variances_for_type_defn<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
         let (span, name) = match ast_generics.params[index] {
             hir::GenericParam::Lifetime(ref ld) => (ld.lifetime.span, ld.lifetime.name.name()),
             hir::GenericParam::Type(ref tp) => (tp.span, tp.name),
+            hir::GenericParam::Const(ref cp) => (cp.span, cp.name),
         };
         report_bivariance(tcx, span, name);
     }
@@ -19,8 +19,9 @@ use rustc::hir::intravisit::{self, NestedVisitorMap, Visitor};
 use rustc::infer::InferCtxt;
 use rustc::ty::{self, Ty, TyCtxt};
 use rustc::ty::adjustment::{Adjust, Adjustment};
+use rustc::ty::subst::Substs;
 use rustc::ty::fold::{TypeFoldable, TypeFolder};
-use rustc::util::nodemap::DefIdSet;
+use rustc::util::nodemap::{DefIdSet, FxHashSet};
 use syntax::ast;
 use syntax_pos::Span;
 use std::mem;
@@ -297,17 +298,7 @@ impl<'cx, 'gcx, 'tcx> Visitor<'gcx> for WritebackCx<'cx, 'gcx, 'tcx> {
 impl<'cx, 'gcx, 'tcx> WritebackCx<'cx, 'gcx, 'tcx> {
     fn visit_upvar_borrow_map(&mut self) {
         for (upvar_id, upvar_capture) in self.fcx.tables.borrow().upvar_capture_map.iter() {
-            let new_upvar_capture = match *upvar_capture {
-                ty::UpvarCapture::ByValue => ty::UpvarCapture::ByValue,
-                ty::UpvarCapture::ByRef(ref upvar_borrow) => {
-                    let r = upvar_borrow.region;
-                    let r = self.resolve(&r, &upvar_id.var_id);
-                    ty::UpvarCapture::ByRef(ty::UpvarBorrow {
-                        kind: upvar_borrow.kind,
-                        region: r,
-                    })
-                }
-            };
+            let new_upvar_capture = self.resolve_upvar_capture(*upvar_id, upvar_capture);
             debug!(
                 "Upvar capture for {:?} resolved to {:?}",
                 upvar_id,
@@ -317,6 +308,41 @@ impl<'cx, 'gcx, 'tcx> WritebackCx<'cx, 'gcx, 'tcx> {
                 .upvar_capture_map
                 .insert(*upvar_id, new_upvar_capture);
         }
+
+        for (upvar_id, places) in self.fcx.tables.borrow().upvar_places_map.iter() {
+            let new_places = places
+                .iter()
+                .map(|place| ty::CapturedPlace {
+                    projections: place
+                        .projections
+                        .iter()
+                        .map(|&(projection, ty)| {
+                            (projection, self.resolve(&ty, &upvar_id.var_id))
+                        })
+                        .collect(),
+                    capture: self.resolve_upvar_capture(*upvar_id, &place.capture),
+                })
+                .collect();
+            self.tables.upvar_places_map.insert(*upvar_id, new_places);
+        }
+    }
+
+    fn resolve_upvar_capture(
+        &self,
+        upvar_id: ty::UpvarId,
+        upvar_capture: &ty::UpvarCapture<'tcx>,
+    ) -> ty::UpvarCapture<'gcx> {
+        match *upvar_capture {
+            ty::UpvarCapture::ByValue => ty::UpvarCapture::ByValue,
+            ty::UpvarCapture::ByRef(ref upvar_borrow) => {
+                let r = upvar_borrow.region;
+                let r = self.resolve(&r, &upvar_id.var_id);
+                ty::UpvarCapture::ByRef(ty::UpvarBorrow {
+                    kind: upvar_borrow.kind,
+                    region: r,
+                })
+            }
+        }
     }
 
     fn visit_closures(&mut self) {
@@ -387,17 +413,61 @@ impl<'cx, 'gcx, 'tcx> WritebackCx<'cx, 'gcx, 'tcx> {
 
     fn visit_anon_types(&mut self) {
         let gcx = self.tcx().global_tcx();
+        let body_owner_def_id = gcx.hir.body_owner_def_id(self.body.id());
         for (&def_id, anon_defn) in self.fcx.anon_types.borrow().iter() {
             let node_id = gcx.hir.as_local_node_id(def_id).unwrap();
+            let anon_parent_def_id = gcx.hir.local_def_id(gcx.hir.get_parent(node_id));
+            let is_alias = anon_parent_def_id != body_owner_def_id;
+
+            if is_alias && !self.check_defining_substs(anon_defn.substs) {
+                continue;
+            }
+
             let instantiated_ty = self.resolve(&anon_defn.concrete_ty, &node_id);
             let definition_ty = self.fcx.infer_anon_definition_from_instantiation(
                 def_id,
                 anon_defn,
                 instantiated_ty,
             );
-            let hir_id = self.tcx().hir.node_to_hir_id(node_id);
-            self.tables.node_types_mut().insert(hir_id, definition_ty);
+
+            if !is_alias {
+                let hir_id = self.tcx().hir.node_to_hir_id(node_id);
+                self.tables.node_types_mut().insert(hir_id, definition_ty);
+                continue;
+            }
+
+            // This is a defining use of an `impl Trait` type alias or
+            // associated type. Returning the alias itself (e.g. by calling
+            // another defining function) does not constrain it.
+            if let ty::TyAnon(anon_def_id, _) = definition_ty.sty {
+                if anon_def_id == def_id {
+                    continue;
+                }
+            }
+            self.tables.concrete_existential_types.insert(def_id, definition_ty);
+        }
+    }
+
+    /// An `impl Trait` type alias must be used with distinct generic
+    /// parameters of the defining function, otherwise its concrete type
+    /// cannot be expressed in terms of the alias' own parameters.
+    fn check_defining_substs(&self, substs: &Substs<'tcx>) -> bool {
+        let mut seen_params = FxHashSet();
+        for ty in substs.types() {
+            let is_fresh_param = match ty.sty {
+                ty::TyParam(p) => seen_params.insert(p.idx),
+                _ => false,
+            };
+            if !is_fresh_param {
+                self.tcx().sess.span_err(
+                    self.body.value.span,
+                    &format!("non-defining use of `impl Trait` type alias: \
+                              expected a distinct generic parameter, found `{}`", ty),
+                );
+                return false;
+            }
         }
+        true
     }
 
     fn visit_field_id(&mut self, node_id: ast::NodeId) {
@@ -29,6 +29,7 @@ use lint;
 use constrained_type_params as ctp;
 use middle::lang_items::SizedTraitLangItem;
 use middle::resolve_lifetime as rl;
+use rustc::infer::anon_types::may_define_existential_type;
 use rustc::mir::mono::Linkage;
 use rustc::ty::subst::Substs;
 use rustc::ty::{ToPredicate, ReprOptions};
@@ -135,6 +136,17 @@ impl<'a, 'tcx> Visitor<'tcx> for CollectItemTypesVisitor<'a, 'tcx> {
             let def_id = self.tcx.hir.local_def_id(ty.id);
             self.tcx.generics_of(def_id);
             self.tcx.predicates_of(def_id);
+
+            // Ensure `impl Trait` type aliases have a (unique) defining use.
+            match self.tcx.hir.get(self.tcx.hir.get_parent(ty.id)) {
+                hir::map::NodeItem(&hir::Item { node: hir::ItemTy(..), .. }) |
+                hir::map::NodeImplItem(&hir::ImplItem {
+                    node: hir::ImplItemKind::Type(..), ..
+                }) => {
+                    self.tcx.type_of(def_id);
+                }
+                _ => {}
+            }
         }
         intravisit::walk_ty(self, ty);
     }
@@ -196,6 +208,15 @@ impl<'a, 'tcx> AstConv<'tcx, 'tcx> for ItemCtxt<'a, 'tcx> {
         self.tcx().types.err
     }
 
+    fn ct_infer_for_def(&self,
+                        def: &ty::ConstParameterDef,
+                        span: Span) -> &'tcx ty::Const<'tcx> {
+        self.tcx().sess.span_err(
+            span,
+            &format!("the const parameter `{}` must be specified in item signatures", def.name));
+        self.tcx().mk_const_err()
+    }
+
     fn projected_ty_from_poly_trait_ref(&self,
                                         span: Span,
                                         item_def_id: DefId,
@@ -875,13 +896,14 @@ fn generics_of<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
     let has_self = opt_self.is_some();
     let mut parent_has_self = false;
     let mut own_start = has_self as u32;
-    let (parent_regions, parent_types) = parent_def_id.map_or((0, 0), |def_id| {
+    let (parent_regions, parent_types, parent_consts) = parent_def_id.map_or((0, 0, 0), |def_id| {
         let generics = tcx.generics_of(def_id);
         assert_eq!(has_self, false);
         parent_has_self = generics.has_self;
         own_start = generics.count() as u32;
         (generics.parent_regions + generics.regions.len() as u32,
-            generics.parent_types + generics.types.len() as u32)
+            generics.parent_types + generics.types.len() as u32,
+            generics.parent_consts + generics.consts.len() as u32)
     });
 
     let early_lifetimes = early_bound_lifetimes_from_generics(tcx, ast_generics);
@@ -972,13 +994,30 @@ fn generics_of<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
                                    .map(|param| (param.def_id, param.index))
                                    .collect();
 
+    // Const parameters come after all the type parameters.
+    let const_start = type_start + (types.len() - has_self as usize) as u32;
+    let consts = ast_generics.const_params().enumerate().map(|(i, p)| {
+        ty::ConstParameterDef {
+            index: const_start + i as u32,
+            name: p.name.as_str(),
+            def_id: tcx.hir.local_def_id(p.id),
+        }
+    }).collect::<Vec<_>>();
+
+    let const_param_to_index = consts.iter()
+                                     .map(|param| (param.def_id, param.index))
+                                     .collect();
+
     tcx.alloc_generics(ty::Generics {
         parent: parent_def_id,
         parent_regions,
         parent_types,
+        parent_consts,
         regions,
         types,
+        consts,
         type_param_to_index,
+        const_param_to_index,
         has_self: has_self || parent_has_self,
         has_late_bound_regions: has_late_bound_regions(tcx, node),
     })
@@ -1098,7 +1137,8 @@ fn type_of<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
                         let region = def.to_early_bound_region_data();
                         tcx.mk_region(ty::ReEarlyBound(region))
                     },
-                    |def, _| tcx.mk_param_from_def(def)
+                    |def, _| tcx.mk_param_from_def(def),
+                    |def, _| tcx.mk_const_param_from_def(def)
                 )
             };
 
@@ -1126,10 +1166,33 @@ fn type_of<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
             icx.to_ty(ty)
         }
 
+        NodeConstParam(&hir::ConstParam { ref ty, .. }) => {
+            let param_ty = icx.to_ty(ty);
+            match param_ty.sty {
+                ty::TyBool | ty::TyChar | ty::TyInt(_) | ty::TyUint(_) | ty::TyError => {}
+                _ => {
+                    tcx.sess.span_err(ty.span, &format!(
+                        "const parameters must have an integral, `bool` or `char` type, \
+                         found `{}`", param_ty));
+                }
+            }
+            param_ty
+        }
+
         NodeTy(&hir::Ty { node: TyImplTraitExistential(..), .. }) => {
-            let owner = tcx.hir.get_parent_did(node_id);
-            let hir_id = tcx.hir.node_to_hir_id(node_id);
-            tcx.typeck_tables_of(owner).node_id_to_type(hir_id)
+            let owner_node_id = tcx.hir.get_parent(node_id);
+            match tcx.hir.get(owner_node_id) {
+                // `type Foo = impl Trait;` and `type Item = impl Trait;`
+                NodeItem(&hir::Item { node: ItemTy(..), .. }) |
+                NodeImplItem(&hir::ImplItem { node: ImplItemKind::Type(..), .. }) => {
+                    find_existential_constraints(tcx, def_id)
+                }
+                _ => {
+                    let owner = tcx.hir.local_def_id(owner_node_id);
+                    let hir_id = tcx.hir.node_to_hir_id(node_id);
+                    tcx.typeck_tables_of(owner).node_id_to_type(hir_id)
+                }
+            }
         }
 
         x => {
@@ -1138,6 +1201,51 @@ fn type_of<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
     }
 }
 
+/// Infers the concrete type of an `impl Trait` type alias or associated
+/// type from the bodies in its defining scope, all of which must agree.
+fn find_existential_constraints<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
+                                          def_id: DefId)
+                                          -> Ty<'tcx> {
+    let node_id = tcx.hir.as_local_node_id(def_id).unwrap();
+    let mut found: Option<(DefId, Ty<'tcx>)> = None;
+
+    for owner in tcx.body_owners() {
+        if tcx.is_closure(owner) ||
+           !may_define_existential_type(tcx, owner, node_id) {
+            continue;
+        }
+        let tables = tcx.typeck_tables_of(owner);
+        let concrete_ty = match tables.concrete_existential_types.get(&def_id) {
+            Some(&concrete_ty) => concrete_ty,
+            None => continue,
+        };
+        match found {
+            Some((prev_owner, prev_ty)) if prev_ty != concrete_ty => {
+                if !prev_ty.references_error() && !concrete_ty.references_error() {
+                    let mut err = tcx.sess.struct_span_err(
+                        tcx.def_span(owner),
+                        "concrete type differs from previous defining `impl Trait` use",
+                    );
+                    err.span_label(tcx.def_span(owner),
+                                   format!("expected `{}`, got `{}`", prev_ty, concrete_ty));
+                    err.span_note(tcx.def_span(prev_owner), "previous use here");
+                    err.emit();
+                }
+            }
+            Some(_) => {}
+            None => found = Some((owner, concrete_ty)),
+        }
+    }
+
+    match found {
+        Some((_, concrete_ty)) => concrete_ty,
+        None => {
+            tcx.sess.span_err(tcx.def_span(def_id), "could not find defining uses");
+            tcx.types.err
+        }
+    }
+}
+
 fn fn_sig<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
                     def_id: DefId)
                     -> ty::PolyFnSig<'tcx> {
@@ -1811,6 +1919,7 @@ fn trans_fn_attrs<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>, id: DefId) -> TransFnAt
     let whitelist = tcx.target_features_whitelist(LOCAL_CRATE);
 
     let mut inline_span = None;
+    let mut track_caller_span = None;
     for attr in attrs.iter() {
         if attr.check_name("cold") {
             trans_fn_attrs.flags |= TransFnAttrFlags::COLD;
@@ -1828,6 +1937,16 @@ fn trans_fn_attrs<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>, id: DefId) -> TransFnAt
             trans_fn_attrs.flags |= TransFnAttrFlags::RUSTC_STD_INTERNAL_SYMBOL;
         } else if attr.check_name("no_debug") {
             trans_fn_attrs.flags |= TransFnAttrFlags::NO_DEBUG;
+        } else if attr.check_name("track_caller") {
+            if tcx.fn_sig(id).abi() != abi::Abi::Rust {
+                tcx.sess.span_err(attr.span, "#[track_caller] requires Rust ABI");
+            }
+            if tcx.trait_of_item(id).is_some() {
+                tcx.sess.span_err(attr.span, "#[track_caller] is not supported \
+                                              on trait methods");
+            }
+            track_caller_span = Some(attr.span);
+            trans_fn_attrs.flags |= TransFnAttrFlags::TRACK_CALLER;
         } else if attr.check_name("inline") {
             trans_fn_attrs.inline = attrs.iter().fold(InlineAttr::None, |ia, attr| {
                 if attr.path != "inline" {
@@ -1899,5 +2018,13 @@ fn trans_fn_attrs<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>, id: DefId) -> TransFnAt
         }
     }
 
+    // Naked functions have no prologue in which to receive the implicit
+    // caller location argument.
+    if trans_fn_attrs.flags.contains(TransFnAttrFlags::NAKED) {
+        if let Some(span) = track_caller_span {
+            tcx.sess.span_err(span, "cannot use #[track_caller] with #[naked]");
+        }
+    }
+
     trans_fn_attrs
 }
@@ -4687,6 +4687,39 @@ ambiguity for some types, we disallow calling methods on raw pointers when
 the type is unknown.
 "##,
 
+E0912: r##"
+The `else` block of a `let...else` statement can complete normally.
+
+Erroneous code example:
+
+```compile_fail,E0912
+#![feature(let_else)]
+
+fn first(v: &[u32]) -> u32 {
+    let Some(&x) = v.first() else {
+        0 // error: the `else` block must diverge
+    };
+    x
+}
+```
+
+The `else` block runs when the pattern doesn't match, in which case the
+bindings of the pattern are never initialized. So it must not continue to the
+code after the statement: it has to leave it with `return`, `break` or
+`continue`, or call a function that never returns, such as `panic!`:
+
+```
+#![feature(let_else)]
+
+fn first(v: &[u32]) -> u32 {
+    let Some(&x) = v.first() else {
+        return 0;
+    };
+    x
+}
+```
+"##,
+
 }
 
 register_diagnostics! {
@@ -101,6 +101,7 @@ use rustc::middle;
 use rustc::session;
 use rustc::util;
 
+use hir::def_id::DefId;
 use hir::map as hir_map;
 use rustc::infer::InferOk;
 use rustc::ty::subst::Substs;
@@ -202,6 +203,8 @@ fn check_main_fn_ty<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
                 _ => ()
             }
 
+            check_entry_fn_not_track_caller(tcx, main_def_id, "`main` function");
+
             let actual = tcx.fn_sig(main_def_id);
             let expected_return_type = if tcx.lang_items().termination().is_some() {
                 // we take the return type of the given main function, the real check is done
@@ -236,6 +239,17 @@ fn check_main_fn_ty<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
     }
 }
 
+/// The entry point is called with the plain Rust ABI, which has no room for
+/// the caller location a `#[track_caller]` function receives.
+fn check_entry_fn_not_track_caller<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
+                                             def_id: DefId,
+                                             what: &str) {
+    if let Some(attr) = tcx.get_attrs(def_id).iter().find(|a| a.check_name("track_caller")) {
+        tcx.sess.span_err(attr.span,
+                          &format!("{} is not allowed to be #[track_caller]", what));
+    }
+}
+
 fn check_start_fn_ty<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
                                start_id: ast::NodeId,
                                start_span: Span) {
@@ -261,6 +275,8 @@ fn check_start_fn_ty<'a, 'tcx>(tcx: TyCtxt<'a, 'tcx, 'tcx>,
                 _ => ()
             }
 
+            check_entry_fn_not_track_caller(tcx, start_def_id, "start function");
+
             let se_ty = tcx.mk_fn_ptr(ty::Binder::bind(
                 tcx.mk_fn_sig(
                     [
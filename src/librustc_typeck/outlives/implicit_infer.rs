@@ -400,6 +400,10 @@ fn insert_outlives_predicate<'tcx>(
             }
             required_predicates.insert(ty::OutlivesPredicate(kind, outlived_region));
         }
+
+        UnpackedKind::Const(_) => {
+            // Consts don't impose any outlives requirements.
+        }
     }
 }
 
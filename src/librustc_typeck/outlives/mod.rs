@@ -95,6 +95,9 @@ fn inferred_outlives_crate<'tcx>(
                         UnpackedKind::Lifetime(region1) => ty::Predicate::RegionOutlives(
                             ty::Binder::bind(ty::OutlivesPredicate(region1, region2)),
                         ),
+                        UnpackedKind::Const(_) => {
+                            bug!("const in outlives predicate: {:?}", kind1)
+                        }
                     },
                 )
                 .collect();
@@ -390,6 +390,10 @@ impl<'a, 'tcx> ConstraintContext<'a, 'tcx> {
                 UnpackedKind::Type(ty) => {
                     self.add_constraints_from_ty(current, ty, variance_i)
                 }
+                UnpackedKind::Const(_) => {
+                    // Consts cannot mention type or lifetime parameters,
+                    // so they add no constraints.
+                }
             }
         }
     }
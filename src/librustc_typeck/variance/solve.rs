@@ -109,6 +109,12 @@ impl<'a, 'tcx> SolveContext<'a, 'tcx> {
                 }
             }
 
+            // Const parameters are always invariant.
+            let consts_start = generics.count() - generics.consts.len();
+            for variance in &mut variances[consts_start..] {
+                *variance = ty::Invariant;
+            }
+
             (def_id, Lrc::new(variances))
         }).collect()
     }
@@ -246,6 +246,7 @@ impl<'a, 'tcx, 'rcx> AutoTraitFinder<'a, 'tcx, 'rcx> {
         hir::PathParameters {
             lifetimes: lifetimes,
             types: types,
+            consts: HirVec::new(),
             bindings: HirVec::new(),
             parenthesized: false,
         }
@@ -1340,6 +1340,30 @@ impl Clean<TyParam> for hir::TyParam {
     }
 }
 
+#[derive(Clone, RustcEncodable, RustcDecodable, PartialEq, Eq, Debug, Hash)]
+pub struct ConstParam {
+    pub name: String,
+    pub ty: Type,
+}
+
+impl Clean<ConstParam> for hir::ConstParam {
+    fn clean(&self, cx: &DocContext) -> ConstParam {
+        ConstParam {
+            name: self.name.clean(cx),
+            ty: self.ty.clean(cx),
+        }
+    }
+}
+
+impl<'tcx> Clean<ConstParam> for ty::ConstParameterDef {
+    fn clean(&self, cx: &DocContext) -> ConstParam {
+        ConstParam {
+            name: self.name.to_string(),
+            ty: cx.tcx.type_of(self.def_id).clean(cx),
+        }
+    }
+}
+
 impl<'tcx> Clean<TyParam> for ty::TypeParameterDef {
     fn clean(&self, cx: &DocContext) -> TyParam {
         cx.renderinfo.borrow_mut().external_typarams.insert(self.def_id, self.name.clean(cx));
@@ -1420,6 +1444,7 @@ fn external_path_params(cx: &DocContext, trait_did: Option<DefId>, has_self: boo
                         bindings: Vec<TypeBinding>, substs: &Substs) -> PathParameters {
     let lifetimes = substs.regions().filter_map(|v| v.clean(cx)).collect();
     let types = substs.types().skip(has_self as usize).collect::<Vec<_>>();
+    let consts = substs.consts().map(|c| print_const(cx, c)).collect();
 
     match trait_did {
         // Attempt to sugar an external path like Fn<(A, B,), C> to Fn(A, B) -> C
@@ -1431,6 +1456,7 @@ fn external_path_params(cx: &DocContext, trait_did: Option<DefId>, has_self: boo
                     return PathParameters::AngleBracketed {
                         lifetimes,
                         types: types.clean(cx),
+                        consts,
                         bindings,
                     }
                 }
@@ -1450,6 +1476,7 @@ fn external_path_params(cx: &DocContext, trait_did: Option<DefId>, has_self: boo
             PathParameters::AngleBracketed {
                 lifetimes,
                 types: types.clean(cx),
+                consts,
                 bindings,
             }
         }
@@ -1725,6 +1752,7 @@ impl<'tcx> Clean<Type> for ty::ProjectionTy<'tcx> {
 pub enum GenericParam {
     Lifetime(Lifetime),
     Type(TyParam),
+    Const(ConstParam),
 }
 
 impl GenericParam {
@@ -1742,6 +1770,7 @@ impl Clean<GenericParam> for hir::GenericParam {
         match *self {
             hir::GenericParam::Lifetime(ref l) => GenericParam::Lifetime(l.clean(cx)),
             hir::GenericParam::Type(ref t) => GenericParam::Type(t.clean(cx)),
+            hir::GenericParam::Const(ref c) => GenericParam::Const(c.clean(cx)),
         }
     }
 }
@@ -1852,6 +1881,7 @@ impl<'a, 'tcx> Clean<Generics> for (&'a ty::Generics,
                         .into_iter()
                         .map(|tp| GenericParam::Type(tp))
                 )
+                .chain(gens.consts.clean(cx).into_iter().map(|cp| GenericParam::Const(cp)))
                 .collect(),
             where_predicates: simplify::where_clauses(cx, where_predicates),
         }
@@ -3236,6 +3266,7 @@ impl Path {
                 params: PathParameters::AngleBracketed {
                     lifetimes: Vec::new(),
                     types: Vec::new(),
+                    consts: Vec::new(),
                     bindings: Vec::new(),
                 }
             }]
@@ -3262,6 +3293,7 @@ pub enum PathParameters {
     AngleBracketed {
         lifetimes: Vec<Lifetime>,
         types: Vec<Type>,
+        consts: Vec<String>,
         bindings: Vec<TypeBinding>,
     },
     Parenthesized {
@@ -3286,6 +3318,9 @@ impl Clean<PathParameters> for hir::PathParameters {
                     self.lifetimes.clean(cx)
                 },
                 types: self.types.clean(cx),
+                consts: self.consts.iter().map(|c| {
+                    hir::print::to_string(hir::print::NO_ANN, |s| s.print_const_arg(c))
+                }).collect(),
                 bindings: self.bindings.clean(cx),
             }
         }
@@ -3339,6 +3374,7 @@ fn strip_path(path: &Path) -> Path {
             params: PathParameters::AngleBracketed {
                 lifetimes: Vec::new(),
                 types: Vec::new(),
+                consts: Vec::new(),
                 bindings: Vec::new(),
             }
         }
@@ -3808,6 +3844,8 @@ fn name_from_pat(p: &hir::Pat) -> String {
             let end = end.iter().map(|p| name_from_pat(&**p));
             format!("[{}]", begin.chain(mid).chain(end).collect::<Vec<_>>().join(", "))
         },
+        PatKind::Or(ref pats) => format!("({})", pats.iter().map(|p| name_from_pat(&**p))
+                                            .collect::<Vec<String>>().join(" | ")),
     }
 }
 
@@ -3830,6 +3868,8 @@ fn print_const(cx: &DocContext, n: &ty::Const) -> String {
             }
             s
         },
+        ConstVal::Param(ref p) => p.name.to_string(),
+        ConstVal::Infer(_) => "_".to_string(),
     }
 }
 
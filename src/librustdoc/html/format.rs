@@ -24,6 +24,7 @@ use rustc::hir;
 
 use clean::{self, PrimitiveType};
 use core::DocAccessLevels;
+use html::escape::Escape;
 use html::item_type::ItemType;
 use html::render::{self, cache, CURRENT_LOCATION_KEY};
 
@@ -142,6 +143,13 @@ impl fmt::Display for clean::GenericParam {
 
                 Ok(())
             }
+            clean::GenericParam::Const(ref cp) => {
+                if f.alternate() {
+                    write!(f, "const {}: {:#}", cp.name, cp.ty)
+                } else {
+                    write!(f, "const {}:&nbsp;{}", cp.name, cp.ty)
+                }
+            }
         }
     }
 }
@@ -292,9 +300,10 @@ impl fmt::Display for clean::PathParameters {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             clean::PathParameters::AngleBracketed {
-                ref lifetimes, ref types, ref bindings
+                ref lifetimes, ref types, ref consts, ref bindings
             } => {
-                if !lifetimes.is_empty() || !types.is_empty() || !bindings.is_empty() {
+                if !lifetimes.is_empty() || !types.is_empty() || !consts.is_empty() ||
+                   !bindings.is_empty() {
                     if f.alternate() {
                         f.write_str("<")?;
                     } else {
@@ -319,6 +328,13 @@ impl fmt::Display for clean::PathParameters {
                             write!(f, "{}", *ty)?;
                         }
                     }
+                    for ct in consts {
+                        if comma {
+                            f.write_str(", ")?;
+                        }
+                        comma = true;
+                        write!(f, "{}", Escape(ct))?;
+                    }
                     for binding in bindings {
                         if comma {
                             f.write_str(", ")?;
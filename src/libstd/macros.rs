@@ -61,6 +61,28 @@
 #[macro_export]
 #[stable(feature = "rust1", since = "1.0.0")]
 #[allow_internal_unstable]
+#[cfg(not(stage0))]
+macro_rules! panic {
+    () => ({
+        panic!("explicit panic")
+    });
+    ($msg:expr) => ({
+        $crate::rt::begin_panic($msg, $crate::rt::caller_location())
+    });
+    ($msg:expr,) => ({
+        panic!($msg)
+    });
+    ($fmt:expr, $($arg:tt)+) => ({
+        $crate::rt::begin_panic_fmt(&format_args!($fmt, $($arg)+),
+                                    $crate::rt::caller_location())
+    });
+}
+
+/// Entry point of thread panic, see the `panic!` above for details.
+#[macro_export]
+#[stable(feature = "rust1", since = "1.0.0")]
+#[allow_internal_unstable]
+#[cfg(stage0)]
 macro_rules! panic {
     () => ({
         panic!("explicit panic")
@@ -150,6 +172,18 @@ macro_rules! print {
 /// ```
 #[macro_export]
 #[stable(feature = "rust1", since = "1.0.0")]
+#[allow_internal_unstable]
+#[cfg(not(stage0))]
+macro_rules! println {
+    () => (print!("\n"));
+    ($($arg:tt)*) => ($crate::io::_print(format_args_nl!($($arg)*)));
+}
+
+/// Macro for printing to the standard output, with a newline, see the
+/// `println!` above for details.
+#[macro_export]
+#[stable(feature = "rust1", since = "1.0.0")]
+#[cfg(stage0)]
 macro_rules! println {
     () => (print!("\n"));
     ($fmt:expr) => (print!(concat!($fmt, "\n")));
@@ -207,12 +241,128 @@ macro_rules! eprint {
 /// ```
 #[macro_export]
 #[stable(feature = "eprint", since = "1.19.0")]
+#[allow_internal_unstable]
+#[cfg(not(stage0))]
+macro_rules! eprintln {
+    () => (eprint!("\n"));
+    ($($arg:tt)*) => ($crate::io::_eprint(format_args_nl!($($arg)*)));
+}
+
+/// Macro for printing to the standard error, with a newline, see the
+/// `eprintln!` above for details.
+#[macro_export]
+#[stable(feature = "eprint", since = "1.19.0")]
+#[cfg(stage0)]
 macro_rules! eprintln {
     () => (eprint!("\n"));
     ($fmt:expr) => (eprint!(concat!($fmt, "\n")));
     ($fmt:expr, $($arg:tt)*) => (eprint!(concat!($fmt, "\n"), $($arg)*));
 }
 
+/// Prints and returns the value of a given expression for quick and dirty
+/// debugging.
+///
+/// The value is printed to [`io::stderr`] with its pretty [`Debug`]
+/// representation, preceded by the file and line of the `dbg!` invocation
+/// and the source code of the expression.
+///
+/// `dbg!` takes ownership of the value and returns it unchanged, so it can
+/// wrap any sub-expression. Borrow with `dbg!(&expr)` to keep ownership of a
+/// value whose type is not `Copy`. Given several expressions, `dbg!` prints
+/// each of them in order and returns their values as a tuple.
+///
+/// Nothing is printed unless debug assertions are enabled, as they are in
+/// unoptimized builds. In release builds `dbg!` only evaluates and returns
+/// its arguments, unless output is asked for with `-C debug-assertions`.
+///
+/// [`io::stderr`]: ../std/io/struct.Stderr.html
+/// [`Debug`]: ../std/fmt/trait.Debug.html
+///
+/// # Panics
+///
+/// Panics if writing to `io::stderr` fails.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(dbg_macro)]
+///
+/// let a = 2;
+/// let b = dbg!(a * 2) + 1; // prints `[src/main.rs:4] a * 2 = 4`
+/// assert_eq!(b, 5);
+///
+/// let (x, y) = dbg!(a, b);
+/// assert_eq!((x, y), (2, 5));
+/// ```
+#[macro_export]
+#[unstable(feature = "dbg_macro", issue = "54306")]
+macro_rules! dbg {
+    ($val:expr) => {
+        // `match` keeps any temporaries in `$val` alive until it is printed.
+        match $val {
+            tmp => {
+                if cfg!(debug_assertions) {
+                    eprintln!("[{}:{}] {} = {:#?}",
+                              file!(), line!(), stringify!($val), &tmp);
+                }
+                tmp
+            }
+        }
+    };
+    ($val:expr,) => (dbg!($val));
+    ($($val:expr),+ $(,)*) => (($(dbg!($val)),+,));
+}
+
+/// Awaits the completion of a future inside an `async` block or `async fn`.
+///
+/// `await!` polls the future it is given, suspending the enclosing `async`
+/// computation until the future is ready and evaluating to its output.
+/// Borrows of the enclosing computation's locals may be held across the
+/// suspension, since `async` futures are never moved once they are polled.
+///
+/// `await!` can only be used in the body of an `async` block or `async fn`.
+///
+/// # Examples
+///
+/// ```ignore (requires-2018-edition)
+/// #![feature(async_await, await_macro, futures_api)]
+///
+/// use std::future::Future;
+///
+/// async fn double(x: u32) -> u32 {
+///     x * 2
+/// }
+///
+/// fn answer() -> impl Future<Output = u32> {
+///     async {
+///         let twenty = 20;
+///         let r = &twenty;
+///         await!(double(*r)) + 2
+///     }
+/// }
+/// ```
+#[macro_export]
+#[unstable(feature = "await_macro", issue = "50547")]
+#[allow_internal_unstable]
+#[allow_internal_unsafe]
+macro_rules! await {
+    ($e:expr) => { {
+        let mut pinned = $e;
+        loop {
+            // `pinned` lives in the enclosing immovable generator, so it is
+            // never moved again after the first poll.
+            if let $crate::task::Poll::Ready(x) =
+                $crate::future::poll_in_task_cx(unsafe {
+                    $crate::mem::Pin::new_unchecked(&mut pinned)
+                })
+            {
+                break x;
+            }
+            yield
+        }
+    } }
+}
+
 /// A macro to select an event from a number of receivers.
 ///
 /// This macro is used to wait for the first event to occur on a number of
@@ -755,6 +905,17 @@ pub mod builtin {
     /// be provided with or without arguments for formatting.  See [`std::fmt`]
     /// for syntax for this form.
     ///
+    /// # Captured Values
+    ///
+    /// With the unstable `generic_assert` feature, the panic message without a
+    /// custom message also lists the values of the operands of the comparisons
+    /// (`==`, `<`, ...) in the expression, looking through `&&`, `||` and `!`.
+    /// Operands are recorded by reference, so recording them never moves out
+    /// of or otherwise affects them. Those that implement `Debug` are printed,
+    /// while other operands, and those that were not evaluated, are shown as
+    /// `N/A`. Literals, and operands that may borrow from a temporary of their
+    /// own, such as `s().as_str()`, are not listed.
+    ///
     /// [`panic!`]: macro.panic.html
     /// [`debug_assert!`]: macro.debug_assert.html
     /// [testing]: ../book/second-edition/ch11-01-writing-tests.html#checking-results-with-the-assert-macro
@@ -25,6 +25,8 @@
 
 // Re-export some of our utilities which are expected by other crates.
 pub use panicking::{begin_panic, begin_panic_fmt, update_panic_count};
+#[cfg(not(stage0))]
+pub use core::panicking::caller_location;
 
 // To reduce the generated code of the new `lang_start`, this function is doing
 // the real work.
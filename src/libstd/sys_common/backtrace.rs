@@ -253,7 +253,8 @@ fn output_fileline(w: &mut Write,
 //
 // Note that this demangler isn't quite as fancy as it could be. We have lots
 // of other information in our symbols like hashes, version, type information,
-// etc. Additionally, this doesn't handle glue symbols at all.
+// etc. Additionally, this doesn't handle glue symbols at all. Symbols using
+// the `v0` mangling scheme are handled separately by `demangle_v0`.
 pub fn demangle(writer: &mut Write, mut s: &str, format: PrintFormat) -> io::Result<()> {
     // During ThinLTO LLVM may import and rename internal symbols, so strip out
     // those endings first as they're one of the last manglings applied to
@@ -273,6 +274,10 @@ pub fn demangle(writer: &mut Write, mut s: &str, format: PrintFormat) -> io::Res
         }
     }
 
+    if let Some(demangled) = demangle_v0(s, format) {
+        return writer.write_all(demangled.as_bytes());
+    }
+
     // Validate the symbol. If it doesn't look like anything we're
     // expecting, we just print it literally. Note that we must handle non-rust
     // symbols because we could have any function in the backtrace.
@@ -407,6 +412,540 @@ pub fn demangle(writer: &mut Write, mut s: &str, format: PrintFormat) -> io::Res
     Ok(())
 }
 
+// Symbols mangled with `-Z symbol-mangling-version=v0` start with "_R" and
+// encode the full path of the item, including generic arguments, impls and
+// closures (see src/librustc_trans_utils/symbol_names_v0.rs for the
+// grammar). Unlike the legacy symbols they can't be demangled piecewise, so
+// they are parsed into a string first. Returns `None` for anything that
+// isn't a well-formed `v0` symbol.
+fn demangle_v0(s: &str, format: PrintFormat) -> Option<String> {
+    if !s.starts_with("_R") {
+        return None;
+    }
+    let mut parser = V0Parser {
+        sym: s[2..].as_bytes(),
+        next: 0,
+        depth: 0,
+        steps: 0,
+        bound_lifetime_depth: 0,
+        full: format == PrintFormat::Full,
+    };
+    let mut out = String::new();
+    parser.path(&mut out).ok()?;
+
+    // The instantiating crate is only there to keep symbols unique.
+    if parser.next < parser.sym.len() {
+        parser.path(&mut String::new()).ok()?;
+    }
+    if parser.next != parser.sym.len() {
+        return None;
+    }
+    Some(out)
+}
+
+struct V0Parser<'s> {
+    sym: &'s [u8],
+    next: usize,
+    depth: u32,
+    // The number of paths and types parsed so far, counting those parsed
+    // again through a backref.
+    steps: u32,
+    // The number of lifetimes bound by the binders we are in.
+    bound_lifetime_depth: u64,
+    full: bool,
+}
+
+type V0Result = Result<(), ()>;
+
+impl<'s> V0Parser<'s> {
+    fn peek(&self) -> Option<u8> {
+        self.sym.get(self.next).cloned()
+    }
+
+    fn eat(&mut self, b: u8) -> bool {
+        if self.peek() == Some(b) {
+            self.next += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn next(&mut self) -> Result<u8, ()> {
+        let b = self.peek().ok_or(())?;
+        self.next += 1;
+        Ok(b)
+    }
+
+    fn integer_62(&mut self) -> Result<u64, ()> {
+        if self.eat(b'_') {
+            return Ok(0);
+        }
+        let mut x: u64 = 0;
+        while !self.eat(b'_') {
+            let d = match self.next()? {
+                d @ b'0'...b'9' => d - b'0',
+                d @ b'a'...b'z' => 10 + (d - b'a'),
+                d @ b'A'...b'Z' => 36 + (d - b'A'),
+                _ => return Err(()),
+            };
+            x = x.checked_mul(62).and_then(|x| x.checked_add(d as u64)).ok_or(())?;
+        }
+        x.checked_add(1).ok_or(())
+    }
+
+    fn disambiguator(&mut self) -> Result<u64, ()> {
+        if self.eat(b's') {
+            self.integer_62()?.checked_add(1).ok_or(())
+        } else {
+            Ok(0)
+        }
+    }
+
+    fn ident(&mut self) -> Result<String, ()> {
+        let escaped = self.eat(b'u');
+        let start = self.next;
+        while let Some(b'0'...b'9') = self.peek() {
+            self.next += 1;
+        }
+        let len: usize = str::from_utf8(&self.sym[start..self.next]).map_err(|_| ())?
+            .parse().map_err(|_| ())?;
+        self.eat(b'_');
+        let end = self.next.checked_add(len).ok_or(())?;
+        let bytes = self.sym.get(self.next..end).ok_or(())?;
+        self.next = end;
+        let ident = str::from_utf8(bytes).map_err(|_| ())?;
+        if !escaped {
+            return Ok(ident.to_string());
+        }
+
+        // Non-ASCII identifiers are encoded with Punycode, with `_` as the
+        // delimiter between the ASCII characters and the encoded rest.
+        let (basic, encoded) = match ident.rfind('_') {
+            Some(i) => (&ident[..i], &ident[i + 1..]),
+            None => ("", ident),
+        };
+        punycode_decode(basic, encoded)
+    }
+
+    fn print_lifetime(&self, i: u64, out: &mut String) -> V0Result {
+        if i == 0 {
+            out.push_str("'_");
+            return Ok(());
+        }
+        // `i` counts the bound lifetimes from the innermost one, but they
+        // are named from the outermost one.
+        match self.bound_lifetime_depth.checked_sub(i).ok_or(())? {
+            depth @ 0...25 => {
+                out.push('\'');
+                out.push((b'a' + depth as u8) as char);
+            }
+            depth => out.push_str(&format!("'_{}", depth)),
+        }
+        Ok(())
+    }
+
+    fn lifetime(&mut self, out: &mut String) -> V0Result {
+        let i = self.integer_62()?;
+        self.print_lifetime(i, out)
+    }
+
+    // Parses an optional binder, printing it as `for<'a, ...> `, and
+    // returns the number of lifetimes it binds.
+    fn binder(&mut self, out: &mut String) -> Result<u64, ()> {
+        if !self.eat(b'G') {
+            return Ok(0);
+        }
+        let lifetimes = self.integer_62()?.checked_add(1).ok_or(())?;
+        // Every bound lifetime is referred to somewhere in the symbol.
+        if lifetimes > self.sym.len() as u64 {
+            return Err(());
+        }
+        out.push_str("for<");
+        for i in 0..lifetimes {
+            if i > 0 {
+                out.push_str(", ");
+            }
+            self.bound_lifetime_depth += 1;
+            self.print_lifetime(1, out)?;
+        }
+        out.push_str("> ");
+        Ok(lifetimes)
+    }
+
+    fn enter(&mut self) -> V0Result {
+        // Guard against unbounded recursion on malicious symbols, and
+        // against backrefs to backrefs, which can expand to exponentially
+        // long output.
+        self.depth += 1;
+        self.steps += 1;
+        if self.depth > 500 || self.steps > 10_000 { Err(()) } else { Ok(()) }
+    }
+
+    // Parses a backref, the `B` of which was just eaten, by parsing what it
+    // refers to with `f`.
+    fn backref<F>(&mut self, out: &mut String, f: F) -> V0Result
+        where F: FnOnce(&mut Self, &mut String) -> V0Result
+    {
+        let start = self.next - 1;
+        let target = self.integer_62()?;
+        // Only refer backwards, so that backrefs can't loop.
+        if target >= start as u64 {
+            return Err(());
+        }
+        let next = self.next;
+        self.next = target as usize;
+        f(self, out)?;
+        self.next = next;
+        Ok(())
+    }
+
+    fn path(&mut self, out: &mut String) -> V0Result {
+        self.enter()?;
+        match self.next()? {
+            b'B' => self.backref(out, |p, out| p.path(out))?,
+            b'C' => {
+                let dis = self.disambiguator()?;
+                out.push_str(&self.ident()?);
+                if self.full && dis != 0 {
+                    out.push_str(&format!("[{:x}]", dis));
+                }
+            }
+            b'N' => {
+                let ns = self.next()?;
+                self.path(out)?;
+                let dis = self.disambiguator()?;
+                let ident = self.ident()?;
+                out.push_str("::");
+                match ns {
+                    b't' | b'v' => out.push_str(&ident),
+                    b'C' => out.push_str(&format!("{{closure#{}}}", dis)),
+                    b'S' => out.push_str(&format!("{{shim:{}}}", ident)),
+                    b'A' if ident.is_empty() => out.push_str(&format!("{{anon#{}}}", dis)),
+                    b'A' => out.push_str(&format!("{{{}#{}}}", ident, dis)),
+                    _ => return Err(()),
+                }
+            }
+            tag @ b'M' | tag @ b'X' => {
+                // The path of the module containing the impl isn't printed.
+                self.disambiguator()?;
+                self.path(&mut String::new())?;
+                out.push('<');
+                self.ty(out)?;
+                if tag == b'X' {
+                    out.push_str(" as ");
+                    self.path(out)?;
+                }
+                out.push('>');
+            }
+            b'Y' => {
+                out.push('<');
+                self.ty(out)?;
+                out.push_str(" as ");
+                self.path(out)?;
+                out.push('>');
+            }
+            b'I' => {
+                self.path(out)?;
+                out.push('<');
+                let mut first = true;
+                while !self.eat(b'E') {
+                    if !first {
+                        out.push_str(", ");
+                    }
+                    first = false;
+                    self.generic_arg(out)?;
+                }
+                out.push('>');
+            }
+            _ => return Err(()),
+        }
+        self.depth -= 1;
+        Ok(())
+    }
+
+    fn generic_arg(&mut self, out: &mut String) -> V0Result {
+        if self.eat(b'L') {
+            self.lifetime(out)
+        } else if self.eat(b'K') {
+            self.konst(out)
+        } else {
+            self.ty(out)
+        }
+    }
+
+    fn basic_type(tag: u8) -> Option<&'static str> {
+        Some(match tag {
+            b'b' => "bool",
+            b'c' => "char",
+            b'e' => "str",
+            b'u' => "()",
+            b'a' => "i8",
+            b's' => "i16",
+            b'l' => "i32",
+            b'x' => "i64",
+            b'n' => "i128",
+            b'i' => "isize",
+            b'h' => "u8",
+            b't' => "u16",
+            b'm' => "u32",
+            b'y' => "u64",
+            b'o' => "u128",
+            b'j' => "usize",
+            b'f' => "f32",
+            b'd' => "f64",
+            b'z' => "!",
+            b'p' => "_",
+            _ => return None,
+        })
+    }
+
+    fn ty(&mut self, out: &mut String) -> V0Result {
+        self.enter()?;
+        let tag = self.peek().ok_or(())?;
+        if let Some(ty) = V0Parser::basic_type(tag) {
+            self.next += 1;
+            out.push_str(ty);
+            self.depth -= 1;
+            return Ok(());
+        }
+
+        match tag {
+            b'R' | b'Q' => {
+                self.next += 1;
+                out.push('&');
+                if self.eat(b'L') {
+                    self.lifetime(out)?;
+                    out.push(' ');
+                }
+                if tag == b'Q' {
+                    out.push_str("mut ");
+                }
+                self.ty(out)?;
+            }
+            b'B' => {
+                self.next += 1;
+                self.backref(out, |p, out| p.ty(out))?;
+            }
+            b'P' | b'O' => {
+                self.next += 1;
+                out.push_str(if tag == b'P' { "*const " } else { "*mut " });
+                self.ty(out)?;
+            }
+            b'A' => {
+                self.next += 1;
+                out.push('[');
+                self.ty(out)?;
+                out.push_str("; ");
+                self.konst(out)?;
+                out.push(']');
+            }
+            b'S' => {
+                self.next += 1;
+                out.push('[');
+                self.ty(out)?;
+                out.push(']');
+            }
+            b'T' => {
+                self.next += 1;
+                out.push('(');
+                let mut count = 0;
+                while !self.eat(b'E') {
+                    if count > 0 {
+                        out.push_str(", ");
+                    }
+                    count += 1;
+                    self.ty(out)?;
+                }
+                if count == 1 {
+                    out.push(',');
+                }
+                out.push(')');
+            }
+            b'F' => {
+                self.next += 1;
+                let lifetimes = self.binder(out)?;
+                if self.eat(b'U') {
+                    out.push_str("unsafe ");
+                }
+                if self.eat(b'K') {
+                    let abi = if self.eat(b'C') {
+                        "C".to_string()
+                    } else {
+                        self.ident()?.replace('_', "-")
+                    };
+                    out.push_str(&format!("extern \"{}\" ", abi));
+                }
+                out.push_str("fn(");
+                let mut first = true;
+                while !self.eat(b'E') {
+                    if !first {
+                        out.push_str(", ");
+                    }
+                    first = false;
+                    if self.eat(b'v') {
+                        out.push_str("...");
+                    } else {
+                        self.ty(out)?;
+                    }
+                }
+                out.push(')');
+                let mut ret = String::new();
+                self.ty(&mut ret)?;
+                if ret != "()" {
+                    out.push_str(" -> ");
+                    out.push_str(&ret);
+                }
+                self.bound_lifetime_depth -= lifetimes;
+            }
+            b'D' => {
+                self.next += 1;
+                out.push_str("dyn ");
+                let lifetimes = self.binder(out)?;
+                let mut first = true;
+                while !self.eat(b'E') {
+                    if !first {
+                        out.push_str(" + ");
+                    }
+                    first = false;
+                    let mut bound = String::new();
+                    self.path(&mut bound)?;
+                    let mut bindings = vec![];
+                    while self.eat(b'p') {
+                        let name = self.ident()?;
+                        let mut ty = String::new();
+                        self.ty(&mut ty)?;
+                        bindings.push(format!("{} = {}", name, ty));
+                    }
+                    if !bindings.is_empty() {
+                        if bound.ends_with('>') {
+                            bound.pop();
+                            bound.push_str(", ");
+                        } else {
+                            bound.push('<');
+                        }
+                        bound.push_str(&bindings.join(", "));
+                        bound.push('>');
+                    }
+                    out.push_str(&bound);
+                }
+                self.bound_lifetime_depth -= lifetimes;
+                if !self.eat(b'L') {
+                    return Err(());
+                }
+                let mut lifetime = String::new();
+                self.lifetime(&mut lifetime)?;
+                if lifetime != "'_" {
+                    out.push_str(" + ");
+                    out.push_str(&lifetime);
+                }
+            }
+            _ => self.path(out)?,
+        }
+        self.depth -= 1;
+        Ok(())
+    }
+
+    fn konst(&mut self, out: &mut String) -> V0Result {
+        if self.eat(b'p') {
+            out.push('_');
+            return Ok(());
+        }
+        if self.eat(b'B') {
+            return self.backref(out, |p, out| p.konst(out));
+        }
+        let ty = self.next()?;
+        V0Parser::basic_type(ty).ok_or(())?;
+        let negative = self.eat(b'n');
+        let start = self.next;
+        while self.peek().map_or(false, |b| (b as char).is_digit(16)) {
+            self.next += 1;
+        }
+        let digits = str::from_utf8(&self.sym[start..self.next]).map_err(|_| ())?;
+        if !self.eat(b'_') {
+            return Err(());
+        }
+        let value = u128::from_str_radix(digits, 16).map_err(|_| ())?;
+        match ty {
+            b'b' if value == 0 => out.push_str("false"),
+            b'b' if value == 1 => out.push_str("true"),
+            b'c' => {
+                let c = ::char::from_u32(value as u32).ok_or(())?;
+                out.push_str(&format!("{:?}", c));
+            }
+            b'b' | b'e' | b'u' | b'z' | b'p' | b'f' | b'd' => return Err(()),
+            _ => {
+                if negative {
+                    out.push('-');
+                }
+                out.push_str(&value.to_string());
+            }
+        }
+        Ok(())
+    }
+}
+
+// Parameters of the Punycode encoding, from RFC 3492.
+const PUNYCODE_BASE: u64 = 36;
+const PUNYCODE_TMIN: u64 = 1;
+const PUNYCODE_TMAX: u64 = 26;
+const PUNYCODE_SKEW: u64 = 38;
+const PUNYCODE_DAMP: u64 = 700;
+const PUNYCODE_INITIAL_BIAS: u64 = 72;
+const PUNYCODE_INITIAL_N: u64 = 128;
+
+fn punycode_adapt(delta: u64, num_points: u64, first_time: bool) -> u64 {
+    let mut delta = if first_time { delta / PUNYCODE_DAMP } else { delta / 2 };
+    delta += delta / num_points;
+    let mut k = 0;
+    while delta > ((PUNYCODE_BASE - PUNYCODE_TMIN) * PUNYCODE_TMAX) / 2 {
+        delta /= PUNYCODE_BASE - PUNYCODE_TMIN;
+        k += PUNYCODE_BASE;
+    }
+    k + (PUNYCODE_BASE - PUNYCODE_TMIN + 1) * delta / (delta + PUNYCODE_SKEW)
+}
+
+fn punycode_decode(basic: &str, encoded: &str) -> Result<String, ()> {
+    let mut out: Vec<char> = basic.chars().collect();
+    let mut n = PUNYCODE_INITIAL_N;
+    let mut i: u64 = 0;
+    let mut bias = PUNYCODE_INITIAL_BIAS;
+    let mut digits = encoded.bytes();
+    while digits.len() > 0 {
+        let old_i = i;
+        let mut w: u64 = 1;
+        let mut k = PUNYCODE_BASE;
+        loop {
+            let digit = match digits.next().ok_or(())? {
+                d @ b'a'...b'z' => d - b'a',
+                d @ b'0'...b'9' => 26 + (d - b'0'),
+                _ => return Err(()),
+            } as u64;
+            i = digit.checked_mul(w).and_then(|x| i.checked_add(x)).ok_or(())?;
+            let t = if k <= bias {
+                PUNYCODE_TMIN
+            } else if k >= bias + PUNYCODE_TMAX {
+                PUNYCODE_TMAX
+            } else {
+                k - bias
+            };
+            if digit < t {
+                break;
+            }
+            w = w.checked_mul(PUNYCODE_BASE - t).ok_or(())?;
+            k += PUNYCODE_BASE;
+        }
+        let len = out.len() as u64 + 1;
+        bias = punycode_adapt(i - old_i, len, old_i == 0);
+        n = n.checked_add(i / len).ok_or(())?;
+        i %= len;
+        let c = if n > ::char::MAX as u64 { None } else { ::char::from_u32(n as u32) };
+        out.insert(i as usize, c.ok_or(())?);
+        i += 1;
+    }
+    Ok(out.into_iter().collect())
+}
+
 #[cfg(test)]
 mod tests {
     use sys_common;
@@ -460,4 +999,136 @@ mod tests {
         t!("_ZN71_$LT$Test$u20$$u2b$$u20$$u27$static$u20$as$u20$foo..Bar$LT$Test$GT$$GT$3barE",
            "<Test + 'static as foo::Bar<Test>>::bar");
     }
+
+    #[test]
+    fn demangle_v0() {
+        t!("_RNvC4test4main", "test::main");
+        t!("_RNvNtCs1_4test3foo3bar", "test[3]::foo::bar");
+        t!("_RNvNtC4test3foo3barC5other", "test::foo::bar");
+        t!("_RNCNvC4test4mains_0", "test::main::{closure#1}");
+        t!("_RNSNvC4test3foo5reify", "test::foo::{shim:reify}");
+        t!("_RNvC4test", "_RNvC4test");
+    }
+
+    #[test]
+    fn demangle_v0_generics() {
+        t!("_RINvC4test3fooTmRehEE", "test::foo<(u32, &str, u8)>");
+        t!("_RINvC4test3fooKjf_Kanf_Kb1_Kc61_E", "test::foo<15, -15, true, 'a'>");
+        t!("_RINvC4test3fooAhj4_SlQPbE", "test::foo<[u8; 4], [i32], &mut *const bool>");
+        t!("_RINvC4test3fooFUKCaEuFhEzE", "test::foo<unsafe extern \"C\" fn(i8), fn(u8) -> !>");
+    }
+
+    #[test]
+    fn demangle_v0_impls() {
+        t!("_RNvMNtC4test3fooNtC4test3Bar3new", "<test::Bar>::new");
+        t!("_RNvXC4testNtC4test3FooNtNtC4core5clone5Clone5clone",
+           "<test::Foo as core::clone::Clone>::clone");
+        t!("_RNvYhNtNtC4core5clone5Clone5clone", "<u8 as core::clone::Clone>::clone");
+        t!("_RINvC4test3fooDNtC4test5Traitp4ItemmNtC4core4SendEL_E",
+           "test::foo<dyn test::Trait<Item = u32> + core::Send>");
+        t!("_RINvC4test3fooDINtC4test5TraitmEp4ItemmEL_E",
+           "test::foo<dyn test::Trait<u32, Item = u32>>");
+    }
+
+    #[test]
+    fn demangle_v0_nested_paths() {
+        t!("_RNvNtNtC4test1a1b1c", "test::a::b::c");
+        t!("_RNvNtNtCs4_4test1as_1bs0_1c", "test[6]::a::b::c");
+        t!("_RNANvC4test4main0", "test::main::{anon#0}");
+        t!("_RINvC4test3fooINtC4test3VechEE", "test::foo<test::Vec<u8>>");
+        t!("_RINvC4test3fooTEuE", "test::foo<(), ()>");
+    }
+
+    #[test]
+    fn demangle_v0_generic_impls() {
+        t!("_RINvMC4testINtC4test3BarmE3newhE", "<test::Bar<u32>>::new<u8>");
+        t!("_RNvXC4testINtC4test3BarmEINtC4test5TraithE4call",
+           "<test::Bar<u32> as test::Trait<u8>>::call");
+    }
+
+    #[test]
+    fn demangle_v0_closures() {
+        t!("_RNCNCNvC4test4main0s_0", "test::main::{closure#0}::{closure#1}");
+        t!("_RNCINvC4test3foohE0", "test::foo<u8>::{closure#0}");
+    }
+
+    #[test]
+    fn demangle_v0_consts() {
+        t!("_RINvC4test3fooKb0_Kjff_KpE", "test::foo<false, 255, _>");
+        t!("_RINvC4test3fooAhpE", "test::foo<[u8; _]>");
+    }
+
+    #[test]
+    fn demangle_v0_binders() {
+        t!("_RINvC4test3fooFG_RL0_hEuE", "test::foo<for<'a> fn(&'a u8)>");
+        t!("_RINvC4test3fooFRhEuE", "test::foo<fn(&u8)>");
+        t!("_RINvC4test3fooFG0_RL1_hRL0_hERL1_hE",
+           "test::foo<for<'a, 'b> fn(&'a u8, &'b u8) -> &'a u8>");
+        t!("_RINvC4test3fooFG_FG_RL1_hQL0_hEuEuE",
+           "test::foo<for<'a> fn(for<'b> fn(&'a u8, &'b mut u8))>");
+        t!("_RINvC4test3fooDG_INtC4test5TraitL0_EEL_E",
+           "test::foo<dyn for<'a> test::Trait<'a>>");
+    }
+
+    #[test]
+    fn demangle_v0_backrefs() {
+        t!("_RINvC4test3fooNtB2_3BarBc_E", "test::foo<test::Bar, test::Bar>");
+        t!("_RINvC4test3fooKj1_KBd_E", "test::foo<1, 1>");
+    }
+
+    #[test]
+    fn demangle_v0_punycode() {
+        t!("_RNvC4testu8gdel_5qa", "test::gödel");
+        t!("_RNvC4testu6wgv71a", "test::日本");
+        t!("_RNvC4testu10f_bar_juaa", "test::föö_bar");
+    }
+
+    #[test]
+    fn demangle_v0_invalid() {
+        // Malformed symbols are printed as they are.
+        t!("_R", "_R");
+        t!("_RNvC4test4mai", "_RNvC4test4mai");
+        t!("_RNvC4test4main_", "_RNvC4test4main_");
+        t!("_RNvC99999999999999999999999test", "_RNvC99999999999999999999999test");
+        t!("_RINvC4test3fooKjzz_E", "_RINvC4test3fooKjzz_E");
+        t!("_RINvC4test3fooKcd800_E", "_RINvC4test3fooKcd800_E");
+        t!("_RB_", "_RB_");
+        t!("_RNvB0_4main", "_RNvB0_4main");
+        t!("_RINvC4test3fooRL0_hE", "_RINvC4test3fooRL0_hE");
+        t!("_RINvC4test3fooFGzzzzzz_EuE", "_RINvC4test3fooFGzzzzzz_EuE");
+        t!("_RINvC4test3fooFGzzzzzzzzzzzzzzzzzzzz_EuE",
+           "_RINvC4test3fooFGzzzzzzzzzzzzzzzzzzzz_EuE");
+        t!("_RNvC4testu3a_!", "_RNvC4testu3a_!");
+
+        let deep = format!("_RINvC4test3foo{}hE", "R".repeat(1000));
+        t!(&deep, deep);
+
+        // Each tuple refers to the previous one twice, so printing the last
+        // one would take 2^40 steps.
+        fn integer_62(x: usize) -> String {
+            let digits = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+            let mut s = String::from("_");
+            if let Some(mut x) = x.checked_sub(1) {
+                loop {
+                    s.insert(0, digits[x % 62] as char);
+                    x /= 62;
+                    if x == 0 {
+                        break;
+                    }
+                }
+            }
+            s
+        }
+        let mut exponential = String::from("_RINvC4test3foo");
+        let mut prev = exponential.len() - 2;
+        exponential.push_str("TuE");
+        for _ in 0..40 {
+            let start = exponential.len() - 2;
+            let backref = format!("B{}", integer_62(prev));
+            exponential.push_str(&format!("T{}{}E", backref, backref));
+            prev = start;
+        }
+        exponential.push('E');
+        t!(&exponential, exponential);
+    }
 }
@@ -175,6 +175,11 @@ pub struct AngleBracketedParameterData {
     pub lifetimes: Vec<Lifetime>,
     /// The type parameters for this path segment, if present.
     pub types: Vec<P<Ty>>,
+    /// The const parameters for this path segment, if present.
+    ///
+    /// E.g., the `3` in `Buf<3>`. A bare path like the `N` in `Buf<N>` is
+    /// parsed as a type and only found to be a const once it is resolved.
+    pub consts: Vec<P<Expr>>,
     /// Bindings (equality constraints) on associated types, if present.
     ///
     /// E.g., `Foo<A=Bar>`.
@@ -310,10 +315,20 @@ pub struct TyParam {
     pub default: Option<P<Ty>>,
 }
 
+/// A const parameter, e.g. the `const N: usize` in `struct Buf<const N: usize>`.
+#[derive(Clone, PartialEq, Eq, RustcEncodable, RustcDecodable, Hash, Debug)]
+pub struct ConstParam {
+    pub attrs: ThinVec<Attribute>,
+    pub ident: Ident,
+    pub id: NodeId,
+    pub ty: P<Ty>,
+}
+
 #[derive(Clone, PartialEq, Eq, RustcEncodable, RustcDecodable, Hash, Debug)]
 pub enum GenericParam {
     Lifetime(LifetimeDef),
     Type(TyParam),
+    Const(ConstParam),
 }
 
 impl GenericParam {
@@ -330,6 +345,13 @@ impl GenericParam {
             _ => false,
         }
     }
+
+    pub fn is_const_param(&self) -> bool {
+        match *self {
+            GenericParam::Const(_) => true,
+            _ => false,
+        }
+    }
 }
 
 /// Represents lifetime, type and const parameters attached to a declaration of
@@ -565,7 +587,7 @@ impl Pat {
             PatKind::Struct(_, ref fields, _) => {
                 fields.iter().all(|field| field.node.pat.walk(it))
             }
-            PatKind::TupleStruct(_, ref s, _) | PatKind::Tuple(ref s, _) => {
+            PatKind::TupleStruct(_, ref s, _) | PatKind::Tuple(ref s, _) | PatKind::Or(ref s) => {
                 s.iter().all(|p| p.walk(it))
             }
             PatKind::Box(ref s) | PatKind::Ref(ref s, _) | PatKind::Paren(ref s) => {
@@ -664,6 +686,9 @@ pub enum PatKind {
     Slice(Vec<P<Pat>>, Option<P<Pat>>, Vec<P<Pat>>),
     /// Parentheses in patters used for grouping, i.e. `(PAT)`.
     Paren(P<Pat>),
+    /// Alternatives nested inside another pattern, e.g. the `A | B` in `Some(A | B)`.
+    /// The top-level alternatives of a `match` arm are kept in `Arm::pats` instead.
+    Or(Vec<P<Pat>>),
     /// A macro pattern; pre-expansion
     Mac(Mac),
 }
@@ -877,6 +902,9 @@ pub struct Local {
     pub ty: Option<P<Ty>>,
     /// Initializer expression to set the value, if any
     pub init: Option<P<Expr>>,
+    /// Diverging block run when the pattern doesn't match, as in
+    /// `let Some(x) = opt else { return };`
+    pub els: Option<P<Block>>,
     pub id: NodeId,
     pub span: Span,
     pub attrs: ThinVec<Attribute>,
@@ -900,6 +928,16 @@ pub struct Arm {
     pub body: P<Expr>,
 }
 
+/// One condition of an `if let` or `while let` chain, e.g. either side of the
+/// `&&` in `if let Some(x) = a && x > 3 { .. }`.
+#[derive(Clone, PartialEq, Eq, RustcEncodable, RustcDecodable, Hash, Debug)]
+pub enum ChainCond {
+    /// `let pat = expr`
+    Let(Vec<P<Pat>>, P<Expr>),
+    /// A boolean condition
+    Expr(P<Expr>),
+}
+
 #[derive(Clone, PartialEq, Eq, RustcEncodable, RustcDecodable, Hash, Debug)]
 pub struct Field {
     pub ident: Ident,
@@ -1006,15 +1044,16 @@ impl Expr {
             ExprKind::Lit(_) => ExprPrecedence::Lit,
             ExprKind::Type(..) | ExprKind::Cast(..) => ExprPrecedence::Cast,
             ExprKind::If(..) => ExprPrecedence::If,
-            ExprKind::IfLet(..) => ExprPrecedence::IfLet,
+            ExprKind::IfLet(..) | ExprKind::IfLetChain(..) => ExprPrecedence::IfLet,
             ExprKind::While(..) => ExprPrecedence::While,
-            ExprKind::WhileLet(..) => ExprPrecedence::WhileLet,
+            ExprKind::WhileLet(..) | ExprKind::WhileLetChain(..) => ExprPrecedence::WhileLet,
             ExprKind::ForLoop(..) => ExprPrecedence::ForLoop,
             ExprKind::Loop(..) => ExprPrecedence::Loop,
             ExprKind::Match(..) => ExprPrecedence::Match,
             ExprKind::Closure(..) => ExprPrecedence::Closure,
             ExprKind::Block(..) => ExprPrecedence::Block,
             ExprKind::Catch(..) => ExprPrecedence::Catch,
+            ExprKind::Async(..) => ExprPrecedence::Async,
             ExprKind::Assign(..) => ExprPrecedence::Assign,
             ExprKind::AssignOp(..) => ExprPrecedence::AssignOp,
             ExprKind::Field(..) => ExprPrecedence::Field,
@@ -1095,6 +1134,12 @@ pub enum ExprKind {
     ///
     /// This is desugared to a `match` expression.
     IfLet(Vec<P<Pat>>, P<Expr>, P<Block>, Option<P<Expr>>),
+    /// An `if let` chain with an optional else block
+    ///
+    /// `if let pat = expr && cond && let pat = expr { block } else { expr }`
+    ///
+    /// This is desugared to nested `match` and `if` expressions.
+    IfLetChain(Vec<ChainCond>, P<Block>, Option<P<Expr>>),
     /// A while loop, with an optional label
     ///
     /// `'label: while expr { block }`
@@ -1105,6 +1150,12 @@ pub enum ExprKind {
     ///
     /// This is desugared to a combination of `loop` and `match` expressions.
     WhileLet(Vec<P<Pat>>, P<Expr>, P<Block>, Option<Label>),
+    /// A `while let` chain, with an optional label
+    ///
+    /// `'label: while let pat = expr && cond { block }`
+    ///
+    /// This is desugared to a `loop` around nested `match` and `if` expressions.
+    WhileLetChain(Vec<ChainCond>, P<Block>, Option<Label>),
     /// A for loop, with an optional label
     ///
     /// `'label: for pat in expr { block }`
@@ -1125,6 +1176,15 @@ pub enum ExprKind {
     Block(P<Block>),
     /// A catch block (`catch { ... }`)
     Catch(P<Block>),
+    /// An async block (`async move { ... }`)
+    ///
+    /// The `NodeId` is the `NodeId` for the closure that results from
+    /// desugaring an async block, just like the NodeId field in the
+    /// `IsAsync` enum. This is necessary in order to create a def for the
+    /// closure which can be used as a parent of any child defs. Defs
+    /// created during lowering cannot be made the parent of any other
+    /// preexisting defs.
+    Async(CaptureBy, NodeId, P<Block>),
 
     /// An assignment (`a = foo()`)
     Assign(P<Expr>, P<Expr>),
@@ -1339,6 +1399,7 @@ pub struct MutTy {
 #[derive(Clone, PartialEq, Eq, RustcEncodable, RustcDecodable, Hash, Debug)]
 pub struct MethodSig {
     pub unsafety: Unsafety,
+    pub asyncness: IsAsync,
     pub constness: Spanned<Constness>,
     pub abi: Abi,
     pub decl: P<FnDecl>,
@@ -1756,6 +1817,36 @@ pub enum Unsafety {
     Normal,
 }
 
+#[derive(Copy, Clone, PartialEq, Eq, RustcEncodable, RustcDecodable, Hash, Debug)]
+pub enum IsAsync {
+    Async {
+        /// `NodeId` of the generator closure the body of the function is
+        /// lowered to.
+        closure_id: NodeId,
+        /// `NodeId` of the `impl Future` type the return type is lowered to.
+        return_impl_trait_id: NodeId,
+    },
+    NotAsync,
+}
+
+impl IsAsync {
+    pub fn is_async(self) -> bool {
+        if let IsAsync::Async { .. } = self {
+            true
+        } else {
+            false
+        }
+    }
+
+    /// In case this is an `Async` return the `NodeId` for the generated impl Trait item
+    pub fn opt_return_id(self) -> Option<NodeId> {
+        match self {
+            IsAsync::Async { return_impl_trait_id, .. } => Some(return_impl_trait_id),
+            IsAsync::NotAsync => None,
+        }
+    }
+}
+
 #[derive(Copy, Clone, PartialEq, Eq, RustcEncodable, RustcDecodable, Hash, Debug)]
 pub enum Constness {
     Const,
@@ -2075,7 +2166,7 @@ pub enum ItemKind {
     /// A function declaration (`fn` or `pub fn`).
     ///
     /// E.g. `fn foo(bar: usize) -> usize { .. }`
-    Fn(P<FnDecl>, Unsafety, Spanned<Constness>, Abi, Generics, P<Block>),
+    Fn(P<FnDecl>, Unsafety, IsAsync, Spanned<Constness>, Abi, Generics, P<Block>),
     /// A module declaration (`mod` or `pub mod`).
     ///
     /// E.g. `mod foo;` or `mod foo { .. }`
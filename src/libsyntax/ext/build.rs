@@ -327,7 +327,9 @@ impl<'a> AstBuilder for ExtCtxt<'a> {
             ast::PathSegment::from_ident(ident.with_span_pos(span))
         }));
         let parameters = if !lifetimes.is_empty() || !types.is_empty() || !bindings.is_empty() {
-            ast::AngleBracketedParameterData { lifetimes, types, bindings, span }.into()
+            ast::AngleBracketedParameterData {
+                lifetimes, types, consts: Vec::new(), bindings, span
+            }.into()
         } else {
             None
         };
@@ -365,7 +367,9 @@ impl<'a> AstBuilder for ExtCtxt<'a> {
                  -> (ast::QSelf, ast::Path) {
         let mut path = trait_path;
         let parameters = if !lifetimes.is_empty() || !types.is_empty() || !bindings.is_empty() {
-            ast::AngleBracketedParameterData { lifetimes, types, bindings, span: ident.span }.into()
+            ast::AngleBracketedParameterData {
+                lifetimes, types, consts: Vec::new(), bindings, span: ident.span
+            }.into()
         } else {
             None
         };
@@ -515,6 +519,7 @@ impl<'a> AstBuilder for ExtCtxt<'a> {
             pat,
             ty: None,
             init: Some(ex),
+            els: None,
             id: ast::DUMMY_NODE_ID,
             span: sp,
             attrs: ast::ThinVec::new(),
@@ -543,6 +548,7 @@ impl<'a> AstBuilder for ExtCtxt<'a> {
             pat,
             ty: Some(typ),
             init: Some(ex),
+            els: None,
             id: ast::DUMMY_NODE_ID,
             span: sp,
             attrs: ast::ThinVec::new(),
@@ -560,6 +566,7 @@ impl<'a> AstBuilder for ExtCtxt<'a> {
             pat: self.pat_wild(span),
             ty: Some(ty),
             init: None,
+            els: None,
             id: ast::DUMMY_NODE_ID,
             span,
             attrs: ast::ThinVec::new(),
@@ -1007,6 +1014,7 @@ impl<'a> AstBuilder for ExtCtxt<'a> {
                   Vec::new(),
                   ast::ItemKind::Fn(self.fn_decl(inputs, ast::FunctionRetTy::Ty(output)),
                               ast::Unsafety::Normal,
+                              ast::IsAsync::NotAsync,
                               dummy_spanned(ast::Constness::NotConst),
                               Abi::Rust,
                               generics,
@@ -1185,8 +1185,10 @@ impl<'a, 'b> Folder for InvocationCollector<'a, 'b> {
                 self.cx.current_expansion.directory_ownership = orig_directory_ownership;
                 result
             }
-            // Ensure that test functions are accessible from the test harness.
-            ast::ItemKind::Fn(..) if self.cx.ecfg.should_test => {
+            // Ensure that test functions and test cases are accessible from the test harness.
+            ast::ItemKind::Fn(..) |
+            ast::ItemKind::Static(..) |
+            ast::ItemKind::Const(..) if self.cx.ecfg.should_test => {
                 if item.attrs.iter().any(|attr| is_test_or_bench(attr)) {
                     item = item.map(|mut item| {
                         item.vis = respan(item.vis.span, ast::VisibilityKind::Public);
@@ -1438,6 +1440,10 @@ impl<'feat> ExpansionConfig<'feat> {
         fn enable_trace_macros = trace_macros,
         fn enable_allow_internal_unstable = allow_internal_unstable,
         fn enable_custom_derive = custom_derive,
+        fn enable_format_args_capture = format_args_capture,
+        fn enable_format_args_nl = format_args_nl,
+        fn enable_generic_assert = generic_assert,
+        fn enable_derive_default_enum = derive_default_enum,
         fn proc_macro_enabled = proc_macro,
         fn macros_in_extern_enabled = macros_in_extern,
         fn proc_macro_mod = proc_macro_mod,
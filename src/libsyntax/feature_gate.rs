@@ -466,6 +466,62 @@ declare_features! (
 
     // #[doc(alias = "...")]
     (active, doc_alias, "1.27.0", Some(50146), None),
+
+    // Allows `#![move_size_limit = "N"]` to configure the `large_assignments` lint
+    (active, large_assignments, "1.27.0", Some(83518), None),
+
+    // Allows `#[track_caller]` to report panic locations of the caller
+    (active, track_caller, "1.27.0", Some(47809), None),
+
+    // Allows `format_args!("{name}")` to capture `name` from the surrounding scope
+    (active, format_args_capture, "1.27.0", Some(67984), None),
+
+    // `format_args_nl!`, used by `println!` and friends
+    (active, format_args_nl, "1.27.0", None, None),
+
+    // Allows `assert!` to print the values of the operands of a failed comparison
+    (active, generic_assert, "1.27.0", Some(44838), None),
+
+    // Allows `#[derive(Default)]` on enums with a `#[default]` unit variant
+    (active, derive_default_enum, "1.27.0", Some(86985), None),
+
+    // Allows `async fn` and `async` blocks
+    (active, async_await, "1.27.0", Some(50547), None),
+
+    // Allows `if`, `match`, loops and `let` bindings in constants and `const fn`
+    (active, const_control_flow, "1.27.0", Some(49146), None),
+
+    // Allows `#![const_eval_limit = "N"]` and `#![const_eval_warn_limit = "N"]`
+    (active, const_eval_limit, "1.27.0", None, None),
+
+    // Allows `|` alternatives nested inside patterns, e.g. `Some(A | B)`
+    (active, or_patterns, "1.27.0", Some(54883), None),
+
+    // Allows `match` on integers and `char`s to be exhaustive without a `_` arm
+    (active, exhaustive_integer_patterns, "1.27.0", Some(50907), None),
+
+    // Allows `let PAT = EXPR else { DIVERGING };`
+    (active, let_else, "1.27.0", Some(87335), None),
+
+    // Allows closures to capture the fields of a variable they use instead of
+    // the whole variable
+    (active, capture_disjoint_fields, "1.27.0", Some(53488), None),
+
+    // Allows const generic parameters, e.g. `struct Buf<const N: usize>`
+    (active, const_generics, "1.27.0", Some(44580), None),
+
+    // Allows `#![test_runner]` and `#[test_case]` for custom test frameworks
+    (active, custom_test_frameworks, "1.27.0", Some(50297), None),
+
+    // Allows `type Foo = impl Trait;` and `impl Trait` as the type of an
+    // associated type in an impl
+    (active, type_alias_impl_trait, "1.27.0", Some(63063), None),
+
+    // Allows `let x: impl Trait = ..;`
+    (active, impl_trait_in_bindings, "1.27.0", Some(63065), None),
+
+    // Allows `if let` and `while let` chains: `if let Some(x) = a && x > 3 { .. }`
+    (active, let_chains, "1.27.0", Some(53667), None),
 );
 
 declare_features! (
@@ -697,6 +753,14 @@ pub const BUILTIN_ATTRIBUTES: &'static [(&'static str, AttributeType, AttributeG
     ("ignore", Normal, Ungated),
     ("no_implicit_prelude", Normal, Ungated),
     ("reexport_test_harness_main", Normal, Ungated),
+    ("test_runner", CrateLevel, Gated(Stability::Unstable,
+                                      "custom_test_frameworks",
+                                      "custom test frameworks are an unstable feature",
+                                      cfg_fn!(custom_test_frameworks))),
+    ("test_case", Normal, Gated(Stability::Unstable,
+                                "custom_test_frameworks",
+                                "custom test frameworks are an unstable feature",
+                                cfg_fn!(custom_test_frameworks))),
     ("link_args", Normal, Gated(Stability::Unstable,
                                 "link_args",
                                 "the `link_args` attribute is experimental and not \
@@ -973,6 +1037,11 @@ pub const BUILTIN_ATTRIBUTES: &'static [(&'static str, AttributeType, AttributeG
            "dropck_eyepatch",
            "may_dangle has unstable semantics and may be removed in the future",
            cfg_fn!(dropck_eyepatch))),
+    ("track_caller", Whitelisted, Gated(Stability::Unstable,
+                                        "track_caller",
+                                        "the `#[track_caller]` attribute \
+                                         is an experimental feature",
+                                        cfg_fn!(track_caller))),
     ("unwind", Whitelisted, Gated(Stability::Unstable,
                                   "unwind_attributes",
                                   "#[unwind] is experimental",
@@ -1062,6 +1131,21 @@ pub const BUILTIN_ATTRIBUTES: &'static [(&'static str, AttributeType, AttributeG
     ("no_builtins", CrateLevel, Ungated),
     ("recursion_limit", CrateLevel, Ungated),
     ("type_length_limit", CrateLevel, Ungated),
+    ("move_size_limit", CrateLevel, Gated(Stability::Unstable,
+                                          "large_assignments",
+                                          "the `#[move_size_limit]` attribute is an \
+                                           experimental feature",
+                                          cfg_fn!(large_assignments))),
+    ("const_eval_limit", CrateLevel, Gated(Stability::Unstable,
+                                           "const_eval_limit",
+                                           "the `#[const_eval_limit]` attribute is an \
+                                            experimental feature",
+                                           cfg_fn!(const_eval_limit))),
+    ("const_eval_warn_limit", CrateLevel, Gated(Stability::Unstable,
+                                                "const_eval_limit",
+                                                "the `#[const_eval_warn_limit]` attribute is an \
+                                                 experimental feature",
+                                                cfg_fn!(const_eval_limit))),
 ];
 
 // cfg(...)'s that are feature gated
@@ -1290,6 +1374,12 @@ pub const EXPLAIN_LOG_SYNTAX: &'static str =
 pub const EXPLAIN_CONCAT_IDENTS: &'static str =
     "`concat_idents` is not stable enough for use and is subject to change";
 
+pub const EXPLAIN_FORMAT_ARGS_NL: &'static str =
+    "`format_args_nl` is only for internal language use and is subject to change";
+
+pub const EXPLAIN_DEFAULT_ATTR: &'static str =
+    "the `#[default]` attribute is an experimental feature";
+
 pub const EXPLAIN_TRACE_MACROS: &'static str =
     "`trace_macros` is not stable enough for use and is subject to change";
 pub const EXPLAIN_ALLOW_INTERNAL_UNSTABLE: &'static str =
@@ -1582,6 +1672,13 @@ impl<'a> Visitor<'a> for PostExpansionVisitor<'a> {
                                    "trait aliases are not yet fully implemented");
             }
 
+            ast::ItemKind::Ty(ref ty, _) => {
+                if let ast::TyKind::ImplTrait(..) = ty.node {
+                    gate_feature_post!(&self, type_alias_impl_trait, ty.span,
+                                       "`impl Trait` in type aliases is unstable");
+                }
+            }
+
             ast::ItemKind::Impl(_, polarity, defaultness, _, _, _, ref impl_items) => {
                 if polarity == ast::ImplPolarity::Negative {
                     gate_feature_post!(&self, optin_builtin_traits,
@@ -1666,6 +1763,24 @@ impl<'a> Visitor<'a> for PostExpansionVisitor<'a> {
         visit::walk_ty(self, ty)
     }
 
+    fn visit_generic_param(&mut self, param: &'a ast::GenericParam) {
+        if let ast::GenericParam::Const(ref c) = *param {
+            gate_feature_post!(&self, const_generics, c.ident.span.to(c.ty.span),
+                               "const generics are unstable");
+        }
+        visit::walk_generic_param(self, param)
+    }
+
+    fn visit_local(&mut self, local: &'a ast::Local) {
+        if let Some(ref ty) = local.ty {
+            if let ast::TyKind::ImplTrait(..) = ty.node {
+                gate_feature_post!(&self, impl_trait_in_bindings, ty.span,
+                                   "`impl Trait` in let bindings is unstable");
+            }
+        }
+        visit::walk_local(self, local)
+    }
+
     fn visit_fn_ret_ty(&mut self, ret_ty: &'a ast::FunctionRetTy) {
         if let ast::FunctionRetTy::Ty(ref output_ty) = *ret_ty {
             if output_ty.node != ast::TyKind::Never {
@@ -1691,12 +1806,28 @@ impl<'a> Visitor<'a> for PostExpansionVisitor<'a> {
             ast::ExprKind::Catch(_) => {
                 gate_feature_post!(&self, catch_expr, e.span, "`catch` expression is experimental");
             }
+            ast::ExprKind::Async(..) => {
+                gate_feature_post!(&self, async_await, e.span, "async blocks are unstable");
+            }
             ast::ExprKind::IfLet(ref pats, ..) | ast::ExprKind::WhileLet(ref pats, ..) => {
                 if pats.len() > 1 {
                     gate_feature_post!(&self, if_while_or_patterns, e.span,
                                     "multiple patterns in `if let` and `while let` are unstable");
                 }
             }
+            ast::ExprKind::IfLetChain(ref conds, ..) |
+            ast::ExprKind::WhileLetChain(ref conds, ..) => {
+                // `let_chains` itself is gated by the parser, which only
+                // parses a chain without it if one is clearly intended.
+                for cond in conds {
+                    if let ast::ChainCond::Let(ref pats, _) = *cond {
+                        if pats.len() > 1 {
+                            gate_feature_post!(&self, if_while_or_patterns, e.span,
+                                "multiple patterns in `if let` and `while let` are unstable");
+                        }
+                    }
+                }
+            }
             _ => {}
         }
         visit::walk_expr(self, e);
@@ -1706,6 +1837,14 @@ impl<'a> Visitor<'a> for PostExpansionVisitor<'a> {
         visit::walk_arm(self, arm)
     }
 
+    fn visit_local(&mut self, local: &'a ast::Local) {
+        if local.els.is_some() {
+            gate_feature_post!(&self, let_else, local.span,
+                               "`let...else` statements are unstable");
+        }
+        visit::walk_local(self, local)
+    }
+
     fn visit_pat(&mut self, pattern: &'a ast::Pat) {
         match pattern.node {
             PatKind::Slice(_, Some(ref subslice), _) => {
@@ -1726,6 +1865,10 @@ impl<'a> Visitor<'a> for PostExpansionVisitor<'a> {
                 gate_feature_post!(&self, pattern_parentheses, pattern.span,
                                    "parentheses in patterns are unstable");
             }
+            PatKind::Or(..) => {
+                gate_feature_post!(&self, or_patterns, pattern.span,
+                                   "nested or-patterns are unstable");
+            }
             _ => {}
         }
         visit::walk_pat(self, pattern)
@@ -1737,17 +1880,25 @@ impl<'a> Visitor<'a> for PostExpansionVisitor<'a> {
                 span: Span,
                 _node_id: NodeId) {
         // check for const fn declarations
-        if let FnKind::ItemFn(_, _, Spanned { node: ast::Constness::Const, .. }, _, _, _) =
+        if let FnKind::ItemFn(_, _, _, Spanned { node: ast::Constness::Const, .. }, _, _, _) =
             fn_kind {
             gate_feature_post!(&self, const_fn, span, "const fn is unstable");
         }
+        // check for async fn declarations
+        match fn_kind {
+            FnKind::ItemFn(_, _, asyncness, ..) |
+            FnKind::Method(_, &ast::MethodSig { asyncness, .. }, ..) if asyncness.is_async() => {
+                gate_feature_post!(&self, async_await, span, "async fn is unstable");
+            }
+            _ => {}
+        }
         // stability of const fn methods are covered in
         // visit_trait_item and visit_impl_item below; this is
         // because default methods don't pass through this
         // point.
 
         match fn_kind {
-            FnKind::ItemFn(_, _, _, abi, _, _) |
+            FnKind::ItemFn(_, _, _, _, abi, _, _) |
             FnKind::Method(_, &ast::MethodSig { abi, .. }, _, _) => {
                 self.check_abi(abi, span);
             }
@@ -1800,9 +1951,15 @@ impl<'a> Visitor<'a> for PostExpansionVisitor<'a> {
                     gate_feature_post!(&self, const_fn, ii.span, "const fn is unstable");
                 }
             }
-            ast::ImplItemKind::Type(_) if ii.generics.is_parameterized() => {
-                gate_feature_post!(&self, generic_associated_types, ii.span,
-                                   "generic associated types are unstable");
+            ast::ImplItemKind::Type(ref ty) => {
+                if ii.generics.is_parameterized() {
+                    gate_feature_post!(&self, generic_associated_types, ii.span,
+                                       "generic associated types are unstable");
+                }
+                if let ast::TyKind::ImplTrait(..) = ty.node {
+                    gate_feature_post!(&self, type_alias_impl_trait, ty.span,
+                                       "`impl Trait` in associated types is unstable");
+                }
             }
             _ => {}
         }
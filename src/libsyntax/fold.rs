@@ -96,6 +96,10 @@ pub trait Folder : Sized {
         noop_fold_fn_decl(d, self)
     }
 
+    fn fold_asyncness(&mut self, a: IsAsync) -> IsAsync {
+        noop_fold_asyncness(a, self)
+    }
+
     fn fold_block(&mut self, b: P<Block>) -> P<Block> {
         noop_fold_block(b, self)
     }
@@ -355,6 +359,15 @@ pub fn noop_fold_arm<T: Folder>(Arm {attrs, pats, guard, body}: Arm,
     }
 }
 
+pub fn noop_fold_chain_cond<T: Folder>(cond: ChainCond, fld: &mut T) -> ChainCond {
+    match cond {
+        ChainCond::Let(pats, expr) => {
+            ChainCond::Let(pats.move_map(|x| fld.fold_pat(x)), fld.fold_expr(expr))
+        }
+        ChainCond::Expr(expr) => ChainCond::Expr(fld.fold_expr(expr)),
+    }
+}
+
 pub fn noop_fold_ty_binding<T: Folder>(b: TypeBinding, fld: &mut T) -> TypeBinding {
     TypeBinding {
         id: fld.new_id(b.id),
@@ -472,9 +485,10 @@ pub fn noop_fold_angle_bracketed_parameter_data<T: Folder>(data: AngleBracketedP
                                                            fld: &mut T)
                                                            -> AngleBracketedParameterData
 {
-    let AngleBracketedParameterData { lifetimes, types, bindings, span } = data;
+    let AngleBracketedParameterData { lifetimes, types, consts, bindings, span } = data;
     AngleBracketedParameterData { lifetimes: fld.fold_lifetimes(lifetimes),
                                   types: types.move_map(|ty| fld.fold_ty(ty)),
+                                  consts: consts.move_map(|c| fld.fold_expr(c)),
                                   bindings: bindings.move_map(|b| fld.fold_ty_binding(b)),
                                   span: fld.new_span(span) }
 }
@@ -490,11 +504,12 @@ pub fn noop_fold_parenthesized_parameter_data<T: Folder>(data: ParenthesizedPara
 }
 
 pub fn noop_fold_local<T: Folder>(l: P<Local>, fld: &mut T) -> P<Local> {
-    l.map(|Local {id, pat, ty, init, span, attrs}| Local {
+    l.map(|Local {id, pat, ty, init, els, span, attrs}| Local {
         id: fld.new_id(id),
         pat: fld.fold_pat(pat),
         ty: ty.map(|t| fld.fold_ty(t)),
         init: init.map(|e| fld.fold_expr(e)),
+        els: els.map(|b| fld.fold_block(b)),
         span: fld.new_span(span),
         attrs: fold_attrs(attrs.into(), fld).into(),
     })
@@ -657,6 +672,16 @@ pub fn noop_fold_interpolated<T: Folder>(nt: token::Nonterminal, fld: &mut T)
     }
 }
 
+pub fn noop_fold_asyncness<T: Folder>(asyncness: IsAsync, fld: &mut T) -> IsAsync {
+    match asyncness {
+        IsAsync::Async { closure_id, return_impl_trait_id } => IsAsync::Async {
+            closure_id: fld.new_id(closure_id),
+            return_impl_trait_id: fld.new_id(return_impl_trait_id),
+        },
+        IsAsync::NotAsync => IsAsync::NotAsync,
+    }
+}
+
 pub fn noop_fold_fn_decl<T: Folder>(decl: P<FnDecl>, fld: &mut T) -> P<FnDecl> {
     decl.map(|FnDecl {inputs, output, variadic}| FnDecl {
         inputs: inputs.move_map(|x| fld.fold_arg(x)),
@@ -696,6 +721,21 @@ pub fn noop_fold_generic_param<T: Folder>(param: GenericParam, fld: &mut T) -> G
     match param {
         GenericParam::Lifetime(l) => GenericParam::Lifetime(fld.fold_lifetime_def(l)),
         GenericParam::Type(t) => GenericParam::Type(fld.fold_ty_param(t)),
+        GenericParam::Const(c) => GenericParam::Const(noop_fold_const_param(c, fld)),
+    }
+}
+
+fn noop_fold_const_param<T: Folder>(cp: ConstParam, fld: &mut T) -> ConstParam {
+    let ConstParam {attrs, id, ident, ty} = cp;
+    let attrs: Vec<_> = attrs.into();
+    ConstParam {
+        attrs: attrs.into_iter()
+            .flat_map(|x| fld.fold_attribute(x).into_iter())
+            .collect::<Vec<_>>()
+            .into(),
+        id: fld.new_id(id),
+        ident: fld.fold_ident(ident),
+        ty: fld.fold_ty(ty),
     }
 }
 
@@ -901,11 +941,12 @@ pub fn noop_fold_item_kind<T: Folder>(i: ItemKind, folder: &mut T) -> ItemKind {
         ItemKind::Const(t, e) => {
             ItemKind::Const(folder.fold_ty(t), folder.fold_expr(e))
         }
-        ItemKind::Fn(decl, unsafety, constness, abi, generics, body) => {
+        ItemKind::Fn(decl, unsafety, asyncness, constness, abi, generics, body) => {
             let generics = folder.fold_generics(generics);
             let decl = folder.fold_fn_decl(decl);
+            let asyncness = folder.fold_asyncness(asyncness);
             let body = folder.fold_block(body);
-            ItemKind::Fn(decl, unsafety, constness, abi, generics, body)
+            ItemKind::Fn(decl, unsafety, asyncness, constness, abi, generics, body)
         }
         ItemKind::Mod(m) => ItemKind::Mod(folder.fold_mod(m)),
         ItemKind::ForeignMod(nm) => ItemKind::ForeignMod(folder.fold_foreign_mod(nm)),
@@ -1104,6 +1145,7 @@ pub fn noop_fold_method_sig<T: Folder>(sig: MethodSig, folder: &mut T) -> Method
     MethodSig {
         abi: sig.abi,
         unsafety: sig.unsafety,
+        asyncness: folder.fold_asyncness(sig.asyncness),
         constness: sig.constness,
         decl: folder.fold_fn_decl(sig.decl)
     }
@@ -1159,6 +1201,7 @@ pub fn noop_fold_pat<T: Folder>(p: P<Pat>, folder: &mut T) -> P<Pat> {
                        after.move_map(|x| folder.fold_pat(x)))
             }
             PatKind::Paren(inner) => PatKind::Paren(folder.fold_pat(inner)),
+            PatKind::Or(pats) => PatKind::Or(pats.move_map(|x| folder.fold_pat(x))),
             PatKind::Mac(mac) => PatKind::Mac(folder.fold_mac(mac))
         },
         span: folder.new_span(span)
@@ -1234,6 +1277,16 @@ pub fn noop_fold_expr<T: Folder>(Expr {id, node, span, attrs}: Expr, folder: &mu
                              folder.fold_block(body),
                              opt_label.map(|label| folder.fold_label(label)))
             }
+            ExprKind::IfLetChain(conds, tr, fl) => {
+                ExprKind::IfLetChain(conds.move_map(|cond| noop_fold_chain_cond(cond, folder)),
+                                     folder.fold_block(tr),
+                                     fl.map(|x| folder.fold_expr(x)))
+            }
+            ExprKind::WhileLetChain(conds, body, opt_label) => {
+                ExprKind::WhileLetChain(conds.move_map(|cond| noop_fold_chain_cond(cond, folder)),
+                                        folder.fold_block(body),
+                                        opt_label.map(|label| folder.fold_label(label)))
+            }
             ExprKind::ForLoop(pat, iter, body, opt_label) => {
                 ExprKind::ForLoop(folder.fold_pat(pat),
                             folder.fold_expr(iter),
@@ -1327,6 +1380,11 @@ pub fn noop_fold_expr<T: Folder>(Expr {id, node, span, attrs}: Expr, folder: &mu
             ExprKind::Yield(ex) => ExprKind::Yield(ex.map(|x| folder.fold_expr(x))),
             ExprKind::Try(ex) => ExprKind::Try(folder.fold_expr(ex)),
             ExprKind::Catch(body) => ExprKind::Catch(folder.fold_block(body)),
+            ExprKind::Async(capture_clause, node_id, body) => {
+                ExprKind::Async(capture_clause,
+                                folder.new_id(node_id),
+                                folder.fold_block(body))
+            }
         },
         id: folder.new_id(id),
         span: folder.new_span(span),
@@ -25,10 +25,12 @@ pub fn expr_requires_semi_to_be_stmt(e: &ast::Expr) -> bool {
     match e.node {
         ast::ExprKind::If(..) |
         ast::ExprKind::IfLet(..) |
+        ast::ExprKind::IfLetChain(..) |
         ast::ExprKind::Match(..) |
         ast::ExprKind::Block(_) |
         ast::ExprKind::While(..) |
         ast::ExprKind::WhileLet(..) |
+        ast::ExprKind::WhileLetChain(..) |
         ast::ExprKind::Loop(..) |
         ast::ExprKind::ForLoop(..) |
         ast::ExprKind::Catch(..) => false,
@@ -36,6 +38,30 @@ pub fn expr_requires_semi_to_be_stmt(e: &ast::Expr) -> bool {
     }
 }
 
+/// Does this expression end with a block, like `if c { .. }` or
+/// `a + match x { .. }` do? Used to reject `let ... = EXPR else { .. }`
+/// where `EXPR` ends with a `}`, which reads like an `if`/`else`.
+pub fn expr_trailing_brace(e: &ast::Expr) -> bool {
+    if !expr_requires_semi_to_be_stmt(e) {
+        return true;
+    }
+    match e.node {
+        ast::ExprKind::Struct(..) |
+        ast::ExprKind::Async(..) => true,
+        ast::ExprKind::Box(ref e) |
+        ast::ExprKind::Binary(_, _, ref e) |
+        ast::ExprKind::Unary(_, ref e) |
+        ast::ExprKind::Closure(_, _, _, ref e, _) |
+        ast::ExprKind::Assign(_, ref e) |
+        ast::ExprKind::AssignOp(_, _, ref e) |
+        ast::ExprKind::AddrOf(_, ref e) |
+        ast::ExprKind::Range(_, Some(ref e), _) |
+        ast::ExprKind::Break(_, Some(ref e)) |
+        ast::ExprKind::Ret(Some(ref e)) => expr_trailing_brace(e),
+        _ => false,
+    }
+}
+
 /// this statement requires a semicolon after it.
 /// note that in one case (`stmt_semi`), we've already
 /// seen the semicolon, and thus don't need another.
@@ -1159,6 +1159,8 @@ impl<'a> StringReader<'a> {
                         let span = self.mk_sp(raw_start, self.pos);
                         self.sess.raw_identifier_spans.borrow_mut().push(span);
                     }
+                    let span = self.mk_sp(start, self.pos);
+                    self.sess.symbol_gallery.borrow_mut().entry(ident.name).or_insert(span);
                     token::Ident(ident, is_raw_ident)
                 }));
             }
@@ -1785,6 +1787,7 @@ mod tests {
     use std::io;
     use std::path::PathBuf;
     use diagnostics::plugin::ErrorMap;
+    use rustc_data_structures::fx::FxHashMap;
     use rustc_data_structures::sync::Lock;
     use with_globals;
     fn mk_sess(cm: Lrc<CodeMap>) -> ParseSess {
@@ -1800,6 +1803,7 @@ mod tests {
             code_map: cm,
             missing_fragment_specifiers: Lock::new(HashSet::new()),
             raw_identifier_spans: Lock::new(Vec::new()),
+            symbol_gallery: Lock::new(FxHashMap()),
             registered_diagnostics: Lock::new(ErrorMap::new()),
             non_modrs_mods: Lock::new(vec![]),
         }
@@ -10,7 +10,8 @@
 
 //! The main parser interface
 
-use rustc_data_structures::sync::{Lrc, Lock};
+use rustc_data_structures::fx::FxHashMap;
+use rustc_data_structures::sync::{Lrc, Lock, LockCell};
 use ast::{self, CrateConfig};
 use codemap::{CodeMap, FilePathMapping};
 use syntax_pos::{self, Span, FileMap, NO_EXPANSION, FileName};
@@ -49,11 +50,18 @@ pub struct ParseSess {
     /// Places where raw identifiers were used. This is used for feature gating
     /// raw identifiers
     pub raw_identifier_spans: Lock<Vec<Span>>,
+    /// Every identifier symbol lexed in this session, along with the span of
+    /// its first occurrence. Used by the `non_ascii_idents` family of lints.
+    pub symbol_gallery: Lock<FxHashMap<Symbol, Span>>,
     /// The registered diagnostics codes
     pub registered_diagnostics: Lock<ErrorMap>,
     // Spans where a `mod foo;` statement was included in a non-mod.rs file.
     // These are used to issue errors if the non_modrs_mods feature is not enabled.
     pub non_modrs_mods: Lock<Vec<(ast::Ident, Span)>>,
+    /// Whether the crate being parsed enables `let_chains`, which changes how
+    /// `&&` is parsed in the condition of an `if` or `while`. Set once the
+    /// inner attributes of the crate have been parsed.
+    pub let_chains: LockCell<bool>,
     /// Used to determine and report recursive mod inclusions
     included_mod_stack: Lock<Vec<PathBuf>>,
     code_map: Lrc<CodeMap>,
@@ -76,10 +84,12 @@ impl ParseSess {
             config: HashSet::new(),
             missing_fragment_specifiers: Lock::new(HashSet::new()),
             raw_identifier_spans: Lock::new(Vec::new()),
+            symbol_gallery: Lock::new(FxHashMap()),
             registered_diagnostics: Lock::new(ErrorMap::new()),
             included_mod_stack: Lock::new(vec![]),
             code_map,
             non_modrs_mods: Lock::new(vec![]),
+            let_chains: LockCell::new(false),
         }
     }
 
@@ -928,6 +938,7 @@ mod tests {
                                     variadic: false
                                 }),
                                         ast::Unsafety::Normal,
+                                        ast::IsAsync::NotAsync,
                                         Spanned {
                                             span: sp(0,2),
                                             node: ast::Constness::NotConst,
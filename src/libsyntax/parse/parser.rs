@@ -14,15 +14,15 @@ use ast::{RegionTyParamBound, TraitTyParamBound, TraitBoundModifier};
 use ast::Unsafety;
 use ast::{Mod, Arg, Arm, Attribute, BindingMode, TraitItemKind};
 use ast::Block;
-use ast::{BlockCheckMode, CaptureBy, Movability};
-use ast::{Constness, Crate};
+use ast::{BlockCheckMode, CaptureBy, ChainCond, Movability};
+use ast::{ConstParam, Constness, Crate};
 use ast::Defaultness;
 use ast::EnumDef;
 use ast::{Expr, ExprKind, RangeLimits};
 use ast::{Field, FnDecl};
 use ast::{ForeignItem, ForeignItemKind, FunctionRetTy};
 use ast::GenericParam;
-use ast::{Ident, ImplItem, IsAuto, Item, ItemKind};
+use ast::{Ident, ImplItem, IsAsync, IsAuto, Item, ItemKind};
 use ast::{Label, Lifetime, LifetimeDef, Lit, LitKind, UintTy};
 use ast::Local;
 use ast::MacStmtStyle;
@@ -44,6 +44,7 @@ use {ast, attr};
 use codemap::{self, CodeMap, Spanned, respan};
 use syntax_pos::{self, Span, MultiSpan, BytePos, FileName, DUMMY_SP};
 use errors::{self, DiagnosticBuilder};
+use feature_gate::{self, GateIssue};
 use parse::{self, classify, token};
 use parse::common::SeqSep;
 use parse::lexer::TokenAndSpan;
@@ -1365,6 +1366,19 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Parse asyncness: `async` or nothing
+    fn parse_asyncness(&mut self) -> IsAsync {
+        // `async` is only a keyword in Rust 2018.
+        if self.span.rust_2018() && self.eat_keyword(keywords::Async) {
+            IsAsync::Async {
+                closure_id: ast::DUMMY_NODE_ID,
+                return_impl_trait_id: ast::DUMMY_NODE_ID,
+            }
+        } else {
+            IsAsync::NotAsync
+        }
+    }
+
     /// Parse the items in a trait declaration
     pub fn parse_trait_item(&mut self, at_end: &mut bool) -> PResult<'a, TraitItem> {
         maybe_whole!(self, NtTraitItem, |x| x);
@@ -1407,7 +1421,7 @@ impl<'a> Parser<'a> {
             // trait item macro.
             (keywords::Invalid.ident(), ast::TraitItemKind::Macro(mac), ast::Generics::default())
         } else {
-            let (constness, unsafety, abi) = self.parse_fn_front_matter()?;
+            let (constness, unsafety, asyncness, abi) = self.parse_fn_front_matter()?;
 
             let ident = self.parse_ident()?;
             let mut generics = self.parse_generics()?;
@@ -1422,6 +1436,7 @@ impl<'a> Parser<'a> {
 
             let sig = ast::MethodSig {
                 unsafety,
+                asyncness,
                 constness,
                 decl: d,
                 abi,
@@ -1765,6 +1780,9 @@ impl<'a> Parser<'a> {
             debug!("parse_arg_general parse_pat (require_name:{})",
                    require_name);
             let pat = self.parse_pat()?;
+            if self.token == token::BinOp(token::Or) {
+                return Err(self.parenthesize_param_alternatives(pat));
+            }
 
             self.expect(&token::Colon)?;
             pat
@@ -1787,6 +1805,27 @@ impl<'a> Parser<'a> {
         })
     }
 
+    /// Reports an or-pattern that isn't parenthesized in the parameters of a function.
+    fn parenthesize_param_alternatives(&mut self, pat: P<Pat>) -> DiagnosticBuilder<'a> {
+        let mut pats = vec![pat];
+        while self.eat(&token::BinOp(token::Or)) {
+            match self.parse_pat() {
+                Ok(pat) => pats.push(pat),
+                Err(mut err) => {
+                    err.cancel();
+                    break;
+                }
+            }
+        }
+        let span = pats[0].span.to(pats[pats.len() - 1].span);
+        let mut err = self.struct_span_err(span, "top-level or-patterns are not allowed in \
+                                                  function parameters");
+        if let Ok(snippet) = self.sess.codemap().span_to_snippet(span) {
+            err.span_suggestion(span, "wrap the pattern in parentheses", format!("({})", snippet));
+        }
+        err
+    }
+
     /// Parse a single function argument
     pub fn parse_arg(&mut self) -> PResult<'a, Arg> {
         self.parse_arg_general(true)
@@ -2013,10 +2052,10 @@ impl<'a> Parser<'a> {
 
             let parameters = if self.eat_lt() {
                 // `<'a, T, A = U>`
-                let (lifetimes, types, bindings) = self.parse_generic_args()?;
+                let (lifetimes, types, consts, bindings) = self.parse_generic_args()?;
                 self.expect_gt()?;
                 let span = lo.to(self.prev_span);
-                AngleBracketedParameterData { lifetimes, types, bindings, span }.into()
+                AngleBracketedParameterData { lifetimes, types, consts, bindings, span }.into()
             } else {
                 // `(T, U) -> R`
                 self.bump(); // `(`
@@ -2348,6 +2387,9 @@ impl<'a> Parser<'a> {
                     assert!(self.eat_keyword(keywords::Catch));
                     return self.parse_catch_expr(lo, attrs);
                 }
+                if self.is_async_block() {
+                    return self.parse_async_block(attrs);
+                }
                 if self.eat_keyword(keywords::Return) {
                     if self.token.can_begin_expr() {
                         let e = self.parse_expr()?;
@@ -2539,7 +2581,7 @@ impl<'a> Parser<'a> {
                 attrs.extend::<Vec<_>>(expr.attrs.into());
                 expr.attrs = attrs;
                 match expr.node {
-                    ExprKind::If(..) | ExprKind::IfLet(..) => {
+                    ExprKind::If(..) | ExprKind::IfLet(..) | ExprKind::IfLetChain(..) => {
                         if !expr.attrs.is_empty() {
                             // Just point to the first attribute in there...
                             let span = expr.attrs[0].span;
@@ -3182,11 +3224,16 @@ impl<'a> Parser<'a> {
 
     /// Parse an 'if' or 'if let' expression ('if' token already eaten)
     pub fn parse_if_expr(&mut self, attrs: ThinVec<Attribute>) -> PResult<'a, P<Expr>> {
-        if self.check_keyword(keywords::Let) {
-            return self.parse_if_let_expr(attrs);
-        }
         let lo = self.prev_span;
-        let cond = self.parse_expr_res(Restrictions::NO_STRUCT_LITERAL, None)?;
+        let mut conds = self.parse_let_chain()?;
+        let cond = match (conds.len(), conds.pop()) {
+            (1, Some(ChainCond::Expr(cond))) => cond,
+            (_, Some(cond)) => {
+                conds.push(cond);
+                return self.parse_if_let_expr(lo, conds, attrs);
+            }
+            (_, None) => unreachable!(),
+        };
 
         // Verify that the parsed `if` condition makes sense as a condition. If it is a block, then
         // verify that the last statement is either an implicit return (no `;`) or an explicit
@@ -3216,14 +3263,10 @@ impl<'a> Parser<'a> {
         Ok(self.mk_expr(lo.to(hi), ExprKind::If(cond, thn, els), attrs))
     }
 
-    /// Parse an 'if let' expression ('if' token already eaten)
-    pub fn parse_if_let_expr(&mut self, attrs: ThinVec<Attribute>)
-                             -> PResult<'a, P<Expr>> {
-        let lo = self.prev_span;
-        self.expect_keyword(keywords::Let)?;
-        let pats = self.parse_pats()?;
-        self.expect(&token::Eq)?;
-        let expr = self.parse_expr_res(Restrictions::NO_STRUCT_LITERAL, None)?;
+    /// Parse the rest of an 'if let' expression, the conditions of which
+    /// have been parsed
+    fn parse_if_let_expr(&mut self, lo: Span, mut conds: Vec<ChainCond>,
+                         attrs: ThinVec<Attribute>) -> PResult<'a, P<Expr>> {
         let thn = self.parse_block()?;
         let (hi, els) = if self.eat_keyword(keywords::Else) {
             let expr = self.parse_else_expr()?;
@@ -3231,7 +3274,102 @@ impl<'a> Parser<'a> {
         } else {
             (thn.span, None)
         };
-        Ok(self.mk_expr(lo.to(hi), ExprKind::IfLet(pats, expr, thn, els), attrs))
+        let kind = if conds.len() > 1 {
+            ExprKind::IfLetChain(conds, thn, els)
+        } else if let Some(ChainCond::Let(pats, expr)) = conds.pop() {
+            ExprKind::IfLet(pats, expr, thn, els)
+        } else {
+            unreachable!()
+        };
+        Ok(self.mk_expr(lo.to(hi), kind, attrs))
+    }
+
+    /// Parse the condition of an `if` or `while` into its `&&`-separated
+    /// conditions, such as `let Some(x) = a && x > 3` or `c && let Ok(y) = b`.
+    /// A condition without any `let` comes back as a single `ChainCond::Expr`.
+    ///
+    /// In a chain, the scrutinee of each `let` binds tighter than `&&`, so
+    /// that `let Some(x) = a && x > 3` tests `x > 3` after matching `a`.
+    /// Without `let_chains`, only a `&&` directly followed by `let` starts a
+    /// chain, which is then reported as unstable, so that stable code like
+    /// `if let true = a && b` keeps its meaning.
+    fn parse_let_chain(&mut self) -> PResult<'a, Vec<ChainCond>> {
+        let lo = self.span;
+        let min_prec = AssocOp::LAnd.precedence() + 1;
+        let mut in_chain = self.sess.let_chains.get();
+        let mut gated = false;
+        let mut conds = vec![];
+        let mut and_spans = vec![];
+        loop {
+            let pats = if self.eat_keyword(keywords::Let) {
+                let pats = self.parse_pats()?;
+                self.expect(&token::Eq)?;
+                Some(pats)
+            } else {
+                None
+            };
+            let mut expr = self.with_res(Restrictions::NO_STRUCT_LITERAL, |this| {
+                this.parse_assoc_expr_with(min_prec, LhsExpr::NotYetParsed)
+            })?;
+            if !in_chain && self.token == token::AndAnd &&
+               self.look_ahead(1, |t| t.is_keyword(keywords::Let)) {
+                in_chain = true;
+                gated = true;
+            }
+            if conds.is_empty() && !(in_chain && self.token == token::AndAnd) {
+                // Not a chain; keep accepting any condition or scrutinee,
+                // such as `if let x = a || b` or `while let _ = 0..n`.
+                expr = self.with_res(Restrictions::NO_STRUCT_LITERAL, |this| {
+                    this.parse_assoc_expr_with(0, LhsExpr::AlreadyParsed(expr))
+                })?;
+            }
+            conds.push(match pats {
+                Some(pats) => ChainCond::Let(pats, expr),
+                None => ChainCond::Expr(expr),
+            });
+
+            if !self.eat(&token::AndAnd) {
+                break;
+            }
+            and_spans.push(self.prev_span);
+        }
+
+        if gated {
+            feature_gate::emit_feature_err(self.sess, "let_chains", lo.to(self.prev_span),
+                                           GateIssue::Language,
+                                           "`let` chains are unstable");
+        }
+
+        let is_chain = conds.iter().any(|cond| match *cond {
+            ChainCond::Let(..) => true,
+            ChainCond::Expr(_) => false,
+        });
+        if !is_chain {
+            // Put the `&&`s back together and parse the rest of the
+            // expression, such as the `|| c` of `a && b || c`.
+            let mut exprs = conds.into_iter().map(|cond| match cond {
+                ChainCond::Expr(expr) => expr,
+                ChainCond::Let(..) => unreachable!(),
+            });
+            let first = exprs.next().unwrap();
+            let expr = exprs.zip(and_spans).fold(first, |lhs, (rhs, and_span)| {
+                let span = lhs.span.to(rhs.span);
+                let binary = self.mk_binary(respan(and_span, BinOpKind::And), lhs, rhs);
+                self.mk_expr(span, binary, ThinVec::new())
+            });
+            let expr = self.with_res(Restrictions::NO_STRUCT_LITERAL, |this| {
+                this.parse_assoc_expr_with(0, LhsExpr::AlreadyParsed(expr))
+            })?;
+            return Ok(vec![ChainCond::Expr(expr)]);
+        }
+
+        if conds.len() > 1 && self.token == token::OrOr {
+            let mut err = self.diagnostic()
+                .struct_span_err(self.span, "`||` operators are not allowed in `let` chains");
+            err.help("wrap the `||` expression in parentheses");
+            return Err(err);
+        }
+        Ok(conds)
     }
 
     // `move |args| expr`
@@ -3307,28 +3445,38 @@ impl<'a> Parser<'a> {
     pub fn parse_while_expr(&mut self, opt_label: Option<Label>,
                             span_lo: Span,
                             mut attrs: ThinVec<Attribute>) -> PResult<'a, P<Expr>> {
-        if self.token.is_keyword(keywords::Let) {
-            return self.parse_while_let_expr(opt_label, span_lo, attrs);
-        }
-        let cond = self.parse_expr_res(Restrictions::NO_STRUCT_LITERAL, None)?;
+        let mut conds = self.parse_let_chain()?;
+        let cond = match (conds.len(), conds.pop()) {
+            (1, Some(ChainCond::Expr(cond))) => cond,
+            (_, Some(cond)) => {
+                conds.push(cond);
+                return self.parse_while_let_expr(opt_label, span_lo, conds, attrs);
+            }
+            (_, None) => unreachable!(),
+        };
         let (iattrs, body) = self.parse_inner_attrs_and_block()?;
         attrs.extend(iattrs);
         let span = span_lo.to(body.span);
         return Ok(self.mk_expr(span, ExprKind::While(cond, body, opt_label), attrs));
     }
 
-    /// Parse a 'while let' expression ('while' token already eaten)
-    pub fn parse_while_let_expr(&mut self, opt_label: Option<Label>,
-                                span_lo: Span,
-                                mut attrs: ThinVec<Attribute>) -> PResult<'a, P<Expr>> {
-        self.expect_keyword(keywords::Let)?;
-        let pats = self.parse_pats()?;
-        self.expect(&token::Eq)?;
-        let expr = self.parse_expr_res(Restrictions::NO_STRUCT_LITERAL, None)?;
+    /// Parse the rest of a 'while let' expression, the conditions of which
+    /// have been parsed
+    fn parse_while_let_expr(&mut self, opt_label: Option<Label>,
+                            span_lo: Span,
+                            mut conds: Vec<ChainCond>,
+                            mut attrs: ThinVec<Attribute>) -> PResult<'a, P<Expr>> {
         let (iattrs, body) = self.parse_inner_attrs_and_block()?;
         attrs.extend(iattrs);
         let span = span_lo.to(body.span);
-        return Ok(self.mk_expr(span, ExprKind::WhileLet(pats, expr, body, opt_label), attrs));
+        let kind = if conds.len() > 1 {
+            ExprKind::WhileLetChain(conds, body, opt_label)
+        } else if let Some(ChainCond::Let(pats, expr)) = conds.pop() {
+            ExprKind::WhileLet(pats, expr, body, opt_label)
+        } else {
+            unreachable!()
+        };
+        return Ok(self.mk_expr(span, kind, attrs));
     }
 
     // parse `loop {...}`, `loop` token already eaten
@@ -3341,6 +3489,24 @@ impl<'a> Parser<'a> {
         Ok(self.mk_expr(span, ExprKind::Loop(body, opt_label), attrs))
     }
 
+    /// Parse an `async move {...}` expression
+    pub fn parse_async_block(&mut self, mut attrs: ThinVec<Attribute>)
+        -> PResult<'a, P<Expr>>
+    {
+        let span_lo = self.span;
+        self.expect_keyword(keywords::Async)?;
+        let capture_clause = if self.eat_keyword(keywords::Move) {
+            CaptureBy::Value
+        } else {
+            CaptureBy::Ref
+        };
+        let (iattrs, body) = self.parse_inner_attrs_and_block()?;
+        attrs.extend(iattrs);
+        Ok(self.mk_expr(
+            span_lo.to(body.span),
+            ExprKind::Async(capture_clause, ast::DUMMY_NODE_ID, body), attrs))
+    }
+
     /// Parse a `do catch {...}` expression (`do catch` token already eaten)
     pub fn parse_catch_expr(&mut self, span_lo: Span, mut attrs: ThinVec<Attribute>)
         -> PResult<'a, P<Expr>>
@@ -3542,7 +3708,7 @@ impl<'a> Parser<'a> {
                                   "`..` can only be used once per tuple or tuple struct pattern");
                 }
             } else if !self.check(&token::CloseDelim(token::Paren)) {
-                fields.push(self.parse_pat()?);
+                fields.push(self.parse_pat_with_or()?);
             } else {
                 break
             }
@@ -3598,7 +3764,7 @@ impl<'a> Parser<'a> {
                 }
             }
 
-            let subpat = self.parse_pat()?;
+            let subpat = self.parse_pat_with_or()?;
             if before_slice && self.eat(&token::DotDot) {
                 slice = Some(subpat);
                 before_slice = false;
@@ -3662,7 +3828,7 @@ impl<'a> Parser<'a> {
                 // Parsing a pattern of the form "fieldname: pat"
                 let fieldname = self.parse_field_name()?;
                 self.bump();
-                let pat = self.parse_pat()?;
+                let pat = self.parse_pat_with_or()?;
                 hi = pat.span;
                 (pat, fieldname, false)
             } else {
@@ -3780,6 +3946,35 @@ impl<'a> Parser<'a> {
         self.parse_pat_with_range_pat(true)
     }
 
+    /// Parse a pattern that may consist of several alternatives separated by `|`, e.g. the
+    /// `A | B` in `Some(A | B)`. Besides delimited subpatterns, this is only done for the
+    /// pattern of a `let` statement. `|` already separates the patterns of a `match` arm and
+    /// delimits the parameters of a closure, so alternatives there, and in the parameters of
+    /// a function, have to be parenthesized: `|(A | B)| ..`.
+    fn parse_pat_with_or(&mut self) -> PResult<'a, P<Pat>> {
+        let pat = self.parse_pat()?;
+        self.parse_pat_alternatives(pat)
+    }
+
+    /// Parse the alternatives following `pat`, if any.
+    fn parse_pat_alternatives(&mut self, pat: P<Pat>) -> PResult<'a, P<Pat>> {
+        if self.token != token::BinOp(token::Or) {
+            return Ok(pat);
+        }
+
+        let lo = pat.span;
+        let mut pats = vec![pat];
+        while self.token == token::BinOp(token::Or) {
+            self.bump();
+            pats.push(self.parse_pat()?);
+        }
+        Ok(P(Pat {
+            id: ast::DUMMY_NODE_ID,
+            node: PatKind::Or(pats),
+            span: lo.to(self.prev_span),
+        }))
+    }
+
     /// Parse a pattern, with a setting whether modern range patterns e.g. `a..=b`, `a..b` are
     /// allowed.
     fn parse_pat_with_range_pat(&mut self, allow_range_pat: bool) -> PResult<'a, P<Pat>> {
@@ -3999,6 +4194,7 @@ impl<'a> Parser<'a> {
     fn parse_local(&mut self, attrs: ThinVec<Attribute>) -> PResult<'a, P<Local>> {
         let lo = self.prev_span;
         let pat = self.parse_top_level_pat()?;
+        let pat = self.parse_pat_alternatives(pat)?;
 
         let (err, ty) = if self.eat(&token::Colon) {
             // Save the state of the parser before parsing type normally, in case there is a `:`
@@ -4050,6 +4246,25 @@ impl<'a> Parser<'a> {
                 return Err(err);
             }
         };
+        // `let PAT = EXPR else { ... };`
+        let els = match init {
+            Some(ref init) if self.token.is_keyword(keywords::Else) => {
+                if classify::expr_trailing_brace(init) {
+                    let msg = "right curly brace `}` before `else` in a `let...else` statement \
+                               not allowed";
+                    let init_str = self.sess.codemap().span_to_snippet(init.span)
+                                           .unwrap_or(pprust::expr_to_string(init));
+                    let mut err = self.struct_span_err(init.span, msg);
+                    err.span_suggestion(init.span,
+                                        "try wrapping the expression in parentheses",
+                                        format!("({})", init_str));
+                    err.emit();
+                }
+                self.bump();
+                Some(self.parse_block()?)
+            }
+            _ => None,
+        };
         let hi = if self.token == token::Semi {
             self.span
         } else {
@@ -4059,6 +4274,7 @@ impl<'a> Parser<'a> {
             ty,
             pat,
             init,
+            els,
             id: ast::DUMMY_NODE_ID,
             span: lo.to(hi),
             attrs,
@@ -4193,6 +4409,27 @@ impl<'a> Parser<'a> {
         !self.restrictions.contains(Restrictions::NO_STRUCT_LITERAL)
     }
 
+    fn is_async_block(&mut self) -> bool {
+        self.span.rust_2018() &&
+        self.token.is_keyword(keywords::Async) &&
+        (
+            // `async move {`
+            (self.look_ahead(1, |t| t.is_keyword(keywords::Move)) &&
+             self.look_ahead(2, |t| *t == token::OpenDelim(token::Brace))) ||
+            // `async {`
+            self.look_ahead(1, |t| *t == token::OpenDelim(token::Brace))
+        ) &&
+
+        // prevent `while async {} {}`, `if async {} {} else {}`, etc.
+        !self.restrictions.contains(Restrictions::NO_STRUCT_LITERAL)
+    }
+
+    fn is_async_fn(&mut self) -> bool {
+        self.span.rust_2018() &&
+        self.token.is_keyword(keywords::Async) &&
+        self.look_ahead(1, |t| t.is_keyword(keywords::Fn) || t.is_keyword(keywords::Unsafe))
+    }
+
     fn is_union_item(&self) -> bool {
         self.token.is_keyword(keywords::Union) &&
         self.look_ahead(1, |t| t.is_ident() && !t.is_reserved_ident())
@@ -4310,7 +4547,9 @@ impl<'a> Parser<'a> {
                   !self.is_union_item() &&
                   !self.is_crate_vis() &&
                   !self.is_extern_non_path() &&
-                  !self.is_auto_trait_item() {
+                  !self.is_auto_trait_item() &&
+                  !self.is_async_block() &&
+                  !self.is_async_fn() {
             let pth = self.parse_path(PathStyle::Expr)?;
 
             if !self.eat(&token::Not) {
@@ -4764,11 +5003,26 @@ impl<'a> Parser<'a> {
         }))
     }
 
-    /// Parses (possibly empty) list of lifetime and type parameters, possibly including
+    /// Matches constparam = IDENT ":" ty
+    fn parse_const_param(&mut self, preceding_attrs: Vec<Attribute>) -> PResult<'a, ConstParam> {
+        let ident = self.parse_ident()?;
+        self.expect(&token::Colon)?;
+        let ty = self.parse_ty()?;
+
+        Ok(ConstParam {
+            attrs: preceding_attrs.into(),
+            ident,
+            id: ast::DUMMY_NODE_ID,
+            ty,
+        })
+    }
+
+    /// Parses (possibly empty) list of lifetime, type and const parameters, possibly including
     /// trailing comma and erroneous trailing attributes.
     pub fn parse_generic_params(&mut self) -> PResult<'a, Vec<ast::GenericParam>> {
         let mut params = Vec::new();
         let mut seen_ty_param = false;
+        let mut seen_const_param = false;
         loop {
             let attrs = self.parse_outer_attributes()?;
             if self.check_lifetime() {
@@ -4784,18 +5038,32 @@ impl<'a> Parser<'a> {
                     lifetime,
                     bounds,
                 }));
-                if seen_ty_param {
+                if seen_ty_param || seen_const_param {
                     self.span_err(self.prev_span,
                         "lifetime parameters must be declared prior to type parameters");
                 }
+            } else if self.eat_keyword(keywords::Const) {
+                // Parse const parameter.
+                params.push(ast::GenericParam::Const(self.parse_const_param(attrs)?));
+                seen_const_param = true;
             } else if self.check_ident() {
                 // Parse type parameter.
                 params.push(ast::GenericParam::Type(self.parse_ty_param(attrs)?));
+                if seen_const_param {
+                    self.span_err(self.prev_span,
+                        "type parameters must be declared prior to const parameters");
+                }
                 seen_ty_param = true;
             } else {
                 // Check for trailing attributes and stop parsing.
                 if !attrs.is_empty() {
-                    let param_kind = if seen_ty_param { "type" } else { "lifetime" };
+                    let param_kind = if seen_const_param {
+                        "const"
+                    } else if seen_ty_param {
+                        "type"
+                    } else {
+                        "lifetime"
+                    };
                     self.span_err(attrs[0].span,
                         &format!("trailing attribute after {} parameters", param_kind));
                 }
@@ -4837,19 +5105,47 @@ impl<'a> Parser<'a> {
         }
     }
 
-    /// Parses (possibly empty) list of lifetime and type arguments and associated type bindings,
-    /// possibly including trailing comma.
-    fn parse_generic_args(&mut self) -> PResult<'a, (Vec<Lifetime>, Vec<P<Ty>>, Vec<TypeBinding>)> {
+    /// Checks whether a const argument starts here: a literal, possibly negated,
+    /// or a block.
+    fn check_const_arg(&mut self) -> bool {
+        self.token.is_lit() ||
+        self.token.is_keyword(keywords::True) ||
+        self.token.is_keyword(keywords::False) ||
+        self.token == token::BinOp(token::Minus) && self.look_ahead(1, |t| t.is_lit()) ||
+        self.token == token::OpenDelim(token::Brace)
+    }
+
+    /// Matches constarg = ( "-"? LIT ) | block
+    fn parse_const_arg(&mut self) -> PResult<'a, P<Expr>> {
+        if self.token == token::OpenDelim(token::Brace) {
+            let lo = self.span;
+            self.parse_block_expr(lo, BlockCheckMode::Default, ThinVec::new())
+        } else {
+            self.parse_pat_literal_maybe_minus()
+        }
+    }
+
+    /// Parses (possibly empty) list of lifetime, type and const arguments and associated type
+    /// bindings, possibly including trailing comma.
+    ///
+    /// A path can be either a type or a const argument, it is parsed as a type unless it follows
+    /// a const argument that is not a path.
+    fn parse_generic_args(&mut self) -> PResult<'a, (Vec<Lifetime>,
+                                                     Vec<P<Ty>>,
+                                                     Vec<P<Expr>>,
+                                                     Vec<TypeBinding>)> {
         let mut lifetimes = Vec::new();
         let mut types = Vec::new();
+        let mut consts = Vec::new();
         let mut bindings = Vec::new();
         let mut seen_type = false;
+        let mut seen_const = false;
         let mut seen_binding = false;
         loop {
             if self.check_lifetime() && self.look_ahead(1, |t| t != &token::BinOp(token::Plus)) {
                 // Parse lifetime argument.
                 lifetimes.push(self.expect_lifetime());
-                if seen_type || seen_binding {
+                if seen_type || seen_const || seen_binding {
                     self.span_err(self.prev_span,
                         "lifetime parameters must be declared prior to type parameters");
                 }
@@ -4866,12 +5162,30 @@ impl<'a> Parser<'a> {
                     span: lo.to(self.prev_span),
                 });
                 seen_binding = true;
+            } else if self.check_const_arg() || seen_const && self.check_path() {
+                // Parse const argument.
+                let arg = if self.check_const_arg() {
+                    self.parse_const_arg()?
+                } else {
+                    let lo = self.span;
+                    let path = self.parse_path(PathStyle::Expr)?;
+                    self.mk_expr(lo.to(self.prev_span), ExprKind::Path(None, path), ThinVec::new())
+                };
+                if seen_binding {
+                    self.span_err(arg.span,
+                        "const parameters must be declared prior to associated type bindings");
+                }
+                consts.push(arg);
+                seen_const = true;
             } else if self.check_type() {
                 // Parse type argument.
                 types.push(self.parse_ty()?);
                 if seen_binding {
                     self.span_err(types[types.len() - 1].span,
                         "type parameters must be declared prior to associated type bindings");
+                } else if seen_const {
+                    self.span_err(types[types.len() - 1].span,
+                        "type parameters must be declared prior to const parameters");
                 }
                 seen_type = true;
             } else {
@@ -4882,7 +5196,7 @@ impl<'a> Parser<'a> {
                 break
             }
         }
-        Ok((lifetimes, types, bindings))
+        Ok((lifetimes, types, consts, bindings))
     }
 
     /// Parses an optional `where` clause and places it in `generics`.
@@ -5240,6 +5554,7 @@ impl<'a> Parser<'a> {
     /// Parse an item-position function declaration.
     fn parse_item_fn(&mut self,
                      unsafety: Unsafety,
+                     asyncness: IsAsync,
                      constness: Spanned<Constness>,
                      abi: Abi)
                      -> PResult<'a, ItemInfo> {
@@ -5247,7 +5562,8 @@ impl<'a> Parser<'a> {
         let decl = self.parse_fn_decl(false)?;
         generics.where_clause = self.parse_where_clause()?;
         let (inner_attrs, body) = self.parse_inner_attrs_and_block()?;
-        Ok((ident, ItemKind::Fn(decl, unsafety, constness, abi, generics, body), Some(inner_attrs)))
+        let item = ItemKind::Fn(decl, unsafety, asyncness, constness, abi, generics, body);
+        Ok((ident, item, Some(inner_attrs)))
     }
 
     /// true if we are looking at `const ID`, false for things like `const fn` etc
@@ -5263,11 +5579,20 @@ impl<'a> Parser<'a> {
     /// - `const fn`
     /// - `unsafe fn`
     /// - `const unsafe fn`
+    /// - `async fn`
+    /// - `async unsafe fn`
     /// - `extern fn`
     /// - etc
-    pub fn parse_fn_front_matter(&mut self) -> PResult<'a, (Spanned<Constness>, Unsafety, Abi)> {
+    pub fn parse_fn_front_matter(&mut self)
+        -> PResult<'a, (Spanned<Constness>, Unsafety, IsAsync, Abi)>
+    {
         let is_const_fn = self.eat_keyword(keywords::Const);
         let const_span = self.prev_span;
+        let asyncness = if is_const_fn {
+            IsAsync::NotAsync
+        } else {
+            self.parse_asyncness()
+        };
         let unsafety = self.parse_unsafety();
         let (constness, unsafety, abi) = if is_const_fn {
             (respan(const_span, Constness::Const), unsafety, Abi::Rust)
@@ -5280,7 +5605,7 @@ impl<'a> Parser<'a> {
             (respan(self.prev_span, Constness::NotConst), unsafety, abi)
         };
         self.expect_keyword(keywords::Fn)?;
-        Ok((constness, unsafety, abi))
+        Ok((constness, unsafety, asyncness, abi))
     }
 
     /// Parse an impl item.
@@ -5412,7 +5737,7 @@ impl<'a> Parser<'a> {
             Ok((keywords::Invalid.ident(), vec![], ast::Generics::default(),
                 ast::ImplItemKind::Macro(mac)))
         } else {
-            let (constness, unsafety, abi) = self.parse_fn_front_matter()?;
+            let (constness, unsafety, asyncness, abi) = self.parse_fn_front_matter()?;
             let ident = self.parse_ident()?;
             let mut generics = self.parse_generics()?;
             let decl = self.parse_fn_decl_with_self(|p| p.parse_arg())?;
@@ -5422,6 +5747,7 @@ impl<'a> Parser<'a> {
             Ok((ident, inner_attrs, generics, ast::ImplItemKind::Method(ast::MethodSig {
                 abi,
                 unsafety,
+                asyncness,
                 constness,
                 decl,
              }, body)))
@@ -6473,6 +6799,7 @@ impl<'a> Parser<'a> {
                 let abi = opt_abi.unwrap_or(Abi::C);
                 let (ident, item_, extra_attrs) =
                     self.parse_item_fn(Unsafety::Normal,
+                                       IsAsync::NotAsync,
                                        respan(fn_span, Constness::NotConst),
                                        abi)?;
                 let prev_span = self.prev_span;
@@ -6516,6 +6843,7 @@ impl<'a> Parser<'a> {
                 self.bump();
                 let (ident, item_, extra_attrs) =
                     self.parse_item_fn(unsafety,
+                                       IsAsync::NotAsync,
                                        respan(const_span, Constness::Const),
                                        Abi::Rust)?;
                 let prev_span = self.prev_span;
@@ -6582,12 +6910,32 @@ impl<'a> Parser<'a> {
             return Ok(Some(self.mk_item(span, ident, item, visibility,
                                         maybe_append(attrs, extra_attrs))));
         }
+        if self.is_async_fn() {
+            // ASYNC FUNCTION ITEM
+            let asyncness = self.parse_asyncness();
+            let unsafety = self.parse_unsafety();
+            self.expect_keyword(keywords::Fn)?;
+            let fn_span = self.prev_span;
+            let (ident, item_, extra_attrs) =
+                self.parse_item_fn(unsafety,
+                                   asyncness,
+                                   respan(fn_span, Constness::NotConst),
+                                   Abi::Rust)?;
+            let prev_span = self.prev_span;
+            let item = self.mk_item(lo.to(prev_span),
+                                    ident,
+                                    item_,
+                                    visibility,
+                                    maybe_append(attrs, extra_attrs));
+            return Ok(Some(item));
+        }
         if self.check_keyword(keywords::Fn) {
             // FUNCTION ITEM
             self.bump();
             let fn_span = self.prev_span;
             let (ident, item_, extra_attrs) =
                 self.parse_item_fn(Unsafety::Normal,
+                                   IsAsync::NotAsync,
                                    respan(fn_span, Constness::NotConst),
                                    Abi::Rust)?;
             let prev_span = self.prev_span;
@@ -6613,6 +6961,7 @@ impl<'a> Parser<'a> {
             let fn_span = self.prev_span;
             let (ident, item_, extra_attrs) =
                 self.parse_item_fn(Unsafety::Unsafe,
+                                   IsAsync::NotAsync,
                                    respan(fn_span, Constness::NotConst),
                                    abi)?;
             let prev_span = self.prev_span;
@@ -6883,7 +7232,7 @@ impl<'a> Parser<'a> {
     fn parse_assoc_macro_invoc(&mut self, item_kind: &str, vis: Option<&Visibility>,
                                at_end: &mut bool) -> PResult<'a, Option<Mac>>
     {
-        if self.token.is_path_start() && !self.is_extern_non_path() {
+        if self.token.is_path_start() && !self.is_extern_non_path() && !self.is_async_fn() {
             let prev_span = self.prev_span;
             let lo = self.span;
             let pth = self.parse_path(PathStyle::Mod)?;
@@ -7059,8 +7408,15 @@ impl<'a> Parser<'a> {
     /// entry point for the parser.
     pub fn parse_crate_mod(&mut self) -> PResult<'a, Crate> {
         let lo = self.span;
+        let attrs = self.parse_inner_attributes()?;
+        let let_chains = attrs.iter().filter(|attr| attr.check_name("feature")).any(|attr| {
+            attr.meta_item_list().map_or(false, |list| {
+                list.iter().any(|item| item.check_name("let_chains"))
+            })
+        });
+        self.sess.let_chains.set(let_chains);
         Ok(ast::Crate {
-            attrs: self.parse_inner_attributes()?,
+            attrs,
             module: self.parse_mod_items(&token::Eof, lo)?,
             span: lo.to(self.span),
         })
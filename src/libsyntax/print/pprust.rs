@@ -372,13 +372,14 @@ pub fn vis_to_string(v: &ast::Visibility) -> String {
 
 pub fn fun_to_string(decl: &ast::FnDecl,
                      unsafety: ast::Unsafety,
+                     asyncness: ast::IsAsync,
                      constness: ast::Constness,
                      name: ast::Ident,
                      generics: &ast::Generics)
                      -> String {
     to_string(|s| {
         s.head("")?;
-        s.print_fn(decl, unsafety, constness, Abi::Rust, Some(name),
+        s.print_fn(decl, unsafety, asyncness, constness, Abi::Rust, Some(name),
                    generics, &codemap::dummy_spanned(ast::VisibilityKind::Inherited))?;
         s.end()?; // Close the head box
         s.end() // Close the outer box
@@ -1106,6 +1107,7 @@ impl<'a> State<'a> {
             ast::ForeignItemKind::Fn(ref decl, ref generics) => {
                 self.head("")?;
                 self.print_fn(decl, ast::Unsafety::Normal,
+                              ast::IsAsync::NotAsync,
                               ast::Constness::NotConst,
                               Abi::Rust, Some(item.ident),
                               generics, &item.vis)?;
@@ -1233,11 +1235,13 @@ impl<'a> State<'a> {
                 self.s.word(";")?;
                 self.end()?; // end the outer cbox
             }
-            ast::ItemKind::Fn(ref decl, unsafety, constness, abi, ref typarams, ref body) => {
+            ast::ItemKind::Fn(ref decl, unsafety, asyncness, constness, abi,
+                              ref typarams, ref body) => {
                 self.head("")?;
                 self.print_fn(
                     decl,
                     unsafety,
+                    asyncness,
                     constness.node,
                     abi,
                     Some(item.ident),
@@ -1559,6 +1563,7 @@ impl<'a> State<'a> {
                             -> io::Result<()> {
         self.print_fn(&m.decl,
                       m.unsafety,
+                      m.asyncness,
                       m.constness.node,
                       m.abi,
                       Some(ident),
@@ -1668,6 +1673,12 @@ impl<'a> State<'a> {
                     self.word_space("=")?;
                     self.print_expr(init)?;
                 }
+                if let Some(ref els) = loc.els {
+                    self.cbox(INDENT_UNIT - 1)?;
+                    self.ibox(0)?;
+                    self.s.word(" else ")?;
+                    self.print_block(els)?;
+                }
                 self.s.word(";")?;
                 self.end()?;
             }
@@ -1784,6 +1795,16 @@ impl<'a> State<'a> {
                         self.print_block(then)?;
                         self.print_else(e.as_ref().map(|e| &**e))
                     }
+                    // "another else-if-let-chain"
+                    ast::ExprKind::IfLetChain(ref conds, ref then, ref e) => {
+                        self.cbox(INDENT_UNIT - 1)?;
+                        self.ibox(0)?;
+                        self.s.word(" else if ")?;
+                        self.print_chain_conds(conds)?;
+                        self.s.space()?;
+                        self.print_block(then)?;
+                        self.print_else(e.as_ref().map(|e| &**e))
+                    }
                     // "final else"
                     ast::ExprKind::Block(ref b) => {
                         self.cbox(INDENT_UNIT - 1)?;
@@ -1822,6 +1843,37 @@ impl<'a> State<'a> {
         self.print_else(elseopt)
     }
 
+    /// Print the `&&`-separated conditions of an `if let` or `while let` chain
+    pub fn print_chain_conds(&mut self, conds: &[ast::ChainCond]) -> io::Result<()> {
+        // Operands binding no tighter than `&&` would otherwise be split or
+        // merged differently when parsed back.
+        let prec = AssocOp::LAnd.precedence() as i8;
+        for (i, cond) in conds.iter().enumerate() {
+            if i > 0 {
+                self.s.space()?;
+                self.word_space("&&")?;
+            }
+            let expr = match *cond {
+                ast::ChainCond::Let(ref pats, ref expr) => {
+                    self.word_nbsp("let")?;
+                    self.print_pats(pats)?;
+                    self.s.space()?;
+                    self.word_space("=")?;
+                    expr
+                }
+                ast::ChainCond::Expr(ref expr) => expr,
+            };
+            if expr.precedence().order() <= prec {
+                self.popen()?;
+                self.print_expr(expr)?;
+                self.pclose()?;
+            } else {
+                self.print_expr_as_cond(expr)?;
+            }
+        }
+        Ok(())
+    }
+
     pub fn print_mac(&mut self, m: &ast::Mac, delim: token::DelimToken)
                      -> io::Result<()> {
         self.print_path(&m.node.path, false, 0)?;
@@ -2103,6 +2155,13 @@ impl<'a> State<'a> {
             ast::ExprKind::IfLet(ref pats, ref expr, ref blk, ref elseopt) => {
                 self.print_if_let(pats, expr, blk, elseopt.as_ref().map(|e| &**e))?;
             }
+            ast::ExprKind::IfLetChain(ref conds, ref blk, ref elseopt) => {
+                self.head("if")?;
+                self.print_chain_conds(conds)?;
+                self.s.space()?;
+                self.print_block(blk)?;
+                self.print_else(elseopt.as_ref().map(|e| &**e))?;
+            }
             ast::ExprKind::While(ref test, ref blk, opt_label) => {
                 if let Some(label) = opt_label {
                     self.print_ident(label.ident)?;
@@ -2126,6 +2185,16 @@ impl<'a> State<'a> {
                 self.s.space()?;
                 self.print_block_with_attrs(blk, attrs)?;
             }
+            ast::ExprKind::WhileLetChain(ref conds, ref blk, opt_label) => {
+                if let Some(label) = opt_label {
+                    self.print_ident(label.ident)?;
+                    self.word_space(":")?;
+                }
+                self.head("while")?;
+                self.print_chain_conds(conds)?;
+                self.s.space()?;
+                self.print_block_with_attrs(blk, attrs)?;
+            }
             ast::ExprKind::ForLoop(ref pat, ref iter, ref blk, opt_label) => {
                 if let Some(label) = opt_label {
                     self.print_ident(label.ident)?;
@@ -2348,6 +2417,15 @@ impl<'a> State<'a> {
                 self.s.space()?;
                 self.print_block_with_attrs(blk, attrs)?
             }
+            ast::ExprKind::Async(capture_clause, _, ref blk) => {
+                self.word_nbsp("async")?;
+                self.print_capture_clause(capture_clause)?;
+                self.s.space()?;
+                // cbox/ibox in analogy to the `ExprKind::Block` arm above
+                self.cbox(INDENT_UNIT)?;
+                self.ibox(0)?;
+                self.print_block_with_attrs(blk, attrs)?;
+            }
         }
         self.ann.post(self, NodeExpr(expr))?;
         self.end()
@@ -2480,6 +2558,17 @@ impl<'a> State<'a> {
                         comma = true;
                 }
 
+                if !data.consts.is_empty() {
+                    if comma {
+                        self.word_space(",")?
+                    }
+                    self.commasep(
+                        Inconsistent,
+                        &data.consts,
+                        |s, c| s.print_expr(c))?;
+                    comma = true;
+                }
+
                 for binding in data.bindings.iter() {
                     if comma {
                         self.word_space(",")?
@@ -2649,6 +2738,7 @@ impl<'a> State<'a> {
                 self.print_pat(inner)?;
                 self.pclose()?;
             }
+            PatKind::Or(ref pats) => self.print_pats(pats)?,
             PatKind::Mac(ref m) => self.print_mac(m, token::Paren)?,
         }
         self.ann.post(self, NodePat(pat))
@@ -2730,12 +2820,13 @@ impl<'a> State<'a> {
     pub fn print_fn(&mut self,
                     decl: &ast::FnDecl,
                     unsafety: ast::Unsafety,
+                    asyncness: ast::IsAsync,
                     constness: ast::Constness,
                     abi: abi::Abi,
                     name: Option<ast::Ident>,
                     generics: &ast::Generics,
                     vis: &ast::Visibility) -> io::Result<()> {
-        self.print_fn_header_info(unsafety, constness, abi, vis)?;
+        self.print_fn_header_info(unsafety, asyncness, constness, abi, vis)?;
 
         if let Some(name) = name {
             self.nbsp()?;
@@ -2872,6 +2963,7 @@ impl<'a> State<'a> {
                     s.print_lifetime_bounds(&lifetime_def.lifetime, &lifetime_def.bounds)
                 },
                 ast::GenericParam::Type(ref ty_param) => s.print_ty_param(ty_param),
+                ast::GenericParam::Const(ref const_param) => s.print_const_param(const_param),
             }
         })?;
 
@@ -2893,6 +2985,14 @@ impl<'a> State<'a> {
         }
     }
 
+    pub fn print_const_param(&mut self, param: &ast::ConstParam) -> io::Result<()> {
+        self.print_outer_attributes_inline(&param.attrs)?;
+        self.word_space("const")?;
+        self.print_ident(param.ident)?;
+        self.word_space(":")?;
+        self.print_type(&param.ty)
+    }
+
     pub fn print_where_clause(&mut self, where_clause: &ast::WhereClause)
                               -> io::Result<()> {
         if where_clause.predicates.is_empty() {
@@ -3053,6 +3153,7 @@ impl<'a> State<'a> {
         };
         self.print_fn(decl,
                       unsafety,
+                      ast::IsAsync::NotAsync,
                       ast::Constness::NotConst,
                       abi,
                       name,
@@ -3118,6 +3219,7 @@ impl<'a> State<'a> {
 
     pub fn print_fn_header_info(&mut self,
                                 unsafety: ast::Unsafety,
+                                asyncness: ast::IsAsync,
                                 constness: ast::Constness,
                                 abi: Abi,
                                 vis: &ast::Visibility) -> io::Result<()> {
@@ -3128,6 +3230,7 @@ impl<'a> State<'a> {
             ast::Constness::Const => self.word_nbsp("const")?
         }
 
+        self.print_asyncness(asyncness)?;
         self.print_unsafety(unsafety)?;
 
         if abi != Abi::Rust {
@@ -3138,6 +3241,13 @@ impl<'a> State<'a> {
         self.s.word("fn")
     }
 
+    pub fn print_asyncness(&mut self, asyncness: ast::IsAsync) -> io::Result<()> {
+        if asyncness.is_async() {
+            self.word_nbsp("async")?;
+        }
+        Ok(())
+    }
+
     pub fn print_unsafety(&mut self, s: ast::Unsafety) -> io::Result<()> {
         match s {
             ast::Unsafety::Normal => Ok(()),
@@ -3176,6 +3286,7 @@ mod tests {
             };
             let generics = ast::Generics::default();
             assert_eq!(fun_to_string(&decl, ast::Unsafety::Normal,
+                                    ast::IsAsync::NotAsync,
                                     ast::Constness::NotConst,
                                     abba_ident, &generics),
                     "fn abba()");
@@ -35,6 +35,7 @@ use feature_gate::Features;
 use util::move_map::MoveMap;
 use fold;
 use parse::{token, ParseSess};
+use parse::parser::PathStyle;
 use print::pprust;
 use ast::{self, Ident};
 use ptr::P;
@@ -60,7 +61,11 @@ struct TestCtxt<'a> {
     path: Vec<Ident>,
     ext_cx: ExtCtxt<'a>,
     testfns: Vec<Test>,
+    // paths to the `#[test_case]` items, handed to the custom test runner
+    test_cases: Vec<Vec<Ident>>,
     reexport_test_harness_main: Option<Symbol>,
+    // path to the function named by `#![test_runner]`, if any
+    test_runner: Option<ast::Path>,
     is_libtest: bool,
     ctxt: SyntaxContext,
     features: &'a Features,
@@ -85,14 +90,51 @@ pub fn modify_for_testing(sess: &ParseSess,
         attr::first_attr_value_str_by_name(&krate.attrs,
                                            "reexport_test_harness_main");
 
+    // Likewise for #![test_runner(path::to::runner)], which replaces
+    // libtest's console runner with a user-provided function.
+    let test_runner = get_test_runner(sess, &krate);
+
     if should_test {
-        generate_test_harness(sess, resolver, reexport_test_harness_main,
+        generate_test_harness(sess, resolver, reexport_test_harness_main, test_runner,
                               krate, span_diagnostic, features)
     } else {
         krate
     }
 }
 
+fn get_test_runner(sess: &ParseSess, krate: &ast::Crate) -> Option<ast::Path> {
+    let test_attr = attr::find_by_name(&krate.attrs, "test_runner")?;
+    let mut paths = match test_attr.parse_list(sess, |p| p.parse_path(PathStyle::Mod)) {
+        Ok(paths) => paths,
+        Err(mut err) => {
+            err.emit();
+            return None;
+        }
+    };
+    if paths.len() != 1 {
+        sess.span_diagnostic.span_err(test_attr.span,
+                                      "attribute must be of the form \
+                                       `#![test_runner(path::to::runner)]`");
+        return None;
+    }
+
+    // The runner is called from the generated `main`, so its path is taken
+    // from the crate root, like the path of a `use` item.
+    let mut path = paths.pop().unwrap();
+    if path.segments[0].ident.name == keywords::CrateRoot.name() {
+        path.segments.remove(0);
+    }
+    if let Some(segment) = path.segments.iter().find(|s| token::is_path_segment_keyword(s.ident)) {
+        sess.span_diagnostic.span_err(segment.ident.span,
+                                      &format!("`{}` in a `#![test_runner]` path is not \
+                                                supported, the path is relative to the \
+                                                crate root",
+                                               segment.ident));
+        return None;
+    }
+    Some(path)
+}
+
 struct TestHarnessGenerator<'a> {
     cx: TestCtxt<'a>,
     tests: Vec<Ident>,
@@ -124,10 +166,14 @@ impl<'a> fold::Folder for TestHarnessGenerator<'a> {
 
         if is_test_fn(&self.cx, &i) || is_bench_fn(&self.cx, &i) {
             match i.node {
-                ast::ItemKind::Fn(_, ast::Unsafety::Unsafe, _, _, _, _) => {
+                ast::ItemKind::Fn(_, ast::Unsafety::Unsafe, _, _, _, _, _) => {
                     let diag = self.cx.span_diagnostic;
                     diag.span_fatal(i.span, "unsafe functions cannot be used for tests").raise();
                 }
+                ast::ItemKind::Fn(_, _, ast::IsAsync::Async { .. }, _, _, _, _) => {
+                    let diag = self.cx.span_diagnostic;
+                    diag.span_fatal(i.span, "async functions cannot be used for tests").raise();
+                }
                 _ => {
                     debug!("this is a test function");
                     let test = Test {
@@ -142,6 +188,10 @@ impl<'a> fold::Folder for TestHarnessGenerator<'a> {
                     self.tests.push(i.ident);
                 }
             }
+        } else if is_test_case(&self.cx, &i) {
+            debug!("this is a test case");
+            self.cx.test_cases.push(self.cx.path.clone());
+            self.tests.push(i.ident);
         }
 
         let mut item = i.into_inner();
@@ -268,6 +318,7 @@ fn mk_reexport_mod(cx: &mut TestCtxt,
 fn generate_test_harness(sess: &ParseSess,
                          resolver: &mut Resolver,
                          reexport_test_harness_main: Option<Symbol>,
+                         test_runner: Option<ast::Path>,
                          krate: ast::Crate,
                          sd: &errors::Handler,
                          features: &Features) -> ast::Crate {
@@ -285,7 +336,9 @@ fn generate_test_harness(sess: &ParseSess,
         ext_cx: ExtCtxt::new(sess, econfig, resolver),
         path: Vec::new(),
         testfns: Vec::new(),
+        test_cases: Vec::new(),
         reexport_test_harness_main,
+        test_runner,
         // NB: doesn't consider the value of `--crate-name` passed on the command line.
         is_libtest: attr::find_crate_name(&krate.attrs).map(|s| s == "test").unwrap_or(false),
         toplevel_reexport: None,
@@ -337,7 +390,7 @@ fn is_test_fn(cx: &TestCtxt, i: &ast::Item) -> bool {
     fn has_test_signature(cx: &TestCtxt, i: &ast::Item) -> HasTestSignature {
         let has_should_panic_attr = attr::contains_name(&i.attrs, "should_panic");
         match i.node {
-            ast::ItemKind::Fn(ref decl, _, _, _, ref generics, _) => {
+            ast::ItemKind::Fn(ref decl, _, _, _, _, ref generics, _) => {
                 // If the termination trait is active, the compiler will check that the output
                 // type implements the `Termination` trait as `libtest` enforces that.
                 let has_output = match decl.output {
@@ -396,7 +449,7 @@ fn is_bench_fn(cx: &TestCtxt, i: &ast::Item) -> bool {
 
     fn has_bench_signature(cx: &TestCtxt, i: &ast::Item) -> bool {
         match i.node {
-            ast::ItemKind::Fn(ref decl, _, _, _, ref generics, _) => {
+            ast::ItemKind::Fn(ref decl, _, _, _, _, ref generics, _) => {
                 let input_cnt = decl.inputs.len();
 
                 // If the termination trait is active, the compiler will check that the output
@@ -441,6 +494,26 @@ fn is_bench_fn(cx: &TestCtxt, i: &ast::Item) -> bool {
     has_bench_attr && has_bench_signature
 }
 
+fn is_test_case(cx: &TestCtxt, i: &ast::Item) -> bool {
+    if !attr::contains_name(&i.attrs, "test_case") {
+        return false;
+    }
+
+    let diag = cx.span_diagnostic;
+    match i.node {
+        ast::ItemKind::Fn(..) | ast::ItemKind::Static(..) | ast::ItemKind::Const(..) => {}
+        _ => {
+            diag.span_err(i.span, "only functions, statics and constants may be test cases");
+            return false;
+        }
+    }
+    if cx.test_runner.is_none() {
+        diag.span_err(i.span, "`#[test_case]` items require a `#![test_runner]` crate attribute");
+        return false;
+    }
+    true
+}
+
 fn is_ignored(i: &ast::Item) -> bool {
     attr::contains_name(&i.attrs, "ignore")
 }
@@ -541,18 +614,30 @@ fn mk_main(cx: &mut TestCtxt) -> P<ast::Item> {
     //            test::test_main_static(::std::os::args().as_slice(), TESTS, test::Options::new());
     //        }
 
+    //
+    // or, with `#![test_runner(path::to::runner)]`:
+    //        pub fn main() {
+    //            #![main]
+    //            path::to::runner(&[&TESTS[0], .., &test_case_0, ..]);
+    //        }
+
     let sp = ignored_span(cx, DUMMY_SP);
-    let ecx = &cx.ext_cx;
+    let call_test_main = match cx.test_runner {
+        Some(ref runner) => mk_call_test_runner(cx, runner, sp),
+        None => {
+            let ecx = &cx.ext_cx;
 
-    // test::test_main_static
-    let test_main_path =
-        ecx.path(sp, vec![Ident::from_str("test"), Ident::from_str("test_main_static")]);
+            // test::test_main_static
+            let test_main_path =
+                ecx.path(sp, vec![Ident::from_str("test"), Ident::from_str("test_main_static")]);
 
-    // test::test_main_static(...)
-    let test_main_path_expr = ecx.expr_path(test_main_path);
-    let tests_ident_expr = ecx.expr_ident(sp, Ident::from_str("TESTS"));
-    let call_test_main = ecx.expr_call(sp, test_main_path_expr,
-                                       vec![tests_ident_expr]);
+            // test::test_main_static(...)
+            let test_main_path_expr = ecx.expr_path(test_main_path);
+            let tests_ident_expr = ecx.expr_ident(sp, Ident::from_str("TESTS"));
+            ecx.expr_call(sp, test_main_path_expr, vec![tests_ident_expr])
+        }
+    };
+    let ecx = &cx.ext_cx;
     let call_test_main = ecx.stmt_expr(call_test_main);
     // #![main]
     let main_meta = ecx.meta_word(sp, Symbol::intern("main"));
@@ -562,6 +647,7 @@ fn mk_main(cx: &mut TestCtxt) -> P<ast::Item> {
     let main_body = ecx.block(sp, vec![call_test_main]);
     let main = ast::ItemKind::Fn(ecx.fn_decl(vec![], ast::FunctionRetTy::Ty(main_ret_ty)),
                            ast::Unsafety::Normal,
+                           ast::IsAsync::NotAsync,
                            dummy_spanned(ast::Constness::NotConst),
                            ::abi::Abi::Rust, ast::Generics::default(), main_body);
     P(ast::Item {
@@ -575,20 +661,50 @@ fn mk_main(cx: &mut TestCtxt) -> P<ast::Item> {
     })
 }
 
+fn mk_call_test_runner(cx: &TestCtxt, runner: &ast::Path, sp: Span) -> P<ast::Expr> {
+    let ecx = &cx.ext_cx;
+
+    // &TESTS[i] for every #[test] and #[bench] function
+    let tests = (0..cx.testfns.len()).map(|i| {
+        let tests_ident_expr = ecx.expr_ident(sp, Ident::from_str("TESTS"));
+        let index = ecx.expr(sp, ast::ExprKind::Index(tests_ident_expr, ecx.expr_usize(sp, i)));
+        ecx.expr_addr_of(sp, index)
+    });
+    // &path::to::test_case for every #[test_case] item
+    let test_cases = cx.test_cases.iter().map(|path| {
+        let path = ecx.path_global(sp, mk_reexport_path(cx, path));
+        ecx.expr_addr_of(sp, ecx.expr_path(path))
+    });
+    let tests = ecx.expr_vec_slice(sp, tests.chain(test_cases).collect());
+
+    // path::to::runner(&[...])
+    let runner_idents: Vec<_> = runner.segments.iter().map(|segment| segment.ident).collect();
+    let runner_path = mk_visible_path(cx, &runner_idents);
+    let runner_expr = ecx.expr_path(ecx.path_global(runner.span, runner_path));
+    ecx.expr_call(sp, runner_expr, vec![tests])
+}
+
 fn mk_test_module(cx: &mut TestCtxt) -> (P<ast::Item>, Option<P<ast::Item>>) {
-    // Link to test crate
-    let import = mk_std(cx);
+    // A custom test runner only needs libtest if there are #[test] or
+    // #[bench] functions to describe.
+    let uses_libtest = cx.test_runner.is_none() || !cx.testfns.is_empty();
 
-    // A constant vector of test descriptors.
-    let tests = mk_tests(cx);
+    let mut items = vec![];
+    if uses_libtest {
+        // Link to test crate
+        items.push(mk_std(cx));
+
+        // A constant vector of test descriptors.
+        items.push(mk_tests(cx));
+    }
 
     // The synthesized main function which will call the console test runner
-    // with our list of tests
-    let mainfn = mk_main(cx);
+    // (or the custom one) with our list of tests
+    items.push(mk_main(cx));
 
     let testmod = ast::Mod {
         inner: DUMMY_SP,
-        items: vec![import, mainfn, tests],
+        items,
     };
     let item_ = ast::ItemKind::Mod(testmod);
     let mod_ident = Ident::with_empty_ctxt(Symbol::gensym("__test"));
@@ -696,6 +812,31 @@ fn mk_test_descs(cx: &TestCtxt) -> P<ast::Expr> {
     })
 }
 
+/// The path to a crate-root relative item, as seen from the `__test` module.
+fn mk_visible_path(cx: &TestCtxt, path: &[Ident]) -> Vec<Ident> {
+    let mut visible_path = vec![];
+    if cx.features.extern_absolute_paths {
+        visible_path.push(keywords::Crate.ident());
+    }
+    visible_path.extend_from_slice(path);
+    visible_path
+}
+
+/// The path to a test item through the `__test_reexports` modules, which make
+/// private tests reachable from the `__test` module.
+fn mk_reexport_path(cx: &TestCtxt, path: &[Ident]) -> Vec<Ident> {
+    let mut reexport_path = vec![];
+    match cx.toplevel_reexport {
+        Some(id) => reexport_path.push(id),
+        None => {
+            let diag = cx.span_diagnostic;
+            diag.bug("expected to find top-level re-export name, but found None");
+        }
+    };
+    reexport_path.extend_from_slice(path);
+    mk_visible_path(cx, &reexport_path)
+}
+
 fn mk_test_desc_and_fn_rec(cx: &TestCtxt, test: &Test) -> P<ast::Expr> {
     // FIXME #15962: should be using quote_expr, but that stringifies
     // __test_reexports, causing it to be reinterned, losing the
@@ -753,18 +894,7 @@ fn mk_test_desc_and_fn_rec(cx: &TestCtxt, test: &Test) -> P<ast::Expr> {
              field("should_panic", fail_expr),
              field("allow_fail", allow_fail_expr)]);
 
-    let mut visible_path = vec![];
-    if cx.features.extern_absolute_paths {
-        visible_path.push(keywords::Crate.ident());
-    }
-    match cx.toplevel_reexport {
-        Some(id) => visible_path.push(id),
-        None => {
-            let diag = cx.span_diagnostic;
-            diag.bug("expected to find top-level re-export name, but found None");
-        }
-    };
-    visible_path.extend_from_slice(&test.path[..]);
+    let visible_path = mk_reexport_path(cx, &test.path);
 
     // Rather than directly give the test function to the test
     // harness, we create a wrapper like one of the following:
@@ -33,7 +33,7 @@ use tokenstream::{TokenTree, TokenStream};
 #[derive(Copy, Clone, PartialEq, Eq)]
 pub enum FnKind<'a> {
     /// fn foo() or extern "Abi" fn foo()
-    ItemFn(Ident, Unsafety, Spanned<Constness>, Abi, &'a Visibility, &'a Block),
+    ItemFn(Ident, Unsafety, IsAsync, Spanned<Constness>, Abi, &'a Visibility, &'a Block),
 
     /// fn foo(&self)
     Method(Ident, &'a MethodSig, Option<&'a Visibility>, &'a Block),
@@ -186,6 +186,7 @@ pub fn walk_local<'a, V: Visitor<'a>>(visitor: &mut V, local: &'a Local) {
     visitor.visit_pat(&local.pat);
     walk_list!(visitor, visit_ty, &local.ty);
     walk_list!(visitor, visit_expr, &local.init);
+    walk_list!(visitor, visit_block, &local.els);
 }
 
 pub fn walk_label<'a, V: Visitor<'a>>(visitor: &mut V, label: &'a Label) {
@@ -226,9 +227,10 @@ pub fn walk_item<'a, V: Visitor<'a>>(visitor: &mut V, item: &'a Item) {
             visitor.visit_ty(typ);
             visitor.visit_expr(expr);
         }
-        ItemKind::Fn(ref declaration, unsafety, constness, abi, ref generics, ref body) => {
+        ItemKind::Fn(ref declaration, unsafety, asyncness, constness, abi,
+                     ref generics, ref body) => {
             visitor.visit_generics(generics);
-            visitor.visit_fn(FnKind::ItemFn(item.ident, unsafety,
+            visitor.visit_fn(FnKind::ItemFn(item.ident, unsafety, asyncness,
                                             constness, abi, &item.vis, body),
                              declaration,
                              item.span,
@@ -386,6 +388,7 @@ pub fn walk_path_parameters<'a, V>(visitor: &mut V,
     match *path_parameters {
         PathParameters::AngleBracketed(ref data) => {
             walk_list!(visitor, visit_ty, &data.types);
+            walk_list!(visitor, visit_expr, &data.consts);
             walk_list!(visitor, visit_lifetime, &data.lifetimes);
             walk_list!(visitor, visit_assoc_type_binding, &data.bindings);
         }
@@ -425,6 +428,9 @@ pub fn walk_pat<'a, V: Visitor<'a>>(visitor: &mut V, pattern: &'a Pat) {
         PatKind::Tuple(ref tuple_elements, _) => {
             walk_list!(visitor, visit_pat, tuple_elements);
         }
+        PatKind::Or(ref alternatives) => {
+            walk_list!(visitor, visit_pat, alternatives);
+        }
         PatKind::Box(ref subpattern) |
         PatKind::Ref(ref subpattern, _) |
         PatKind::Paren(ref subpattern) => {
@@ -494,6 +500,11 @@ pub fn walk_generic_param<'a, V: Visitor<'a>>(visitor: &mut V, param: &'a Generi
             walk_list!(visitor, visit_ty, &t.default);
             walk_list!(visitor, visit_attribute, &*t.attrs);
         }
+        GenericParam::Const(ref c) => {
+            visitor.visit_ident(c.ident);
+            visitor.visit_ty(&c.ty);
+            walk_list!(visitor, visit_attribute, &*c.attrs);
+        }
     }
 }
 
@@ -545,7 +556,7 @@ pub fn walk_fn<'a, V>(visitor: &mut V, kind: FnKind<'a>, declaration: &'a FnDecl
     where V: Visitor<'a>,
 {
     match kind {
-        FnKind::ItemFn(_, _, _, _, _, body) => {
+        FnKind::ItemFn(_, _, _, _, _, _, body) => {
             walk_fn_decl(visitor, declaration);
             visitor.visit_block(body);
         }
@@ -716,6 +727,20 @@ pub fn walk_expr<'a, V: Visitor<'a>>(visitor: &mut V, expression: &'a Expr) {
             visitor.visit_expr(subexpression);
             visitor.visit_block(block);
         }
+        ExprKind::IfLetChain(ref conds, ref if_block, ref optional_else) => {
+            for cond in conds {
+                walk_chain_cond(visitor, cond);
+            }
+            visitor.visit_block(if_block);
+            walk_list!(visitor, visit_expr, optional_else);
+        }
+        ExprKind::WhileLetChain(ref conds, ref block, ref opt_label) => {
+            walk_list!(visitor, visit_label, opt_label);
+            for cond in conds {
+                walk_chain_cond(visitor, cond);
+            }
+            visitor.visit_block(block);
+        }
         ExprKind::ForLoop(ref pattern, ref subexpression, ref block, ref opt_label) => {
             walk_list!(visitor, visit_label, opt_label);
             visitor.visit_pat(pattern);
@@ -794,6 +819,9 @@ pub fn walk_expr<'a, V: Visitor<'a>>(visitor: &mut V, expression: &'a Expr) {
         ExprKind::Catch(ref body) => {
             visitor.visit_block(body)
         }
+        ExprKind::Async(_, _, ref body) => {
+            visitor.visit_block(body)
+        }
     }
 
     visitor.visit_expr_post(expression)
@@ -806,6 +834,16 @@ pub fn walk_arm<'a, V: Visitor<'a>>(visitor: &mut V, arm: &'a Arm) {
     walk_list!(visitor, visit_attribute, &arm.attrs);
 }
 
+pub fn walk_chain_cond<'a, V: Visitor<'a>>(visitor: &mut V, cond: &'a ChainCond) {
+    match *cond {
+        ChainCond::Let(ref pats, ref subexpression) => {
+            walk_list!(visitor, visit_pat, pats);
+            visitor.visit_expr(subexpression);
+        }
+        ChainCond::Expr(ref subexpression) => visitor.visit_expr(subexpression),
+    }
+}
+
 pub fn walk_vis<'a, V: Visitor<'a>>(visitor: &mut V, vis: &'a Visibility) {
     if let VisibilityKind::Restricted { ref path, id } = vis.node {
         visitor.visit_path(path, id);
@@ -9,11 +9,12 @@
 // except according to those terms.
 
 use syntax::ast::*;
-use syntax::codemap::Spanned;
+use syntax::codemap::{respan, Spanned};
 use syntax::ext::base::*;
 use syntax::ext::build::AstBuilder;
 use syntax::parse::token;
 use syntax::print::pprust;
+use syntax::ptr::P;
 use syntax::symbol::Symbol;
 use syntax::tokenstream::{TokenStream, TokenTree};
 use syntax_pos::{Span, DUMMY_SP};
@@ -24,7 +25,7 @@ pub fn expand_assert<'cx>(
     tts: &[TokenTree],
 ) -> Box<MacResult + 'cx> {
     let mut parser = cx.new_parser_from_tts(tts);
-    let cond_expr = panictry!(parser.parse_expr());
+    let mut cond_expr = panictry!(parser.parse_expr());
     let custom_msg_args = if parser.eat(&token::Comma) {
         let ts = parser.parse_tokens();
         if !ts.is_empty() {
@@ -37,25 +38,40 @@ pub fn expand_assert<'cx>(
     };
 
     let sp = sp.apply_mark(cx.current_expansion.mark);
+    let mut captures = Vec::new();
+    let panic_tts = if let Some(ts) = custom_msg_args {
+        ts.into()
+    } else {
+        // `expr_to_string` escapes the string literals with `.escape_default()`
+        // which escapes all non-ASCII characters with `\u`.
+        let escaped_expr = escape_format_string(&unescape_printable_unicode(
+            &pprust::expr_to_string(&cond_expr),
+        ));
+        let mut msg = format!("assertion failed: {}", escaped_expr);
+
+        if cx.ecfg.enable_generic_assert() {
+            cond_expr = capture_operands(cx, sp, cond_expr, &mut captures);
+            if !captures.is_empty() {
+                msg.push_str("\\nwith captured values:");
+            }
+            for capture in &captures {
+                msg.push_str(&format!("\\n  {} = {{:?}}", capture.text));
+            }
+        }
+
+        let mut tts = vec![TokenStream::from(TokenTree::Token(
+            DUMMY_SP,
+            token::Literal(token::Lit::Str_(Name::intern(&msg)), None),
+        ))];
+        for capture in &captures {
+            tts.push(TokenStream::from(token::Comma));
+            tts.push(TokenStream::from(token::Ident(capture.ident, false)));
+        }
+        TokenStream::concat(tts).into()
+    };
     let panic_call = Mac_ {
         path: Path::from_ident(Ident::new(Symbol::intern("panic"), sp)),
-        tts: if let Some(ts) = custom_msg_args {
-            ts.into()
-        } else {
-            // `expr_to_string` escapes the string literals with `.escape_default()`
-            // which escapes all non-ASCII characters with `\u`.
-            let escaped_expr = escape_format_string(&unescape_printable_unicode(
-                &pprust::expr_to_string(&cond_expr),
-            ));
-
-            TokenStream::from(TokenTree::Token(
-                DUMMY_SP,
-                token::Literal(
-                    token::Lit::Str_(Name::intern(&format!("assertion failed: {}", escaped_expr))),
-                    None,
-                ),
-            )).into()
-        },
+        tts: panic_tts,
     };
     let if_expr = cx.expr_if(
         sp,
@@ -69,7 +85,289 @@ pub fn expand_assert<'cx>(
         ),
         None,
     );
-    MacEager::expr(if_expr)
+    if captures.is_empty() {
+        return MacEager::expr(if_expr);
+    }
+
+    // {
+    //     use $crate::asserting::{TryCaptureGeneric, TryCapturePrintable};
+    //     let __value1;
+    //     ...
+    //     let mut __capture0 = $crate::asserting::Capture::new();
+    //     ...
+    //     if !cond { panic!(msg, __capture0, ...) }
+    // }
+    let imports = [
+        Ident::new(Symbol::intern("TryCaptureGeneric"), sp),
+        Ident::new(Symbol::intern("TryCapturePrintable"), sp),
+    ];
+    let vis = respan(sp.shrink_to_lo(), VisibilityKind::Inherited);
+    let mut stmts = vec![
+        cx.stmt_item(sp, cx.item_use_list(sp, vis, cx.std_path(&["asserting"]), &imports)),
+    ];
+    // The slots are declared first, so that they outlive the captures that
+    // borrow from them.
+    for ident in captures.iter().filter_map(|capture| capture.value) {
+        stmts.push(stmt_let_uninit(cx, sp, ident));
+    }
+    for capture in &captures {
+        let new = cx.std_path(&["asserting", "Capture", "new"]);
+        stmts.push(cx.stmt_let(sp, true, capture.ident, cx.expr_call_global(sp, new, vec![])));
+    }
+    stmts.push(cx.stmt_expr(if_expr));
+    MacEager::expr(cx.expr_block(cx.block(sp, stmts)))
+}
+
+/// An operand of a comparison in the asserted condition, whose value is
+/// printed if the assertion fails.
+struct Capture {
+    /// The local holding the `asserting::Capture` of the operand.
+    ident: Ident,
+    /// The operand as written, escaped for use in a format string.
+    text: String,
+    /// The local the value of an operand that isn't a place is moved into,
+    /// so that it lives as long as its capture.
+    value: Option<Ident>,
+}
+
+/// How an operand of a comparison is captured.
+#[derive(Copy, Clone, PartialEq)]
+enum OperandKind {
+    /// A place rooted in a local, which is captured by reference.
+    Place,
+    /// A value, or a field of one, which is moved into a slot first.
+    Value,
+    /// Literals and constants, whose value is evident from the message,
+    /// places reached through the result of a call, which would have to keep
+    /// e.g. a lock guard alive until the end of the assertion, and values
+    /// that may borrow from a temporary, which moving them would drop.
+    Skip,
+}
+
+fn operand_kind(expr: &Expr) -> OperandKind {
+    match expr.node {
+        ExprKind::Lit(_) => OperandKind::Skip,
+        ExprKind::Path(None, ref path) => {
+            let name = path.segments[0].ident.name.as_str();
+            let is_local = name.starts_with(|c: char| c == '_' || c.is_lowercase());
+            if path.segments.len() == 1 && is_local {
+                OperandKind::Place
+            } else {
+                OperandKind::Skip
+            }
+        }
+        ExprKind::Path(Some(_), _) => OperandKind::Skip,
+        ExprKind::Paren(ref base) | ExprKind::Field(ref base, _) => operand_kind(base),
+        ExprKind::Unary(UnOp::Deref, ref base) | ExprKind::Index(ref base, _) => {
+            match operand_kind(base) {
+                OperandKind::Value => OperandKind::Skip,
+                kind => kind,
+            }
+        }
+        _ if may_borrow_temporary(expr) => OperandKind::Skip,
+        _ => OperandKind::Value,
+    }
+}
+
+/// Whether the value of `expr` may borrow from a temporary created while
+/// evaluating it, as `s().as_str()` borrows the result of `s()`. Method
+/// receivers and `&` operands other than places and literals are assumed to
+/// be borrowed, since it isn't known yet whether they are autoref'd.
+fn may_borrow_temporary(expr: &Expr) -> bool {
+    match expr.node {
+        ExprKind::Lit(_) | ExprKind::Path(..) => false,
+        ExprKind::AddrOf(_, ref base) => !is_place_or_literal(base),
+        ExprKind::MethodCall(_, ref args) => {
+            !is_place_or_literal(&args[0]) || args[1..].iter().any(|arg| may_borrow_temporary(arg))
+        }
+        ExprKind::Call(ref callee, ref args) => {
+            may_borrow_temporary(callee) || args.iter().any(|arg| may_borrow_temporary(arg))
+        }
+        ExprKind::Binary(_, ref lhs, ref rhs) => {
+            may_borrow_temporary(lhs) || may_borrow_temporary(rhs)
+        }
+        ExprKind::Unary(UnOp::Not, ref base) |
+        ExprKind::Unary(UnOp::Neg, ref base) |
+        ExprKind::Cast(ref base, _) |
+        ExprKind::Type(ref base, _) |
+        ExprKind::Paren(ref base) |
+        ExprKind::Field(ref base, _) |
+        ExprKind::Repeat(ref base, _) => may_borrow_temporary(base),
+        ExprKind::Tup(ref elems) | ExprKind::Array(ref elems) => {
+            elems.iter().any(|elem| may_borrow_temporary(elem))
+        }
+        ExprKind::Struct(_, ref fields, ref base) => {
+            fields.iter().any(|field| may_borrow_temporary(&field.expr)) ||
+                base.as_ref().map_or(false, |base| may_borrow_temporary(base))
+        }
+        _ => true,
+    }
+}
+
+fn is_place_or_literal(expr: &Expr) -> bool {
+    match expr.node {
+        ExprKind::Lit(_) => true,
+        _ => operand_kind(expr) == OperandKind::Place,
+    }
+}
+
+/// Splits a `Value` operand into the value it is a field of and the operand
+/// with that value replaced by the local `slot`.
+fn split_value(cx: &ExtCtxt, expr: P<Expr>, slot: Ident) -> (P<Expr>, P<Expr>) {
+    expr.and_then(|Expr { id, node, span, attrs }| {
+        let node = match node {
+            ExprKind::Paren(base) => {
+                let (value, base) = split_value(cx, base, slot);
+                return (value, P(Expr { id, node: ExprKind::Paren(base), span, attrs }));
+            }
+            ExprKind::Field(base, field) => {
+                let (value, base) = split_value(cx, base, slot);
+                return (value, P(Expr { id, node: ExprKind::Field(base, field), span, attrs }));
+            }
+            node => node,
+        };
+        (P(Expr { id, node, span, attrs }), cx.expr_ident(span, slot))
+    })
+}
+
+/// `let ident;`
+fn stmt_let_uninit(cx: &ExtCtxt, sp: Span, ident: Ident) -> Stmt {
+    let local = P(Local {
+        pat: cx.pat_ident(sp, ident),
+        ty: None,
+        init: None,
+        els: None,
+        id: DUMMY_NODE_ID,
+        span: sp,
+        attrs: ThinVec::new(),
+    });
+    Stmt { id: DUMMY_NODE_ID, node: StmtKind::Local(local), span: sp }
+}
+
+/// Rewrites the comparisons in `expr`, looking through `&&`, `||`, `!` and
+/// parentheses, so that their operands are recorded when they are evaluated.
+fn capture_operands(
+    cx: &ExtCtxt,
+    sp: Span,
+    expr: P<Expr>,
+    captures: &mut Vec<Capture>,
+) -> P<Expr> {
+    expr.and_then(|Expr { id, node, span, attrs }| {
+        let node = match node {
+            ExprKind::Binary(op, lhs, rhs) => match op.node {
+                BinOpKind::And | BinOpKind::Or => {
+                    let lhs = capture_operands(cx, sp, lhs, captures);
+                    let rhs = capture_operands(cx, sp, rhs, captures);
+                    ExprKind::Binary(op, lhs, rhs)
+                }
+                _ if op.node.is_comparison() => {
+                    return capture_comparison(cx, sp, span, op, lhs, rhs, captures);
+                }
+                _ => ExprKind::Binary(op, lhs, rhs),
+            },
+            ExprKind::Unary(UnOp::Not, operand) => {
+                ExprKind::Unary(UnOp::Not, capture_operands(cx, sp, operand, captures))
+            }
+            ExprKind::Paren(inner) => ExprKind::Paren(capture_operands(cx, sp, inner, captures)),
+            node => node,
+        };
+        P(Expr { id, node, span, attrs })
+    })
+}
+
+/// Expands `lhs op rhs` into
+///
+/// ```ignore
+/// match (&lhs, &rhs) {
+///     (__assert_lhs, __assert_rhs) => {
+///         (&Wrapper(__assert_lhs)).try_capture(&mut __capture0);
+///         (&Wrapper(__assert_rhs)).try_capture(&mut __capture1);
+///         *__assert_lhs op *__assert_rhs
+///     }
+/// }
+/// ```
+///
+/// which evaluates the operands exactly as the comparison itself would, by
+/// reference and from left to right. The captures borrow the operands until
+/// the assertion fails, so an operand that isn't a place, such as `f()`, is
+/// first moved into a slot declared before the condition:
+///
+/// ```ignore
+/// match ({ __value0 = f(); &__value0 }, &rhs) { ... }
+/// ```
+///
+/// Its temporaries are dropped once it has been moved, so an operand that
+/// may borrow from one of them, such as `s().as_str()`, isn't captured. See
+/// `OperandKind` for the operands that aren't captured.
+fn capture_comparison(
+    cx: &ExtCtxt,
+    sp: Span,
+    cmp_span: Span,
+    op: BinOp,
+    lhs: P<Expr>,
+    rhs: P<Expr>,
+    captures: &mut Vec<Capture>,
+) -> P<Expr> {
+    let lhs_ident = Ident::new(Symbol::intern("__assert_lhs"), sp);
+    let rhs_ident = Ident::new(Symbol::intern("__assert_rhs"), sp);
+
+    let mut stmts = Vec::new();
+    let mut operands = Vec::new();
+    for (ident, operand) in vec![(lhs_ident, lhs), (rhs_ident, rhs)] {
+        let kind = operand_kind(&operand);
+        if kind == OperandKind::Skip {
+            operands.push(cx.expr_addr_of(sp, operand));
+            continue;
+        }
+        let index = captures.len();
+        let capture = Capture {
+            ident: Ident::new(Symbol::intern(&format!("__capture{}", index)), sp),
+            text: escape_format_string(&unescape_printable_unicode(
+                &pprust::expr_to_string(&operand),
+            )),
+            value: if kind == OperandKind::Value {
+                Some(Ident::new(Symbol::intern(&format!("__value{}", index)), sp))
+            } else {
+                None
+            },
+        };
+        operands.push(match capture.value {
+            Some(slot) => {
+                let (value, operand) = split_value(cx, operand, slot);
+                let assign = cx.expr(sp, ExprKind::Assign(cx.expr_ident(sp, slot), value));
+                cx.expr_block(cx.block(sp, vec![
+                    cx.stmt_semi(assign),
+                    cx.stmt_expr(cx.expr_addr_of(sp, operand)),
+                ]))
+            }
+            None => cx.expr_addr_of(sp, operand),
+        });
+        let wrapper = cx.expr_call_global(
+            sp,
+            cx.std_path(&["asserting", "Wrapper"]),
+            vec![cx.expr_ident(sp, ident)],
+        );
+        let try_capture = cx.expr_method_call(
+            sp,
+            cx.expr_addr_of(sp, wrapper),
+            Ident::from_str("try_capture"),
+            vec![cx.expr_mut_addr_of(sp, cx.expr_ident(sp, capture.ident))],
+        );
+        stmts.push(cx.stmt_semi(try_capture));
+        captures.push(capture);
+    }
+    let cmp = cx.expr_binary(
+        cmp_span,
+        op.node,
+        cx.expr_deref(sp, cx.expr_ident(sp, lhs_ident)),
+        cx.expr_deref(sp, cx.expr_ident(sp, rhs_ident)),
+    );
+    stmts.push(cx.stmt_expr(cmp));
+
+    let pat = cx.pat_tuple(sp, vec![cx.pat_ident(sp, lhs_ident), cx.pat_ident(sp, rhs_ident)]);
+    let arm = cx.arm(sp, vec![pat], cx.expr_block(cx.block(sp, stmts)));
+    let operands = cx.expr_tuple(sp, operands);
+    cx.expr_match(cmp_span, operands, vec![arm])
 }
 
 /// Escapes a string for use as a formatting string.
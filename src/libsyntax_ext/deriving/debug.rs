@@ -137,6 +137,7 @@ fn stmt_let_undescore(cx: &mut ExtCtxt, sp: Span, expr: P<ast::Expr>) -> ast::St
         pat: cx.pat_wild(sp),
         ty: None,
         init: Some(expr),
+        els: None,
         id: ast::DUMMY_NODE_ID,
         span: sp,
         attrs: ast::ThinVec::new(),
@@ -12,11 +12,14 @@ use deriving::path_std;
 use deriving::generic::*;
 use deriving::generic::ty::*;
 
-use syntax::ast::{Expr, MetaItem};
-use syntax::ext::base::{Annotatable, ExtCtxt};
+use syntax::ast::{self, Attribute, Expr, Generics, Mac, MetaItem, NodeId, Variant};
+use syntax::attr;
+use syntax::ext::base::{Annotatable, DummyResult, ExtCtxt};
 use syntax::ext::build::AstBuilder;
+use syntax::feature_gate;
 use syntax::ptr::P;
 use syntax::symbol::Symbol;
+use syntax::visit::{self, Visitor};
 use syntax_pos::Span;
 
 pub fn expand_deriving_default(cx: &mut ExtCtxt,
@@ -24,6 +27,10 @@ pub fn expand_deriving_default(cx: &mut ExtCtxt,
                                mitem: &MetaItem,
                                item: &Annotatable,
                                push: &mut FnMut(Annotatable)) {
+    if let Annotatable::Item(ref item) = *item {
+        DefaultAttrs { cx }.visit_item(item);
+    }
+
     let inline = cx.meta_word(span, Symbol::intern("inline"));
     let attrs = vec![cx.attribute(span, inline)];
     let trait_def = TraitDef {
@@ -75,12 +82,97 @@ fn default_substructure(cx: &mut ExtCtxt, trait_span: Span, substr: &Substructur
                 }
             }
         }
-        StaticEnum(..) => {
-            cx.span_err(trait_span,
-                        "`Default` cannot be derived for enums, only structs");
-            // let compilation continue
-            cx.expr_usize(trait_span, 0)
+        StaticEnum(enum_def, _) => {
+            match default_variant(cx, trait_span, enum_def) {
+                Some(variant) => {
+                    let path = cx.path(variant.span, vec![substr.type_ident, variant.node.ident]);
+                    cx.expr_path(path)
+                }
+                // let compilation continue
+                None => DummyResult::raw_expr(trait_span),
+            }
         }
         _ => cx.span_bug(trait_span, "Non-static method in `derive(Default)`"),
     };
 }
+
+/// Finds the single unit variant marked `#[default]`, reporting an error if
+/// there isn't exactly one or if it has fields.
+fn default_variant<'a>(cx: &ExtCtxt,
+                       trait_span: Span,
+                       enum_def: &'a ast::EnumDef)
+                       -> Option<&'a ast::Variant> {
+    let defaults: Vec<_> = enum_def.variants.iter()
+        .filter(|variant| variant.node.attrs.iter().any(is_default_attr))
+        .collect();
+
+    let variant = match defaults.len() {
+        0 => {
+            cx.struct_span_err(trait_span, "no default declared")
+                .help("make a unit variant default by placing `#[default]` above it")
+                .emit();
+            return None;
+        }
+        1 => defaults[0],
+        _ => {
+            let mut err = cx.struct_span_err(trait_span, "multiple declared defaults");
+            err.span_label(defaults[0].span, "first default");
+            for variant in &defaults[1..] {
+                err.span_label(variant.span, "additional default");
+            }
+            err.note("only one variant can be default");
+            err.emit();
+            return None;
+        }
+    };
+
+    if !variant.node.data.is_unit() {
+        cx.struct_span_err(variant.span,
+                           "the `#[default]` attribute may only be used on unit enum variants")
+            .help("consider a manual implementation of `Default`")
+            .emit();
+        return None;
+    }
+
+    Some(variant)
+}
+
+/// `#[default]` is a helper attribute of `derive(Default)`, other attributes
+/// named `default`, like `#[default = "..."]`, may belong to other derives.
+fn is_default_attr(attr: &Attribute) -> bool {
+    attr.path == "default" && attr.is_word()
+}
+
+/// Marks the `#[default]` attributes of the variants as known, and reports
+/// those placed anywhere else in the item.
+struct DefaultAttrs<'a, 'b: 'a> {
+    cx: &'a ExtCtxt<'b>,
+}
+
+impl<'a, 'b, 'ast> Visitor<'ast> for DefaultAttrs<'a, 'b> {
+    fn visit_variant(&mut self, variant: &'ast Variant, generics: &'ast Generics, item_id: NodeId) {
+        for attr in variant.node.attrs.iter().filter(|attr| is_default_attr(attr)) {
+            if !self.cx.ecfg.enable_derive_default_enum() {
+                feature_gate::emit_feature_err(&self.cx.parse_sess,
+                                               "derive_default_enum",
+                                               attr.span,
+                                               feature_gate::GateIssue::Language,
+                                               feature_gate::EXPLAIN_DEFAULT_ATTR);
+            }
+            attr::mark_used(attr);
+            attr::mark_known(attr);
+        }
+        visit::walk_variant(self, variant, generics, item_id)
+    }
+
+    fn visit_attribute(&mut self, attr: &'ast Attribute) {
+        if is_default_attr(attr) && !attr::is_known(attr) {
+            self.cx.span_err(attr.span,
+                             "the `#[default]` attribute may only be used on enum variants");
+            attr::mark_used(attr);
+            attr::mark_known(attr);
+        }
+    }
+
+    fn visit_mac(&mut self, _mac: &'ast Mac) {}
+}
@@ -551,6 +551,7 @@ impl<'a> TraitDef<'a> {
         params.extend(generics.params.iter().map(|param| {
             match *param {
                 ref l @ GenericParam::Lifetime(_) => l.clone(),
+                ref c @ GenericParam::Const(_) => c.clone(),
                 GenericParam::Type(ref ty_param) => {
                     // I don't think this can be moved out of the loop, since
                     // a TyParamBound requires an ast id
@@ -666,12 +667,15 @@ impl<'a> TraitDef<'a> {
         // Create the reference to the trait.
         let trait_ref = cx.trait_ref(trait_path);
 
-        // Create the type parameters on the `self` path.
+        // Create the type parameters on the `self` path. Const parameters are
+        // passed as paths too, they are told apart from types when resolved.
         let self_ty_params = generics.params
             .iter()
             .filter_map(|param| match *param {
                 GenericParam::Type(ref ty_param)
                     => Some(cx.ty_ident(self.span, ty_param.ident)),
+                GenericParam::Const(ref const_param)
+                    => Some(cx.ty_ident(self.span, const_param.ident)),
                 _ => None,
             })
             .collect();
@@ -988,6 +992,7 @@ impl<'a> MethodDef<'a> {
             node: ast::ImplItemKind::Method(ast::MethodSig {
                                                 abi,
                                                 unsafety,
+                                                asyncness: ast::IsAsync::NotAsync,
                                                 constness:
                                                     dummy_spanned(ast::Constness::NotConst),
                                                 decl: fn_decl,
@@ -188,6 +188,7 @@ impl<'a> Ty<'a> {
                     .iter()
                     .filter_map(|param| match *param {
                         GenericParam::Type(ref ty_param) => Some(cx.ty_ident(span, ty_param.ident)),
+                        GenericParam::Const(ref c) => Some(cx.ty_ident(span, c.ident)),
                         _ => None,
                     })
                     .collect();
@@ -17,6 +17,7 @@ use syntax::ast;
 use syntax::ext::base::*;
 use syntax::ext::base;
 use syntax::ext::build::AstBuilder;
+use syntax::feature_gate;
 use syntax::parse::token;
 use syntax::ptr::P;
 use syntax::symbol::Symbol;
@@ -44,6 +45,12 @@ struct Context<'a, 'b: 'a> {
     macsp: Span,
     /// The span of the format string literal.
     fmtsp: Span,
+    /// The span of the format string as written, if it was written as a
+    /// literal rather than produced by another macro such as `concat!`.
+    /// Only literal format strings may capture arguments from the
+    /// surrounding scope, and the captured identifiers are resolved with
+    /// the hygiene of this span.
+    literal_span: Option<Span>,
 
     /// List of parsed argument expressions.
     /// Named expressions are resolved early, and are appended to the end of
@@ -332,13 +339,25 @@ impl<'a, 'b> Context<'a, 'b> {
             }
 
             Named(name) => {
-                let idx = match self.names.get(&name) {
-                    Some(e) => *e,
-                    None => {
-                        let msg = format!("there is no argument named `{}`", name);
-                        self.ecx.span_err(self.fmtsp, &msg[..]);
-                        return;
-                    }
+                let idx = match self.names.get(&name).cloned() {
+                    Some(idx) => idx,
+                    None => match self.literal_span {
+                        Some(span) if self.ecx.ecfg.enable_format_args_capture() => {
+                            self.capture_named_arg(&name, span)
+                        }
+                        literal_span => {
+                            let msg = format!("there is no argument named `{}`", name);
+                            let mut err = self.ecx.struct_span_err(self.fmtsp, &msg[..]);
+                            if literal_span.is_some() {
+                                err.help(&format!("if you intended to capture `{}` from the \
+                                                   surrounding scope, add \
+                                                   `#![feature(format_args_capture)]` to \
+                                                   the crate attributes", name));
+                            }
+                            err.emit();
+                            return;
+                        }
+                    },
                 };
                 // Treat as positional arg.
                 self.verify_arg_type(Exact(idx), ty)
@@ -346,6 +365,22 @@ impl<'a, 'b> Context<'a, 'b> {
         }
     }
 
+    /// Appends an implicit argument referring to the variable `name` in the
+    /// scope of the format string, and returns its index.
+    ///
+    /// The identifier is given the span of the literal so that it resolves
+    /// hygienically where the literal was written, which may be inside a
+    /// macro definition rather than at the `format_args!` call.
+    fn capture_named_arg(&mut self, name: &str, span: Span) -> usize {
+        let ident = ast::Ident::new(Symbol::intern(name), span);
+        let idx = self.args.len();
+        self.args.push(self.ecx.expr_ident(span, ident));
+        self.arg_types.push(Vec::new());
+        self.arg_unique_types.push(Vec::new());
+        self.names.insert(name.to_string(), idx);
+        idx
+    }
+
     /// Builds the mapping between format placeholders and argument objects.
     fn build_index_map(&mut self) {
         // NOTE: Keep the ordering the same as `into_expr`'s expansion would do!
@@ -683,7 +718,31 @@ pub fn expand_format_args<'cx>(ecx: &'cx mut ExtCtxt,
     sp = sp.apply_mark(ecx.current_expansion.mark);
     match parse_args(ecx, sp, tts) {
         Some((efmt, args, names)) => {
-            MacEager::expr(expand_preparsed_format_args(ecx, sp, efmt, args, names))
+            MacEager::expr(expand_preparsed_format_args(ecx, sp, efmt, args, names, false))
+        }
+        None => DummyResult::expr(sp),
+    }
+}
+
+/// Expands `format_args_nl!`, which behaves like `format_args!` with a newline
+/// appended to the format string. Unlike `concat!(fmt, "\n")`, this keeps the
+/// format string a literal, so `println!` and friends can capture arguments.
+pub fn expand_format_args_nl<'cx>(ecx: &'cx mut ExtCtxt,
+                                  mut sp: Span,
+                                  tts: &[tokenstream::TokenTree])
+                                  -> Box<base::MacResult + 'cx> {
+    if !sp.allows_unstable() && !ecx.ecfg.enable_format_args_nl() {
+        feature_gate::emit_feature_err(&ecx.parse_sess,
+                                       "format_args_nl",
+                                       sp,
+                                       feature_gate::GateIssue::Language,
+                                       feature_gate::EXPLAIN_FORMAT_ARGS_NL);
+        return DummyResult::expr(sp);
+    }
+    sp = sp.apply_mark(ecx.current_expansion.mark);
+    match parse_args(ecx, sp, tts) {
+        Some((efmt, args, names)) => {
+            MacEager::expr(expand_preparsed_format_args(ecx, sp, efmt, args, names, true))
         }
         None => DummyResult::expr(sp),
     }
@@ -695,7 +754,8 @@ pub fn expand_preparsed_format_args(ecx: &mut ExtCtxt,
                                     sp: Span,
                                     efmt: P<ast::Expr>,
                                     args: Vec<P<ast::Expr>>,
-                                    names: HashMap<String, usize>)
+                                    names: HashMap<String, usize>,
+                                    append_newline: bool)
                                     -> P<ast::Expr> {
     // NOTE: this verbose way of initializing `Vec<Vec<ArgumentType>>` is because
     // `ArgumentType` does not derive `Clone`.
@@ -703,11 +763,18 @@ pub fn expand_preparsed_format_args(ecx: &mut ExtCtxt,
     let arg_unique_types: Vec<_> = (0..args.len()).map(|_| Vec::new()).collect();
     let mut macsp = ecx.call_site();
     macsp = macsp.apply_mark(ecx.current_expansion.mark);
+    let literal_span = match efmt.node {
+        ast::ExprKind::Lit(_) => Some(efmt.span),
+        _ => None,
+    };
     let msg = "format argument must be a string literal.";
-    let fmt = match expr_to_spanned_string(ecx, efmt, msg) {
+    let mut fmt = match expr_to_spanned_string(ecx, efmt, msg) {
         Some(fmt) => fmt,
         None => return DummyResult::raw_expr(sp),
     };
+    if append_newline {
+        fmt.node.0 = Symbol::intern(&format!("{}\n", fmt.node.0));
+    }
 
     let mut cx = Context {
         ecx,
@@ -727,6 +794,7 @@ pub fn expand_preparsed_format_args(ecx: &mut ExtCtxt,
         all_pieces_simple: true,
         macsp,
         fmtsp: fmt.span,
+        literal_span,
         invalid_refs: Vec::new(),
     };
 
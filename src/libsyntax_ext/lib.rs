@@ -118,9 +118,18 @@ pub fn register_builtins(resolver: &mut syntax::ext::base::Resolver,
         log_syntax: log_syntax::expand_syntax_ext,
         trace_macros: trace_macros::expand_trace_macros,
         compile_error: compile_error::expand_compile_error,
-        assert: assert::expand_assert,
     }
 
+    // `assert!` refers to unstable `asserting` internals with `generic_assert`.
+    register(Symbol::intern("assert"),
+             NormalTT {
+                expander: Box::new(assert::expand_assert),
+                def_info: None,
+                allow_internal_unstable: true,
+                allow_internal_unsafe: false,
+                unstable_feature: None
+            });
+
     // format_args uses `unstable` things internally.
     register(Symbol::intern("format_args"),
              NormalTT {
@@ -130,6 +139,14 @@ pub fn register_builtins(resolver: &mut syntax::ext::base::Resolver,
                 allow_internal_unsafe: false,
                 unstable_feature: None
             });
+    register(Symbol::intern("format_args_nl"),
+             NormalTT {
+                expander: Box::new(format::expand_format_args_nl),
+                def_info: None,
+                allow_internal_unstable: true,
+                allow_internal_unsafe: false,
+                unstable_feature: None
+            });
 
     for (name, ext) in user_exts {
         register(name, ext);
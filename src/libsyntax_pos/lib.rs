@@ -20,6 +20,7 @@
 
 #![feature(const_fn)]
 #![feature(custom_attribute)]
+#![feature(non_exhaustive)]
 #![feature(optin_builtin_traits)]
 #![allow(unused_attributes)]
 #![feature(specialization)]
@@ -47,6 +48,8 @@ extern crate serialize as rustc_serialize; // used by deriving
 
 extern crate unicode_width;
 
+pub mod edition;
+use edition::Edition;
 pub mod hygiene;
 pub use hygiene::{Mark, SyntaxContext, ExpnInfo, ExpnFormat, NameAndSpan, CompilerDesugaringKind};
 
@@ -306,6 +309,18 @@ impl Span {
         self.ctxt().outer().expn_info().map(source_callee)
     }
 
+    /// The edition of the code the span is in. Expansions don't record the
+    /// edition of the crate their macro was defined in, so this is always the
+    /// edition of the crate being compiled.
+    pub fn edition(self) -> Edition {
+        hygiene::default_edition()
+    }
+
+    #[inline]
+    pub fn rust_2018(self) -> bool {
+        self.edition() >= Edition::Edition2018
+    }
+
     /// Check if a span is "internal" to a macro in which #[unstable]
     /// items can be used (that is, a macro marked with
     /// `#[allow_internal_unstable]`).
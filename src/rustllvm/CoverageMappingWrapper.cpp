@@ -0,0 +1,154 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#include "rustllvm.h"
+
+#if LLVM_VERSION_GE(5, 0)
+#include "llvm/ADT/SmallVector.h"
+#include "llvm/IR/Function.h"
+#include "llvm/ProfileData/Coverage/CoverageMapping.h"
+#include "llvm/ProfileData/Coverage/CoverageMappingWriter.h"
+#include "llvm/ProfileData/InstrProf.h"
+#define COVERAGE_AVAILABLE
+#endif
+
+using namespace llvm;
+
+// A source region mapped to a single counter, as laid out by `CounterRegion`
+// in `librustc_llvm`. Lines and columns are 1-based, and the end column is
+// one past the last character of the region.
+struct LLVMRustCounterMappingRegion {
+  uint32_t CounterId;
+  uint32_t FileId;
+  uint32_t LineStart;
+  uint32_t ColumnStart;
+  uint32_t LineEnd;
+  uint32_t ColumnEnd;
+};
+
+extern "C" bool
+LLVMRustCoverageAvailable() {
+#ifdef COVERAGE_AVAILABLE
+  return true;
+#else
+  return false;
+#endif
+}
+
+#ifdef COVERAGE_AVAILABLE
+
+extern "C" void
+LLVMRustCoverageWriteFilenamesSectionToBuffer(const char **Filenames,
+                                              size_t FilenamesLen,
+                                              RustStringRef BufferOut) {
+  SmallVector<StringRef, 32> FilenameRefs;
+  for (size_t I = 0; I < FilenamesLen; I++) {
+    FilenameRefs.push_back(StringRef(Filenames[I]));
+  }
+  coverage::CoverageFilenamesSectionWriter Writer(makeArrayRef(FilenameRefs));
+  RawRustStringOstream OS(BufferOut);
+  Writer.write(OS);
+}
+
+extern "C" void
+LLVMRustCoverageWriteMappingToBuffer(const unsigned *VirtualFileMappingIds,
+                                     unsigned NumVirtualFileMappingIds,
+                                     const LLVMRustCounterMappingRegion *Regions,
+                                     unsigned NumRegions,
+                                     RustStringRef BufferOut) {
+  SmallVector<coverage::CounterMappingRegion, 16> MappingRegions;
+  for (unsigned I = 0; I < NumRegions; I++) {
+    const LLVMRustCounterMappingRegion &R = Regions[I];
+    MappingRegions.push_back(coverage::CounterMappingRegion::makeRegion(
+        coverage::Counter::getCounter(R.CounterId), R.FileId,
+        R.LineStart, R.ColumnStart, R.LineEnd, R.ColumnEnd));
+  }
+  coverage::CoverageMappingWriter Writer(
+      makeArrayRef(VirtualFileMappingIds, NumVirtualFileMappingIds),
+      ArrayRef<coverage::CounterExpression>(),
+      makeMutableArrayRef(MappingRegions));
+  RawRustStringOstream OS(BufferOut);
+  Writer.write(OS);
+}
+
+// Creates the `__profn_` variable holding the name under which the counters
+// of `F` are recorded in the profile.
+extern "C" LLVMValueRef
+LLVMRustCoverageCreatePGOFuncNameVar(LLVMValueRef F, const char *FuncName) {
+  return wrap(createPGOFuncNameVar(*unwrap<Function>(F), StringRef(FuncName)));
+}
+
+extern "C" uint64_t
+LLVMRustCoverageHashFunctionName(const char *FuncName) {
+  return IndexedInstrProf::ComputeHash(StringRef(FuncName));
+}
+
+extern "C" void
+LLVMRustCoverageWriteSectionNameToString(LLVMModuleRef M, RustStringRef Str) {
+  Triple TargetTriple(unwrap(M)->getTargetTriple());
+  RawRustStringOstream OS(Str);
+  OS << getInstrProfSectionName(IPSK_covmap, TargetTriple.getObjectFormat());
+}
+
+extern "C" void
+LLVMRustCoverageWriteMappingVarNameToString(RustStringRef Str) {
+  RawRustStringOstream OS(Str);
+  OS << getCoverageMappingVarName();
+}
+
+extern "C" uint32_t
+LLVMRustCoverageMappingVersion() {
+  return coverage::CovMapVersion::CurrentVersion;
+}
+
+#else
+
+extern "C" void
+LLVMRustCoverageWriteFilenamesSectionToBuffer(const char **Filenames,
+                                              size_t FilenamesLen,
+                                              RustStringRef BufferOut) {
+  report_fatal_error("coverage instrumentation not available");
+}
+
+extern "C" void
+LLVMRustCoverageWriteMappingToBuffer(const unsigned *VirtualFileMappingIds,
+                                     unsigned NumVirtualFileMappingIds,
+                                     const LLVMRustCounterMappingRegion *Regions,
+                                     unsigned NumRegions,
+                                     RustStringRef BufferOut) {
+  report_fatal_error("coverage instrumentation not available");
+}
+
+extern "C" LLVMValueRef
+LLVMRustCoverageCreatePGOFuncNameVar(LLVMValueRef F, const char *FuncName) {
+  report_fatal_error("coverage instrumentation not available");
+}
+
+extern "C" uint64_t
+LLVMRustCoverageHashFunctionName(const char *FuncName) {
+  report_fatal_error("coverage instrumentation not available");
+}
+
+extern "C" void
+LLVMRustCoverageWriteSectionNameToString(LLVMModuleRef M, RustStringRef Str) {
+  report_fatal_error("coverage instrumentation not available");
+}
+
+extern "C" void
+LLVMRustCoverageWriteMappingVarNameToString(RustStringRef Str) {
+  report_fatal_error("coverage instrumentation not available");
+}
+
+extern "C" uint32_t
+LLVMRustCoverageMappingVersion() {
+  report_fatal_error("coverage instrumentation not available");
+}
+
+#endif // COVERAGE_AVAILABLE
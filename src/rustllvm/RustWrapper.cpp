@@ -15,6 +15,9 @@
 #include "llvm/IR/Instructions.h"
 #include "llvm/Object/Archive.h"
 #include "llvm/Object/ObjectFile.h"
+#include "llvm/Object/ELFObjectFile.h"
+#include "llvm/Support/Endian.h"
+#include "llvm/Support/LEB128.h"
 #include "llvm/Bitcode/BitcodeWriterPass.h"
 
 #include "llvm/IR/CallSite.h"
@@ -25,6 +28,8 @@
 #include <cstdlib>
 #endif
 
+#include <map>
+
 //===----------------------------------------------------------------------===
 //
 // This file defines alternate interfaces to core functions that are more
@@ -942,6 +947,162 @@ extern "C" size_t LLVMRustGetSectionName(LLVMSectionIteratorRef SI,
   return Ret.size();
 }
 
+#if LLVM_VERSION_GE(6, 0)
+// Writes a `call <caller> <callee>` line for every relocation in the code
+// section `Sec` against a symbol that isn't defined in the object, such as
+// a call to `memcpy` that LLVM inserted.
+static void writeExternalCalls(
+    const object::ObjectFile *Obj, const object::SectionRef &RelSec,
+    object::section_iterator Sec,
+    const std::map<std::pair<uint64_t, uint64_t>, StringRef> &Functions,
+    raw_ostream &OS) {
+  uint64_t SecIndex = Sec->getIndex();
+  for (const object::RelocationRef &Rel : RelSec.relocations()) {
+    object::symbol_iterator Sym = Rel.getSymbol();
+    if (Sym == Obj->symbol_end() ||
+        !(Sym->getFlags() & object::SymbolRef::SF_Undefined))
+      continue;
+    Expected<StringRef> Callee = Sym->getName();
+    if (!Callee) {
+      consumeError(Callee.takeError());
+      continue;
+    }
+    if (Callee->empty())
+      continue;
+
+    // The caller is the last function starting at or before the relocation.
+    auto Caller = Functions.upper_bound(std::make_pair(SecIndex, Rel.getOffset()));
+    if (Caller == Functions.begin())
+      continue;
+    --Caller;
+    if (Caller->first.first != SecIndex)
+      continue;
+    OS << "call " << Caller->second << ' ' << *Callee << '\n';
+  }
+}
+#endif
+
+// Describes the functions of the ELF object in `Data`, with one line for:
+//
+// * every function defined in the object: `function <symbol>`,
+// * every entry of the `.stack_sizes` section: `size <stack size> <symbol>`.
+//   An entry is the address of a function, which a relocation ties to its
+//   symbol, followed by the ULEB128 encoded size of the function's frame,
+// * every reference from the code of a function to a symbol defined
+//   elsewhere, see `writeExternalCalls`.
+extern "C" bool LLVMRustWriteStackSizesToString(const char *Data, size_t Len,
+                                                RustStringRef Str) {
+#if LLVM_VERSION_GE(6, 0)
+  auto ObjOrErr = object::ObjectFile::createObjectFile(
+      MemoryBufferRef(StringRef(Data, Len), "stack_sizes"));
+  if (!ObjOrErr) {
+    LLVMRustSetLastError(toString(ObjOrErr.takeError()).c_str());
+    return false;
+  }
+  object::ObjectFile *Obj = ObjOrErr->get();
+  if (!isa<object::ELFObjectFileBase>(Obj)) {
+    LLVMRustSetLastError("stack sizes are only emitted into ELF objects");
+    return false;
+  }
+  unsigned AddressSize = Obj->getBytesInAddress();
+
+  RawRustStringOstream OS(Str);
+
+  // Relocations against local functions usually refer to the symbol of the
+  // section the function is in, so also index functions by their location.
+  std::map<std::pair<uint64_t, uint64_t>, StringRef> Functions;
+  for (const object::SymbolRef &Sym : Obj->symbols()) {
+    Expected<object::SymbolRef::Type> Type = Sym.getType();
+    Expected<StringRef> Name = Sym.getName();
+    Expected<object::section_iterator> Sec = Sym.getSection();
+    if (!Type || !Name || !Sec) {
+      consumeError(Type.takeError());
+      consumeError(Name.takeError());
+      consumeError(Sec.takeError());
+      continue;
+    }
+    if (*Type == object::SymbolRef::ST_Function && *Sec != Obj->section_end()) {
+      Functions[std::make_pair((*Sec)->getIndex(), Sym.getValue())] = *Name;
+      OS << "function " << *Name << '\n';
+    }
+  }
+
+  for (const object::SectionRef &RelSec : Obj->sections()) {
+    object::section_iterator Sec = RelSec.getRelocatedSection();
+    StringRef SecName;
+    if (Sec == Obj->section_end() || Sec->getName(SecName))
+      continue;
+    if (Sec->isText()) {
+      writeExternalCalls(Obj, RelSec, Sec, Functions, OS);
+      continue;
+    }
+    if (SecName != ".stack_sizes")
+      continue;
+    StringRef Contents;
+    if (std::error_code EC = Sec->getContents(Contents)) {
+      LLVMRustSetLastError(EC.message().c_str());
+      return false;
+    }
+
+    for (const object::RelocationRef &Rel : RelSec.relocations()) {
+      uint64_t Offset = Rel.getOffset();
+      if (Offset + AddressSize >= Contents.size())
+        continue;
+      const uint8_t *Entry = Contents.bytes_begin() + Offset;
+
+      object::symbol_iterator Sym = Rel.getSymbol();
+      if (Sym == Obj->symbol_end())
+        continue;
+      Expected<object::SymbolRef::Type> Type = Sym->getType();
+      Expected<object::section_iterator> SymSec = Sym->getSection();
+      if (!Type || !SymSec) {
+        consumeError(Type.takeError());
+        consumeError(SymSec.takeError());
+        continue;
+      }
+
+      StringRef Name;
+      if (*Type == object::SymbolRef::ST_Function) {
+        Expected<StringRef> SymName = Sym->getName();
+        if (!SymName) {
+          consumeError(SymName.takeError());
+          continue;
+        }
+        Name = *SymName;
+      } else if (*SymSec != Obj->section_end()) {
+        // Targets using REL relocations keep the addend in the entry itself.
+        int64_t Addend;
+        Expected<int64_t> RelAddend = object::ELFRelocationRef(Rel).getAddend();
+        if (RelAddend) {
+          Addend = *RelAddend;
+        } else {
+          consumeError(RelAddend.takeError());
+          bool LE = Obj->isLittleEndian();
+          Addend = AddressSize == 8
+              ? (LE ? support::endian::read64le(Entry) : support::endian::read64be(Entry))
+              : (LE ? support::endian::read32le(Entry) : support::endian::read32be(Entry));
+        }
+        auto Function = Functions.find(
+            std::make_pair((*SymSec)->getIndex(), Sym->getValue() + Addend));
+        if (Function == Functions.end())
+          continue;
+        Name = Function->second;
+      } else {
+        continue;
+      }
+
+      unsigned SizeLen;
+      uint64_t StackSize = decodeULEB128(Entry + AddressSize, &SizeLen);
+      OS << "size " << StackSize << ' ' << Name << '\n';
+    }
+  }
+  return true;
+#else
+  LLVMRustSetLastError("stack sizes require LLVM 6.0 or later");
+  return false;
+#endif
+}
+
 // LLVMArrayType function does not support 64-bit ElementCount
 extern "C" LLVMTypeRef LLVMRustArrayType(LLVMTypeRef ElementTy,
                                          uint64_t ElementCount) {
@@ -0,0 +1,42 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(capture_disjoint_fields)]
+
+struct Point {
+    x: Vec<u8>,
+    y: Vec<u8>,
+}
+
+struct Guarded {
+    a: String,
+    b: String,
+}
+
+impl Drop for Guarded {
+    fn drop(&mut self) {}
+}
+
+fn same_field(mut p: Point) {
+    let x = &mut p.x;
+    let c = || p.x.len(); //~ ERROR cannot borrow `p.x` as immutable
+    x.push(1);
+    c();
+}
+
+fn field_of_drop_type(g: Guarded) {
+    // A field cannot be moved out of a type with a destructor, so the
+    // closure takes all of `g`.
+    let c = move || g.a.len();
+    c();
+    g.b.len(); //~ ERROR use of moved value
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(const_fn, const_control_flow, const_eval_limit)]
+#![const_eval_limit = "1000"]
+#![deny(const_err)]
+
+const fn endless() -> u32 {
+    loop {}
+}
+
+pub const ENDLESS: u32 = endless(); //~ ERROR this constant cannot be used
+
+fn main() {}
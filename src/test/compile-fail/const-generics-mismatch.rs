@@ -0,0 +1,21 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(const_generics)]
+
+struct Buf<const N: usize>([u8; N]);
+
+struct Pair<const A: u8, const B: u8>;
+
+fn main() {
+    let _: Buf<3> = Buf::<4>([0; 4]); //~ ERROR mismatched types
+    let _: Pair<1> = Pair; //~ ERROR wrong number of const arguments: expected 2, found 1
+    let _: Buf<true> = Buf([]); //~ ERROR this const argument is not a valid value of type `usize`
+}
@@ -0,0 +1,19 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags: --test
+
+#![feature(custom_test_frameworks)]
+
+#[test_case]
+const ONE: u32 = 1; //~ ERROR `#[test_case]` items require a `#![test_runner]` crate attribute
+
+#[test_case]
+struct Two; //~ ERROR only functions, statics and constants may be test cases
@@ -0,0 +1,31 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(type_alias_impl_trait)]
+
+use std::fmt::Debug;
+
+mod inner {
+    pub type Foo = impl ::std::fmt::Debug; //~ ERROR could not find defining uses
+}
+
+// Outside of the module containing `Foo`, so this is not a defining use.
+fn foo() -> inner::Foo {
+    42 //~ ERROR mismatched types
+}
+
+type Bar<T> = impl Debug; //~ ERROR could not find defining uses
+
+fn bar() -> Bar<u32> {
+    //~^ ERROR non-defining use of `impl Trait` type alias
+    42
+}
+
+fn main() {}
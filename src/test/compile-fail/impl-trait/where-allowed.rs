@@ -11,6 +11,8 @@
 //! A simple test for testing many permutations of allowedness of
 //! impl Trait
 #![feature(dyn_trait)]
+#![feature(type_alias_impl_trait)]
+#![feature(impl_trait_in_bindings)]
 use std::fmt::Debug;
 
 // Allowed
@@ -131,7 +133,7 @@ trait DummyTrait {
 }
 impl DummyTrait for () {
     type Out = impl Debug;
-    //~^ ERROR `impl Trait` not allowed outside of function and inherent method return types
+    //~^ ERROR could not find defining uses
 
     fn in_trait_impl_parameter(_: impl Debug) { }
     // Allowed
@@ -166,7 +168,7 @@ extern "C" fn in_extern_fn_return() -> impl Debug {
 }
 
 type InTypeAlias<R> = impl Debug;
-//~^ ERROR `impl Trait` not allowed outside of function and inherent method return types
+//~^ ERROR could not find defining uses
 
 type InReturnInTypeAlias<R> = fn() -> impl Debug;
 //~^ ERROR `impl Trait` not allowed outside of function and inherent method return types
@@ -228,8 +230,9 @@ fn in_Fn_return_in_fn_where_clause<T>()
 }
 
 fn main() {
+    // Allowed
     let _in_local_variable: impl Fn() = || {};
-    //~^ ERROR `impl Trait` not allowed outside of function and inherent method return types
+
     let _in_return_in_local_variable = || -> impl Fn() { || {} };
     //~^ ERROR `impl Trait` not allowed outside of function and inherent method return types
 }
@@ -0,0 +1,20 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(let_chains)]
+
+fn main() {
+    let a = Some(5);
+    if let Some(x) = a && x > 3 {
+    } else {
+        let _ = x; //~ ERROR cannot find value `x` in this scope
+    }
+    if let Some(_) = a && y > 3 && let Some(y) = a {} //~ ERROR cannot find value `y` in this scope
+}
@@ -0,0 +1,19 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(let_else)]
+
+fn main() {
+    // The bindings of the pattern are not in scope in the `else` block.
+    let Some(x) = Some(1) else {
+        panic!("{}", x); //~ ERROR cannot find value `x` in this scope
+    };
+    let _ = x;
+}
@@ -0,0 +1,38 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(or_patterns, pattern_parentheses)]
+#![deny(unreachable_patterns)]
+
+enum E {
+    A(u8),
+    B(u8),
+    C,
+}
+
+fn main() {
+    match Some(0u8) { //~ ERROR non-exhaustive patterns: `Some(_)` not covered
+        Some(0 | 1) => {}
+        None => {}
+    }
+
+    match Some(E::C) { //~ ERROR non-exhaustive patterns: `Some(C)` not covered
+        Some(E::A(_) | E::B(_)) => {}
+        None => {}
+    }
+
+    match Some(E::C) {
+        Some(E::A(_) | E::B(_) | E::C) => {}
+        None => {}
+        Some(_) => {} //~ ERROR unreachable pattern
+    }
+
+    let (E::A(z) | E::B(z)) = E::C; //~ ERROR refutable pattern in local binding
+}
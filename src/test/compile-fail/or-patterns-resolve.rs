@@ -0,0 +1,31 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(or_patterns)]
+
+enum E {
+    A(u8),
+    B(u8),
+    C,
+}
+
+fn main() {
+    match Some(E::C) {
+        Some(E::A(x) | E::B(_)) => {} //~ ERROR variable `x` is not bound in all patterns
+        Some(E::A(y) | E::B(y)) => {}
+        _ => {}
+    }
+
+    match (E::C, E::C) {
+        (E::A(x) | E::B(x), E::A(x)) => {}
+        //~^ ERROR identifier `x` is bound more than once in the same pattern
+        _ => {}
+    }
+}
@@ -0,0 +1,74 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags: -Z symbol-mangling-version=v0
+
+#![feature(rustc_attrs, const_generics)]
+#![allow(dead_code)]
+
+mod foo {
+    pub struct Foo { x: u32 }
+
+    #[rustc_symbol_name] //~ ERROR 8v0_basic3foo4main)
+    fn main() { }
+
+    impl Foo {
+        #[rustc_symbol_name] //~ ERROR 3Foo3bar)
+        fn bar() { }
+    }
+
+    impl Clone for Foo {
+        #[rustc_symbol_name] //~ ERROR 4core5clone5Clone5clone)
+        fn clone(&self) -> Foo { Foo { x: self.x } }
+    }
+}
+
+trait Name {
+    fn name();
+}
+
+impl Name for &'static str {
+    #[rustc_symbol_name] //~ ERROR 8v0_basicReNtB2_4Name4name)
+    fn name() { }
+}
+
+impl Name for [u8; 4] {
+    #[rustc_symbol_name] //~ ERROR 8v0_basicAhj4_NtB4_4Name4name)
+    fn name() { }
+}
+
+impl Name for <u8 as Assoc>::Ty {
+    #[rustc_symbol_name] //~ ERROR 8v0_basictNtB5_4Name4name)
+    fn name() { }
+}
+
+trait Assoc {
+    type Ty;
+}
+
+impl Assoc for u8 {
+    type Ty = u16;
+}
+
+#[rustc_symbol_name] //~ ERROR 8v0_basic7genericpE
+fn generic<T>(_: T) { }
+
+#[rustc_symbol_name] //~ ERROR 8v0_basic5sizedKpE
+fn sized<const N: usize>() { }
+
+fn with_closure() {
+    let _ = || {
+        #[rustc_symbol_name] //~ ERROR 12with_closure05inner)
+        fn inner() { }
+    };
+}
+
+fn main() {
+}
@@ -0,0 +1,30 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Operands that aren't `Copy` are captured by reference and printed.
+
+// error-pattern:assertion failed: name == expected && items == all
+// error-pattern:with captured values:
+// error-pattern:  name = "ferris"
+// error-pattern:  expected = "ferris"
+// error-pattern:  items = [1, 2]
+// error-pattern:  all = [1, 2, 3]
+
+#![feature(generic_assert)]
+
+fn main() {
+    let name = String::from("ferris");
+    let expected = "ferris".to_string();
+    let items = vec![1, 2];
+    let all = vec![1, 2, 3];
+    assert!(name == expected && items == all);
+    // None of the operands has been moved.
+    drop((name, expected, items, all));
+}
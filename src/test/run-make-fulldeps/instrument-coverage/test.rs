@@ -0,0 +1,35 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::num::ParseIntError;
+
+macro_rules! double {
+    ($e:expr) => { $e * 2 }
+}
+
+fn parse(s: &str) -> Result<u32, ParseIntError> {
+    let n = s.parse::<u32>()?;
+    Ok(double!(n))
+}
+
+fn main() {
+    let add_one = |x: u32| x + 1;
+    let mut total = 0;
+    for s in &["1", "x", "3"] {
+        match parse(s) {
+            Ok(n) => total += add_one(n),
+            Err(_) => {}
+        }
+    }
+    if total > 1000 {
+        unreachable!();
+    }
+    assert_eq!(total, 6);
+}
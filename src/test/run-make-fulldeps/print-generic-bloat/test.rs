@@ -0,0 +1,25 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::ops::Add;
+
+fn sum<T: Add<Output = T> + Copy + Default>(values: &[T]) -> T {
+    let mut total = T::default();
+    for &value in values {
+        total = total + value;
+    }
+    total
+}
+
+fn main() {
+    println!("{}", sum(&[1u8, 2, 3]));
+    println!("{}", sum(&[1u32, 2, 3]));
+    println!("{}", sum(&[1u64, 2, 3]));
+}
@@ -0,0 +1,43 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Checks the stack size report written for `test.rs`.
+
+#![feature(rustc_private)]
+
+extern crate serialize;
+
+use serialize::json::{self, Json};
+use std::env;
+use std::fs::File;
+
+fn function<'a>(report: &'a Json, name: &str) -> &'a Json {
+    report["functions"].as_array().unwrap().iter().find(|function| {
+        let function_name = function["name"].as_string().unwrap();
+        function_name == name || function_name.ends_with(&format!("::{}", name))
+    }).unwrap_or_else(|| panic!("`{}` is missing from the report", name))
+}
+
+fn main() {
+    let path = env::args().nth(1).unwrap();
+    let report = json::from_reader(&mut File::open(&path).unwrap()).unwrap();
+
+    // `leaf` keeps a 64 byte array on its stack and calls `fill`.
+    let leaf = function(&report, "leaf");
+    assert!(leaf["max_stack"].as_u64().unwrap() >= 64, "{}", leaf);
+
+    let countdown = function(&report, "countdown");
+    assert_eq!(countdown["recursive"], Json::Boolean(true));
+    assert_eq!(countdown["max_stack"], Json::Null);
+
+    let call_dyn = function(&report, "call_dyn");
+    assert!(!call_dyn["indirect_calls"].as_array().unwrap().is_empty(), "{}", call_dyn);
+    assert_eq!(call_dyn["max_stack"], Json::Null);
+}
@@ -0,0 +1,39 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#[inline(never)]
+fn fill(x: &mut [u8; 64], seed: u8) {
+    for (i, b) in x.iter_mut().enumerate() {
+        *b = seed.wrapping_add(i as u8);
+    }
+}
+
+#[inline(never)]
+fn leaf(seed: u8) -> u8 {
+    let mut buf = [0u8; 64];
+    fill(&mut buf, seed);
+    buf.iter().fold(0, |a, &b| a ^ b)
+}
+
+#[inline(never)]
+fn countdown(n: u32) -> u32 {
+    if n == 0 { 0 } else { countdown(n - 1) + 1 }
+}
+
+#[inline(never)]
+fn call_dyn(f: &dyn Fn(u32) -> u32) -> u32 {
+    f(1)
+}
+
+fn main() {
+    let seed = std::env::args().count() as u8;
+    let n = call_dyn(&|x| x + seed as u32);
+    println!("{} {} {}", leaf(seed), countdown(3), n);
+}
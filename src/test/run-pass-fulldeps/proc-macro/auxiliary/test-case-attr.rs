@@ -0,0 +1,28 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// no-prefer-dynamic
+#![feature(proc_macro)]
+#![crate_type = "proc-macro"]
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+
+/// Registers the function it is applied to as a test case for the runner
+/// of `test-case-attr.rs`.
+#[proc_macro_attribute]
+pub fn my_test(_: TokenStream, input: TokenStream) -> TokenStream {
+    let input = input.to_string();
+    let name = input.split("fn ").nth(1).unwrap().split('(').next().unwrap().trim();
+    format!("{} #[test_case] const {}_CASE: ::runner::Case = \
+             ::runner::Case {{ name: \"{}\", run: {} }};",
+            input, name.to_uppercase(), name, name).parse().unwrap()
+}
@@ -0,0 +1,28 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// aux-build:derive-default-helper.rs
+// ignore-stage1
+
+// `#[default]` of `derive(Default)` doesn't claim the `default` helper
+// attributes of other derives, with or without `derive_default_enum`.
+
+#[macro_use]
+extern crate derive_default_helper;
+
+#[derive(Default, SmartDefault)]
+struct Config {
+    #[default = "3"]
+    retries: u32,
+}
+
+fn main() {
+    assert_eq!(Config::default().retries, 0);
+}
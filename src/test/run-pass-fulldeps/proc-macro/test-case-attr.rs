@@ -0,0 +1,43 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// aux-build:test-case-attr.rs
+// ignore-stage1
+// compile-flags: --test
+
+// An attribute macro can register test cases by emitting `#[test_case]`.
+
+#![feature(proc_macro, custom_test_frameworks)]
+#![test_runner(runner::run)]
+
+extern crate test_case_attr;
+
+use test_case_attr::my_test;
+
+mod runner {
+    pub struct Case {
+        pub name: &'static str,
+        pub run: fn(),
+    }
+
+    pub fn run(cases: &[&Case]) {
+        let names: Vec<_> = cases.iter().map(|case| case.name).collect();
+        assert_eq!(names, ["first", "second"]);
+        for case in cases {
+            (case.run)();
+        }
+    }
+}
+
+#[my_test]
+fn first() {}
+
+#[my_test]
+fn second() {}
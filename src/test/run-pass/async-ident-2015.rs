@@ -0,0 +1,28 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags: --edition=2015 -Zunstable-options
+
+// `async` is only a keyword in Rust 2018.
+
+#![allow(non_camel_case_types)]
+
+struct async {
+    x: u8,
+}
+
+fn async(async: u8) -> async {
+    async { x: async }
+}
+
+fn main() {
+    let async = async(1);
+    assert_eq!(async.x, 1);
+}
@@ -0,0 +1,78 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(capture_disjoint_fields)]
+
+use std::cell::RefCell;
+
+struct Point {
+    x: Vec<u8>,
+    y: Vec<u8>,
+}
+
+impl Point {
+    fn push_y_len(&mut self) {
+        let x = &mut self.x;
+        let len = || self.y.len();
+        x.push(len() as u8);
+    }
+}
+
+struct Noisy<'a>(&'a RefCell<Vec<u32>>, u32);
+
+impl<'a> Drop for Noisy<'a> {
+    fn drop(&mut self) {
+        self.0.borrow_mut().push(self.1);
+    }
+}
+
+fn main() {
+    let mut p = Point { x: vec![], y: vec![1, 2, 3] };
+    p.push_y_len();
+    assert_eq!(p.x, [3]);
+
+    // Mutable borrows of different fields can coexist.
+    {
+        let mut push_x = || p.x.push(4);
+        let mut push_y = || p.y.push(4);
+        push_x();
+        push_y();
+    }
+    assert_eq!(p.x, [3, 4]);
+    assert_eq!(p.y, [1, 2, 3, 4]);
+
+    // Paths through tuple fields, boxes and nested closures.
+    let mut t = (String::from("a"), Box::new(Point { x: vec![5], y: vec![] }));
+    {
+        let s = &mut t.0;
+        let outer = || {
+            let inner = || t.1.x.len();
+            inner()
+        };
+        s.push_str(&outer().to_string());
+    }
+    assert_eq!(t.0, "a1");
+
+    // A `move` closure only takes the fields it uses...
+    let x_len = move || p.x.len();
+    assert_eq!(x_len(), 2);
+    assert_eq!(p.y.len(), 4);
+
+    // ...and drops them when it is dropped itself.
+    let order = RefCell::new(vec![]);
+    {
+        let pair = (Noisy(&order, 1), Noisy(&order, 2));
+        let second = move || (pair.1).1;
+        assert_eq!(second(), 2);
+        drop(second);
+        order.borrow_mut().push(0);
+    }
+    assert_eq!(*order.borrow(), [2, 0, 1]);
+}
@@ -0,0 +1,98 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(const_fn, const_control_flow)]
+
+const fn crc32_entry(mut crc: u32) -> u32 {
+    let mut bit = 0;
+    while bit < 8 {
+        crc = if crc & 1 == 1 { (crc >> 1) ^ 0xEDB8_8320 } else { crc >> 1 };
+        bit += 1;
+    }
+    crc
+}
+
+const fn crc32_table() -> [u32; 256] {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        table[i] = crc32_entry(i as u32);
+        i += 1;
+    }
+    table
+}
+
+static CRC32_TABLE: [u32; 256] = crc32_table();
+
+const fn log2(mut n: u32) -> u32 {
+    let mut log = 0;
+    loop {
+        n >>= 1;
+        if n == 0 {
+            break log;
+        }
+        log += 1;
+    }
+}
+
+const fn unwrap_or(x: Option<u8>, default: u8) -> u8 {
+    match x {
+        Some(v) => v,
+        None => default,
+    }
+}
+
+const fn sign(x: i32) -> i32 {
+    if x < 0 {
+        -1
+    } else if x == 0 {
+        0
+    } else {
+        1
+    }
+}
+
+const fn in_range(x: u8) -> bool {
+    x >= 10 && x < 20
+}
+
+const SIGNS: [i32; 3] = [sign(-5), sign(0), sign(7)];
+
+const DIGITS: u8 = {
+    let mut n = 12345u32;
+    let mut digits = 0;
+    while n > 0 {
+        n /= 10;
+        digits += 1;
+    }
+    digits
+};
+
+fn main() {
+    assert_eq!(CRC32_TABLE[0], 0);
+    assert_eq!(CRC32_TABLE[1], 0x7707_3096);
+    assert_eq!(CRC32_TABLE[255], 0x2D02_EF8D);
+
+    let array: [u8; log2(1024) as usize] = [0; 10];
+    assert_eq!(array.len(), 10);
+
+    const SOME: u8 = unwrap_or(Some(3), 7);
+    const NONE: u8 = unwrap_or(None, 7);
+    assert_eq!(SOME, 3);
+    assert_eq!(NONE, 7);
+
+    assert_eq!(SIGNS, [-1, 0, 1]);
+    assert_eq!(DIGITS, 5);
+
+    const IN: bool = in_range(15);
+    const OUT: bool = in_range(25);
+    assert!(IN);
+    assert!(!OUT);
+}
@@ -0,0 +1,49 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(const_generics)]
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Buf<const N: usize> {
+    data: [u8; N],
+}
+
+impl<const N: usize> Buf<N> {
+    fn new() -> Buf<N> {
+        Buf { data: [0; N] }
+    }
+
+    fn len(&self) -> usize {
+        N
+    }
+}
+
+fn first<const N: usize>(xs: [u32; N]) -> u32 {
+    xs[0]
+}
+
+fn flag<const B: bool>() -> bool {
+    B
+}
+
+fn main() {
+    let a = Buf::<4>::new();
+    assert_eq!(a.len(), 4);
+    assert_eq!(a.data, [0; 4]);
+
+    let b: Buf<16> = Buf::new();
+    assert_eq!(b.len(), 16);
+
+    assert_eq!(first([7, 8, 9]), 7);
+    assert_eq!(first::<1>([3]), 3);
+
+    assert!(flag::<true>());
+    assert!(!flag::<false>());
+}
@@ -0,0 +1,54 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags: --test
+
+#![feature(custom_test_frameworks)]
+#![test_runner(runner::run)]
+
+mod runner {
+    pub struct Case {
+        pub name: &'static str,
+        pub run: fn() -> bool,
+    }
+
+    pub fn run(tests: &[&Case]) {
+        let names: Vec<_> = tests.iter().map(|t| t.name).collect();
+        assert_eq!(names, ["one", "two", "three"]);
+        for test in tests {
+            assert!((test.run)(), "test case `{}` failed", test.name);
+        }
+    }
+}
+
+use runner::Case;
+
+#[test_case]
+const ONE: Case = Case { name: "one", run: yes };
+
+#[test_case]
+static TWO: Case = Case { name: "two", run: yes };
+
+mod private {
+    use runner::Case;
+
+    // Test cases don't need to be reachable from the crate root.
+    #[test_case]
+    const THREE: Case = Case { name: "three", run: super::yes };
+}
+
+fn yes() -> bool {
+    true
+}
+
+// Without --test, `main` is kept and the test cases are configured away.
+pub fn main() {
+    panic!("the custom test runner should replace `main`");
+}
@@ -0,0 +1,61 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags: -C debug-assertions
+// ignore-cloudabi spawning processes is not supported
+// ignore-emscripten spawning processes is not supported
+
+#![feature(dbg_macro)]
+
+use std::{env, process};
+
+#[derive(Debug, PartialEq)]
+struct Point {
+    x: u8,
+    y: u8,
+}
+
+fn child() {
+    let sum = dbg!(1 + 2) * 2;
+    assert_eq!(sum, 6);
+
+    let point = Point { x: 1, y: 2 };
+    let point = dbg!(point);
+    assert_eq!(dbg!(&point).x, 1);
+
+    let pair = dbg!(1u8, "two",);
+    assert_eq!(pair, (1, "two"));
+}
+
+fn parent() {
+    let this = env::args().next().unwrap();
+    let output = process::Command::new(this).arg("-").output().unwrap();
+    assert!(output.status.success());
+
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    let expected = format!("\
+[{0}:26] 1 + 2 = 3
+[{0}:30] point = Point {{
+    x: 1,
+    y: 2
+}}
+[{0}:31] &point = Point {{
+    x: 1,
+    y: 2
+}}
+[{0}:33] 1u8 = 1
+[{0}:33] \"two\" = \"two\"
+", file!());
+    assert_eq!(stderr, expected);
+}
+
+fn main() {
+    if env::args().count() == 2 { child() } else { parent() }
+}
@@ -0,0 +1,39 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(derive_default_enum)]
+
+#[derive(Debug, Default, PartialEq)]
+enum Level {
+    Low,
+    #[default]
+    Medium,
+    High,
+}
+
+#[derive(Debug, Default, PartialEq)]
+enum Choice<T> {
+    Some(T),
+    #[default]
+    Nothing,
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct Config {
+    level: Level,
+    choice: Choice<String>,
+}
+
+fn main() {
+    assert_eq!(Level::default(), Level::Medium);
+    assert_eq!(Choice::<u8>::default(), Choice::Nothing);
+    assert_eq!(Config::default(), Config { level: Level::Medium, choice: Choice::Nothing });
+    let _ = (Level::Low, Level::High, Choice::Some(()));
+}
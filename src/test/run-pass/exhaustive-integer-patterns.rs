@@ -0,0 +1,67 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(exhaustive_integer_patterns)]
+
+fn classify(c: char) -> u8 {
+    match c {
+        '\u{0}'..='/' => 0,
+        '0'..='9' => 1,
+        ':'..='\u{D7FF}' | '\u{E000}'..='\u{10FFFF}' => 2,
+    }
+}
+
+fn sign(x: i8) -> i8 {
+    match x {
+        -128..=-1 => -1,
+        0 => 0,
+        1..=127 => 1,
+    }
+}
+
+fn half(x: u8, high: bool) -> u8 {
+    match (x, high) {
+        (0..=127, false) => 0,
+        (0..=127, true) => 1,
+        (128..=255, _) => 2,
+    }
+}
+
+fn wide(x: u128) -> bool {
+    match x {
+        0..=340282366920938463463374607431768211454 => false,
+        340282366920938463463374607431768211455 => true,
+    }
+}
+
+fn main() {
+    assert_eq!(classify(' '), 0);
+    assert_eq!(classify('5'), 1);
+    assert_eq!(classify('é'), 2);
+
+    assert_eq!(sign(-128), -1);
+    assert_eq!(sign(0), 0);
+    assert_eq!(sign(127), 1);
+
+    assert_eq!(half(10, false), 0);
+    assert_eq!(half(10, true), 1);
+    assert_eq!(half(200, false), 2);
+
+    assert!(!wide(0));
+    assert!(wide(!0));
+
+    let n: u16 = 512;
+    let size = match n {
+        0 => "none",
+        1..=255 => "small",
+        256..=65535 => "large",
+    };
+    assert_eq!(size, "large");
+}
@@ -0,0 +1,56 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(format_args_capture)]
+
+use std::fmt::Write;
+
+fn main() {
+    named_argument_takes_precedence_to_captured();
+    formatting_parameters_can_be_captured();
+    newline_macros_capture();
+    captures_are_hygienic();
+}
+
+fn named_argument_takes_precedence_to_captured() {
+    let foo = "captured";
+    assert_eq!(format!("{foo}"), "captured");
+    assert_eq!(format!("{foo}", foo = "named"), "named");
+    assert_eq!(format!("{foo} {}", "positional"), "captured positional");
+    assert_eq!(format!("{foo} {foo:?}"), "captured \"captured\"");
+}
+
+fn formatting_parameters_can_be_captured() {
+    let x = 1.5;
+    let width = 6;
+    let precision = 2;
+    assert_eq!(format!("{x:width$.precision$}"), "  1.50");
+    assert_eq!(format!("{:>width$}", "a"), "     a");
+}
+
+fn newline_macros_capture() {
+    let name = "world";
+    let mut s = String::new();
+    writeln!(s, "hello {name}").unwrap();
+    assert_eq!(s, "hello world\n");
+    println!("hello {name}");
+}
+
+fn captures_are_hygienic() {
+    let x = "definition site";
+    macro_rules! show_x {
+        () => (format!("{x}"))
+    }
+    {
+        let x = "call site";
+        assert_eq!(show_x!(), "definition site");
+        assert_eq!(format!("{x}"), "call site");
+    }
+}
@@ -0,0 +1,103 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// ignore-wasm32-bare compiled with panic=abort by default
+
+#![feature(generic_assert)]
+
+use std::any::Any;
+use std::panic;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[derive(PartialEq)]
+struct NotDebug(i32);
+
+fn message<F: FnOnce() + panic::UnwindSafe>(f: F) -> String {
+    let payload: Box<Any + Send> = panic::catch_unwind(f).unwrap_err();
+    match payload.downcast::<String>() {
+        Ok(s) => *s,
+        Err(payload) => payload.downcast::<&str>().unwrap().to_string(),
+    }
+}
+
+fn name() -> String {
+    String::from("a")
+}
+
+fn generic<T: PartialEq>(a: T, b: T) {
+    assert!(a == b);
+}
+
+fn main() {
+    panic::set_hook(Box::new(|_| {}));
+
+    let (a, b) = (3, 2);
+    let v = vec![1, 2];
+    assert_eq!(message(|| assert!(a < b && v.len() == 2)),
+               "assertion failed: a < b && v.len() == 2\n\
+                with captured values:\n  a = 3\n  b = 2\n  v.len() = N/A");
+    assert_eq!(message(|| assert!(a > b && v.len() == 3)),
+               "assertion failed: a > b && v.len() == 3\n\
+                with captured values:\n  a = 3\n  b = 2\n  v.len() = 2");
+    assert_eq!(message(|| assert!(!(a == 3))),
+               "assertion failed: !(a == 3)\nwith captured values:\n  a = 3");
+
+    let p = Point { x: 1, y: 2 };
+    assert_eq!(message(|| assert!(p == Point { x: 1, y: 3 })),
+               "assertion failed: p == Point{x: 1, y: 3,}\n\
+                with captured values:\n  p = Point { x: 1, y: 2 }\n  \
+                Point{x: 1, y: 3,} = Point { x: 1, y: 3 }");
+
+    // Operands are captured by reference, those that aren't `Debug` are not
+    // printed.
+    let s = String::from("a");
+    assert_eq!(message(|| assert!(s == "b")),
+               "assertion failed: s == \"b\"\nwith captured values:\n  s = \"a\"");
+    assert_eq!(message(|| assert!(v[..] == [1] || v.len() > 2)),
+               "assertion failed: v[..] == [1] || v.len() > 2\n\
+                with captured values:\n  v[..] = [1, 2]\n  [1] = [1]\n  v.len() = 2");
+    assert_eq!(message(|| assert!(NotDebug(1) == NotDebug(2))),
+               "assertion failed: NotDebug(1) == NotDebug(2)\n\
+                with captured values:\n  NotDebug(1) = N/A\n  NotDebug(2) = N/A");
+    generic(NotDebug(1), NotDebug(1));
+
+    // Values that aren't places live until the end of the assertion, but
+    // places reached through them aren't captured.
+    let cell = std::cell::RefCell::new(1);
+    assert_eq!(message(|| assert!(*cell.borrow_mut() == 2 && *cell.borrow_mut() == 2)),
+               "assertion failed: *cell.borrow_mut() == 2 && *cell.borrow_mut() == 2");
+
+    // Values that may borrow from a temporary of their own aren't captured,
+    // so the temporary lives as long as the comparison.
+    assert!(name().as_str() == "a");
+    assert!(name().as_str() == s.as_str());
+    assert_eq!(message(|| assert!(name().as_str() == "b")),
+               "assertion failed: name().as_str() == \"b\"");
+    assert_eq!(message(|| assert!(&name()[..] == s && a < b)),
+               "assertion failed: &name()[..] == s && a < b\n\
+                with captured values:\n  s = \"a\"\n  a = 3\n  b = 2");
+
+    // Conditions without comparisons and custom messages are unchanged.
+    assert_eq!(message(|| assert!(v.is_empty())), "assertion failed: v.is_empty()");
+    assert_eq!(message(|| assert!(a < b, "custom {}", a)), "custom 3");
+
+    // Operands are evaluated exactly once.
+    let mut calls = 0;
+    {
+        let mut next = || { calls += 1; calls };
+        assert!(next() == 1 && next() == 2);
+    }
+    assert_eq!(calls, 2);
+}
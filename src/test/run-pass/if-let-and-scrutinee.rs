@@ -0,0 +1,29 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Without `let_chains`, a `&&` after the scrutinee of an `if let` or
+// `while let` is part of the scrutinee.
+
+fn main() {
+    let (a, b) = (true, false);
+    if let true = a && b {
+        panic!();
+    }
+    if let false = a && b || b {
+    } else {
+        panic!();
+    }
+
+    let mut n = 0;
+    while let true = n < 3 && a {
+        n += 1;
+    }
+    assert_eq!(n, 3);
+}
@@ -0,0 +1,40 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(impl_trait_in_bindings)]
+
+use std::fmt::Debug;
+
+fn evens(n: u32) -> u32 {
+    let iter: impl Iterator<Item = u32> = (0..n).filter(|x| x % 2 == 0);
+    iter.sum()
+}
+
+fn describe<T: Debug>(x: T) -> String {
+    let wrapped: impl Debug = vec![x];
+    format!("{:?}", wrapped)
+}
+
+fn main() {
+    let x: impl Debug = 42;
+    assert_eq!(format!("{:?}", x), "42");
+
+    let add_one: impl Fn(u32) -> u32 = |x| x + 1;
+    assert_eq!(add_one(1), 2);
+
+    assert_eq!(evens(10), 20);
+    assert_eq!(describe("a"), "[\"a\"]");
+
+    let in_closure = || {
+        let y: impl Clone = String::from("b");
+        y.clone()
+    };
+    assert_eq!(in_closure(), "b");
+}
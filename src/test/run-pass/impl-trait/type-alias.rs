@@ -0,0 +1,60 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(type_alias_impl_trait)]
+
+use std::fmt::Debug;
+
+type Counter = impl Iterator<Item = u32>;
+
+fn counter(n: u32) -> Counter {
+    0..n
+}
+
+// Returning the alias itself does not constrain it.
+fn counter_again() -> Counter {
+    counter(4)
+}
+
+struct Holder {
+    counter: Counter,
+}
+
+type Wrapped<T> = impl Debug;
+
+fn wrap<U: Debug>(x: U) -> Wrapped<U> {
+    vec![x]
+}
+
+struct Evens(u32);
+
+impl Iterator for Evens {
+    type Item = impl Debug;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0 += 2;
+        Some(self.0)
+    }
+}
+
+fn main() {
+    assert_eq!(counter(3).sum::<u32>(), 3);
+    assert_eq!(counter_again().count(), 4);
+
+    let holder = Holder { counter: counter(2) };
+    assert_eq!(holder.counter.collect::<Vec<_>>(), vec![0, 1]);
+
+    assert_eq!(format!("{:?}", wrap(1)), "[1]");
+    assert_eq!(format!("{:?}", wrap("a")), "[\"a\"]");
+
+    let mut evens = Evens(0);
+    evens.next();
+    assert_eq!(format!("{:?}", evens.next().unwrap()), "4");
+}
@@ -0,0 +1,112 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(let_chains)]
+
+use std::cell::RefCell;
+
+fn parse(s: &str) -> Result<u32, ()> {
+    s.parse().map_err(|_| ())
+}
+
+fn classify(a: Option<&str>) -> &'static str {
+    if let Some(s) = a && s.len() > 1 && let Ok(n) = parse(s) {
+        if n > 50 { "big" } else { "small" }
+    } else if let Some(s) = a && s.is_empty() {
+        "empty"
+    } else {
+        "other"
+    }
+}
+
+struct Noisy<'a>(&'a RefCell<Vec<&'static str>>, &'static str);
+
+impl<'a> Drop for Noisy<'a> {
+    fn drop(&mut self) {
+        self.0.borrow_mut().push(self.1);
+    }
+}
+
+fn main() {
+    assert_eq!(classify(Some("42")), "small");
+    assert_eq!(classify(Some("420")), "big");
+    assert_eq!(classify(Some("4")), "other");
+    assert_eq!(classify(Some("abc")), "other");
+    assert_eq!(classify(Some("")), "empty");
+    assert_eq!(classify(None), "other");
+
+    // Without an `else`.
+    let mut hits = 0;
+    let a = Some(5);
+    if let Some(x) = a && x > 3 && let Some(y) = Some(x * 2) {
+        hits += y;
+    }
+    if let Some(x) = a && x > 10 {
+        hits += 100;
+    }
+    assert_eq!(hits, 10);
+
+    // A scrutinee binds tighter than `&&`.
+    let b = true;
+    if let true = b && false {
+        panic!();
+    }
+
+    // A chain may start with a plain condition.
+    let c = true;
+    if c && let Some(x) = a && x == 5 {
+        hits += 1;
+    }
+    if !c && let Some(_) = a {
+        panic!();
+    }
+    assert_eq!(hits, 11);
+
+    // Conditions without any `let` are plain expressions.
+    if b && false || c {
+        hits += 1;
+    }
+    assert_eq!(hits, 12);
+
+    // `while` chains.
+    let mut stack = vec![1, 2, 3, 40, 5];
+    let mut sum = 0;
+    while let Some(x) = stack.pop() && x < 10 {
+        sum += x;
+    }
+    assert_eq!(sum, 5);
+    assert_eq!(stack, vec![1, 2, 3]);
+
+    let mut stack = vec![Some(1), None, Some(2), Some(3)];
+    let mut seen = vec![];
+    'outer: while let Some(item) = stack.pop() && let Some(x) = item {
+        if x == 3 {
+            continue 'outer;
+        }
+        seen.push(x);
+    }
+    assert_eq!(seen, vec![2]);
+
+    let mut n = 0;
+    let mut items = vec![1, 2, 3];
+    while n < 2 && let Some(_) = items.pop() {
+        n += 1;
+    }
+    assert_eq!(items, vec![1]);
+
+    // Temporaries of the conditions live until the end of the body.
+    let log = RefCell::new(vec![]);
+    if let Some(_) = Some(Noisy(&log, "scrutinee")).as_ref() && log.borrow().is_empty() {
+        log.borrow_mut().push("body");
+    } else {
+        log.borrow_mut().push("else");
+    }
+    assert_eq!(*log.borrow(), vec!["body", "scrutinee"]);
+}
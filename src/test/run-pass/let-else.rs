@@ -0,0 +1,98 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(let_else)]
+
+use std::cell::RefCell;
+use std::sync::Mutex;
+
+#[derive(Debug, PartialEq)]
+enum Token {
+    Num(u32),
+    Word(String),
+}
+
+fn parse_num(s: &str) -> Result<u32, String> {
+    let Ok(n) = s.parse::<u32>() else {
+        return Err(format!("not a number: {}", s));
+    };
+    Ok(n * 2)
+}
+
+fn first_word(tokens: &[Token]) -> &str {
+    let Some(&Token::Word(ref w)) = tokens.iter().find(|t| match **t {
+        Token::Word(_) => true,
+        _ => false,
+    }) else {
+        return "";
+    };
+    &w[..]
+}
+
+fn sum_nums(tokens: &[Token]) -> u32 {
+    let mut sum = 0;
+    for t in tokens {
+        let Token::Num(n) = *t else { continue };
+        sum += n;
+    }
+    sum
+}
+
+fn main() {
+    assert_eq!(parse_num("21"), Ok(42));
+    assert!(parse_num("x").is_err());
+
+    let tokens = vec![Token::Num(1), Token::Word("hi".to_string()), Token::Num(2)];
+    assert_eq!(first_word(&tokens), "hi");
+    assert_eq!(first_word(&tokens[..1]), "");
+    assert_eq!(sum_nums(&tokens), 3);
+
+    // Several bindings, a type annotation and a mutable binding.
+    let (Some(a), mut b): (Option<u8>, u8) = (Some(1), 2) else { panic!() };
+    b += a;
+    assert_eq!(b, 3);
+
+    // Nested in a block whose value is used.
+    let v = {
+        let Some(x) = Some(10) else { unreachable!() };
+        let Some(y) = Some(x + 1) else { unreachable!() };
+        x + y
+    };
+    assert_eq!(v, 21);
+
+    let mut n = 0;
+    loop {
+        let 0...4 = n else { break };
+        n += 1;
+    }
+    assert_eq!(n, 5);
+
+    // The temporaries of the initializer are dropped at the end of the
+    // statement, not kept alive for the rest of the block.
+    let cell = RefCell::new(Some(1));
+    let Some(c) = *cell.borrow_mut() else { panic!() };
+    *cell.borrow_mut() = None;
+    assert_eq!(c, 1);
+    let mutex = Mutex::new(Some(2));
+    let Some(m) = *mutex.lock().unwrap() else { panic!() };
+    *mutex.lock().unwrap() = None;
+    assert_eq!(m, 2);
+
+    // Binding by reference into a place.
+    let opt = Some("hello".to_string());
+    let Some(ref s) = opt else { panic!() };
+    assert_eq!(s, "hello");
+    assert!(opt.is_some());
+
+    // The initializer is coerced to the annotated type.
+    let arr = [1u8, 2];
+    let &[first, second]: &[u8] = &arr else { panic!() };
+    assert_eq!(first + second, 3);
+}
@@ -0,0 +1,106 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(or_patterns, pattern_parentheses)]
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum E {
+    A(u8),
+    B(u8),
+    C,
+}
+
+struct S {
+    e: E,
+    n: u32,
+}
+
+fn classify(x: Option<u8>) -> u8 {
+    match x {
+        Some(0 | 1) => 0,
+        Some(2 | 3 | 4) => 1,
+        Some(_) => 2,
+        None => 3,
+    }
+}
+
+fn inner((E::A(x) | E::B(x), y): (E, u8)) -> u8 {
+    x + y
+}
+
+fn param((E::A(x) | E::B(x)): E) -> u8 {
+    x
+}
+
+fn bits(x: (u8, u8, u8, u8)) -> bool {
+    match x {
+        (0 | 1, 0 | 1, 0 | 1, 0 | 1) => true,
+        _ => false,
+    }
+}
+
+fn main() {
+    assert_eq!(classify(Some(0)), 0);
+    assert_eq!(classify(Some(1)), 0);
+    assert_eq!(classify(Some(3)), 1);
+    assert_eq!(classify(Some(5)), 2);
+    assert_eq!(classify(None), 3);
+
+    // Bindings are shared between the alternatives.
+    let v = [E::A(1), E::B(2), E::C];
+    let mut sum = 0;
+    for e in v.iter() {
+        if let Some(E::A(x) | E::B(x)) = Some(*e) {
+            sum += x;
+        }
+    }
+    assert_eq!(sum, 3);
+
+    let r: Result<u32, u32> = Err(7);
+    let (Ok(x) | Err(x)) = r;
+    assert_eq!(x, 7);
+
+    let Ok(x) | Err(x) = r;
+    assert_eq!(x, 7);
+
+    assert_eq!(inner((E::B(4), 1)), 5);
+    assert_eq!(param(E::A(6)), 6);
+    let closure = |(E::A(x) | E::B(x)): E| x;
+    assert_eq!(closure(E::B(8)), 8);
+
+    assert!(bits((0, 1, 1, 0)));
+    assert!(!bits((0, 1, 2, 0)));
+
+    let n = match (E::B(2), 5) {
+        (E::A(x) | E::B(x), 4 | 5) if x > 2 => x,
+        (E::A(x) | E::B(x), 4 | 5) => x + 10,
+        _ => 0,
+    };
+    assert_eq!(n, 12);
+
+    let s = S { e: E::C, n: 3 };
+    let n = match s {
+        S { e: E::A(_) | E::C, n: 1 | 3 } => 1,
+        S { .. } => 0,
+    };
+    assert_eq!(n, 1);
+
+    let n = match [1, 2, 3] {
+        [1 | 2, x, 3 | 4] => x,
+        _ => 0,
+    };
+    assert_eq!(n, 2);
+
+    let n = match (Some(E::B(9)), true) {
+        (Some(E::A(n) | E::B(n)), true | false) => n,
+        (None, _) | (Some(E::C), _) => 0,
+    };
+    assert_eq!(n, 9);
+}
@@ -0,0 +1,72 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// ignore-wasm32-bare compiled with panic=abort by default
+
+#![feature(track_caller)]
+
+use std::panic::{self, Location};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static PANIC_LINE: AtomicUsize = AtomicUsize::new(0);
+
+#[track_caller]
+fn caller_location() -> Location<'static> {
+    Location::caller()
+}
+
+#[track_caller]
+fn nested_caller_location() -> Location<'static> {
+    caller_location()
+}
+
+#[track_caller]
+fn check_positive(x: i32) {
+    if x <= 0 {
+        panic!("{} is not positive", x);
+    }
+}
+
+fn panic_line<F: FnOnce() + panic::UnwindSafe>(f: F) -> usize {
+    assert!(panic::catch_unwind(f).is_err());
+    PANIC_LINE.load(Ordering::SeqCst)
+}
+
+fn main() {
+    let location = caller_location();
+    assert_eq!(location.file(), file!());
+    assert_eq!(location.line(), line!() - 2);
+    assert_eq!(location.column(), 20);
+
+    let location = nested_caller_location();
+    assert_eq!(location.line(), line!() - 1);
+
+    // Through a fn pointer, the location is the one of the reifying shim.
+    let f: fn() -> Location<'static> = caller_location;
+    assert_eq!(f().file(), file!());
+
+    panic::set_hook(Box::new(|info| {
+        let line = info.location().unwrap().line();
+        PANIC_LINE.store(line as usize, Ordering::SeqCst);
+    }));
+
+    let line = panic_line(|| check_positive(-1));
+    assert_eq!(line, line!() - 1);
+
+    let line = panic_line(|| { None::<i32>.unwrap(); });
+    assert_eq!(line, line!() - 1);
+
+    let line = panic_line(|| { Err::<i32, ()>(()).expect("an error"); });
+    assert_eq!(line, line!() - 1);
+
+    let v = vec![1, 2, 3];
+    let line = panic_line(|| { v[10]; });
+    assert_eq!(line, line!() - 1);
+}
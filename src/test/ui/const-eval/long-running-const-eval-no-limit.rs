@@ -0,0 +1,32 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-pass
+
+// With no limit, the lint doesn't say when evaluation is aborted.
+
+#![feature(const_fn, const_control_flow, const_eval_limit)]
+#![const_eval_warn_limit = "100"]
+#![const_eval_limit = "0"]
+
+const fn spin(n: u32) -> u32 {
+    let mut i = 0;
+    while i < n {
+        i += 1;
+    }
+    i
+}
+
+pub const SHORT: u32 = spin(5);
+
+pub const LONG: u32 = spin(1000);
+//~^ WARN constant evaluation took more than 100 steps
+
+fn main() {}
@@ -0,0 +1,48 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(derive_default_enum)]
+
+#[derive(Default)] //~ ERROR no default declared
+enum NoDefault {
+    A,
+}
+
+#[derive(Default)] //~ ERROR multiple declared defaults
+enum MultipleDefaults {
+    #[default]
+    A,
+    #[default]
+    B,
+}
+
+#[derive(Default)]
+enum NonUnitDefault {
+    #[default]
+    A(u8), //~ ERROR the `#[default]` attribute may only be used on unit enum variants
+}
+
+#[derive(Default)]
+struct Field {
+    #[default] //~ ERROR the `#[default]` attribute may only be used on enum variants
+    a: u8,
+}
+
+#[derive(Default)]
+enum FieldInVariant {
+    #[default]
+    A,
+    B {
+        #[default] //~ ERROR the `#[default]` attribute may only be used on enum variants
+        b: u8,
+    },
+}
+
+fn main() {}
@@ -0,0 +1,60 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(exhaustive_integer_patterns)]
+#![feature(exclusive_range_pattern)]
+#![deny(unreachable_patterns)]
+
+fn main() {
+    let x: u8 = 0;
+
+    // Ranges that together cover every value need no `_` arm.
+    match x {
+        0..=127 => {}
+        128..=255 => {}
+    }
+
+    match x { //~ ERROR non-exhaustive patterns: `200..=255` not covered
+        0..=199 => {}
+    }
+
+    match x { //~ ERROR non-exhaustive patterns: `0`, `11..=99` and `201..=255` not covered
+        1..=10 => {}
+        100..=200 => {}
+    }
+
+    let y: i8 = 0;
+    match y { //~ ERROR non-exhaustive patterns: `-128..=-1` and `101..=127` not covered
+        0..=100 => {}
+    }
+
+    let c = 'a';
+    match c { //~ ERROR non-exhaustive patterns: `'\u{10ffff}'` not covered
+        '\u{0}'..='\u{D7FF}' => {}
+        '\u{E000}'..='\u{10FFFE}' => {}
+    }
+
+    match x {
+        0..=100 => {}
+        50..=255 => {} //~ WARN multiple patterns covering the same range
+    }
+
+    match x {
+        0..=100 => {}
+        101..=255 => {}
+        150 => {} //~ ERROR unreachable pattern
+    }
+
+    match x {
+        0..128 => {}
+        128..=255 => {}
+        _ => {} //~ ERROR unreachable pattern
+    }
+}
@@ -0,0 +1,25 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Without `capture_disjoint_fields`, a closure captures the whole variable
+// even if it only uses one of its fields.
+
+struct Point {
+    x: Vec<u8>,
+    y: Vec<u8>,
+}
+
+fn main() {
+    let mut p = Point { x: vec![], y: vec![] };
+    let x = &mut p.x;
+    let c = || p.y.len(); //~ ERROR cannot borrow `p` as immutable
+    x.push(1);
+    c();
+}
@@ -0,0 +1,24 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// run-pass
+// ignore-wasm32-bare compiled with panic=abort by default
+
+// Without `#![feature(generic_assert)]`, a failed `assert!` only reports the
+// asserted expression.
+
+use std::panic;
+
+fn main() {
+    panic::set_hook(Box::new(|_| {}));
+    let (a, b) = (1, 2);
+    let err = panic::catch_unwind(|| assert!(a == b)).unwrap_err();
+    assert_eq!(*err.downcast::<&str>().unwrap(), "assertion failed: a == b");
+}
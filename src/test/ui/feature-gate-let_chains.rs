@@ -0,0 +1,16 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+fn main() {
+    let a = Some(5);
+    if let Some(_) = a && let Some(_) = Some(3) {} //~ ERROR `let` chains are unstable
+    if a.is_some() && let Some(_) = a {} //~ ERROR `let` chains are unstable
+    while false && let Some(_) = a {} //~ ERROR `let` chains are unstable
+}
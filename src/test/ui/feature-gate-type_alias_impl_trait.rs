@@ -0,0 +1,27 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::fmt::Debug;
+
+type Foo = impl Debug; //~ ERROR `impl Trait` in type aliases is unstable
+
+struct Bar;
+
+impl Iterator for Bar {
+    type Item = impl Debug; //~ ERROR `impl Trait` in associated types is unstable
+
+    fn next(&mut self) -> Option<Self::Item> {
+        None::<u8>
+    }
+}
+
+fn define() -> Foo { 42 }
+
+fn main() {}
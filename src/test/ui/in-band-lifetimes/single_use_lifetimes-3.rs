@@ -7,13 +7,16 @@
 // <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
+// compile-pass
+
 #![deny(single_use_lifetime)]
-struct Foo<'x> { //~ ERROR lifetime name `'x` only used once
-    x: &'x u32 // no warning!
+#![allow(dead_code)]
+struct Foo<'x> {
+    x: &'x u32
 }
 
-// Once #44524 is fixed, this should issue a warning.
-impl<'y> Foo<'y> { //~ ERROR lifetime name `'y` only used once
+// FIXME(#44524): this should warn once lifetimes can be elided in impl headers.
+impl<'y> Foo<'y> {
     fn method() { }
 }
 
@@ -7,13 +7,16 @@
 // <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
+// compile-pass
+
 #![deny(single_use_lifetime)]
- // Neither should issue a warning, as explicit lifetimes are mandatory in this case
-struct Foo<'x> { //~ ERROR lifetime name `'x` only used once
+#![allow(dead_code)]
+// Neither should issue a warning, as explicit lifetimes are mandatory in this case
+struct Foo<'x> {
     x: &'x u32
 }
 
-enum Bar<'x> { //~ ERROR lifetime name `'x` only used once
+enum Bar<'x> {
     Variant(&'x u32)
 }
 
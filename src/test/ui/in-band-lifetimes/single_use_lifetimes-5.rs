@@ -7,9 +7,11 @@
 // <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
+// compile-pass
+
 #![deny(single_use_lifetime)]
-// Should not issue a warning, as explicit lifetimes are mandatory in this case:
-trait Foo<'x> { //~ ERROR lifetime name `'x` only used once
+// Should not issue a warning, as explicit lifetimes are mandatory in this case.
+trait Foo<'x> {
     fn foo(&self, arg: &'x u32);
 }
 
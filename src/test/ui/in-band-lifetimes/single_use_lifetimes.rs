@@ -9,7 +9,7 @@
 // except according to those terms.
 #![deny(single_use_lifetime)]
 
-fn deref<'x>(v: &'x u32) -> u32 { //~ ERROR lifetime name `'x` only used once
+fn deref<'x>(v: &'x u32) -> u32 { //~ ERROR lifetime parameter `'x` only used once
     *v
 }
 
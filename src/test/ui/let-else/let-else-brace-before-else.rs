@@ -0,0 +1,25 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+
+#![feature(let_else)]
+
+fn f(c: bool, z: u8) -> Option<u8> {
+    let Some(x) = if c { Some(1) } else { None } else { return None };
+    //~^ ERROR right curly brace `}` before `else` in a `let...else` statement not allowed
+    let Some(y) = match z { 0 => None, _ => Some(z) } else { return None };
+    //~^ ERROR right curly brace `}` before `else` in a `let...else` statement not allowed
+    let Some(w) = (match z { 0 => None, _ => Some(z) }) else { return None };
+    Some(x + y + w)
+}
+
+fn main() {
+    f(true, 1);
+}
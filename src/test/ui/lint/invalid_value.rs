@@ -0,0 +1,34 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// This test checks that calling `mem::{uninitialized,zeroed}` with certain
+// types results in a lint.
+
+#![deny(invalid_value)]
+#![allow(dead_code)]
+
+use std::mem;
+use std::ptr::NonNull;
+
+struct Wrap<T> { wrapped: T }
+
+fn main() {
+    unsafe {
+        let _val: &'static i32 = mem::zeroed(); //~ ERROR does not permit zero-initialization
+        let _val: bool = mem::uninitialized(); //~ ERROR does not permit being left uninit
+        let _val: Wrap<fn()> = mem::zeroed(); //~ ERROR does not permit zero-initialization
+        let _val: NonNull<i32> = mem::zeroed(); //~ ERROR does not permit zero-initialization
+
+        // These are fine.
+        let _val: Option<&'static i32> = mem::zeroed();
+        let _val: u32 = mem::zeroed();
+        let _val: bool = mem::zeroed();
+    }
+}
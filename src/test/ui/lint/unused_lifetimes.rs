@@ -0,0 +1,27 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![deny(unused_lifetimes)]
+#![allow(dead_code, unused_variables)]
+
+fn foo<'a>() {} //~ ERROR lifetime parameter `'a` never used
+
+fn bar<'a, 'b>(x: &'b u8) -> &'b u8 { x } //~ ERROR lifetime parameter `'a` never used
+
+struct S;
+
+impl<'a> S { //~ ERROR lifetime parameter `'a` never used
+    // Lifetimes used in the body of a method count as uses.
+    fn baz<'x>(&self, x: &u8) {
+        let y: &'x u8 = &0;
+    }
+}
+
+fn main() {}
@@ -0,0 +1,34 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// compile-flags: -Z print-type-layouts
+// compile-pass
+
+// This file illustrates the JSON output of `-Z print-type-layouts`: fields
+// are listed in memory order along with the padding between them, and the
+// niche of `S` (the unused values of its `bool`) is where `E` stores its tag.
+
+#![feature(start)]
+#![allow(dead_code)]
+
+struct S {
+    a: bool,
+    b: u16,
+}
+
+enum E {
+    A(S),
+    B,
+}
+
+#[start]
+fn start(_: isize, _: *const *const u8) -> isize {
+    0
+}
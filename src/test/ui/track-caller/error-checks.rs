@@ -0,0 +1,28 @@
+// Copyright 2018 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![feature(track_caller, naked_functions)]
+
+#[track_caller] //~ ERROR attribute should be applied to a function
+struct S;
+
+#[track_caller] //~ ERROR #[track_caller] requires Rust ABI
+extern "C" fn f() {}
+
+#[track_caller] //~ ERROR cannot use #[track_caller] with #[naked]
+#[naked]
+fn g() {}
+
+trait T {
+    #[track_caller] //~ ERROR #[track_caller] is not supported on trait methods
+    fn h();
+}
+
+fn main() {}
@@ -242,6 +242,8 @@ pub struct Config {
     pub linker: Option<String>,
     pub llvm_components: String,
     pub llvm_cxxflags: String,
+    /// The directory of the LLVM tools, such as `llvm-profdata` and `llvm-cov`
+    pub llvm_bin_dir: Option<PathBuf>,
     pub nodejs: Option<String>,
 }
 
@@ -2462,6 +2462,10 @@ impl<'test> TestCx<'test> {
             cmd.env("NODE", node);
         }
 
+        if let Some(ref llvm_bin_dir) = self.config.llvm_bin_dir {
+            cmd.env("LLVM_BIN_DIR", llvm_bin_dir);
+        }
+
         if let Some(ref linker) = self.config.linker {
             cmd.env("RUSTC_LINKER", linker);
         }